@@ -1,7 +1,7 @@
 /// Zero-setup machine learning inference for Flutter applications.
 ///
 /// This library provides a unified API for running ML models across different engines:
-/// - **Candle**: PyTorch models (.safetensors, .pt, .pth)
+/// - **Candle**: PyTorch models (.safetensors, .pt, .pth) and quantized GGUF models (.gguf)
 /// - **Linfa**: Classical ML with on-device training
 ///
 /// ## Quick Start
@@ -57,9 +57,12 @@ export 'src/rust/api/inference.dart'
     show
         SessionConfig,
         getAvailableEngines,
+        listDevices,
         isEngineAvailable,
         detectEngineFromPath,
         detectEngineFromBytes;
+export 'src/rust/models/device.dart';
+export 'src/rust/models/session.dart' show LoadMode;
 export 'src/rust/models/tensor.dart';
 export 'src/rust/frb_generated.dart' show RustLib;
 
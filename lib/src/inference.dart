@@ -29,7 +29,7 @@ class Inference {
   /// Load a model with explicit Candle engine
   ///
   /// Forces the use of the Candle engine for PyTorch models.
-  /// Supports .safetensors, .pt, and .pth files.
+  /// Supports .safetensors, .pt, .pth and .gguf files.
   ///
   /// Example:
   /// ```dart
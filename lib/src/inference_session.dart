@@ -6,6 +6,7 @@ import 'inference_result.dart';
 import 'engines/candle_session.dart';
 import 'engines/linfa_session.dart';
 import 'rust/api/inference.dart' as rust_api;
+import 'rust/models/device.dart';
 import 'rust/models/tensor.dart';
 import 'exceptions/inference_exceptions.dart';
 
@@ -57,7 +58,7 @@ abstract class InferenceSession {
   /// Load a model with explicit Candle engine
   ///
   /// Forces the use of the Candle engine for PyTorch models.
-  /// Supports .safetensors, .pt, and .pth files.
+  /// Supports .safetensors, .pt, .pth and .gguf files.
   ///
   /// Example:
   /// ```dart
@@ -73,6 +74,42 @@ abstract class InferenceSession {
     }
   }
 
+  /// Load a model with an explicit session configuration
+  ///
+  /// Use this to choose the device, fallback chain or weight conversion.
+  /// A requested device that is unavailable falls back to the CPU, with the
+  /// reason in `SessionInfo.deviceFallbackReason`.
+  ///
+  /// Example:
+  /// ```dart
+  /// final gpu = InferenceSession.listDevices().firstWhere(
+  ///   (info) => info.available && info.device is DeviceSelection_Cuda,
+  /// );
+  /// final model = await InferenceSession.loadWithConfig(
+  ///   'assets/model.safetensors',
+  ///   SessionConfig(
+  ///     gpuAcceleration: true,
+  ///     device: gpu.device,
+  ///     fallbackChain: ['candle@cpu'],
+  ///     loadMode: LoadMode.eager,
+  ///   ),
+  /// );
+  /// ```
+  static Future<InferenceSession> loadWithConfig(
+    String modelPath,
+    rust_api.SessionConfig config,
+  ) async {
+    try {
+      final sessionInfo = await rust_api.loadModelWithConfig(
+        modelPath: modelPath,
+        config: config,
+      );
+      return _createSessionFromInfo(sessionInfo);
+    } catch (e) {
+      throw ModelLoadException('Failed to load model: $e');
+    }
+  }
+
   /// Load a model from bytes with automatic engine detection
   ///
   /// This method automatically detects the appropriate engine based on
@@ -99,7 +136,7 @@ abstract class InferenceSession {
   /// Load a model from bytes with explicit Candle engine
   ///
   /// Forces the use of the Candle engine for PyTorch models.
-  /// Supports .safetensors, .pt, and .pth files.
+  /// Supports .safetensors, .pt, .pth and .gguf files.
   ///
   /// Example:
   /// ```dart
@@ -113,8 +150,11 @@ abstract class InferenceSession {
       final config = rust_api.SessionConfig(
         engineType: 'candle',
         gpuAcceleration: defaultConfig.gpuAcceleration,
+        device: defaultConfig.device,
+        fallbackChain: defaultConfig.fallbackChain,
         numThreads: defaultConfig.numThreads,
         optimizationLevel: defaultConfig.optimizationLevel,
+        loadMode: defaultConfig.loadMode,
       );
       final sessionInfo = await rust_api.loadModelFromBytes(
         modelBytes: modelBytes,
@@ -243,6 +283,20 @@ abstract class InferenceSession {
     }
   }
 
+  /// List compute devices with their capabilities
+  ///
+  /// Unavailable devices are included with the reason they can't be used.
+  ///
+  /// Example:
+  /// ```dart
+  /// for (final info in InferenceSession.listDevices()) {
+  ///   print('${info.name}: ${info.available ? 'ready' : info.unavailableReason}');
+  /// }
+  /// ```
+  static List<DeviceInfo> listDevices() {
+    return rust_api.listDevices();
+  }
+
   /// Train a Linfa model on-device
   ///
   /// Creates a new model using classical ML algorithms from the Linfa library.
@@ -303,6 +357,40 @@ abstract class InferenceSession {
     }
   }
 
+  /// Make a prediction from inputs matched to the model's inputs by name
+  ///
+  /// Use this for models with several inputs, such as ONNX graphs or BERT
+  /// with an attention mask. Returns every model output by name.
+  ///
+  /// Example:
+  /// ```dart
+  /// final outputs = await model.predictNamed({
+  ///   'input_ids': ids,
+  ///   'attention_mask': mask,
+  /// });
+  /// ```
+  Future<Map<String, InferenceResult>> predictNamed(
+      Map<String, InferenceInput> inputs) async {
+    try {
+      final rustInputs = inputs.entries
+          .map((entry) => rust_api.NamedInput(
+                name: entry.key,
+                input: entry.value.toRustInput(),
+              ))
+          .toList();
+      final rustResults = await rust_api.predictNamed(
+        sessionHandle: sessionHandle,
+        inputs: rustInputs,
+      );
+      return {
+        for (final named in rustResults)
+          named.name: InferenceResult.fromRustResult(named.result),
+      };
+    } catch (e) {
+      throw PredictionException('Named prediction failed: $e');
+    }
+  }
+
   /// Make batch predictions
   ///
   /// Processes multiple inputs in a single call for better performance.
@@ -4,12 +4,18 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../models/device.dart';
 import '../models/error.dart';
+import '../models/optimization.dart';
+import '../models/scheduler.dart';
+import '../models/session.dart';
 import '../models/tensor.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'inference.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `create_session_info`, `download_model_progress_stream`, `download_model_with_progress_callback_fn`, `download_model_with_progress_callback`, `download_model_with_progress_tracking`, `download_model_with_progress`, `download_model`, `get_cache_dir`, `get_directory_size`, `get_hf_cache_dir`, `load_from_cache`, `parse_data_type`, `parse_engine_type`, `save_to_cache`, `url_to_cache_key`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored because they are not marked as `pub`: `cache_config`, `cached_model_path`, `create_predict_context`, `create_session_info`, `detect_repo_architecture`, `download_model_progress_stream`, `download_model_with_progress_callback_fn`, `download_model_with_progress_callback`, `download_model_with_progress_tracking`, `download_model_with_progress`, `download_model`, `engine_config`, `fall_back_session`, `fallback_backends`, `get_cache_dir`, `get_session`, `input_to_tensor`, `into_config`, `load_with_shard_progress`, `optimization_level`, `parse_data_type`, `parse_engine_type`, `replace_session`, `report_generation`, `run_blocking`, `save_to_cache`, `scheduler_config`, `session_builder`, `stream_generation`, `url_to_cache_key`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `default`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`
 
 /// Get current download progress for a repository
 Future<DownloadProgress?> getDownloadProgress({required String repo}) =>
@@ -56,6 +62,23 @@ Future<InferenceResult> predict(
     RustLib.instance.api
         .crateApiInferencePredict(sessionHandle: sessionHandle, input: input);
 
+/// Make a prediction that can be cancelled or bounded by a deadline
+Future<InferenceResult> predictWithOptions(
+        {required BigInt sessionHandle,
+        required InferenceInput input,
+        required PredictOptions options}) =>
+    RustLib.instance.api.crateApiInferencePredictWithOptions(
+        sessionHandle: sessionHandle, input: input, options: options);
+
+/// Make a prediction from inputs matched to the model's input specs by name
+/// 
+/// Returns every model output. Each input is converted to its `data_type`,
+/// so token ids and attention masks can be passed as "i64".
+Future<List<NamedResult>> predictNamed(
+        {required BigInt sessionHandle, required List<NamedInput> inputs}) =>
+    RustLib.instance.api.crateApiInferencePredictNamed(
+        sessionHandle: sessionHandle, inputs: inputs);
+
 /// Make batch predictions
 Future<List<InferenceResult>> predictBatch(
         {required BigInt sessionHandle,
@@ -63,20 +86,160 @@ Future<List<InferenceResult>> predictBatch(
     RustLib.instance.api.crateApiInferencePredictBatch(
         sessionHandle: sessionHandle, inputs: inputs);
 
+/// Make batch predictions that can be cancelled or bounded by a deadline
+/// 
+/// Cancellation and deadlines are checked between batch items.
+Future<List<InferenceResult>> predictBatchWithOptions(
+        {required BigInt sessionHandle,
+        required List<InferenceInput> inputs,
+        required PredictOptions options}) =>
+    RustLib.instance.api.crateApiInferencePredictBatchWithOptions(
+        sessionHandle: sessionHandle, inputs: inputs, options: options);
+
+/// Transcribe mono PCM audio with a Whisper session
+/// 
+/// Samples are resampled to 16 kHz when needed. The spoken language is
+/// detected automatically and segments carry timestamps.
+Future<TranscriptionResult> transcribe(
+        {required BigInt sessionHandle,
+        required List<double> samples,
+        required int sampleRate}) =>
+    RustLib.instance.api.crateApiInferenceTranscribe(
+        sessionHandle: sessionHandle, samples: samples, sampleRate: sampleRate);
+
+/// Stream a text completion from a Llama or Mistral session token by token
+/// 
+/// Each token is sent as a [`GenerationEvent::Token`], followed by a single
+/// [`GenerationEvent::Finished`]. Closing the stream on the Dart side cancels
+/// generation before the next token.
+Stream<GenerationEvent> generateStream(
+        {required BigInt sessionHandle,
+        required String prompt,
+        required GenerationOptions generation,
+        required PredictOptions options}) =>
+    RustLib.instance.api.crateApiInferenceGenerateStream(
+        sessionHandle: sessionHandle,
+        prompt: prompt,
+        generation: generation,
+        options: options);
+
+/// Render chat messages with the session model's chat template
+Future<String> applyChatTemplate(
+        {required BigInt sessionHandle,
+        required List<ChatMessage> messages,
+        required bool addGenerationPrompt}) =>
+    RustLib.instance.api.crateApiInferenceApplyChatTemplate(
+        sessionHandle: sessionHandle,
+        messages: messages,
+        addGenerationPrompt: addGenerationPrompt);
+
+/// Stream the assistant's reply to a list of chat messages
+/// 
+/// The messages are rendered with the model's chat template; events are the
+/// same as for [`generate_stream`]. Nothing is kept between calls, use a
+/// conversation to reuse the KV cache across turns.
+Stream<GenerationEvent> chatStream(
+        {required BigInt sessionHandle,
+        required List<ChatMessage> messages,
+        required GenerationOptions generation,
+        required PredictOptions options}) =>
+    RustLib.instance.api.crateApiInferenceChatStream(
+        sessionHandle: sessionHandle,
+        messages: messages,
+        generation: generation,
+        options: options);
+
+/// Start a multi-turn conversation with a Llama or Mistral session
+/// 
+/// Uses the model's chat template unless `chat_template` (Jinja source) is
+/// given. The conversation keeps the model's KV cache between turns, so each
+/// message only processes the new tokens.
+Future<BigInt> createConversation(
+        {required BigInt sessionHandle,
+        String? systemPrompt,
+        String? chatTemplate}) =>
+    RustLib.instance.api.crateApiInferenceCreateConversation(
+        sessionHandle: sessionHandle,
+        systemPrompt: systemPrompt,
+        chatTemplate: chatTemplate);
+
+/// Send a user message to a conversation and stream the reply
+/// 
+/// Events are the same as for [`generate_stream`]. The reply is added to the
+/// conversation once generation finishes; if it fails, the message is dropped.
+Stream<GenerationEvent> sendMessageStream(
+        {required BigInt conversation,
+        required String content,
+        required GenerationOptions generation,
+        required PredictOptions options}) =>
+    RustLib.instance.api.crateApiInferenceSendMessageStream(
+        conversation: conversation,
+        content: content,
+        generation: generation,
+        options: options);
+
+/// Get the messages exchanged in a conversation so far
+Future<List<ChatMessage>> getConversationMessages(
+        {required BigInt conversation}) =>
+    RustLib.instance.api
+        .crateApiInferenceGetConversationMessages(conversation: conversation);
+
+/// Dispose a conversation and free its history
+Future<void> disposeConversation({required BigInt conversation}) =>
+    RustLib.instance.api
+        .crateApiInferenceDisposeConversation(conversation: conversation);
+
+/// Create a cancellation token for use with `predict_with_options`
+Future<BigInt> createCancellationToken() =>
+    RustLib.instance.api.crateApiInferenceCreateCancellationToken();
+
+/// Cancel every prediction using the given token
+Future<void> cancelPrediction({required BigInt token}) =>
+    RustLib.instance.api.crateApiInferenceCancelPrediction(token: token);
+
+/// Release a cancellation token once it is no longer needed
+Future<void> releaseCancellationToken({required BigInt token}) =>
+    RustLib.instance.api
+        .crateApiInferenceReleaseCancellationToken(token: token);
+
 /// Get session information
 Future<SessionInfo> getSessionInfo({required BigInt sessionHandle}) =>
     RustLib.instance.api
         .crateApiInferenceGetSessionInfo(sessionHandle: sessionHandle);
 
+/// Get request statistics for a session
+Future<SessionStats> getSessionStats({required BigInt sessionHandle}) =>
+    RustLib.instance.api
+        .crateApiInferenceGetSessionStats(sessionHandle: sessionHandle);
+
+/// Reload a session's model from its original file
+/// 
+/// The session keeps its configuration: the device, weight conversion and
+/// load mode are applied again, so lazy sessions defer the reload to their
+/// next prediction and warmed-up sessions are warmed up again. Cached
+/// predictions are invalidated. The previous model keeps serving requests
+/// until the new one has finished loading.
+Future<SessionInfo> reloadSession({required BigInt sessionHandle}) =>
+    RustLib.instance.api
+        .crateApiInferenceReloadSession(sessionHandle: sessionHandle);
+
 /// Dispose of a session and free resources
 Future<void> disposeSession({required BigInt sessionHandle}) =>
     RustLib.instance.api
         .crateApiInferenceDisposeSession(sessionHandle: sessionHandle);
 
 /// Get list of available engines
+/// 
+/// Includes engines registered through the `EngineRegistry`.
 List<String> getAvailableEngines() =>
     RustLib.instance.api.crateApiInferenceGetAvailableEngines();
 
+/// List compute devices with their capabilities
+/// 
+/// Unavailable devices are included with the reason they can't be used.
+List<DeviceInfo> listDevices() =>
+    RustLib.instance.api.crateApiInferenceListDevices();
+
 /// Check if a specific engine is available
 bool isEngineAvailable({required String engineType}) => RustLib.instance.api
     .crateApiInferenceIsEngineAvailable(engineType: engineType);
@@ -113,6 +276,36 @@ Future<void> clearCache() => RustLib.instance.api.crateApiInferenceClearCache();
 Future<BigInt> getCacheSize() =>
     RustLib.instance.api.crateApiInferenceGetCacheSize();
 
+/// One turn of a chat
+class ChatMessage {
+  final ChatRole role;
+  final String content;
+
+  const ChatMessage({
+    required this.role,
+    required this.content,
+  });
+
+  @override
+  int get hashCode => role.hashCode ^ content.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ChatMessage &&
+          runtimeType == other.runtimeType &&
+          role == other.role &&
+          content == other.content;
+}
+
+/// Author of a chat message
+enum ChatRole {
+  system,
+  user,
+  assistant,
+  ;
+}
+
 /// Different phases of the download process
 enum DownloadPhase {
   /// Connecting to server
@@ -180,6 +373,131 @@ class DownloadProgress {
           message == other.message;
 }
 
+@freezed
+sealed class GenerationEvent with _$GenerationEvent {
+  const GenerationEvent._();
+
+  /// A newly generated token
+  const factory GenerationEvent.token({
+    /// Text this token adds to the completion (empty until a split character is complete)
+    required String text,
+    required int tokenId,
+    required double logprob,
+  }) = GenerationEvent_Token;
+  /// Generation finished; always the last message
+  const factory GenerationEvent.finished({
+    /// Full completion
+    required String text,
+    required GenerationStopReason stopReason,
+    required BigInt promptTokens,
+    required BigInt generatedTokens,
+    /// Time from the call until the first token was produced
+    BigInt? timeToFirstTokenMs,
+    required BigInt totalTimeMs,
+  }) = GenerationEvent_Finished;
+}
+
+/// Decoding settings for text generation
+class GenerationOptions {
+  final BigInt maxNewTokens;
+
+  /// Softmax temperature; `0.0` decodes greedily
+  final double temperature;
+  final BigInt? topK;
+  final double? topP;
+  final double? minP;
+
+  /// `1.0` disables the repetition penalty
+  final double repetitionPenalty;
+
+  /// `0.0` disables the presence penalty
+  final double presencePenalty;
+
+  /// How many recent tokens the penalties look at
+  final BigInt penaltyLastN;
+
+  /// Seed for reproducible sampling
+  final BigInt? seed;
+  final List<String> stopSequences;
+
+  /// GBNF grammar the completion must follow
+  final String? grammar;
+
+  /// JSON schema (as JSON text) the completion must be valid against
+  final String? jsonSchema;
+
+  const GenerationOptions({
+    required this.maxNewTokens,
+    required this.temperature,
+    this.topK,
+    this.topP,
+    this.minP,
+    required this.repetitionPenalty,
+    required this.presencePenalty,
+    required this.penaltyLastN,
+    this.seed,
+    required this.stopSequences,
+    this.grammar,
+    this.jsonSchema,
+  });
+
+  static Future<GenerationOptions> default_() =>
+      RustLib.instance.api.crateApiInferenceGenerationOptionsDefault();
+
+  @override
+  int get hashCode =>
+      maxNewTokens.hashCode ^
+      temperature.hashCode ^
+      topK.hashCode ^
+      topP.hashCode ^
+      minP.hashCode ^
+      repetitionPenalty.hashCode ^
+      presencePenalty.hashCode ^
+      penaltyLastN.hashCode ^
+      seed.hashCode ^
+      stopSequences.hashCode ^
+      grammar.hashCode ^
+      jsonSchema.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is GenerationOptions &&
+          runtimeType == other.runtimeType &&
+          maxNewTokens == other.maxNewTokens &&
+          temperature == other.temperature &&
+          topK == other.topK &&
+          topP == other.topP &&
+          minP == other.minP &&
+          repetitionPenalty == other.repetitionPenalty &&
+          presencePenalty == other.presencePenalty &&
+          penaltyLastN == other.penaltyLastN &&
+          seed == other.seed &&
+          stopSequences == other.stopSequences &&
+          grammar == other.grammar &&
+          jsonSchema == other.jsonSchema;
+}
+
+/// Why a streamed generation stopped
+enum GenerationStopReason {
+  /// The model produced an end-of-sequence token
+  endOfSequence,
+
+  /// `max_new_tokens` tokens were generated
+  maxTokens,
+
+  /// The model's context window is full
+  contextFull,
+
+  /// The completion reached one of the stop sequences
+  stopSequence,
+
+  /// Generation ran out of tokens or context before the grammar was
+  /// complete, so the text does not parse
+  grammarIncomplete,
+  ;
+}
+
 /// Input data for inference
 class InferenceInput {
   final Float32List data;
@@ -230,18 +548,129 @@ class InferenceResult {
           dataType == other.dataType;
 }
 
+/// Input matched to a model input spec by name
+class NamedInput {
+  final String name;
+  final InferenceInput input;
+
+  const NamedInput({
+    required this.name,
+    required this.input,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ input.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NamedInput &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          input == other.input;
+}
+
+/// Model output named after its output spec
+class NamedResult {
+  final String name;
+  final InferenceResult result;
+
+  const NamedResult({
+    required this.name,
+    required this.result,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ result.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is NamedResult &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          result == other.result;
+}
+
+/// Per-call options for predictions
+class PredictOptions {
+  /// Token that can be cancelled with `cancel_prediction`
+  final BigInt? cancellationToken;
+
+  /// Maximum time the call may take before failing with `DeadlineExceeded`
+  final BigInt? timeoutMs;
+
+  /// Queue priority when the session is at its concurrency limit
+  final Priority priority;
+
+  const PredictOptions({
+    this.cancellationToken,
+    this.timeoutMs,
+    required this.priority,
+  });
+
+  @override
+  int get hashCode =>
+      cancellationToken.hashCode ^ timeoutMs.hashCode ^ priority.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PredictOptions &&
+          runtimeType == other.runtimeType &&
+          cancellationToken == other.cancellationToken &&
+          timeoutMs == other.timeoutMs &&
+          priority == other.priority;
+}
+
 /// Configuration for inference sessions
 class SessionConfig {
   final String? engineType;
   final bool gpuAcceleration;
+
+  /// Device to run on; `Auto` uses a GPU when `gpu_acceleration` is set and one is available
+  final DeviceSelection device;
+
+  /// Backends to try in order when loading or the first prediction fails,
+  /// written as `engine` or `engine@device` (e.g. "candle@cpu")
+  final List<String> fallbackChain;
   final BigInt? numThreads;
+
+  /// Load-time weight conversion for Candle models: "none", "f16", "bf16", "int8" or "q4"
+  /// 
+  /// Applies to generic SafeTensors/PyTorch and Llama models; other models fail to load with a level.
   final String? optimizationLevel;
+  final LoadMode loadMode;
+
+  /// Maximum number of concurrent predictions (unlimited when `None`)
+  final BigInt? maxConcurrency;
+
+  /// Maximum number of queued predictions before new ones are rejected (unlimited when `None`)
+  final BigInt? maxQueueDepth;
+
+  /// Maximum number of cached outputs; the cache is enabled when either cache limit is set
+  final BigInt? cacheMaxEntries;
+
+  /// Maximum total size of cached inputs and outputs in bytes
+  final BigInt? cacheMaxBytes;
+
+  /// Layer graph (JSON) for generic SafeTensors and PyTorch weights; takes
+  /// the place of a `*.graph.json` next to the weights
+  final String? modelGraph;
 
   const SessionConfig({
     this.engineType,
     required this.gpuAcceleration,
+    required this.device,
+    required this.fallbackChain,
     this.numThreads,
     this.optimizationLevel,
+    required this.loadMode,
+    this.maxConcurrency,
+    this.maxQueueDepth,
+    this.cacheMaxEntries,
+    this.cacheMaxBytes,
+    this.modelGraph,
   });
 
   static Future<SessionConfig> default_() =>
@@ -251,8 +680,16 @@ class SessionConfig {
   int get hashCode =>
       engineType.hashCode ^
       gpuAcceleration.hashCode ^
+      device.hashCode ^
+      fallbackChain.hashCode ^
       numThreads.hashCode ^
-      optimizationLevel.hashCode;
+      optimizationLevel.hashCode ^
+      loadMode.hashCode ^
+      maxConcurrency.hashCode ^
+      maxQueueDepth.hashCode ^
+      cacheMaxEntries.hashCode ^
+      cacheMaxBytes.hashCode ^
+      modelGraph.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -261,8 +698,16 @@ class SessionConfig {
           runtimeType == other.runtimeType &&
           engineType == other.engineType &&
           gpuAcceleration == other.gpuAcceleration &&
+          device == other.device &&
+          fallbackChain == other.fallbackChain &&
           numThreads == other.numThreads &&
-          optimizationLevel == other.optimizationLevel;
+          optimizationLevel == other.optimizationLevel &&
+          loadMode == other.loadMode &&
+          maxConcurrency == other.maxConcurrency &&
+          maxQueueDepth == other.maxQueueDepth &&
+          cacheMaxEntries == other.cacheMaxEntries &&
+          cacheMaxBytes == other.cacheMaxBytes &&
+          modelGraph == other.modelGraph;
 }
 
 /// Session information
@@ -272,11 +717,47 @@ class SessionInfo {
   final List<TensorSpec> inputSpecs;
   final List<TensorSpec> outputSpecs;
 
+  /// Whether the specs are still unknown because the model has not been
+  /// loaded yet (lazy sessions); they are empty until then
+  final bool specsPending;
+  final LoadMode loadMode;
+
+  /// Whether the model weights have been materialized
+  final bool isLoaded;
+
+  /// Time spent loading weights, once loaded
+  final BigInt? loadTimeMs;
+
+  /// Time spent on warm-up inputs, if a warm-up was run
+  final BigInt? warmupTimeMs;
+
+  /// Device the model runs on (e.g. "cpu", "cuda:0")
+  final String device;
+
+  /// Why the model runs on the CPU instead of the requested device
+  final String? deviceFallbackReason;
+
+  /// Why backends earlier in the fallback chain were abandoned, in order;
+  /// `engine_type` and `device` name the backend serving the session
+  final List<String> fallbackReasons;
+
+  /// How the weights were converted at load time, if an optimization level applied
+  final OptimizationReport? optimization;
+
   const SessionInfo({
     required this.handle,
     required this.engineType,
     required this.inputSpecs,
     required this.outputSpecs,
+    required this.specsPending,
+    required this.loadMode,
+    required this.isLoaded,
+    this.loadTimeMs,
+    this.warmupTimeMs,
+    required this.device,
+    this.deviceFallbackReason,
+    required this.fallbackReasons,
+    this.optimization,
   });
 
   @override
@@ -284,7 +765,16 @@ class SessionInfo {
       handle.hashCode ^
       engineType.hashCode ^
       inputSpecs.hashCode ^
-      outputSpecs.hashCode;
+      outputSpecs.hashCode ^
+      specsPending.hashCode ^
+      loadMode.hashCode ^
+      isLoaded.hashCode ^
+      loadTimeMs.hashCode ^
+      warmupTimeMs.hashCode ^
+      device.hashCode ^
+      deviceFallbackReason.hashCode ^
+      fallbackReasons.hashCode ^
+      optimization.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -294,5 +784,81 @@ class SessionInfo {
           handle == other.handle &&
           engineType == other.engineType &&
           inputSpecs == other.inputSpecs &&
-          outputSpecs == other.outputSpecs;
+          outputSpecs == other.outputSpecs &&
+          specsPending == other.specsPending &&
+          loadMode == other.loadMode &&
+          isLoaded == other.isLoaded &&
+          loadTimeMs == other.loadTimeMs &&
+          warmupTimeMs == other.warmupTimeMs &&
+          device == other.device &&
+          deviceFallbackReason == other.deviceFallbackReason &&
+          fallbackReasons == other.fallbackReasons &&
+          optimization == other.optimization;
+}
+
+/// A timed span of transcribed speech
+class TranscriptSegment {
+  final double startSeconds;
+  final double endSeconds;
+  final String text;
+
+  /// Mean log probability of the decoded tokens in the segment's window
+  final double avgLogprob;
+
+  /// Probability that the segment's window contains no speech
+  final double noSpeechProb;
+
+  const TranscriptSegment({
+    required this.startSeconds,
+    required this.endSeconds,
+    required this.text,
+    required this.avgLogprob,
+    required this.noSpeechProb,
+  });
+
+  @override
+  int get hashCode =>
+      startSeconds.hashCode ^
+      endSeconds.hashCode ^
+      text.hashCode ^
+      avgLogprob.hashCode ^
+      noSpeechProb.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TranscriptSegment &&
+          runtimeType == other.runtimeType &&
+          startSeconds == other.startSeconds &&
+          endSeconds == other.endSeconds &&
+          text == other.text &&
+          avgLogprob == other.avgLogprob &&
+          noSpeechProb == other.noSpeechProb;
+}
+
+/// Result from speech transcription
+class TranscriptionResult {
+  final String text;
+
+  /// Language code that was detected (`None` for English-only models)
+  final String? language;
+  final List<TranscriptSegment> segments;
+
+  const TranscriptionResult({
+    required this.text,
+    this.language,
+    required this.segments,
+  });
+
+  @override
+  int get hashCode => text.hashCode ^ language.hashCode ^ segments.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TranscriptionResult &&
+          runtimeType == other.runtimeType &&
+          text == other.text &&
+          language == other.language &&
+          segments == other.segments;
 }
@@ -0,0 +1,504 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'inference.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$GenerationEvent {
+  /// Text this token adds to the completion (empty until a split character is complete)
+  String get text;
+
+  /// Create a copy of GenerationEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $GenerationEventCopyWith<GenerationEvent> get copyWith =>
+      _$GenerationEventCopyWithImpl<GenerationEvent>(
+          this as GenerationEvent, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is GenerationEvent &&
+            (identical(other.text, text) || other.text == text));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, text);
+
+  @override
+  String toString() {
+    return 'GenerationEvent(text: $text)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $GenerationEventCopyWith<$Res> {
+  factory $GenerationEventCopyWith(
+          GenerationEvent value, $Res Function(GenerationEvent) _then) =
+      _$GenerationEventCopyWithImpl;
+  @useResult
+  $Res call({String text});
+}
+
+/// @nodoc
+class _$GenerationEventCopyWithImpl<$Res>
+    implements $GenerationEventCopyWith<$Res> {
+  _$GenerationEventCopyWithImpl(this._self, this._then);
+
+  final GenerationEvent _self;
+  final $Res Function(GenerationEvent) _then;
+
+  /// Create a copy of GenerationEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? text = null,
+  }) {
+    return _then(_self.copyWith(
+      text: null == text
+          ? _self.text
+          : text // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// Adds pattern-matching-related methods to [GenerationEvent].
+extension GenerationEventPatterns on GenerationEvent {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(GenerationEvent_Token value)? token,
+    TResult Function(GenerationEvent_Finished value)? finished,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case GenerationEvent_Token() when token != null:
+        return token(_that);
+      case GenerationEvent_Finished() when finished != null:
+        return finished(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(GenerationEvent_Token value) token,
+    required TResult Function(GenerationEvent_Finished value) finished,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case GenerationEvent_Token():
+        return token(_that);
+      case GenerationEvent_Finished():
+        return finished(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(GenerationEvent_Token value)? token,
+    TResult? Function(GenerationEvent_Finished value)? finished,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case GenerationEvent_Token() when token != null:
+        return token(_that);
+      case GenerationEvent_Finished() when finished != null:
+        return finished(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function(String text, int tokenId, double logprob)? token,
+    TResult Function(
+            String text,
+            GenerationStopReason stopReason,
+            BigInt promptTokens,
+            BigInt generatedTokens,
+            BigInt? timeToFirstTokenMs,
+            BigInt totalTimeMs)?
+        finished,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case GenerationEvent_Token() when token != null:
+        return token(_that.text, _that.tokenId, _that.logprob);
+      case GenerationEvent_Finished() when finished != null:
+        return finished(
+            _that.text,
+            _that.stopReason,
+            _that.promptTokens,
+            _that.generatedTokens,
+            _that.timeToFirstTokenMs,
+            _that.totalTimeMs);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function(String text, int tokenId, double logprob) token,
+    required TResult Function(
+            String text,
+            GenerationStopReason stopReason,
+            BigInt promptTokens,
+            BigInt generatedTokens,
+            BigInt? timeToFirstTokenMs,
+            BigInt totalTimeMs)
+        finished,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case GenerationEvent_Token():
+        return token(_that.text, _that.tokenId, _that.logprob);
+      case GenerationEvent_Finished():
+        return finished(
+            _that.text,
+            _that.stopReason,
+            _that.promptTokens,
+            _that.generatedTokens,
+            _that.timeToFirstTokenMs,
+            _that.totalTimeMs);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function(String text, int tokenId, double logprob)? token,
+    TResult? Function(
+            String text,
+            GenerationStopReason stopReason,
+            BigInt promptTokens,
+            BigInt generatedTokens,
+            BigInt? timeToFirstTokenMs,
+            BigInt totalTimeMs)?
+        finished,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case GenerationEvent_Token() when token != null:
+        return token(_that.text, _that.tokenId, _that.logprob);
+      case GenerationEvent_Finished() when finished != null:
+        return finished(
+            _that.text,
+            _that.stopReason,
+            _that.promptTokens,
+            _that.generatedTokens,
+            _that.timeToFirstTokenMs,
+            _that.totalTimeMs);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// A newly generated token
+
+class GenerationEvent_Token extends GenerationEvent {
+  const GenerationEvent_Token(
+      {required this.text, required this.tokenId, required this.logprob})
+      : super._();
+
+  /// Text this token adds to the completion (empty until a split character is complete)
+  @override
+  final String text;
+  final int tokenId;
+  final double logprob;
+
+  /// Create a copy of GenerationEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $GenerationEvent_TokenCopyWith<GenerationEvent_Token> get copyWith =>
+      _$GenerationEvent_TokenCopyWithImpl<GenerationEvent_Token>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is GenerationEvent_Token &&
+            (identical(other.text, text) || other.text == text) &&
+            (identical(other.tokenId, tokenId) || other.tokenId == tokenId) &&
+            (identical(other.logprob, logprob) || other.logprob == logprob));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, text, tokenId, logprob);
+
+  @override
+  String toString() {
+    return 'GenerationEvent.token(text: $text, tokenId: $tokenId, logprob: $logprob)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $GenerationEvent_TokenCopyWith<$Res>
+    implements $GenerationEventCopyWith<$Res> {
+  factory $GenerationEvent_TokenCopyWith(GenerationEvent_Token value,
+          $Res Function(GenerationEvent_Token) _then) =
+      _$GenerationEvent_TokenCopyWithImpl;
+  @override
+  @useResult
+  $Res call({String text, int tokenId, double logprob});
+}
+
+/// @nodoc
+class _$GenerationEvent_TokenCopyWithImpl<$Res>
+    implements $GenerationEvent_TokenCopyWith<$Res> {
+  _$GenerationEvent_TokenCopyWithImpl(this._self, this._then);
+
+  final GenerationEvent_Token _self;
+  final $Res Function(GenerationEvent_Token) _then;
+
+  /// Create a copy of GenerationEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? text = null,
+    Object? tokenId = null,
+    Object? logprob = null,
+  }) {
+    return _then(GenerationEvent_Token(
+      text: null == text
+          ? _self.text
+          : text // ignore: cast_nullable_to_non_nullable
+              as String,
+      tokenId: null == tokenId
+          ? _self.tokenId
+          : tokenId // ignore: cast_nullable_to_non_nullable
+              as int,
+      logprob: null == logprob
+          ? _self.logprob
+          : logprob // ignore: cast_nullable_to_non_nullable
+              as double,
+    ));
+  }
+}
+
+/// Generation finished; always the last message
+
+class GenerationEvent_Finished extends GenerationEvent {
+  const GenerationEvent_Finished(
+      {required this.text,
+      required this.stopReason,
+      required this.promptTokens,
+      required this.generatedTokens,
+      this.timeToFirstTokenMs,
+      required this.totalTimeMs})
+      : super._();
+
+  /// Full completion
+  @override
+  final String text;
+  final GenerationStopReason stopReason;
+  final BigInt promptTokens;
+  final BigInt generatedTokens;
+  /// Time from the call until the first token was produced
+  final BigInt? timeToFirstTokenMs;
+  final BigInt totalTimeMs;
+
+  /// Create a copy of GenerationEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $GenerationEvent_FinishedCopyWith<GenerationEvent_Finished> get copyWith =>
+      _$GenerationEvent_FinishedCopyWithImpl<GenerationEvent_Finished>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is GenerationEvent_Finished &&
+            (identical(other.text, text) || other.text == text) &&
+            (identical(other.stopReason, stopReason) ||
+                other.stopReason == stopReason) &&
+            (identical(other.promptTokens, promptTokens) ||
+                other.promptTokens == promptTokens) &&
+            (identical(other.generatedTokens, generatedTokens) ||
+                other.generatedTokens == generatedTokens) &&
+            (identical(other.timeToFirstTokenMs, timeToFirstTokenMs) ||
+                other.timeToFirstTokenMs == timeToFirstTokenMs) &&
+            (identical(other.totalTimeMs, totalTimeMs) ||
+                other.totalTimeMs == totalTimeMs));
+  }
+
+  @override
+  int get hashCode => Object.hash(
+      runtimeType,
+      text,
+      stopReason,
+      promptTokens,
+      generatedTokens,
+      timeToFirstTokenMs,
+      totalTimeMs);
+
+  @override
+  String toString() {
+    return 'GenerationEvent.finished(text: $text, stopReason: $stopReason, promptTokens: $promptTokens, generatedTokens: $generatedTokens, timeToFirstTokenMs: $timeToFirstTokenMs, totalTimeMs: $totalTimeMs)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $GenerationEvent_FinishedCopyWith<$Res>
+    implements $GenerationEventCopyWith<$Res> {
+  factory $GenerationEvent_FinishedCopyWith(GenerationEvent_Finished value,
+          $Res Function(GenerationEvent_Finished) _then) =
+      _$GenerationEvent_FinishedCopyWithImpl;
+  @override
+  @useResult
+  $Res call(
+      {String text,
+      GenerationStopReason stopReason,
+      BigInt promptTokens,
+      BigInt generatedTokens,
+      BigInt? timeToFirstTokenMs,
+      BigInt totalTimeMs});
+}
+
+/// @nodoc
+class _$GenerationEvent_FinishedCopyWithImpl<$Res>
+    implements $GenerationEvent_FinishedCopyWith<$Res> {
+  _$GenerationEvent_FinishedCopyWithImpl(this._self, this._then);
+
+  final GenerationEvent_Finished _self;
+  final $Res Function(GenerationEvent_Finished) _then;
+
+  /// Create a copy of GenerationEvent
+  /// with the given fields replaced by the non-null parameter values.
+  @override
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? text = null,
+    Object? stopReason = null,
+    Object? promptTokens = null,
+    Object? generatedTokens = null,
+    Object? timeToFirstTokenMs = freezed,
+    Object? totalTimeMs = null,
+  }) {
+    return _then(GenerationEvent_Finished(
+      text: null == text
+          ? _self.text
+          : text // ignore: cast_nullable_to_non_nullable
+              as String,
+      stopReason: null == stopReason
+          ? _self.stopReason
+          : stopReason // ignore: cast_nullable_to_non_nullable
+              as GenerationStopReason,
+      promptTokens: null == promptTokens
+          ? _self.promptTokens
+          : promptTokens // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      generatedTokens: null == generatedTokens
+          ? _self.generatedTokens
+          : generatedTokens // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+      timeToFirstTokenMs: freezed == timeToFirstTokenMs
+          ? _self.timeToFirstTokenMs
+          : timeToFirstTokenMs // ignore: cast_nullable_to_non_nullable
+              as BigInt?,
+      totalTimeMs: null == totalTimeMs
+          ? _self.totalTimeMs
+          : totalTimeMs // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+// dart format on
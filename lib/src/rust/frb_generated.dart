@@ -10,7 +10,11 @@ import 'dart:convert';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'models/device.dart';
 import 'models/error.dart';
+import 'models/optimization.dart';
+import 'models/scheduler.dart';
+import 'models/session.dart';
 import 'models/tensor.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
@@ -74,7 +78,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => 585097583;
+  int get rustContentHash => 1734381454;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -85,25 +89,62 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
+  Future<String> crateApiInferenceApplyChatTemplate(
+      {required BigInt sessionHandle,
+      required List<ChatMessage> messages,
+      required bool addGenerationPrompt});
+
+  Future<void> crateApiInferenceCancelPrediction({required BigInt token});
+
+  Stream<GenerationEvent> crateApiInferenceChatStream(
+      {required BigInt sessionHandle,
+      required List<ChatMessage> messages,
+      required GenerationOptions generation,
+      required PredictOptions options});
+
   Future<void> crateApiInferenceClearCache();
 
+  Future<BigInt> crateApiInferenceCreateCancellationToken();
+
+  Future<BigInt> crateApiInferenceCreateConversation(
+      {required BigInt sessionHandle,
+      String? systemPrompt,
+      String? chatTemplate});
+
   String crateApiInferenceDetectEngineFromBytes(
       {required List<int> modelBytes});
 
   String crateApiInferenceDetectEngineFromPath({required String modelPath});
 
+  Future<void> crateApiInferenceDisposeConversation(
+      {required BigInt conversation});
+
   Future<void> crateApiInferenceDisposeSession({required BigInt sessionHandle});
 
+  Stream<GenerationEvent> crateApiInferenceGenerateStream(
+      {required BigInt sessionHandle,
+      required String prompt,
+      required GenerationOptions generation,
+      required PredictOptions options});
+
+  Future<GenerationOptions> crateApiInferenceGenerationOptionsDefault();
+
   List<String> crateApiInferenceGetAvailableEngines();
 
   Future<BigInt> crateApiInferenceGetCacheSize();
 
+  Future<List<ChatMessage>> crateApiInferenceGetConversationMessages(
+      {required BigInt conversation});
+
   Future<DownloadProgress?> crateApiInferenceGetDownloadProgress(
       {required String repo});
 
   Future<SessionInfo> crateApiInferenceGetSessionInfo(
       {required BigInt sessionHandle});
 
+  Future<SessionStats> crateApiInferenceGetSessionStats(
+      {required BigInt sessionHandle});
+
   String crateApiSimpleGreet({required String name});
 
   Future<void> crateApiSimpleInitApp();
@@ -112,6 +153,8 @@ abstract class RustLibApi extends BaseApi {
 
   bool crateApiInferenceIsEngineAvailable({required String engineType});
 
+  List<DeviceInfo> crateApiInferenceListDevices();
+
   Future<SessionInfo> crateApiInferenceLoadFromHuggingface(
       {required String repo, String? revision, String? filename});
 
@@ -138,6 +181,31 @@ abstract class RustLibApi extends BaseApi {
   Future<List<InferenceResult>> crateApiInferencePredictBatch(
       {required BigInt sessionHandle, required List<InferenceInput> inputs});
 
+  Future<List<InferenceResult>> crateApiInferencePredictBatchWithOptions(
+      {required BigInt sessionHandle,
+      required List<InferenceInput> inputs,
+      required PredictOptions options});
+
+  Future<List<NamedResult>> crateApiInferencePredictNamed(
+      {required BigInt sessionHandle, required List<NamedInput> inputs});
+
+  Future<InferenceResult> crateApiInferencePredictWithOptions(
+      {required BigInt sessionHandle,
+      required InferenceInput input,
+      required PredictOptions options});
+
+  Future<void> crateApiInferenceReleaseCancellationToken(
+      {required BigInt token});
+
+  Future<SessionInfo> crateApiInferenceReloadSession(
+      {required BigInt sessionHandle});
+
+  Stream<GenerationEvent> crateApiInferenceSendMessageStream(
+      {required BigInt conversation,
+      required String content,
+      required GenerationOptions generation,
+      required PredictOptions options});
+
   Future<SessionConfig> crateApiInferenceSessionConfigDefault();
 
   Future<String> crateApiInferenceStartDownloadWithProgress(
@@ -147,6 +215,11 @@ abstract class RustLibApi extends BaseApi {
       {required List<Float64List> features,
       required String algorithm,
       required Map<String, String> params});
+
+  Future<TranscriptionResult> crateApiInferenceTranscribe(
+      {required BigInt sessionHandle,
+      required List<double> samples,
+      required int sampleRate});
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -158,13 +231,109 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Future<void> crateApiInferenceClearCache() {
+  Future<String> crateApiInferenceApplyChatTemplate(
+      {required BigInt sessionHandle,
+      required List<ChatMessage> messages,
+      required bool addGenerationPrompt}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(sessionHandle, serializer);
+        sse_encode_list_chat_message(messages, serializer);
+        sse_encode_bool(addGenerationPrompt, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
             funcId: 1, port: port_);
       },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceApplyChatTemplateConstMeta,
+      argValues: [sessionHandle, messages, addGenerationPrompt],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceApplyChatTemplateConstMeta =>
+      const TaskConstMeta(
+        debugName: "apply_chat_template",
+        argNames: ["sessionHandle", "messages", "addGenerationPrompt"],
+      );
+
+  @override
+  Future<void> crateApiInferenceCancelPrediction({required BigInt token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 2, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceCancelPredictionConstMeta,
+      argValues: [token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceCancelPredictionConstMeta =>
+      const TaskConstMeta(
+        debugName: "cancel_prediction",
+        argNames: ["token"],
+      );
+
+  @override
+  Stream<GenerationEvent> crateApiInferenceChatStream(
+      {required BigInt sessionHandle,
+      required List<ChatMessage> messages,
+      required GenerationOptions generation,
+      required PredictOptions options}) {
+    final sink = RustStreamSink<GenerationEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(sessionHandle, serializer);
+        sse_encode_list_chat_message(messages, serializer);
+        sse_encode_box_autoadd_generation_options(generation, serializer);
+        sse_encode_box_autoadd_predict_options(options, serializer);
+        sse_encode_StreamSink_generation_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 3, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceChatStreamConstMeta,
+      argValues: [sessionHandle, messages, generation, options, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiInferenceChatStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "chat_stream",
+        argNames: [
+          "sessionHandle",
+          "messages",
+          "generation",
+          "options",
+          "sink"
+        ],
+      );
+
+  @override
+  Future<void> crateApiInferenceClearCache() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 4, port: port_);
+      },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
         decodeErrorData: sse_decode_inference_error,
@@ -181,6 +350,60 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: [],
       );
 
+  @override
+  Future<BigInt> crateApiInferenceCreateCancellationToken() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 5, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiInferenceCreateCancellationTokenConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceCreateCancellationTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_cancellation_token",
+        argNames: [],
+      );
+
+  @override
+  Future<BigInt> crateApiInferenceCreateConversation(
+      {required BigInt sessionHandle,
+      String? systemPrompt,
+      String? chatTemplate}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(sessionHandle, serializer);
+        sse_encode_opt_String(systemPrompt, serializer);
+        sse_encode_opt_String(chatTemplate, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 6, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_u_64,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceCreateConversationConstMeta,
+      argValues: [sessionHandle, systemPrompt, chatTemplate],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceCreateConversationConstMeta =>
+      const TaskConstMeta(
+        debugName: "create_conversation",
+        argNames: ["sessionHandle", "systemPrompt", "chatTemplate"],
+      );
+
   @override
   String crateApiInferenceDetectEngineFromBytes(
       {required List<int> modelBytes}) {
@@ -188,7 +411,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_list_prim_u_8_loose(modelBytes, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -212,7 +435,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(modelPath, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -230,6 +453,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["modelPath"],
       );
 
+  @override
+  Future<void> crateApiInferenceDisposeConversation(
+      {required BigInt conversation}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(conversation, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 9, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceDisposeConversationConstMeta,
+      argValues: [conversation],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceDisposeConversationConstMeta =>
+      const TaskConstMeta(
+        debugName: "dispose_conversation",
+        argNames: ["conversation"],
+      );
+
   @override
   Future<void> crateApiInferenceDisposeSession(
       {required BigInt sessionHandle}) {
@@ -238,7 +487,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_u_64(sessionHandle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 4, port: port_);
+            funcId: 10, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -256,12 +505,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sessionHandle"],
       );
 
+  @override
+  Stream<GenerationEvent> crateApiInferenceGenerateStream(
+      {required BigInt sessionHandle,
+      required String prompt,
+      required GenerationOptions generation,
+      required PredictOptions options}) {
+    final sink = RustStreamSink<GenerationEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(sessionHandle, serializer);
+        sse_encode_String(prompt, serializer);
+        sse_encode_box_autoadd_generation_options(generation, serializer);
+        sse_encode_box_autoadd_predict_options(options, serializer);
+        sse_encode_StreamSink_generation_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 11, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceGenerateStreamConstMeta,
+      argValues: [sessionHandle, prompt, generation, options, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiInferenceGenerateStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_stream",
+        argNames: ["sessionHandle", "prompt", "generation", "options", "sink"],
+      );
+
+  @override
+  Future<GenerationOptions> crateApiInferenceGenerationOptionsDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 12, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_generation_options,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiInferenceGenerationOptionsDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceGenerationOptionsDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "generation_options_default",
+        argNames: [],
+      );
+
   @override
   List<String> crateApiInferenceGetAvailableEngines() {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_String,
@@ -285,7 +593,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 6, port: port_);
+            funcId: 14, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_u_64,
@@ -303,6 +611,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: [],
       );
 
+  @override
+  Future<List<ChatMessage>> crateApiInferenceGetConversationMessages(
+      {required BigInt conversation}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(conversation, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 15, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_chat_message,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceGetConversationMessagesConstMeta,
+      argValues: [conversation],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceGetConversationMessagesConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_conversation_messages",
+        argNames: ["conversation"],
+      );
+
   @override
   Future<DownloadProgress?> crateApiInferenceGetDownloadProgress(
       {required String repo}) {
@@ -311,7 +645,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(repo, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 7, port: port_);
+            funcId: 16, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_opt_box_autoadd_download_progress,
@@ -337,7 +671,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_u_64(sessionHandle, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 8, port: port_);
+            funcId: 17, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_session_info,
@@ -355,13 +689,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["sessionHandle"],
       );
 
+  @override
+  Future<SessionStats> crateApiInferenceGetSessionStats(
+      {required BigInt sessionHandle}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(sessionHandle, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 18, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_session_stats,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceGetSessionStatsConstMeta,
+      argValues: [sessionHandle],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceGetSessionStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_session_stats",
+        argNames: ["sessionHandle"],
+      );
+
   @override
   String crateApiSimpleGreet({required String name}) {
     return handler.executeSync(SyncTask(
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(name, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_String,
@@ -384,7 +744,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 10, port: port_);
+            funcId: 20, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -407,7 +767,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 11, port: port_);
+            funcId: 21, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_unit,
@@ -431,7 +791,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       callFfi: () {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(engineType, serializer);
-        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_bool,
@@ -449,6 +809,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["engineType"],
       );
 
+  @override
+  List<DeviceInfo> crateApiInferenceListDevices() {
+    return handler.executeSync(SyncTask(
+      callFfi: () {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_list_device_info,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiInferenceListDevicesConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceListDevicesConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_devices",
+        argNames: [],
+      );
+
   @override
   Future<SessionInfo> crateApiInferenceLoadFromHuggingface(
       {required String repo, String? revision, String? filename}) {
@@ -459,7 +842,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_opt_String(revision, serializer);
         sse_encode_opt_String(filename, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 13, port: port_);
+            funcId: 24, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_session_info,
@@ -484,7 +867,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(modelPath, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 14, port: port_);
+            funcId: 25, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_session_info,
@@ -510,7 +893,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_list_prim_u_8_loose(modelBytes, serializer);
         sse_encode_box_autoadd_session_config(config, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 15, port: port_);
+            funcId: 26, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_session_info,
@@ -536,7 +919,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(filePath, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 16, port: port_);
+            funcId: 27, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_session_info,
@@ -564,7 +947,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_bool(cache, serializer);
         sse_encode_opt_String(cacheKey, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 17, port: port_);
+            funcId: 28, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_session_info,
@@ -590,7 +973,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         final serializer = SseSerializer(generalizedFrbRustBinding);
         sse_encode_String(modelPath, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 18, port: port_);
+            funcId: 29, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_session_info,
@@ -617,7 +1000,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(modelPath, serializer);
         sse_encode_box_autoadd_session_config(config, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 19, port: port_);
+            funcId: 30, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_session_info,
@@ -644,7 +1027,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_u_64(sessionHandle, serializer);
         sse_encode_box_autoadd_inference_input(input, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 20, port: port_);
+            funcId: 31, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_inference_result,
@@ -670,7 +1053,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_u_64(sessionHandle, serializer);
         sse_encode_list_inference_input(inputs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 21, port: port_);
+            funcId: 32, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_list_inference_result,
@@ -689,43 +1072,217 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<SessionConfig> crateApiInferenceSessionConfigDefault() {
+  Future<List<InferenceResult>> crateApiInferencePredictBatchWithOptions(
+      {required BigInt sessionHandle,
+      required List<InferenceInput> inputs,
+      required PredictOptions options}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(sessionHandle, serializer);
+        sse_encode_list_inference_input(inputs, serializer);
+        sse_encode_box_autoadd_predict_options(options, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 22, port: port_);
+            funcId: 33, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_session_config,
-        decodeErrorData: null,
+        decodeSuccessData: sse_decode_list_inference_result,
+        decodeErrorData: sse_decode_inference_error,
       ),
-      constMeta: kCrateApiInferenceSessionConfigDefaultConstMeta,
-      argValues: [],
+      constMeta: kCrateApiInferencePredictBatchWithOptionsConstMeta,
+      argValues: [sessionHandle, inputs, options],
       apiImpl: this,
     ));
   }
 
-  TaskConstMeta get kCrateApiInferenceSessionConfigDefaultConstMeta =>
+  TaskConstMeta get kCrateApiInferencePredictBatchWithOptionsConstMeta =>
       const TaskConstMeta(
-        debugName: "session_config_default",
-        argNames: [],
+        debugName: "predict_batch_with_options",
+        argNames: ["sessionHandle", "inputs", "options"],
       );
 
   @override
-  Future<String> crateApiInferenceStartDownloadWithProgress(
-      {required String repo, String? revision, String? filename}) {
+  Future<List<NamedResult>> crateApiInferencePredictNamed(
+      {required BigInt sessionHandle, required List<NamedInput> inputs}) {
     return handler.executeNormal(NormalTask(
       callFfi: (port_) {
         final serializer = SseSerializer(generalizedFrbRustBinding);
-        sse_encode_String(repo, serializer);
-        sse_encode_opt_String(revision, serializer);
-        sse_encode_opt_String(filename, serializer);
+        sse_encode_u_64(sessionHandle, serializer);
+        sse_encode_list_named_input(inputs, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 23, port: port_);
+            funcId: 34, port: port_);
       },
       codec: SseCodec(
-        decodeSuccessData: sse_decode_String,
+        decodeSuccessData: sse_decode_list_named_result,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferencePredictNamedConstMeta,
+      argValues: [sessionHandle, inputs],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferencePredictNamedConstMeta =>
+      const TaskConstMeta(
+        debugName: "predict_named",
+        argNames: ["sessionHandle", "inputs"],
+      );
+
+  @override
+  Future<InferenceResult> crateApiInferencePredictWithOptions(
+      {required BigInt sessionHandle,
+      required InferenceInput input,
+      required PredictOptions options}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(sessionHandle, serializer);
+        sse_encode_box_autoadd_inference_input(input, serializer);
+        sse_encode_box_autoadd_predict_options(options, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 35, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_inference_result,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferencePredictWithOptionsConstMeta,
+      argValues: [sessionHandle, input, options],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferencePredictWithOptionsConstMeta =>
+      const TaskConstMeta(
+        debugName: "predict_with_options",
+        argNames: ["sessionHandle", "input", "options"],
+      );
+
+  @override
+  Future<void> crateApiInferenceReleaseCancellationToken(
+      {required BigInt token}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(token, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 36, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceReleaseCancellationTokenConstMeta,
+      argValues: [token],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceReleaseCancellationTokenConstMeta =>
+      const TaskConstMeta(
+        debugName: "release_cancellation_token",
+        argNames: ["token"],
+      );
+
+  @override
+  Future<SessionInfo> crateApiInferenceReloadSession(
+      {required BigInt sessionHandle}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(sessionHandle, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 37, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_session_info,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceReloadSessionConstMeta,
+      argValues: [sessionHandle],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceReloadSessionConstMeta =>
+      const TaskConstMeta(
+        debugName: "reload_session",
+        argNames: ["sessionHandle"],
+      );
+
+  @override
+  Stream<GenerationEvent> crateApiInferenceSendMessageStream(
+      {required BigInt conversation,
+      required String content,
+      required GenerationOptions generation,
+      required PredictOptions options}) {
+    final sink = RustStreamSink<GenerationEvent>();
+    unawaited(handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(conversation, serializer);
+        sse_encode_String(content, serializer);
+        sse_encode_box_autoadd_generation_options(generation, serializer);
+        sse_encode_box_autoadd_predict_options(options, serializer);
+        sse_encode_StreamSink_generation_event_Sse(sink, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 38, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_unit,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceSendMessageStreamConstMeta,
+      argValues: [conversation, content, generation, options, sink],
+      apiImpl: this,
+    )));
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiInferenceSendMessageStreamConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_message_stream",
+        argNames: ["conversation", "content", "generation", "options", "sink"],
+      );
+
+  @override
+  Future<SessionConfig> crateApiInferenceSessionConfigDefault() {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 39, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_session_config,
+        decodeErrorData: null,
+      ),
+      constMeta: kCrateApiInferenceSessionConfigDefaultConstMeta,
+      argValues: [],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceSessionConfigDefaultConstMeta =>
+      const TaskConstMeta(
+        debugName: "session_config_default",
+        argNames: [],
+      );
+
+  @override
+  Future<String> crateApiInferenceStartDownloadWithProgress(
+      {required String repo, String? revision, String? filename}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_String(repo, serializer);
+        sse_encode_opt_String(revision, serializer);
+        sse_encode_opt_String(filename, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 40, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_String,
         decodeErrorData: sse_decode_inference_error,
       ),
       constMeta: kCrateApiInferenceStartDownloadWithProgressConstMeta,
@@ -752,7 +1309,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         sse_encode_String(algorithm, serializer);
         sse_encode_Map_String_String_None(params, serializer);
         pdeCallFfi(generalizedFrbRustBinding, serializer,
-            funcId: 24, port: port_);
+            funcId: 41, port: port_);
       },
       codec: SseCodec(
         decodeSuccessData: sse_decode_session_info,
@@ -770,6 +1327,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         argNames: ["features", "algorithm", "params"],
       );
 
+  @override
+  Future<TranscriptionResult> crateApiInferenceTranscribe(
+      {required BigInt sessionHandle,
+      required List<double> samples,
+      required int sampleRate}) {
+    return handler.executeNormal(NormalTask(
+      callFfi: (port_) {
+        final serializer = SseSerializer(generalizedFrbRustBinding);
+        sse_encode_u_64(sessionHandle, serializer);
+        sse_encode_list_prim_f_32_loose(samples, serializer);
+        sse_encode_u_32(sampleRate, serializer);
+        pdeCallFfi(generalizedFrbRustBinding, serializer,
+            funcId: 42, port: port_);
+      },
+      codec: SseCodec(
+        decodeSuccessData: sse_decode_transcription_result,
+        decodeErrorData: sse_decode_inference_error,
+      ),
+      constMeta: kCrateApiInferenceTranscribeConstMeta,
+      argValues: [sessionHandle, samples, sampleRate],
+      apiImpl: this,
+    ));
+  }
+
+  TaskConstMeta get kCrateApiInferenceTranscribeConstMeta =>
+      const TaskConstMeta(
+        debugName: "transcribe",
+        argNames: ["sessionHandle", "samples", "sampleRate"],
+      );
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
   @protected
   Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -777,6 +1370,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .map((e) => MapEntry(e.$1, e.$2)));
   }
 
+  @protected
+  RustStreamSink<GenerationEvent> dco_decode_StreamSink_generation_event_Sse(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
   @protected
   String dco_decode_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -795,12 +1395,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_download_progress(raw);
   }
 
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_f_32(raw);
+  }
+
+  @protected
+  GenerationOptions dco_decode_box_autoadd_generation_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_generation_options(raw);
+  }
+
   @protected
   InferenceInput dco_decode_box_autoadd_inference_input(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return dco_decode_inference_input(raw);
   }
 
+  @protected
+  OptimizationReport dco_decode_box_autoadd_optimization_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_optimization_report(raw);
+  }
+
+  @protected
+  PredictOptions dco_decode_box_autoadd_predict_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_predict_options(raw);
+  }
+
   @protected
   SessionConfig dco_decode_box_autoadd_session_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -819,12 +1443,67 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dco_decode_usize(raw);
   }
 
+  @protected
+  ChatMessage dco_decode_chat_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ChatMessage(
+      role: dco_decode_chat_role(arr[0]),
+      content: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  ChatRole dco_decode_chat_role(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ChatRole.values[raw as int];
+  }
+
   @protected
   DataType dco_decode_data_type(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     return DataType.values[raw as int];
   }
 
+  @protected
+  DeviceInfo dco_decode_device_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return DeviceInfo(
+      device: dco_decode_device_selection(arr[0]),
+      name: dco_decode_String(arr[1]),
+      available: dco_decode_bool(arr[2]),
+      unavailableReason: dco_decode_opt_String(arr[3]),
+      supportsF16: dco_decode_bool(arr[4]),
+      supportsBf16: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  DeviceSelection dco_decode_device_selection(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return DeviceSelection_Auto();
+      case 1:
+        return DeviceSelection_Cpu();
+      case 2:
+        return DeviceSelection_Cuda(
+          ordinal: dco_decode_usize(raw[1]),
+        );
+      case 3:
+        return DeviceSelection_Metal(
+          ordinal: dco_decode_usize(raw[1]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
   @protected
   DownloadPhase dco_decode_download_phase(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -858,6 +1537,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw as double;
   }
 
+  @protected
+  GenerationEvent dco_decode_generation_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return GenerationEvent_Token(
+          text: dco_decode_String(raw[1]),
+          tokenId: dco_decode_u_32(raw[2]),
+          logprob: dco_decode_f_32(raw[3]),
+        );
+      case 1:
+        return GenerationEvent_Finished(
+          text: dco_decode_String(raw[1]),
+          stopReason: dco_decode_generation_stop_reason(raw[2]),
+          promptTokens: dco_decode_usize(raw[3]),
+          generatedTokens: dco_decode_usize(raw[4]),
+          timeToFirstTokenMs: dco_decode_opt_box_autoadd_u_64(raw[5]),
+          totalTimeMs: dco_decode_u_64(raw[6]),
+        );
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  GenerationOptions dco_decode_generation_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+    return GenerationOptions(
+      maxNewTokens: dco_decode_usize(arr[0]),
+      temperature: dco_decode_f_32(arr[1]),
+      topK: dco_decode_opt_box_autoadd_usize(arr[2]),
+      topP: dco_decode_opt_box_autoadd_f_32(arr[3]),
+      minP: dco_decode_opt_box_autoadd_f_32(arr[4]),
+      repetitionPenalty: dco_decode_f_32(arr[5]),
+      presencePenalty: dco_decode_f_32(arr[6]),
+      penaltyLastN: dco_decode_usize(arr[7]),
+      seed: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      stopSequences: dco_decode_list_String(arr[9]),
+      grammar: dco_decode_opt_String(arr[10]),
+      jsonSchema: dco_decode_opt_String(arr[11]),
+    );
+  }
+
+  @protected
+  GenerationStopReason dco_decode_generation_stop_reason(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return GenerationStopReason.values[raw as int];
+  }
+
   @protected
   int dco_decode_i_32(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -925,6 +1656,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         return InferenceError_FormatDetection(
           dco_decode_String(raw[1]),
         );
+      case 14:
+        return InferenceError_Cancelled(
+          dco_decode_String(raw[1]),
+        );
+      case 15:
+        return InferenceError_DeadlineExceeded(
+          dco_decode_String(raw[1]),
+        );
+      case 16:
+        return InferenceError_QueueFull(
+          dco_decode_String(raw[1]),
+        );
       default:
         throw Exception("unreachable");
     }
@@ -962,6 +1705,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_String).toList();
   }
 
+  @protected
+  List<ChatMessage> dco_decode_list_chat_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_chat_message).toList();
+  }
+
+  @protected
+  List<DeviceInfo> dco_decode_list_device_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_device_info).toList();
+  }
+
   @protected
   List<InferenceInput> dco_decode_list_inference_input(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -982,6 +1737,18 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<NamedInput> dco_decode_list_named_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_named_input).toList();
+  }
+
+  @protected
+  List<NamedResult> dco_decode_list_named_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_named_result).toList();
+  }
+
   @protected
   List<BigInt?> dco_decode_list_opt_box_autoadd_usize(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -990,6 +1757,12 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         .toList();
   }
 
+  @protected
+  List<double> dco_decode_list_prim_f_32_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<double>;
+  }
+
   @protected
   Float32List dco_decode_list_prim_f_32_strict(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1032,6 +1805,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (raw as List<dynamic>).map(dco_decode_tensor_spec).toList();
   }
 
+  @protected
+  List<TranscriptSegment> dco_decode_list_transcript_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_transcript_segment).toList();
+  }
+
+  @protected
+  LoadMode dco_decode_load_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LoadMode.values[raw as int];
+  }
+
+  @protected
+  NamedInput dco_decode_named_input(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NamedInput(
+      name: dco_decode_String(arr[0]),
+      input: dco_decode_inference_input(arr[1]),
+    );
+  }
+
+  @protected
+  NamedResult dco_decode_named_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return NamedResult(
+      name: dco_decode_String(arr[0]),
+      result: dco_decode_inference_result(arr[1]),
+    );
+  }
+
   @protected
   String? dco_decode_opt_String(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1044,6 +1853,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_download_progress(raw);
   }
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_f_32(raw);
+  }
+
+  @protected
+  OptimizationReport? dco_decode_opt_box_autoadd_optimization_report(
+      dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_optimization_report(raw);
+  }
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1056,6 +1878,48 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return raw == null ? null : dco_decode_box_autoadd_usize(raw);
   }
 
+  @protected
+  OptimizationLevel dco_decode_optimization_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return OptimizationLevel.values[raw as int];
+  }
+
+  @protected
+  OptimizationReport dco_decode_optimization_report(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return OptimizationReport(
+      level: dco_decode_optimization_level(arr[0]),
+      tensorsConverted: dco_decode_usize(arr[1]),
+      tensorsSkipped: dco_decode_usize(arr[2]),
+      sizeBeforeBytes: dco_decode_usize(arr[3]),
+      sizeAfterBytes: dco_decode_usize(arr[4]),
+      maxAbsError: dco_decode_opt_box_autoadd_f_32(arr[5]),
+      meanAbsError: dco_decode_opt_box_autoadd_f_32(arr[6]),
+    );
+  }
+
+  @protected
+  PredictOptions dco_decode_predict_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return PredictOptions(
+      cancellationToken: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      timeoutMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      priority: dco_decode_priority(arr[2]),
+    );
+  }
+
+  @protected
+  Priority dco_decode_priority(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Priority.values[raw as int];
+  }
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1073,13 +1937,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   SessionConfig dco_decode_session_config(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 12)
+      throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
     return SessionConfig(
       engineType: dco_decode_opt_String(arr[0]),
       gpuAcceleration: dco_decode_bool(arr[1]),
-      numThreads: dco_decode_opt_box_autoadd_usize(arr[2]),
-      optimizationLevel: dco_decode_opt_String(arr[3]),
+      device: dco_decode_device_selection(arr[2]),
+      fallbackChain: dco_decode_list_String(arr[3]),
+      numThreads: dco_decode_opt_box_autoadd_usize(arr[4]),
+      optimizationLevel: dco_decode_opt_String(arr[5]),
+      loadMode: dco_decode_load_mode(arr[6]),
+      maxConcurrency: dco_decode_opt_box_autoadd_usize(arr[7]),
+      maxQueueDepth: dco_decode_opt_box_autoadd_usize(arr[8]),
+      cacheMaxEntries: dco_decode_opt_box_autoadd_usize(arr[9]),
+      cacheMaxBytes: dco_decode_opt_box_autoadd_usize(arr[10]),
+      modelGraph: dco_decode_opt_String(arr[11]),
     );
   }
 
@@ -1087,13 +1959,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   SessionInfo dco_decode_session_info(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
     final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    if (arr.length != 13)
+      throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
     return SessionInfo(
       handle: dco_decode_u_64(arr[0]),
       engineType: dco_decode_String(arr[1]),
       inputSpecs: dco_decode_list_tensor_spec(arr[2]),
       outputSpecs: dco_decode_list_tensor_spec(arr[3]),
+      specsPending: dco_decode_bool(arr[4]),
+      loadMode: dco_decode_load_mode(arr[5]),
+      isLoaded: dco_decode_bool(arr[6]),
+      loadTimeMs: dco_decode_opt_box_autoadd_u_64(arr[7]),
+      warmupTimeMs: dco_decode_opt_box_autoadd_u_64(arr[8]),
+      device: dco_decode_String(arr[9]),
+      deviceFallbackReason: dco_decode_opt_String(arr[10]),
+      fallbackReasons: dco_decode_list_String(arr[11]),
+      optimization: dco_decode_opt_box_autoadd_optimization_report(arr[12]),
+    );
+  }
+
+  @protected
+  SessionStats dco_decode_session_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 9)
+      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+    return SessionStats(
+      activeRequests: dco_decode_usize(arr[0]),
+      queuedInteractive: dco_decode_usize(arr[1]),
+      queuedBackground: dco_decode_usize(arr[2]),
+      completedRequests: dco_decode_u_64(arr[3]),
+      rejectedRequests: dco_decode_u_64(arr[4]),
+      cacheHits: dco_decode_u_64(arr[5]),
+      cacheMisses: dco_decode_u_64(arr[6]),
+      cacheEntries: dco_decode_usize(arr[7]),
+      cacheBytes: dco_decode_usize(arr[8]),
     );
   }
 
@@ -1111,6 +2011,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  TranscriptSegment dco_decode_transcript_segment(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return TranscriptSegment(
+      startSeconds: dco_decode_f_64(arr[0]),
+      endSeconds: dco_decode_f_64(arr[1]),
+      text: dco_decode_String(arr[2]),
+      avgLogprob: dco_decode_f_64(arr[3]),
+      noSpeechProb: dco_decode_f_64(arr[4]),
+    );
+  }
+
+  @protected
+  TranscriptionResult dco_decode_transcription_result(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return TranscriptionResult(
+      text: dco_decode_String(arr[0]),
+      language: dco_decode_opt_String(arr[1]),
+      segments: dco_decode_list_transcript_segment(arr[2]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
   @protected
   BigInt dco_decode_u_64(dynamic raw) {
     // Codec=Dco (DartCObject based), see doc to use other codecs
@@ -1135,6 +2069,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return dcoDecodeU64(raw);
   }
 
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
   @protected
   Map<String, String> sse_decode_Map_String_String_None(
       SseDeserializer deserializer) {
@@ -1143,6 +2084,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
   }
 
+  @protected
+  RustStreamSink<GenerationEvent> sse_decode_StreamSink_generation_event_Sse(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
   @protected
   String sse_decode_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1163,6 +2111,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_download_progress(deserializer));
   }
 
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_f_32(deserializer));
+  }
+
+  @protected
+  GenerationOptions sse_decode_box_autoadd_generation_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_generation_options(deserializer));
+  }
+
   @protected
   InferenceInput sse_decode_box_autoadd_inference_input(
       SseDeserializer deserializer) {
@@ -1170,6 +2131,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_inference_input(deserializer));
   }
 
+  @protected
+  OptimizationReport sse_decode_box_autoadd_optimization_report(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_optimization_report(deserializer));
+  }
+
+  @protected
+  PredictOptions sse_decode_box_autoadd_predict_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_predict_options(deserializer));
+  }
+
   @protected
   SessionConfig sse_decode_box_autoadd_session_config(
       SseDeserializer deserializer) {
@@ -1189,6 +2164,21 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return (sse_decode_usize(deserializer));
   }
 
+  @protected
+  ChatMessage sse_decode_chat_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_role = sse_decode_chat_role(deserializer);
+    var var_content = sse_decode_String(deserializer);
+    return ChatMessage(role: var_role, content: var_content);
+  }
+
+  @protected
+  ChatRole sse_decode_chat_role(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ChatRole.values[inner];
+  }
+
   @protected
   DataType sse_decode_data_type(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1196,6 +2186,45 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return DataType.values[inner];
   }
 
+  @protected
+  DeviceInfo sse_decode_device_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_device = sse_decode_device_selection(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_available = sse_decode_bool(deserializer);
+    var var_unavailableReason = sse_decode_opt_String(deserializer);
+    var var_supportsF16 = sse_decode_bool(deserializer);
+    var var_supportsBf16 = sse_decode_bool(deserializer);
+    return DeviceInfo(
+        device: var_device,
+        name: var_name,
+        available: var_available,
+        unavailableReason: var_unavailableReason,
+        supportsF16: var_supportsF16,
+        supportsBf16: var_supportsBf16);
+  }
+
+  @protected
+  DeviceSelection sse_decode_device_selection(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return DeviceSelection_Auto();
+      case 1:
+        return DeviceSelection_Cpu();
+      case 2:
+        var var_ordinal = sse_decode_usize(deserializer);
+        return DeviceSelection_Cuda(ordinal: var_ordinal);
+      case 3:
+        var var_ordinal = sse_decode_usize(deserializer);
+        return DeviceSelection_Metal(ordinal: var_ordinal);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
   @protected
   DownloadPhase sse_decode_download_phase(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1231,6 +2260,77 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getFloat64();
   }
 
+  @protected
+  GenerationEvent sse_decode_generation_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_text = sse_decode_String(deserializer);
+        var var_tokenId = sse_decode_u_32(deserializer);
+        var var_logprob = sse_decode_f_32(deserializer);
+        return GenerationEvent_Token(
+            text: var_text, tokenId: var_tokenId, logprob: var_logprob);
+      case 1:
+        var var_text = sse_decode_String(deserializer);
+        var var_stopReason = sse_decode_generation_stop_reason(deserializer);
+        var var_promptTokens = sse_decode_usize(deserializer);
+        var var_generatedTokens = sse_decode_usize(deserializer);
+        var var_timeToFirstTokenMs = sse_decode_opt_box_autoadd_u_64(
+            deserializer);
+        var var_totalTimeMs = sse_decode_u_64(deserializer);
+        return GenerationEvent_Finished(
+            text: var_text,
+            stopReason: var_stopReason,
+            promptTokens: var_promptTokens,
+            generatedTokens: var_generatedTokens,
+            timeToFirstTokenMs: var_timeToFirstTokenMs,
+            totalTimeMs: var_totalTimeMs);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  GenerationOptions sse_decode_generation_options(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxNewTokens = sse_decode_usize(deserializer);
+    var var_temperature = sse_decode_f_32(deserializer);
+    var var_topK = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_topP = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_minP = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_repetitionPenalty = sse_decode_f_32(deserializer);
+    var var_presencePenalty = sse_decode_f_32(deserializer);
+    var var_penaltyLastN = sse_decode_usize(deserializer);
+    var var_seed = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_stopSequences = sse_decode_list_String(deserializer);
+    var var_grammar = sse_decode_opt_String(deserializer);
+    var var_jsonSchema = sse_decode_opt_String(deserializer);
+    return GenerationOptions(
+        maxNewTokens: var_maxNewTokens,
+        temperature: var_temperature,
+        topK: var_topK,
+        topP: var_topP,
+        minP: var_minP,
+        repetitionPenalty: var_repetitionPenalty,
+        presencePenalty: var_presencePenalty,
+        penaltyLastN: var_penaltyLastN,
+        seed: var_seed,
+        stopSequences: var_stopSequences,
+        grammar: var_grammar,
+        jsonSchema: var_jsonSchema);
+  }
+
+  @protected
+  GenerationStopReason sse_decode_generation_stop_reason(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return GenerationStopReason.values[inner];
+  }
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1287,6 +2387,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case 13:
         var var_field0 = sse_decode_String(deserializer);
         return InferenceError_FormatDetection(var_field0);
+      case 14:
+        var var_field0 = sse_decode_String(deserializer);
+        return InferenceError_Cancelled(var_field0);
+      case 15:
+        var var_field0 = sse_decode_String(deserializer);
+        return InferenceError_DeadlineExceeded(var_field0);
+      case 16:
+        var var_field0 = sse_decode_String(deserializer);
+        return InferenceError_QueueFull(var_field0);
       default:
         throw UnimplementedError('');
     }
@@ -1324,6 +2433,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<ChatMessage> sse_decode_list_chat_message(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ChatMessage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_chat_message(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<DeviceInfo> sse_decode_list_device_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <DeviceInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_device_info(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<InferenceInput> sse_decode_list_inference_input(
       SseDeserializer deserializer) {
@@ -1363,6 +2496,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<NamedInput> sse_decode_list_named_input(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NamedInput>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_named_input(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<NamedResult> sse_decode_list_named_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <NamedResult>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_named_result(deserializer));
+    }
+    return ans_;
+  }
+
   @protected
   List<BigInt?> sse_decode_list_opt_box_autoadd_usize(
       SseDeserializer deserializer) {
@@ -1376,6 +2533,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getFloat32List(len_);
+  }
+
   @protected
   Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1436,6 +2600,42 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return ans_;
   }
 
+  @protected
+  List<TranscriptSegment> sse_decode_list_transcript_segment(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TranscriptSegment>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_transcript_segment(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  LoadMode sse_decode_load_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LoadMode.values[inner];
+  }
+
+  @protected
+  NamedInput sse_decode_named_input(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_input = sse_decode_inference_input(deserializer);
+    return NamedInput(name: var_name, input: var_input);
+  }
+
+  @protected
+  NamedResult sse_decode_named_result(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_result = sse_decode_inference_result(deserializer);
+    return NamedResult(name: var_name, result: var_result);
+  }
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1459,6 +2659,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_f_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  OptimizationReport? sse_decode_opt_box_autoadd_optimization_report(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_optimization_report(deserializer));
+    } else {
+      return null;
+    }
+  }
+
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1481,6 +2704,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  OptimizationLevel sse_decode_optimization_level(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return OptimizationLevel.values[inner];
+  }
+
+  @protected
+  OptimizationReport sse_decode_optimization_report(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_optimization_level(deserializer);
+    var var_tensorsConverted = sse_decode_usize(deserializer);
+    var var_tensorsSkipped = sse_decode_usize(deserializer);
+    var var_sizeBeforeBytes = sse_decode_usize(deserializer);
+    var var_sizeAfterBytes = sse_decode_usize(deserializer);
+    var var_maxAbsError = sse_decode_opt_box_autoadd_f_32(deserializer);
+    var var_meanAbsError = sse_decode_opt_box_autoadd_f_32(deserializer);
+    return OptimizationReport(
+        level: var_level,
+        tensorsConverted: var_tensorsConverted,
+        tensorsSkipped: var_tensorsSkipped,
+        sizeBeforeBytes: var_sizeBeforeBytes,
+        sizeAfterBytes: var_sizeAfterBytes,
+        maxAbsError: var_maxAbsError,
+        meanAbsError: var_meanAbsError);
+  }
+
+  @protected
+  PredictOptions sse_decode_predict_options(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_cancellationToken = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_timeoutMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_priority = sse_decode_priority(deserializer);
+    return PredictOptions(
+        cancellationToken: var_cancellationToken,
+        timeoutMs: var_timeoutMs,
+        priority: var_priority);
+  }
+
+  @protected
+  Priority sse_decode_priority(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return Priority.values[inner];
+  }
+
   @protected
   (String, String) sse_decode_record_string_string(
       SseDeserializer deserializer) {
@@ -1495,13 +2766,29 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     var var_engineType = sse_decode_opt_String(deserializer);
     var var_gpuAcceleration = sse_decode_bool(deserializer);
+    var var_device = sse_decode_device_selection(deserializer);
+    var var_fallbackChain = sse_decode_list_String(deserializer);
     var var_numThreads = sse_decode_opt_box_autoadd_usize(deserializer);
     var var_optimizationLevel = sse_decode_opt_String(deserializer);
+    var var_loadMode = sse_decode_load_mode(deserializer);
+    var var_maxConcurrency = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_maxQueueDepth = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_cacheMaxEntries = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_cacheMaxBytes = sse_decode_opt_box_autoadd_usize(deserializer);
+    var var_modelGraph = sse_decode_opt_String(deserializer);
     return SessionConfig(
         engineType: var_engineType,
         gpuAcceleration: var_gpuAcceleration,
+        device: var_device,
+        fallbackChain: var_fallbackChain,
         numThreads: var_numThreads,
-        optimizationLevel: var_optimizationLevel);
+        optimizationLevel: var_optimizationLevel,
+        loadMode: var_loadMode,
+        maxConcurrency: var_maxConcurrency,
+        maxQueueDepth: var_maxQueueDepth,
+        cacheMaxEntries: var_cacheMaxEntries,
+        cacheMaxBytes: var_cacheMaxBytes,
+        modelGraph: var_modelGraph);
   }
 
   @protected
@@ -1511,11 +2798,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     var var_engineType = sse_decode_String(deserializer);
     var var_inputSpecs = sse_decode_list_tensor_spec(deserializer);
     var var_outputSpecs = sse_decode_list_tensor_spec(deserializer);
+    var var_specsPending = sse_decode_bool(deserializer);
+    var var_loadMode = sse_decode_load_mode(deserializer);
+    var var_isLoaded = sse_decode_bool(deserializer);
+    var var_loadTimeMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_warmupTimeMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_device = sse_decode_String(deserializer);
+    var var_deviceFallbackReason = sse_decode_opt_String(deserializer);
+    var var_fallbackReasons = sse_decode_list_String(deserializer);
+    var var_optimization = sse_decode_opt_box_autoadd_optimization_report(
+        deserializer);
     return SessionInfo(
         handle: var_handle,
         engineType: var_engineType,
         inputSpecs: var_inputSpecs,
-        outputSpecs: var_outputSpecs);
+        outputSpecs: var_outputSpecs,
+        specsPending: var_specsPending,
+        loadMode: var_loadMode,
+        isLoaded: var_isLoaded,
+        loadTimeMs: var_loadTimeMs,
+        warmupTimeMs: var_warmupTimeMs,
+        device: var_device,
+        deviceFallbackReason: var_deviceFallbackReason,
+        fallbackReasons: var_fallbackReasons,
+        optimization: var_optimization);
+  }
+
+  @protected
+  SessionStats sse_decode_session_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_activeRequests = sse_decode_usize(deserializer);
+    var var_queuedInteractive = sse_decode_usize(deserializer);
+    var var_queuedBackground = sse_decode_usize(deserializer);
+    var var_completedRequests = sse_decode_u_64(deserializer);
+    var var_rejectedRequests = sse_decode_u_64(deserializer);
+    var var_cacheHits = sse_decode_u_64(deserializer);
+    var var_cacheMisses = sse_decode_u_64(deserializer);
+    var var_cacheEntries = sse_decode_usize(deserializer);
+    var var_cacheBytes = sse_decode_usize(deserializer);
+    return SessionStats(
+        activeRequests: var_activeRequests,
+        queuedInteractive: var_queuedInteractive,
+        queuedBackground: var_queuedBackground,
+        completedRequests: var_completedRequests,
+        rejectedRequests: var_rejectedRequests,
+        cacheHits: var_cacheHits,
+        cacheMisses: var_cacheMisses,
+        cacheEntries: var_cacheEntries,
+        cacheBytes: var_cacheBytes);
   }
 
   @protected
@@ -1532,6 +2862,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         description: var_description);
   }
 
+  @protected
+  TranscriptSegment sse_decode_transcript_segment(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_startSeconds = sse_decode_f_64(deserializer);
+    var var_endSeconds = sse_decode_f_64(deserializer);
+    var var_text = sse_decode_String(deserializer);
+    var var_avgLogprob = sse_decode_f_64(deserializer);
+    var var_noSpeechProb = sse_decode_f_64(deserializer);
+    return TranscriptSegment(
+        startSeconds: var_startSeconds,
+        endSeconds: var_endSeconds,
+        text: var_text,
+        avgLogprob: var_avgLogprob,
+        noSpeechProb: var_noSpeechProb);
+  }
+
+  @protected
+  TranscriptionResult sse_decode_transcription_result(
+      SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_text = sse_decode_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_segments = sse_decode_list_transcript_segment(deserializer);
+    return TranscriptionResult(
+        text: var_text, language: var_language, segments: var_segments);
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
   @protected
   BigInt sse_decode_u_64(SseDeserializer deserializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1555,6 +2919,13 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     return deserializer.buffer.getBigUint64();
   }
 
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
   @protected
   void sse_encode_Map_String_String_None(
       Map<String, String> self, SseSerializer serializer) {
@@ -1563,6 +2934,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         self.entries.map((e) => (e.key, e.value)).toList(), serializer);
   }
 
+  @protected
+  void sse_encode_StreamSink_generation_event_Sse(
+      RustStreamSink<GenerationEvent> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+        self.setupAndSerialize(
+            codec: SseCodec(
+          decodeSuccessData: sse_decode_generation_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        )),
+        serializer);
+  }
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1582,6 +2966,19 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_download_progress(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_generation_options(
+      GenerationOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_generation_options(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_inference_input(
       InferenceInput self, SseSerializer serializer) {
@@ -1589,6 +2986,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_inference_input(self, serializer);
   }
 
+  @protected
+  void sse_encode_box_autoadd_optimization_report(
+      OptimizationReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_optimization_report(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_predict_options(
+      PredictOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_predict_options(self, serializer);
+  }
+
   @protected
   void sse_encode_box_autoadd_session_config(
       SessionConfig self, SseSerializer serializer) {
@@ -1608,12 +3019,54 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize(self, serializer);
   }
 
+  @protected
+  void sse_encode_chat_message(ChatMessage self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_chat_role(self.role, serializer);
+    sse_encode_String(self.content, serializer);
+  }
+
+  @protected
+  void sse_encode_chat_role(ChatRole self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_data_type(DataType self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_i_32(self.index, serializer);
   }
 
+  @protected
+  void sse_encode_device_info(DeviceInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_device_selection(self.device, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_bool(self.available, serializer);
+    sse_encode_opt_String(self.unavailableReason, serializer);
+    sse_encode_bool(self.supportsF16, serializer);
+    sse_encode_bool(self.supportsBf16, serializer);
+  }
+
+  @protected
+  void sse_encode_device_selection(
+      DeviceSelection self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case DeviceSelection_Auto():
+        sse_encode_i_32(0, serializer);
+      case DeviceSelection_Cpu():
+        sse_encode_i_32(1, serializer);
+      case DeviceSelection_Cuda(ordinal: final ordinal):
+        sse_encode_i_32(2, serializer);
+        sse_encode_usize(ordinal, serializer);
+      case DeviceSelection_Metal(ordinal: final ordinal):
+        sse_encode_i_32(3, serializer);
+        sse_encode_usize(ordinal, serializer);
+    }
+  }
+
   @protected
   void sse_encode_download_phase(DownloadPhase self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1643,6 +3096,63 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     serializer.buffer.putFloat64(self);
   }
 
+  @protected
+  void sse_encode_generation_event(
+      GenerationEvent self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    switch (self) {
+      case GenerationEvent_Token(
+          text: final text,
+          tokenId: final tokenId,
+          logprob: final logprob
+        ):
+        sse_encode_i_32(0, serializer);
+        sse_encode_String(text, serializer);
+        sse_encode_u_32(tokenId, serializer);
+        sse_encode_f_32(logprob, serializer);
+      case GenerationEvent_Finished(
+          text: final text,
+          stopReason: final stopReason,
+          promptTokens: final promptTokens,
+          generatedTokens: final generatedTokens,
+          timeToFirstTokenMs: final timeToFirstTokenMs,
+          totalTimeMs: final totalTimeMs
+        ):
+        sse_encode_i_32(1, serializer);
+        sse_encode_String(text, serializer);
+        sse_encode_generation_stop_reason(stopReason, serializer);
+        sse_encode_usize(promptTokens, serializer);
+        sse_encode_usize(generatedTokens, serializer);
+        sse_encode_opt_box_autoadd_u_64(timeToFirstTokenMs, serializer);
+        sse_encode_u_64(totalTimeMs, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_generation_options(
+      GenerationOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.maxNewTokens, serializer);
+    sse_encode_f_32(self.temperature, serializer);
+    sse_encode_opt_box_autoadd_usize(self.topK, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.topP, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.minP, serializer);
+    sse_encode_f_32(self.repetitionPenalty, serializer);
+    sse_encode_f_32(self.presencePenalty, serializer);
+    sse_encode_usize(self.penaltyLastN, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.seed, serializer);
+    sse_encode_list_String(self.stopSequences, serializer);
+    sse_encode_opt_String(self.grammar, serializer);
+    sse_encode_opt_String(self.jsonSchema, serializer);
+  }
+
+  @protected
+  void sse_encode_generation_stop_reason(
+      GenerationStopReason self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1700,6 +3210,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case InferenceError_FormatDetection(field0: final field0):
         sse_encode_i_32(13, serializer);
         sse_encode_String(field0, serializer);
+      case InferenceError_Cancelled(field0: final field0):
+        sse_encode_i_32(14, serializer);
+        sse_encode_String(field0, serializer);
+      case InferenceError_DeadlineExceeded(field0: final field0):
+        sse_encode_i_32(15, serializer);
+        sse_encode_String(field0, serializer);
+      case InferenceError_QueueFull(field0: final field0):
+        sse_encode_i_32(16, serializer);
+        sse_encode_String(field0, serializer);
     }
   }
 
@@ -1730,6 +3249,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_chat_message(
+      List<ChatMessage> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_chat_message(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_device_info(
+      List<DeviceInfo> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_device_info(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_inference_input(
       List<InferenceInput> self, SseSerializer serializer) {
@@ -1760,6 +3299,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_named_input(
+      List<NamedInput> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_named_input(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_named_result(
+      List<NamedResult> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_named_result(item, serializer);
+    }
+  }
+
   @protected
   void sse_encode_list_opt_box_autoadd_usize(
       List<BigInt?> self, SseSerializer serializer) {
@@ -1770,6 +3329,15 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_prim_f_32_loose(
+      List<double> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer
+        .putFloat32List(self is Float32List ? self : Float32List.fromList(self));
+  }
+
   @protected
   void sse_encode_list_prim_f_32_strict(
       Float32List self, SseSerializer serializer) {
@@ -1831,6 +3399,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_list_transcript_segment(
+      List<TranscriptSegment> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_transcript_segment(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_load_mode(LoadMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_named_input(NamedInput self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_inference_input(self.input, serializer);
+  }
+
+  @protected
+  void sse_encode_named_result(NamedResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_inference_result(self.result, serializer);
+  }
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1852,6 +3450,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_f_32(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_optimization_report(
+      OptimizationReport? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_optimization_report(self, serializer);
+    }
+  }
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -1873,6 +3492,41 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_optimization_level(
+      OptimizationLevel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_optimization_report(
+      OptimizationReport self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_optimization_level(self.level, serializer);
+    sse_encode_usize(self.tensorsConverted, serializer);
+    sse_encode_usize(self.tensorsSkipped, serializer);
+    sse_encode_usize(self.sizeBeforeBytes, serializer);
+    sse_encode_usize(self.sizeAfterBytes, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.maxAbsError, serializer);
+    sse_encode_opt_box_autoadd_f_32(self.meanAbsError, serializer);
+  }
+
+  @protected
+  void sse_encode_predict_options(
+      PredictOptions self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.cancellationToken, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.timeoutMs, serializer);
+    sse_encode_priority(self.priority, serializer);
+  }
+
+  @protected
+  void sse_encode_priority(Priority self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_record_string_string(
       (String, String) self, SseSerializer serializer) {
@@ -1886,8 +3540,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
     sse_encode_opt_String(self.engineType, serializer);
     sse_encode_bool(self.gpuAcceleration, serializer);
+    sse_encode_device_selection(self.device, serializer);
+    sse_encode_list_String(self.fallbackChain, serializer);
     sse_encode_opt_box_autoadd_usize(self.numThreads, serializer);
     sse_encode_opt_String(self.optimizationLevel, serializer);
+    sse_encode_load_mode(self.loadMode, serializer);
+    sse_encode_opt_box_autoadd_usize(self.maxConcurrency, serializer);
+    sse_encode_opt_box_autoadd_usize(self.maxQueueDepth, serializer);
+    sse_encode_opt_box_autoadd_usize(self.cacheMaxEntries, serializer);
+    sse_encode_opt_box_autoadd_usize(self.cacheMaxBytes, serializer);
+    sse_encode_opt_String(self.modelGraph, serializer);
   }
 
   @protected
@@ -1897,6 +3559,30 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_String(self.engineType, serializer);
     sse_encode_list_tensor_spec(self.inputSpecs, serializer);
     sse_encode_list_tensor_spec(self.outputSpecs, serializer);
+    sse_encode_bool(self.specsPending, serializer);
+    sse_encode_load_mode(self.loadMode, serializer);
+    sse_encode_bool(self.isLoaded, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.loadTimeMs, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.warmupTimeMs, serializer);
+    sse_encode_String(self.device, serializer);
+    sse_encode_opt_String(self.deviceFallbackReason, serializer);
+    sse_encode_list_String(self.fallbackReasons, serializer);
+    sse_encode_opt_box_autoadd_optimization_report(
+        self.optimization, serializer);
+  }
+
+  @protected
+  void sse_encode_session_stats(SessionStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.activeRequests, serializer);
+    sse_encode_usize(self.queuedInteractive, serializer);
+    sse_encode_usize(self.queuedBackground, serializer);
+    sse_encode_u_64(self.completedRequests, serializer);
+    sse_encode_u_64(self.rejectedRequests, serializer);
+    sse_encode_u_64(self.cacheHits, serializer);
+    sse_encode_u_64(self.cacheMisses, serializer);
+    sse_encode_usize(self.cacheEntries, serializer);
+    sse_encode_usize(self.cacheBytes, serializer);
   }
 
   @protected
@@ -1908,6 +3594,32 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.description, serializer);
   }
 
+  @protected
+  void sse_encode_transcript_segment(
+      TranscriptSegment self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_f_64(self.startSeconds, serializer);
+    sse_encode_f_64(self.endSeconds, serializer);
+    sse_encode_String(self.text, serializer);
+    sse_encode_f_64(self.avgLogprob, serializer);
+    sse_encode_f_64(self.noSpeechProb, serializer);
+  }
+
+  @protected
+  void sse_encode_transcription_result(
+      TranscriptionResult self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.text, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_list_transcript_segment(self.segments, serializer);
+  }
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -9,7 +9,11 @@ import 'dart:async';
 import 'dart:convert';
 import 'dart:ffi' as ffi;
 import 'frb_generated.dart';
+import 'models/device.dart';
 import 'models/error.dart';
+import 'models/optimization.dart';
+import 'models/scheduler.dart';
+import 'models/session.dart';
 import 'models/tensor.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_io.dart';
 
@@ -21,9 +25,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
+
   @protected
   Map<String, String> dco_decode_Map_String_String_None(dynamic raw);
 
+  @protected
+  RustStreamSink<GenerationEvent> dco_decode_StreamSink_generation_event_Sse(
+      dynamic raw);
+
   @protected
   String dco_decode_String(dynamic raw);
 
@@ -33,9 +44,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   DownloadProgress dco_decode_box_autoadd_download_progress(dynamic raw);
 
+  @protected
+  double dco_decode_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  GenerationOptions dco_decode_box_autoadd_generation_options(dynamic raw);
+
   @protected
   InferenceInput dco_decode_box_autoadd_inference_input(dynamic raw);
 
+  @protected
+  OptimizationReport dco_decode_box_autoadd_optimization_report(dynamic raw);
+
+  @protected
+  PredictOptions dco_decode_box_autoadd_predict_options(dynamic raw);
+
   @protected
   SessionConfig dco_decode_box_autoadd_session_config(dynamic raw);
 
@@ -45,9 +68,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_box_autoadd_usize(dynamic raw);
 
+  @protected
+  ChatMessage dco_decode_chat_message(dynamic raw);
+
+  @protected
+  ChatRole dco_decode_chat_role(dynamic raw);
+
   @protected
   DataType dco_decode_data_type(dynamic raw);
 
+  @protected
+  DeviceInfo dco_decode_device_info(dynamic raw);
+
+  @protected
+  DeviceSelection dco_decode_device_selection(dynamic raw);
+
   @protected
   DownloadPhase dco_decode_download_phase(dynamic raw);
 
@@ -60,6 +95,15 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double dco_decode_f_64(dynamic raw);
 
+  @protected
+  GenerationEvent dco_decode_generation_event(dynamic raw);
+
+  @protected
+  GenerationOptions dco_decode_generation_options(dynamic raw);
+
+  @protected
+  GenerationStopReason dco_decode_generation_stop_reason(dynamic raw);
+
   @protected
   int dco_decode_i_32(dynamic raw);
 
@@ -75,6 +119,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<ChatMessage> dco_decode_list_chat_message(dynamic raw);
+
+  @protected
+  List<DeviceInfo> dco_decode_list_device_info(dynamic raw);
+
   @protected
   List<InferenceInput> dco_decode_list_inference_input(dynamic raw);
 
@@ -84,9 +134,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<Float64List> dco_decode_list_list_prim_f_64_strict(dynamic raw);
 
+  @protected
+  List<NamedInput> dco_decode_list_named_input(dynamic raw);
+
+  @protected
+  List<NamedResult> dco_decode_list_named_result(dynamic raw);
+
   @protected
   List<BigInt?> dco_decode_list_opt_box_autoadd_usize(dynamic raw);
 
+  @protected
+  List<double> dco_decode_list_prim_f_32_loose(dynamic raw);
+
   @protected
   Float32List dco_decode_list_prim_f_32_strict(dynamic raw);
 
@@ -108,18 +167,49 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<TensorSpec> dco_decode_list_tensor_spec(dynamic raw);
 
+  @protected
+  List<TranscriptSegment> dco_decode_list_transcript_segment(dynamic raw);
+
+  @protected
+  LoadMode dco_decode_load_mode(dynamic raw);
+
+  @protected
+  NamedInput dco_decode_named_input(dynamic raw);
+
+  @protected
+  NamedResult dco_decode_named_result(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
   @protected
   DownloadProgress? dco_decode_opt_box_autoadd_download_progress(dynamic raw);
 
+  @protected
+  double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+  @protected
+  OptimizationReport? dco_decode_opt_box_autoadd_optimization_report(
+      dynamic raw);
+
   @protected
   BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
   @protected
   BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
 
+  @protected
+  OptimizationLevel dco_decode_optimization_level(dynamic raw);
+
+  @protected
+  OptimizationReport dco_decode_optimization_report(dynamic raw);
+
+  @protected
+  PredictOptions dco_decode_predict_options(dynamic raw);
+
+  @protected
+  Priority dco_decode_priority(dynamic raw);
+
   @protected
   (String, String) dco_decode_record_string_string(dynamic raw);
 
@@ -129,9 +219,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SessionInfo dco_decode_session_info(dynamic raw);
 
+  @protected
+  SessionStats dco_decode_session_stats(dynamic raw);
+
   @protected
   TensorSpec dco_decode_tensor_spec(dynamic raw);
 
+  @protected
+  TranscriptSegment dco_decode_transcript_segment(dynamic raw);
+
+  @protected
+  TranscriptionResult dco_decode_transcription_result(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
   @protected
   BigInt dco_decode_u_64(dynamic raw);
 
@@ -144,10 +246,17 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt dco_decode_usize(dynamic raw);
 
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
   @protected
   Map<String, String> sse_decode_Map_String_String_None(
       SseDeserializer deserializer);
 
+  @protected
+  RustStreamSink<GenerationEvent> sse_decode_StreamSink_generation_event_Sse(
+      SseDeserializer deserializer);
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
@@ -158,10 +267,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   DownloadProgress sse_decode_box_autoadd_download_progress(
       SseDeserializer deserializer);
 
+  @protected
+  double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  GenerationOptions sse_decode_box_autoadd_generation_options(
+      SseDeserializer deserializer);
+
   @protected
   InferenceInput sse_decode_box_autoadd_inference_input(
       SseDeserializer deserializer);
 
+  @protected
+  OptimizationReport sse_decode_box_autoadd_optimization_report(
+      SseDeserializer deserializer);
+
+  @protected
+  PredictOptions sse_decode_box_autoadd_predict_options(
+      SseDeserializer deserializer);
+
   @protected
   SessionConfig sse_decode_box_autoadd_session_config(
       SseDeserializer deserializer);
@@ -172,9 +296,21 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
 
+  @protected
+  ChatMessage sse_decode_chat_message(SseDeserializer deserializer);
+
+  @protected
+  ChatRole sse_decode_chat_role(SseDeserializer deserializer);
+
   @protected
   DataType sse_decode_data_type(SseDeserializer deserializer);
 
+  @protected
+  DeviceInfo sse_decode_device_info(SseDeserializer deserializer);
+
+  @protected
+  DeviceSelection sse_decode_device_selection(SseDeserializer deserializer);
+
   @protected
   DownloadPhase sse_decode_download_phase(SseDeserializer deserializer);
 
@@ -187,6 +323,16 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   double sse_decode_f_64(SseDeserializer deserializer);
 
+  @protected
+  GenerationEvent sse_decode_generation_event(SseDeserializer deserializer);
+
+  @protected
+  GenerationOptions sse_decode_generation_options(SseDeserializer deserializer);
+
+  @protected
+  GenerationStopReason sse_decode_generation_stop_reason(
+      SseDeserializer deserializer);
+
   @protected
   int sse_decode_i_32(SseDeserializer deserializer);
 
@@ -202,6 +348,12 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<String> sse_decode_list_String(SseDeserializer deserializer);
 
+  @protected
+  List<ChatMessage> sse_decode_list_chat_message(SseDeserializer deserializer);
+
+  @protected
+  List<DeviceInfo> sse_decode_list_device_info(SseDeserializer deserializer);
+
   @protected
   List<InferenceInput> sse_decode_list_inference_input(
       SseDeserializer deserializer);
@@ -214,10 +366,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   List<Float64List> sse_decode_list_list_prim_f_64_strict(
       SseDeserializer deserializer);
 
+  @protected
+  List<NamedInput> sse_decode_list_named_input(SseDeserializer deserializer);
+
+  @protected
+  List<NamedResult> sse_decode_list_named_result(SseDeserializer deserializer);
+
   @protected
   List<BigInt?> sse_decode_list_opt_box_autoadd_usize(
       SseDeserializer deserializer);
 
+  @protected
+  List<double> sse_decode_list_prim_f_32_loose(SseDeserializer deserializer);
+
   @protected
   Float32List sse_decode_list_prim_f_32_strict(SseDeserializer deserializer);
 
@@ -240,6 +401,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   List<TensorSpec> sse_decode_list_tensor_spec(SseDeserializer deserializer);
 
+  @protected
+  List<TranscriptSegment> sse_decode_list_transcript_segment(
+      SseDeserializer deserializer);
+
+  @protected
+  LoadMode sse_decode_load_mode(SseDeserializer deserializer);
+
+  @protected
+  NamedInput sse_decode_named_input(SseDeserializer deserializer);
+
+  @protected
+  NamedResult sse_decode_named_result(SseDeserializer deserializer);
+
   @protected
   String? sse_decode_opt_String(SseDeserializer deserializer);
 
@@ -247,12 +421,32 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   DownloadProgress? sse_decode_opt_box_autoadd_download_progress(
       SseDeserializer deserializer);
 
+  @protected
+  double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+  @protected
+  OptimizationReport? sse_decode_opt_box_autoadd_optimization_report(
+      SseDeserializer deserializer);
+
   @protected
   BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
 
   @protected
   BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
 
+  @protected
+  OptimizationLevel sse_decode_optimization_level(SseDeserializer deserializer);
+
+  @protected
+  OptimizationReport sse_decode_optimization_report(
+      SseDeserializer deserializer);
+
+  @protected
+  PredictOptions sse_decode_predict_options(SseDeserializer deserializer);
+
+  @protected
+  Priority sse_decode_priority(SseDeserializer deserializer);
+
   @protected
   (String, String) sse_decode_record_string_string(
       SseDeserializer deserializer);
@@ -263,9 +457,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   SessionInfo sse_decode_session_info(SseDeserializer deserializer);
 
+  @protected
+  SessionStats sse_decode_session_stats(SseDeserializer deserializer);
+
   @protected
   TensorSpec sse_decode_tensor_spec(SseDeserializer deserializer);
 
+  @protected
+  TranscriptSegment sse_decode_transcript_segment(SseDeserializer deserializer);
+
+  @protected
+  TranscriptionResult sse_decode_transcription_result(
+      SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
   @protected
   BigInt sse_decode_u_64(SseDeserializer deserializer);
 
@@ -278,10 +485,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   BigInt sse_decode_usize(SseDeserializer deserializer);
 
+  @protected
+  void sse_encode_AnyhowException(
+      AnyhowException self, SseSerializer serializer);
+
   @protected
   void sse_encode_Map_String_String_None(
       Map<String, String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_StreamSink_generation_event_Sse(
+      RustStreamSink<GenerationEvent> self, SseSerializer serializer);
+
   @protected
   void sse_encode_String(String self, SseSerializer serializer);
 
@@ -292,10 +507,25 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_box_autoadd_download_progress(
       DownloadProgress self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_generation_options(
+      GenerationOptions self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_inference_input(
       InferenceInput self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_box_autoadd_optimization_report(
+      OptimizationReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_predict_options(
+      PredictOptions self, SseSerializer serializer);
+
   @protected
   void sse_encode_box_autoadd_session_config(
       SessionConfig self, SseSerializer serializer);
@@ -306,9 +536,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_chat_message(ChatMessage self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_chat_role(ChatRole self, SseSerializer serializer);
+
   @protected
   void sse_encode_data_type(DataType self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_device_info(DeviceInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_device_selection(
+      DeviceSelection self, SseSerializer serializer);
+
   @protected
   void sse_encode_download_phase(DownloadPhase self, SseSerializer serializer);
 
@@ -322,6 +565,18 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_f_64(double self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_generation_event(
+      GenerationEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_generation_options(
+      GenerationOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_generation_stop_reason(
+      GenerationStopReason self, SseSerializer serializer);
+
   @protected
   void sse_encode_i_32(int self, SseSerializer serializer);
 
@@ -340,6 +595,14 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_list_String(List<String> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_chat_message(
+      List<ChatMessage> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_device_info(
+      List<DeviceInfo> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_inference_input(
       List<InferenceInput> self, SseSerializer serializer);
@@ -352,10 +615,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_list_prim_f_64_strict(
       List<Float64List> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_named_input(
+      List<NamedInput> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_named_result(
+      List<NamedResult> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_opt_box_autoadd_usize(
       List<BigInt?> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_prim_f_32_loose(
+      List<double> self, SseSerializer serializer);
+
   @protected
   void sse_encode_list_prim_f_32_strict(
       Float32List self, SseSerializer serializer);
@@ -383,6 +658,19 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_list_tensor_spec(
       List<TensorSpec> self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_list_transcript_segment(
+      List<TranscriptSegment> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_load_mode(LoadMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_named_input(NamedInput self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_named_result(NamedResult self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_String(String? self, SseSerializer serializer);
 
@@ -390,12 +678,34 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   void sse_encode_opt_box_autoadd_download_progress(
       DownloadProgress? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_optimization_report(
+      OptimizationReport? self, SseSerializer serializer);
+
   @protected
   void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
 
   @protected
   void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_optimization_level(
+      OptimizationLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_optimization_report(
+      OptimizationReport self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_predict_options(
+      PredictOptions self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_priority(Priority self, SseSerializer serializer);
+
   @protected
   void sse_encode_record_string_string(
       (String, String) self, SseSerializer serializer);
@@ -406,9 +716,23 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   void sse_encode_session_info(SessionInfo self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_session_stats(SessionStats self, SseSerializer serializer);
+
   @protected
   void sse_encode_tensor_spec(TensorSpec self, SseSerializer serializer);
 
+  @protected
+  void sse_encode_transcript_segment(
+      TranscriptSegment self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_transcription_result(
+      TranscriptionResult self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
   @protected
   void sse_encode_u_64(BigInt self, SseSerializer serializer);
 
@@ -0,0 +1,78 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'device.freezed.dart';
+
+/// Capability information for a compute device
+class DeviceInfo {
+  /// Selection that targets this device
+  final DeviceSelection device;
+
+  /// Human-readable device name
+  final String name;
+
+  /// Whether sessions can be created on this device
+  final bool available;
+
+  /// Why the device can't be used, when unavailable
+  final String? unavailableReason;
+
+  /// Whether half-precision (f16) weights are supported
+  final bool supportsF16;
+
+  /// Whether bfloat16 weights are supported
+  final bool supportsBf16;
+
+  const DeviceInfo({
+    required this.device,
+    required this.name,
+    required this.available,
+    this.unavailableReason,
+    required this.supportsF16,
+    required this.supportsBf16,
+  });
+
+  @override
+  int get hashCode =>
+      device.hashCode ^
+      name.hashCode ^
+      available.hashCode ^
+      unavailableReason.hashCode ^
+      supportsF16.hashCode ^
+      supportsBf16.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is DeviceInfo &&
+          runtimeType == other.runtimeType &&
+          device == other.device &&
+          name == other.name &&
+          available == other.available &&
+          unavailableReason == other.unavailableReason &&
+          supportsF16 == other.supportsF16 &&
+          supportsBf16 == other.supportsBf16;
+}
+
+@freezed
+sealed class DeviceSelection with _$DeviceSelection {
+  const DeviceSelection._();
+
+  /// Best available accelerator, otherwise the CPU
+  const factory DeviceSelection.auto() = DeviceSelection_Auto;
+  /// Always run on the CPU
+  const factory DeviceSelection.cpu() = DeviceSelection_Cpu;
+  /// NVIDIA GPU with the given ordinal
+  const factory DeviceSelection.cuda({
+    required BigInt ordinal,
+  }) = DeviceSelection_Cuda;
+  /// Apple GPU with the given ordinal
+  const factory DeviceSelection.metal({
+    required BigInt ordinal,
+  }) = DeviceSelection_Metal;
+}
@@ -0,0 +1,415 @@
+// GENERATED CODE - DO NOT MODIFY BY HAND
+// coverage:ignore-file
+// ignore_for_file: type=lint
+// ignore_for_file: unused_element, deprecated_member_use, deprecated_member_use_from_same_package, use_function_type_syntax_for_parameters, unnecessary_const, avoid_init_to_null, invalid_override_different_default_values_named, prefer_expression_function_bodies, annotate_overrides, invalid_annotation_target, unnecessary_question_mark
+
+part of 'device.dart';
+
+// **************************************************************************
+// FreezedGenerator
+// **************************************************************************
+
+// dart format off
+T _$identity<T>(T value) => value;
+
+/// @nodoc
+mixin _$DeviceSelection {
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is DeviceSelection);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  String toString() {
+    return 'DeviceSelection()';
+  }
+}
+
+/// @nodoc
+class $DeviceSelectionCopyWith<$Res> {
+  $DeviceSelectionCopyWith(
+      DeviceSelection _, $Res Function(DeviceSelection) __);
+}
+
+/// Adds pattern-matching-related methods to [DeviceSelection].
+extension DeviceSelectionPatterns on DeviceSelection {
+  /// A variant of `map` that fallback to returning `orElse`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeMap<TResult extends Object?>({
+    TResult Function(DeviceSelection_Auto value)? auto,
+    TResult Function(DeviceSelection_Cpu value)? cpu,
+    TResult Function(DeviceSelection_Cuda value)? cuda,
+    TResult Function(DeviceSelection_Metal value)? metal,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceSelection_Auto() when auto != null:
+        return auto(_that);
+      case DeviceSelection_Cpu() when cpu != null:
+        return cpu(_that);
+      case DeviceSelection_Cuda() when cuda != null:
+        return cuda(_that);
+      case DeviceSelection_Metal() when metal != null:
+        return metal(_that);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// Callbacks receives the raw object, upcasted.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case final Subclass2 value:
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult map<TResult extends Object?>({
+    required TResult Function(DeviceSelection_Auto value) auto,
+    required TResult Function(DeviceSelection_Cpu value) cpu,
+    required TResult Function(DeviceSelection_Cuda value) cuda,
+    required TResult Function(DeviceSelection_Metal value) metal,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceSelection_Auto():
+        return auto(_that);
+      case DeviceSelection_Cpu():
+        return cpu(_that);
+      case DeviceSelection_Cuda():
+        return cuda(_that);
+      case DeviceSelection_Metal():
+        return metal(_that);
+    }
+  }
+
+  /// A variant of `map` that fallback to returning `null`.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case final Subclass value:
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? mapOrNull<TResult extends Object?>({
+    TResult? Function(DeviceSelection_Auto value)? auto,
+    TResult? Function(DeviceSelection_Cpu value)? cpu,
+    TResult? Function(DeviceSelection_Cuda value)? cuda,
+    TResult? Function(DeviceSelection_Metal value)? metal,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceSelection_Auto() when auto != null:
+        return auto(_that);
+      case DeviceSelection_Cpu() when cpu != null:
+        return cpu(_that);
+      case DeviceSelection_Cuda() when cuda != null:
+        return cuda(_that);
+      case DeviceSelection_Metal() when metal != null:
+        return metal(_that);
+      case _:
+        return null;
+    }
+  }
+
+  /// A variant of `when` that fallback to an `orElse` callback.
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return orElse();
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult maybeWhen<TResult extends Object?>({
+    TResult Function()? auto,
+    TResult Function()? cpu,
+    TResult Function(BigInt ordinal)? cuda,
+    TResult Function(BigInt ordinal)? metal,
+    required TResult orElse(),
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceSelection_Auto() when auto != null:
+        return auto();
+      case DeviceSelection_Cpu() when cpu != null:
+        return cpu();
+      case DeviceSelection_Cuda() when cuda != null:
+        return cuda(_that.ordinal);
+      case DeviceSelection_Metal() when metal != null:
+        return metal(_that.ordinal);
+      case _:
+        return orElse();
+    }
+  }
+
+  /// A `switch`-like method, using callbacks.
+  ///
+  /// As opposed to `map`, this offers destructuring.
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case Subclass2(:final field2):
+  ///     return ...;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult when<TResult extends Object?>({
+    required TResult Function() auto,
+    required TResult Function() cpu,
+    required TResult Function(BigInt ordinal) cuda,
+    required TResult Function(BigInt ordinal) metal,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceSelection_Auto():
+        return auto();
+      case DeviceSelection_Cpu():
+        return cpu();
+      case DeviceSelection_Cuda():
+        return cuda(_that.ordinal);
+      case DeviceSelection_Metal():
+        return metal(_that.ordinal);
+    }
+  }
+
+  /// A variant of `when` that fallback to returning `null`
+  ///
+  /// It is equivalent to doing:
+  /// ```dart
+  /// switch (sealedClass) {
+  ///   case Subclass(:final field):
+  ///     return ...;
+  ///   case _:
+  ///     return null;
+  /// }
+  /// ```
+
+  @optionalTypeArgs
+  TResult? whenOrNull<TResult extends Object?>({
+    TResult? Function()? auto,
+    TResult? Function()? cpu,
+    TResult? Function(BigInt ordinal)? cuda,
+    TResult? Function(BigInt ordinal)? metal,
+  }) {
+    final _that = this;
+    switch (_that) {
+      case DeviceSelection_Auto() when auto != null:
+        return auto();
+      case DeviceSelection_Cpu() when cpu != null:
+        return cpu();
+      case DeviceSelection_Cuda() when cuda != null:
+        return cuda(_that.ordinal);
+      case DeviceSelection_Metal() when metal != null:
+        return metal(_that.ordinal);
+      case _:
+        return null;
+    }
+  }
+}
+
+/// Best available accelerator, otherwise the CPU
+
+class DeviceSelection_Auto extends DeviceSelection {
+  const DeviceSelection_Auto() : super._();
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is DeviceSelection_Auto);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  String toString() {
+    return 'DeviceSelection.auto()';
+  }
+}
+
+/// Always run on the CPU
+
+class DeviceSelection_Cpu extends DeviceSelection {
+  const DeviceSelection_Cpu() : super._();
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType && other is DeviceSelection_Cpu);
+  }
+
+  @override
+  int get hashCode => runtimeType.hashCode;
+
+  @override
+  String toString() {
+    return 'DeviceSelection.cpu()';
+  }
+}
+
+/// NVIDIA GPU with the given ordinal
+
+class DeviceSelection_Cuda extends DeviceSelection {
+  const DeviceSelection_Cuda({required this.ordinal}) : super._();
+
+  final BigInt ordinal;
+
+  /// Create a copy of DeviceSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceSelection_CudaCopyWith<DeviceSelection_Cuda> get copyWith =>
+      _$DeviceSelection_CudaCopyWithImpl<DeviceSelection_Cuda>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceSelection_Cuda &&
+            (identical(other.ordinal, ordinal) || other.ordinal == ordinal));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, ordinal);
+
+  @override
+  String toString() {
+    return 'DeviceSelection.cuda(ordinal: $ordinal)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceSelection_CudaCopyWith<$Res>
+    implements $DeviceSelectionCopyWith<$Res> {
+  factory $DeviceSelection_CudaCopyWith(DeviceSelection_Cuda value,
+          $Res Function(DeviceSelection_Cuda) _then) =
+      _$DeviceSelection_CudaCopyWithImpl;
+  @useResult
+  $Res call({BigInt ordinal});
+}
+
+/// @nodoc
+class _$DeviceSelection_CudaCopyWithImpl<$Res>
+    implements $DeviceSelection_CudaCopyWith<$Res> {
+  _$DeviceSelection_CudaCopyWithImpl(this._self, this._then);
+
+  final DeviceSelection_Cuda _self;
+  final $Res Function(DeviceSelection_Cuda) _then;
+
+  /// Create a copy of DeviceSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? ordinal = null,
+  }) {
+    return _then(DeviceSelection_Cuda(
+      ordinal: null == ordinal
+          ? _self.ordinal
+          : ordinal // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+/// Apple GPU with the given ordinal
+
+class DeviceSelection_Metal extends DeviceSelection {
+  const DeviceSelection_Metal({required this.ordinal}) : super._();
+
+  final BigInt ordinal;
+
+  /// Create a copy of DeviceSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $DeviceSelection_MetalCopyWith<DeviceSelection_Metal> get copyWith =>
+      _$DeviceSelection_MetalCopyWithImpl<DeviceSelection_Metal>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is DeviceSelection_Metal &&
+            (identical(other.ordinal, ordinal) || other.ordinal == ordinal));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, ordinal);
+
+  @override
+  String toString() {
+    return 'DeviceSelection.metal(ordinal: $ordinal)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $DeviceSelection_MetalCopyWith<$Res>
+    implements $DeviceSelectionCopyWith<$Res> {
+  factory $DeviceSelection_MetalCopyWith(DeviceSelection_Metal value,
+          $Res Function(DeviceSelection_Metal) _then) =
+      _$DeviceSelection_MetalCopyWithImpl;
+  @useResult
+  $Res call({BigInt ordinal});
+}
+
+/// @nodoc
+class _$DeviceSelection_MetalCopyWithImpl<$Res>
+    implements $DeviceSelection_MetalCopyWith<$Res> {
+  _$DeviceSelection_MetalCopyWithImpl(this._self, this._then);
+
+  final DeviceSelection_Metal _self;
+  final $Res Function(DeviceSelection_Metal) _then;
+
+  /// Create a copy of DeviceSelection
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? ordinal = null,
+  }) {
+    return _then(DeviceSelection_Metal(
+      ordinal: null == ordinal
+          ? _self.ordinal
+          : ordinal // ignore: cast_nullable_to_non_nullable
+              as BigInt,
+    ));
+  }
+}
+
+// dart format on
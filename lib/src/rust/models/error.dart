@@ -55,4 +55,13 @@ sealed class InferenceError with _$InferenceError implements FrbException {
   const factory InferenceError.formatDetection(
     String field0,
   ) = InferenceError_FormatDetection;
+  const factory InferenceError.cancelled(
+    String field0,
+  ) = InferenceError_Cancelled;
+  const factory InferenceError.deadlineExceeded(
+    String field0,
+  ) = InferenceError_DeadlineExceeded;
+  const factory InferenceError.queueFull(
+    String field0,
+  ) = InferenceError_QueueFull;
 }
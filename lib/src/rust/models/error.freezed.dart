@@ -64,6 +64,9 @@ extension InferenceErrorPatterns on InferenceError {
     TResult Function(InferenceError_ThreadPool value)? threadPool,
     TResult Function(InferenceError_Gpu value)? gpu,
     TResult Function(InferenceError_FormatDetection value)? formatDetection,
+    TResult Function(InferenceError_Cancelled value)? cancelled,
+    TResult Function(InferenceError_DeadlineExceeded value)? deadlineExceeded,
+    TResult Function(InferenceError_QueueFull value)? queueFull,
     required TResult orElse(),
   }) {
     final _that = this;
@@ -96,6 +99,12 @@ extension InferenceErrorPatterns on InferenceError {
         return gpu(_that);
       case InferenceError_FormatDetection() when formatDetection != null:
         return formatDetection(_that);
+      case InferenceError_Cancelled() when cancelled != null:
+        return cancelled(_that);
+      case InferenceError_DeadlineExceeded() when deadlineExceeded != null:
+        return deadlineExceeded(_that);
+      case InferenceError_QueueFull() when queueFull != null:
+        return queueFull(_that);
       case _:
         return orElse();
     }
@@ -135,6 +144,10 @@ extension InferenceErrorPatterns on InferenceError {
     required TResult Function(InferenceError_Gpu value) gpu,
     required TResult Function(InferenceError_FormatDetection value)
         formatDetection,
+    required TResult Function(InferenceError_Cancelled value) cancelled,
+    required TResult Function(InferenceError_DeadlineExceeded value)
+        deadlineExceeded,
+    required TResult Function(InferenceError_QueueFull value) queueFull,
   }) {
     final _that = this;
     switch (_that) {
@@ -166,6 +179,12 @@ extension InferenceErrorPatterns on InferenceError {
         return gpu(_that);
       case InferenceError_FormatDetection():
         return formatDetection(_that);
+      case InferenceError_Cancelled():
+        return cancelled(_that);
+      case InferenceError_DeadlineExceeded():
+        return deadlineExceeded(_that);
+      case InferenceError_QueueFull():
+        return queueFull(_that);
     }
   }
 
@@ -199,6 +218,9 @@ extension InferenceErrorPatterns on InferenceError {
     TResult? Function(InferenceError_ThreadPool value)? threadPool,
     TResult? Function(InferenceError_Gpu value)? gpu,
     TResult? Function(InferenceError_FormatDetection value)? formatDetection,
+    TResult? Function(InferenceError_Cancelled value)? cancelled,
+    TResult? Function(InferenceError_DeadlineExceeded value)? deadlineExceeded,
+    TResult? Function(InferenceError_QueueFull value)? queueFull,
   }) {
     final _that = this;
     switch (_that) {
@@ -230,6 +252,12 @@ extension InferenceErrorPatterns on InferenceError {
         return gpu(_that);
       case InferenceError_FormatDetection() when formatDetection != null:
         return formatDetection(_that);
+      case InferenceError_Cancelled() when cancelled != null:
+        return cancelled(_that);
+      case InferenceError_DeadlineExceeded() when deadlineExceeded != null:
+        return deadlineExceeded(_that);
+      case InferenceError_QueueFull() when queueFull != null:
+        return queueFull(_that);
       case _:
         return null;
     }
@@ -263,6 +291,9 @@ extension InferenceErrorPatterns on InferenceError {
     TResult Function(String field0)? threadPool,
     TResult Function(String field0)? gpu,
     TResult Function(String field0)? formatDetection,
+    TResult Function(String field0)? cancelled,
+    TResult Function(String field0)? deadlineExceeded,
+    TResult Function(String field0)? queueFull,
     required TResult orElse(),
   }) {
     final _that = this;
@@ -295,6 +326,12 @@ extension InferenceErrorPatterns on InferenceError {
         return gpu(_that.field0);
       case InferenceError_FormatDetection() when formatDetection != null:
         return formatDetection(_that.field0);
+      case InferenceError_Cancelled() when cancelled != null:
+        return cancelled(_that.field0);
+      case InferenceError_DeadlineExceeded() when deadlineExceeded != null:
+        return deadlineExceeded(_that.field0);
+      case InferenceError_QueueFull() when queueFull != null:
+        return queueFull(_that.field0);
       case _:
         return orElse();
     }
@@ -330,6 +367,9 @@ extension InferenceErrorPatterns on InferenceError {
     required TResult Function(String field0) threadPool,
     required TResult Function(String field0) gpu,
     required TResult Function(String field0) formatDetection,
+    required TResult Function(String field0) cancelled,
+    required TResult Function(String field0) deadlineExceeded,
+    required TResult Function(String field0) queueFull,
   }) {
     final _that = this;
     switch (_that) {
@@ -361,6 +401,12 @@ extension InferenceErrorPatterns on InferenceError {
         return gpu(_that.field0);
       case InferenceError_FormatDetection():
         return formatDetection(_that.field0);
+      case InferenceError_Cancelled():
+        return cancelled(_that.field0);
+      case InferenceError_DeadlineExceeded():
+        return deadlineExceeded(_that.field0);
+      case InferenceError_QueueFull():
+        return queueFull(_that.field0);
     }
   }
 
@@ -392,6 +438,9 @@ extension InferenceErrorPatterns on InferenceError {
     TResult? Function(String field0)? threadPool,
     TResult? Function(String field0)? gpu,
     TResult? Function(String field0)? formatDetection,
+    TResult? Function(String field0)? cancelled,
+    TResult? Function(String field0)? deadlineExceeded,
+    TResult? Function(String field0)? queueFull,
   }) {
     final _that = this;
     switch (_that) {
@@ -423,6 +472,12 @@ extension InferenceErrorPatterns on InferenceError {
         return gpu(_that.field0);
       case InferenceError_FormatDetection() when formatDetection != null:
         return formatDetection(_that.field0);
+      case InferenceError_Cancelled() when cancelled != null:
+        return cancelled(_that.field0);
+      case InferenceError_DeadlineExceeded() when deadlineExceeded != null:
+        return deadlineExceeded(_that.field0);
+      case InferenceError_QueueFull() when queueFull != null:
+        return queueFull(_that.field0);
       case _:
         return null;
     }
@@ -1357,4 +1412,200 @@ class _$InferenceError_FormatDetectionCopyWithImpl<$Res>
   }
 }
 
+/// @nodoc
+
+class InferenceError_Cancelled extends InferenceError {
+  const InferenceError_Cancelled(this.field0) : super._();
+
+  final String field0;
+
+  /// Create a copy of InferenceError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $InferenceError_CancelledCopyWith<InferenceError_Cancelled> get copyWith =>
+      _$InferenceError_CancelledCopyWithImpl<InferenceError_Cancelled>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is InferenceError_Cancelled &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'InferenceError.cancelled(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $InferenceError_CancelledCopyWith<$Res>
+    implements $InferenceErrorCopyWith<$Res> {
+  factory $InferenceError_CancelledCopyWith(InferenceError_Cancelled value,
+          $Res Function(InferenceError_Cancelled) _then) =
+      _$InferenceError_CancelledCopyWithImpl;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class _$InferenceError_CancelledCopyWithImpl<$Res>
+    implements $InferenceError_CancelledCopyWith<$Res> {
+  _$InferenceError_CancelledCopyWithImpl(this._self, this._then);
+
+  final InferenceError_Cancelled _self;
+  final $Res Function(InferenceError_Cancelled) _then;
+
+  /// Create a copy of InferenceError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(InferenceError_Cancelled(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class InferenceError_DeadlineExceeded extends InferenceError {
+  const InferenceError_DeadlineExceeded(this.field0) : super._();
+
+  final String field0;
+
+  /// Create a copy of InferenceError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $InferenceError_DeadlineExceededCopyWith<InferenceError_DeadlineExceeded>
+      get copyWith => _$InferenceError_DeadlineExceededCopyWithImpl<
+          InferenceError_DeadlineExceeded>(this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is InferenceError_DeadlineExceeded &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'InferenceError.deadlineExceeded(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $InferenceError_DeadlineExceededCopyWith<$Res>
+    implements $InferenceErrorCopyWith<$Res> {
+  factory $InferenceError_DeadlineExceededCopyWith(
+          InferenceError_DeadlineExceeded value,
+          $Res Function(InferenceError_DeadlineExceeded) _then) =
+      _$InferenceError_DeadlineExceededCopyWithImpl;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class _$InferenceError_DeadlineExceededCopyWithImpl<$Res>
+    implements $InferenceError_DeadlineExceededCopyWith<$Res> {
+  _$InferenceError_DeadlineExceededCopyWithImpl(this._self, this._then);
+
+  final InferenceError_DeadlineExceeded _self;
+  final $Res Function(InferenceError_DeadlineExceeded) _then;
+
+  /// Create a copy of InferenceError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(InferenceError_DeadlineExceeded(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
+/// @nodoc
+
+class InferenceError_QueueFull extends InferenceError {
+  const InferenceError_QueueFull(this.field0) : super._();
+
+  final String field0;
+
+  /// Create a copy of InferenceError
+  /// with the given fields replaced by the non-null parameter values.
+  @JsonKey(includeFromJson: false, includeToJson: false)
+  @pragma('vm:prefer-inline')
+  $InferenceError_QueueFullCopyWith<InferenceError_QueueFull> get copyWith =>
+      _$InferenceError_QueueFullCopyWithImpl<InferenceError_QueueFull>(
+          this, _$identity);
+
+  @override
+  bool operator ==(Object other) {
+    return identical(this, other) ||
+        (other.runtimeType == runtimeType &&
+            other is InferenceError_QueueFull &&
+            (identical(other.field0, field0) || other.field0 == field0));
+  }
+
+  @override
+  int get hashCode => Object.hash(runtimeType, field0);
+
+  @override
+  String toString() {
+    return 'InferenceError.queueFull(field0: $field0)';
+  }
+}
+
+/// @nodoc
+abstract mixin class $InferenceError_QueueFullCopyWith<$Res>
+    implements $InferenceErrorCopyWith<$Res> {
+  factory $InferenceError_QueueFullCopyWith(InferenceError_QueueFull value,
+          $Res Function(InferenceError_QueueFull) _then) =
+      _$InferenceError_QueueFullCopyWithImpl;
+  @useResult
+  $Res call({String field0});
+}
+
+/// @nodoc
+class _$InferenceError_QueueFullCopyWithImpl<$Res>
+    implements $InferenceError_QueueFullCopyWith<$Res> {
+  _$InferenceError_QueueFullCopyWithImpl(this._self, this._then);
+
+  final InferenceError_QueueFull _self;
+  final $Res Function(InferenceError_QueueFull) _then;
+
+  /// Create a copy of InferenceError
+  /// with the given fields replaced by the non-null parameter values.
+  @pragma('vm:prefer-inline')
+  $Res call({
+    Object? field0 = null,
+  }) {
+    return _then(InferenceError_QueueFull(
+      null == field0
+          ? _self.field0
+          : field0 // ignore: cast_nullable_to_non_nullable
+              as String,
+    ));
+  }
+}
+
 // dart format on
@@ -0,0 +1,83 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Load-time weight transform selected by `SessionConfig::optimization_level`
+enum OptimizationLevel {
+  /// Keep weights as loaded
+  none,
+
+  /// Cast floating-point weights to half precision
+  f16,
+
+  /// Cast floating-point weights to bfloat16
+  bf16,
+
+  /// Quantize Linear weights to 8-bit blocks (Q8_0)
+  int8,
+
+  /// Quantize Linear weights to 4-bit blocks (Q4_0)
+  q4,
+  ;
+}
+
+/// Outcome of converting a model's weights at load time
+class OptimizationReport {
+  /// Transform that was applied
+  final OptimizationLevel level;
+
+  /// Number of tensors that were cast or quantized
+  final BigInt tensorsConverted;
+
+  /// Number of eligible tensors left unchanged (e.g. shapes quantization can't handle)
+  final BigInt tensorsSkipped;
+
+  /// Size of the weights before conversion
+  final BigInt sizeBeforeBytes;
+
+  /// Size of the weights after conversion
+  final BigInt sizeAfterBytes;
+
+  /// Largest absolute output difference from the unconverted model on sample inputs
+  final double? maxAbsError;
+
+  /// Mean absolute output difference from the unconverted model on sample inputs
+  final double? meanAbsError;
+
+  const OptimizationReport({
+    required this.level,
+    required this.tensorsConverted,
+    required this.tensorsSkipped,
+    required this.sizeBeforeBytes,
+    required this.sizeAfterBytes,
+    this.maxAbsError,
+    this.meanAbsError,
+  });
+
+  @override
+  int get hashCode =>
+      level.hashCode ^
+      tensorsConverted.hashCode ^
+      tensorsSkipped.hashCode ^
+      sizeBeforeBytes.hashCode ^
+      sizeAfterBytes.hashCode ^
+      maxAbsError.hashCode ^
+      meanAbsError.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is OptimizationReport &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          tensorsConverted == other.tensorsConverted &&
+          tensorsSkipped == other.tensorsSkipped &&
+          sizeBeforeBytes == other.sizeBeforeBytes &&
+          sizeAfterBytes == other.sizeAfterBytes &&
+          maxAbsError == other.maxAbsError &&
+          meanAbsError == other.meanAbsError;
+}
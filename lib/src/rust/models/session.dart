@@ -0,0 +1,89 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// How model weights are materialized when a session is created
+enum LoadMode {
+  /// Load all weights immediately
+  eager,
+
+  /// Defer weight loading until the first prediction
+  lazy,
+
+  /// Load immediately, then run synthetic inputs built from the input specs
+  eagerWithWarmup,
+  ;
+}
+
+/// Request statistics for a session
+class SessionStats {
+  /// Predictions currently running
+  final BigInt activeRequests;
+
+  /// Interactive predictions waiting for a slot
+  final BigInt queuedInteractive;
+
+  /// Background predictions waiting for a slot
+  final BigInt queuedBackground;
+
+  /// Predictions that have finished
+  final BigInt completedRequests;
+
+  /// Predictions rejected because the queue was full
+  final BigInt rejectedRequests;
+
+  /// Predictions answered from the cache
+  final BigInt cacheHits;
+
+  /// Cache lookups that had to run the model
+  final BigInt cacheMisses;
+
+  /// Number of cached outputs
+  final BigInt cacheEntries;
+
+  /// Total size of cached inputs and outputs in bytes
+  final BigInt cacheBytes;
+
+  const SessionStats({
+    required this.activeRequests,
+    required this.queuedInteractive,
+    required this.queuedBackground,
+    required this.completedRequests,
+    required this.rejectedRequests,
+    required this.cacheHits,
+    required this.cacheMisses,
+    required this.cacheEntries,
+    required this.cacheBytes,
+  });
+
+  @override
+  int get hashCode =>
+      activeRequests.hashCode ^
+      queuedInteractive.hashCode ^
+      queuedBackground.hashCode ^
+      completedRequests.hashCode ^
+      rejectedRequests.hashCode ^
+      cacheHits.hashCode ^
+      cacheMisses.hashCode ^
+      cacheEntries.hashCode ^
+      cacheBytes.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SessionStats &&
+          runtimeType == other.runtimeType &&
+          activeRequests == other.activeRequests &&
+          queuedInteractive == other.queuedInteractive &&
+          queuedBackground == other.queuedBackground &&
+          completedRequests == other.completedRequests &&
+          rejectedRequests == other.rejectedRequests &&
+          cacheHits == other.cacheHits &&
+          cacheMisses == other.cacheMisses &&
+          cacheEntries == other.cacheEntries &&
+          cacheBytes == other.cacheBytes;
+}
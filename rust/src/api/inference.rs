@@ -1,8 +1,9 @@
-use crate::engines::{EngineFactory, EngineType, ModelFormat};
+use crate::engines::{Backend, EngineFactory, EngineType, ModelFormat};
 use crate::engines::factory::EngineConfig;
-use crate::models::{InferenceError, Tensor, DataType, TensorSpec};
-use crate::models::session::Session;
-use crate::utils::ModelDetector;
+use crate::models::{InferenceError, Tensor, DataType, TensorSpec, CancellationToken, PredictContext, Priority, SchedulerConfig, CacheConfig, DeviceInfo, DeviceSelection, OptimizationConfig, OptimizationLevel, OptimizationReport};
+use crate::models::session::{Session, SessionBuilder, SessionStats, LoadMode, LazyModel, ModelSource};
+use crate::utils::{ArchitectureDetection, ArchitectureDetector, ModelDetector};
+use crate::frb_generated::StreamSink;
 use flutter_rust_bridge::frb;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -263,9 +264,41 @@ async fn download_model_with_progress_tracking(
 /// Session handle for managing loaded models
 pub type SessionHandle = u64;
 
+/// Sessions are shared so requests can run without holding the map lock
+type SessionMap = HashMap<SessionHandle, Arc<Session>>;
+
 /// Global session storage
 static SESSION_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
-static SESSIONS: once_cell::sync::Lazy<Arc<RwLock<HashMap<SessionHandle, Session>>>> = 
+static SESSIONS: once_cell::sync::Lazy<Arc<RwLock<SessionMap>>> = 
+    once_cell::sync::Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
+/// Handle for a cancellation token shared with the caller
+pub type CancellationHandle = u64;
+
+/// Global cancellation token storage
+static CANCELLATION_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+static CANCELLATION_TOKENS: once_cell::sync::Lazy<Arc<RwLock<HashMap<CancellationHandle, CancellationToken>>>> = 
+    once_cell::sync::Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
+
+/// Handle for a multi-turn conversation
+pub type ConversationHandle = u64;
+
+/// A conversation and the session whose model it talks to
+#[cfg(feature = "candle")]
+struct ConversationEntry {
+    session_handle: SessionHandle,
+    conversation: crate::engines::candle_engine::chat::Conversation,
+}
+
+/// A conversation entry, locked for the duration of a turn
+#[cfg(feature = "candle")]
+type SharedConversation = Arc<tokio::sync::Mutex<ConversationEntry>>;
+
+/// Global conversation storage
+#[cfg(feature = "candle")]
+static CONVERSATION_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(1);
+#[cfg(feature = "candle")]
+static CONVERSATIONS: once_cell::sync::Lazy<Arc<RwLock<HashMap<ConversationHandle, SharedConversation>>>> = 
     once_cell::sync::Lazy::new(|| Arc::new(RwLock::new(HashMap::new())));
 
 /// Input data for inference
@@ -284,6 +317,20 @@ pub struct InferenceResult {
     pub data_type: String,
 }
 
+/// Input matched to a model input spec by name
+#[derive(Debug, Clone)]
+pub struct NamedInput {
+    pub name: String,
+    pub input: InferenceInput,
+}
+
+/// Model output named after its output spec
+#[derive(Debug, Clone)]
+pub struct NamedResult {
+    pub name: String,
+    pub result: InferenceResult,
+}
+
 // Using TensorSpec from models instead of separate TensorInfo
 
 /// Session information
@@ -293,6 +340,216 @@ pub struct SessionInfo {
     pub engine_type: String,
     pub input_specs: Vec<TensorSpec>,
     pub output_specs: Vec<TensorSpec>,
+    /// Whether the specs are still unknown because the model has not been
+    /// loaded yet (lazy sessions); they are empty until then
+    pub specs_pending: bool,
+    pub load_mode: LoadMode,
+    /// Whether the model weights have been materialized
+    pub is_loaded: bool,
+    /// Time spent loading weights, once loaded
+    pub load_time_ms: Option<u64>,
+    /// Time spent on warm-up inputs, if a warm-up was run
+    pub warmup_time_ms: Option<u64>,
+    /// Device the model runs on (e.g. "cpu", "cuda:0")
+    pub device: String,
+    /// Why the model runs on the CPU instead of the requested device
+    pub device_fallback_reason: Option<String>,
+    /// Why backends earlier in the fallback chain were abandoned, in order;
+    /// `engine_type` and `device` name the backend serving the session
+    pub fallback_reasons: Vec<String>,
+    /// How the weights were converted at load time, if an optimization level applied
+    pub optimization: Option<OptimizationReport>,
+}
+
+/// Per-call options for predictions
+#[derive(Debug, Clone, Default)]
+pub struct PredictOptions {
+    /// Token that can be cancelled with `cancel_prediction`
+    pub cancellation_token: Option<CancellationHandle>,
+    /// Maximum time the call may take before failing with `DeadlineExceeded`
+    pub timeout_ms: Option<u64>,
+    /// Queue priority when the session is at its concurrency limit
+    pub priority: Priority,
+}
+
+/// A timed span of transcribed speech
+#[derive(Debug, Clone)]
+pub struct TranscriptSegment {
+    pub start_seconds: f64,
+    pub end_seconds: f64,
+    pub text: String,
+    /// Mean log probability of the decoded tokens in the segment's window
+    pub avg_logprob: f64,
+    /// Probability that the segment's window contains no speech
+    pub no_speech_prob: f64,
+}
+
+/// Result from speech transcription
+#[derive(Debug, Clone)]
+pub struct TranscriptionResult {
+    pub text: String,
+    /// Language code that was detected (`None` for English-only models)
+    pub language: Option<String>,
+    pub segments: Vec<TranscriptSegment>,
+}
+
+/// Why a streamed generation stopped
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GenerationStopReason {
+    /// The model produced an end-of-sequence token
+    EndOfSequence,
+    /// `max_new_tokens` tokens were generated
+    MaxTokens,
+    /// The model's context window is full
+    ContextFull,
+    /// The completion reached one of the stop sequences
+    StopSequence,
+    /// Generation ran out of tokens or context before the grammar was
+    /// complete, so the text does not parse
+    GrammarIncomplete,
+}
+
+/// Decoding settings for text generation
+#[derive(Debug, Clone)]
+pub struct GenerationOptions {
+    pub max_new_tokens: usize,
+    /// Softmax temperature; `0.0` decodes greedily
+    pub temperature: f32,
+    pub top_k: Option<usize>,
+    pub top_p: Option<f32>,
+    pub min_p: Option<f32>,
+    /// `1.0` disables the repetition penalty
+    pub repetition_penalty: f32,
+    /// `0.0` disables the presence penalty
+    pub presence_penalty: f32,
+    /// How many recent tokens the penalties look at
+    pub penalty_last_n: usize,
+    /// Seed for reproducible sampling
+    pub seed: Option<u64>,
+    pub stop_sequences: Vec<String>,
+    /// GBNF grammar the completion must follow
+    pub grammar: Option<String>,
+    /// JSON schema (as JSON text) the completion must be valid against
+    pub json_schema: Option<String>,
+}
+
+impl Default for GenerationOptions {
+    fn default() -> Self {
+        Self {
+            max_new_tokens: 256,
+            temperature: 0.0,
+            top_k: None,
+            top_p: None,
+            min_p: None,
+            repetition_penalty: 1.0,
+            presence_penalty: 0.0,
+            penalty_last_n: 64,
+            seed: None,
+            stop_sequences: Vec::new(),
+            grammar: None,
+            json_schema: None,
+        }
+    }
+}
+
+#[cfg(feature = "candle")]
+impl GenerationOptions {
+    /// Convert to the engine's decoding settings, parsing any grammar or schema
+    fn into_config(self) -> Result<crate::engines::candle_engine::sampling::GenerationConfig, InferenceError> {
+        use crate::engines::candle_engine::{grammar::Grammar, sampling::GenerationConfig};
+        
+        let grammar = match (&self.grammar, &self.json_schema) {
+            (Some(_), Some(_)) => {
+                return Err(InferenceError::configuration("Set either grammar or json_schema, not both"));
+            }
+            (Some(gbnf), None) => Some(Grammar::parse(gbnf)?),
+            (None, Some(schema)) => {
+                let schema: serde_json::Value = serde_json::from_str(schema)
+                    .map_err(|e| InferenceError::configuration(format!("Invalid JSON schema: {}", e)))?;
+                Some(Grammar::from_json_schema(&schema)?)
+            }
+            (None, None) => None,
+        };
+        Ok(GenerationConfig {
+            max_new_tokens: self.max_new_tokens,
+            temperature: self.temperature,
+            top_k: self.top_k,
+            top_p: self.top_p,
+            min_p: self.min_p,
+            repetition_penalty: self.repetition_penalty,
+            presence_penalty: self.presence_penalty,
+            penalty_last_n: self.penalty_last_n,
+            seed: self.seed,
+            stop_sequences: self.stop_sequences,
+            grammar,
+        })
+    }
+}
+
+/// Author of a chat message
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ChatRole {
+    System,
+    User,
+    Assistant,
+}
+
+/// One turn of a chat
+#[derive(Debug, Clone)]
+pub struct ChatMessage {
+    pub role: ChatRole,
+    pub content: String,
+}
+
+#[cfg(feature = "candle")]
+impl From<ChatMessage> for crate::engines::candle_engine::chat::ChatMessage {
+    fn from(message: ChatMessage) -> Self {
+        use crate::engines::candle_engine::chat;
+        
+        let role = match message.role {
+            ChatRole::System => chat::ChatRole::System,
+            ChatRole::User => chat::ChatRole::User,
+            ChatRole::Assistant => chat::ChatRole::Assistant,
+        };
+        Self::new(role, message.content)
+    }
+}
+
+#[cfg(feature = "candle")]
+impl From<crate::engines::candle_engine::chat::ChatMessage> for ChatMessage {
+    fn from(message: crate::engines::candle_engine::chat::ChatMessage) -> Self {
+        use crate::engines::candle_engine::chat;
+        
+        let role = match message.role {
+            chat::ChatRole::System => ChatRole::System,
+            chat::ChatRole::User => ChatRole::User,
+            chat::ChatRole::Assistant => ChatRole::Assistant,
+        };
+        Self { role, content: message.content }
+    }
+}
+
+/// A message on a text generation stream
+#[derive(Debug, Clone)]
+pub enum GenerationEvent {
+    /// A newly generated token
+    Token {
+        /// Text this token adds to the completion (empty until a split character is complete)
+        text: String,
+        token_id: u32,
+        logprob: f32,
+    },
+    /// Generation finished; always the last message
+    Finished {
+        /// Full completion
+        text: String,
+        stop_reason: GenerationStopReason,
+        prompt_tokens: usize,
+        generated_tokens: usize,
+        /// Time from the call until the first token was produced
+        time_to_first_token_ms: Option<u64>,
+        total_time_ms: u64,
+    },
 }
 
 /// Configuration for inference sessions
@@ -300,8 +557,108 @@ pub struct SessionInfo {
 pub struct SessionConfig {
     pub engine_type: Option<String>,
     pub gpu_acceleration: bool,
+    /// Device to run on; `Auto` uses a GPU when `gpu_acceleration` is set and one is available
+    pub device: DeviceSelection,
+    /// Backends to try in order when loading or the first prediction fails,
+    /// written as `engine` or `engine@device` (e.g. "candle@cpu")
+    pub fallback_chain: Vec<String>,
     pub num_threads: Option<usize>,
+    /// Load-time weight conversion for Candle models: "none", "f16", "bf16", "int8" or "q4"
+    /// 
+    /// Applies to generic SafeTensors/PyTorch and Llama models; other models fail to load with a level.
     pub optimization_level: Option<String>,
+    pub load_mode: LoadMode,
+    /// Maximum number of concurrent predictions (unlimited when `None`)
+    pub max_concurrency: Option<usize>,
+    /// Maximum number of queued predictions before new ones are rejected (unlimited when `None`)
+    pub max_queue_depth: Option<usize>,
+    /// Maximum number of cached outputs; the cache is enabled when either cache limit is set
+    pub cache_max_entries: Option<usize>,
+    /// Maximum total size of cached inputs and outputs in bytes
+    pub cache_max_bytes: Option<usize>,
+    /// Layer graph (JSON) for generic SafeTensors and PyTorch weights; takes
+    /// the place of a `*.graph.json` next to the weights
+    pub model_graph: Option<String>,
+}
+
+impl SessionConfig {
+    /// Build the scheduler limits described by this configuration
+    fn scheduler_config(&self) -> SchedulerConfig {
+        let unbounded = SchedulerConfig::unbounded();
+        SchedulerConfig::new(
+            self.max_concurrency.unwrap_or(unbounded.max_concurrency),
+            self.max_queue_depth.unwrap_or(unbounded.max_queue_depth),
+        )
+    }
+    
+    /// Parse the fallback chain
+    fn fallback_backends(&self) -> Result<Vec<Backend>, InferenceError> {
+        self.fallback_chain.iter().map(|spec| Backend::parse(spec)).collect()
+    }
+    
+    /// Parse the optimization level
+    fn optimization_level(&self) -> Result<OptimizationLevel, InferenceError> {
+        match &self.optimization_level {
+            Some(name) => OptimizationLevel::from_name(name)
+                .ok_or_else(|| InferenceError::configuration(format!("Unknown optimization level: {}", name))),
+            None => Ok(OptimizationLevel::None),
+        }
+    }
+    
+    /// Build the engine configuration for a detected engine type
+    fn engine_config(&self, engine_type: EngineType) -> Result<EngineConfig, InferenceError> {
+        let config = EngineConfig::new()
+            .with_preferred_engine(engine_type)
+            .with_gpu_acceleration(self.gpu_acceleration)
+            .with_device(self.device)
+            .with_fallback_chain(self.fallback_backends()?)
+            .with_optimization(OptimizationConfig::new(self.optimization_level()?));
+        Ok(match &self.model_graph {
+            Some(graph) => config.with_model_graph(graph.clone()),
+            None => config,
+        })
+    }
+    
+    /// Create a session builder with an engine for `format`
+    /// 
+    /// When the engine can't be created and a fallback chain is configured,
+    /// the builder starts from the chain instead.
+    fn session_builder(&self, engine_config: &EngineConfig, format: ModelFormat) -> Result<SessionBuilder, InferenceError> {
+        let mut builder = SessionBuilder::new()
+            .with_device(self.device)
+            .with_fallback_chain(engine_config.fallback_chain.clone())
+            .with_load_mode(self.load_mode)
+            .with_scheduler_config(self.scheduler_config());
+        if let Some(cache_config) = self.cache_config() {
+            builder = builder.with_cache(cache_config);
+        }
+        if let Some(graph) = &engine_config.model_graph {
+            builder = builder.with_model_graph(graph.clone());
+        }
+        builder = builder.with_optimization(engine_config.optimization.clone());
+        
+        match engine_config.create_engine(format.clone()) {
+            Ok(engine) => Ok(builder.with_engine(engine)),
+            Err(e) if !engine_config.fallback_chain.is_empty() => {
+                let reason = format!("{} unavailable: {}", engine_config.primary_backend(format).name(), e);
+                println!("⚠️  {}", reason);
+                Ok(builder.with_fallback_reason(reason))
+            }
+            Err(e) => Err(e),
+        }
+    }
+    
+    /// Build the prediction cache limits, if caching is enabled
+    fn cache_config(&self) -> Option<CacheConfig> {
+        if self.cache_max_entries.is_none() && self.cache_max_bytes.is_none() {
+            return None;
+        }
+        
+        Some(CacheConfig::new(
+            self.cache_max_entries.unwrap_or(usize::MAX),
+            self.cache_max_bytes.unwrap_or(usize::MAX),
+        ))
+    }
 }
 
 impl Default for SessionConfig {
@@ -309,8 +666,16 @@ impl Default for SessionConfig {
         Self {
             engine_type: None,
             gpu_acceleration: true,
+            device: DeviceSelection::Auto,
+            fallback_chain: Vec::new(),
             num_threads: None,
             optimization_level: None,
+            load_mode: LoadMode::Eager,
+            max_concurrency: None,
+            max_queue_depth: None,
+            cache_max_entries: None,
+            cache_max_bytes: None,
+            model_graph: None,
         }
     }
 }
@@ -339,9 +704,7 @@ pub async fn load_model_with_config(
     };
     
     // Create engine configuration
-    let engine_config = EngineConfig::new()
-        .with_preferred_engine(engine_type)
-        .with_gpu_acceleration(config.gpu_acceleration);
+    let engine_config = config.engine_config(engine_type)?;
     println!("🦀 Rust: Created engine config: {:?}", engine_config);
     
     // Detect model format
@@ -357,10 +720,10 @@ pub async fn load_model_with_config(
     };
     
     // Create engine and load model
-    let engine = match engine_config.create_engine(format) {
-        Ok(e) => {
+    let builder = match config.session_builder(&engine_config, format) {
+        Ok(builder) => {
             println!("🦀 Rust: Created engine successfully");
-            e
+            builder
         }
         Err(e) => {
             println!("🦀 Rust: Failed to create engine: {:?}", e);
@@ -368,10 +731,11 @@ pub async fn load_model_with_config(
         }
     };
     
-    let model = match engine.load_model(&model_path).await {
-        Ok(m) => {
-            println!("🦀 Rust: Model loaded successfully");
-            m
+    // Load model and create session according to the load mode
+    let session = match builder.load_from_path(&model_path).await {
+        Ok(s) => {
+            println!("🦀 Rust: Created session ({:?})", config.load_mode);
+            s
         }
         Err(e) => {
             println!("🦀 Rust: Failed to load model: {:?}", e);
@@ -379,16 +743,12 @@ pub async fn load_model_with_config(
         }
     };
     
-    // Create session
-    let session = Session::new(model, engine_type);
-    println!("🦀 Rust: Created session");
-    
     // Store session and return info
     let handle = SESSION_COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
     let session_info = create_session_info(handle, &session);
     
     let mut sessions = SESSIONS.write().await;
-    sessions.insert(handle, session);
+    sessions.insert(handle, Arc::new(session));
     
     println!("🦀 Rust: Successfully stored session with handle: {}", handle);
     Ok(session_info)
@@ -407,9 +767,7 @@ pub async fn load_model_from_bytes(
     };
     
     // Create engine configuration
-    let engine_config = EngineConfig::new()
-        .with_preferred_engine(engine_type)
-        .with_gpu_acceleration(config.gpu_acceleration);
+    let engine_config = config.engine_config(engine_type.clone())?;
     
     // Determine model format based on engine type when explicitly specified
     let format = if config.engine_type.is_some() {
@@ -417,25 +775,25 @@ pub async fn load_model_from_bytes(
         match engine_type {
             EngineType::Candle => ModelFormat::SafeTensors, // Default for Candle
             EngineType::Linfa => ModelFormat::Linfa,        // Default for Linfa
+            EngineType::Onnx => ModelFormat::Onnx,
+            EngineType::Plugin(name) => ModelFormat::Plugin(name),
         }
     } else {
         // Only do content detection when engine type is not specified
         ModelDetector::detect_format_from_bytes(&model_bytes)?
     };
     
-    // Create engine and load model
-    let engine = engine_config.create_engine(format)?;
-    let model = engine.load_from_bytes(&model_bytes).await?;
-    
-    // Create session
-    let session = Session::new(model, engine_type);
+    // Create engine, load model and create session
+    let session = config.session_builder(&engine_config, format)?
+        .load_from_bytes(&model_bytes)
+        .await?;
     
     // Store session and return info
     let handle = SESSION_COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
     let session_info = create_session_info(handle, &session);
     
     let mut sessions = SESSIONS.write().await;
-    sessions.insert(handle, session);
+    sessions.insert(handle, Arc::new(session));
     
     Ok(session_info)
 }
@@ -515,7 +873,7 @@ pub async fn train_linfa_model(
         let session_info = create_session_info(handle, &session);
         
         let mut sessions = SESSIONS.write().await;
-        sessions.insert(handle, session);
+        sessions.insert(handle, Arc::new(session));
         
         Ok(session_info)
     }
@@ -532,15 +890,36 @@ pub async fn predict(
     session_handle: SessionHandle,
     input: InferenceInput,
 ) -> Result<InferenceResult, InferenceError> {
-    let sessions = SESSIONS.read().await;
-    let session = sessions.get(&session_handle)
-        .ok_or_else(|| InferenceError::model_load("Invalid session handle".to_string()))?;
+    predict_with_options(session_handle, input, PredictOptions::default()).await
+}
+
+/// Make a prediction that can be cancelled or bounded by a deadline
+pub async fn predict_with_options(
+    session_handle: SessionHandle,
+    input: InferenceInput,
+    options: PredictOptions,
+) -> Result<InferenceResult, InferenceError> {
+    let ctx = create_predict_context(&options).await?;
     
     // Convert input to tensor
     let input_tensor = Tensor::from_f32(input.data, input.shape)?;
     
-    // Make prediction
-    let output_tensor = session.predict(&input_tensor).await?;
+    // Make prediction, moving along the fallback chain if the first one fails
+    let output_tensor = loop {
+        let (result, generation) = {
+            let session = get_session(session_handle).await?;
+            let generation = session.backend_generation();
+            (session.predict_with_context(&input_tensor, &ctx).await, generation)
+        };
+        match result {
+            Ok(output) => break output,
+            Err(e) => {
+                if !fall_back_session(session_handle, generation, &e).await? {
+                    return Err(e);
+                }
+            }
+        }
+    };
     
     // Convert result
     Ok(InferenceResult {
@@ -550,14 +929,67 @@ pub async fn predict(
     })
 }
 
+/// Make a prediction from inputs matched to the model's input specs by name
+/// 
+/// Returns every model output. Each input is converted to its `data_type`,
+/// so token ids and attention masks can be passed as "i64".
+pub async fn predict_named(
+    session_handle: SessionHandle,
+    inputs: Vec<NamedInput>,
+) -> Result<Vec<NamedResult>, InferenceError> {
+    let input_tensors: Result<Vec<_>, _> = inputs.into_iter()
+        .map(|named| input_to_tensor(named.input).map(|tensor| (named.name, tensor)))
+        .collect();
+    let input_tensors = input_tensors?;
+    
+    // Make prediction, moving along the fallback chain if the first one fails
+    let outputs = loop {
+        let (result, generation) = {
+            let session = get_session(session_handle).await?;
+            let generation = session.backend_generation();
+            (session.predict_named(&input_tensors).await, generation)
+        };
+        match result {
+            Ok(outputs) => break outputs,
+            Err(e) => {
+                if !fall_back_session(session_handle, generation, &e).await? {
+                    return Err(e);
+                }
+            }
+        }
+    };
+    
+    outputs.into_iter()
+        .map(|(name, tensor)| {
+            Ok(NamedResult {
+                name,
+                result: InferenceResult {
+                    data: tensor.to_f32_vec()?,
+                    shape: tensor.shape().to_vec(),
+                    data_type: format!("{:?}", tensor.data_type()),
+                },
+            })
+        })
+        .collect()
+}
+
 /// Make batch predictions
 pub async fn predict_batch(
     session_handle: SessionHandle,
     inputs: Vec<InferenceInput>,
 ) -> Result<Vec<InferenceResult>, InferenceError> {
-    let sessions = SESSIONS.read().await;
-    let session = sessions.get(&session_handle)
-        .ok_or_else(|| InferenceError::model_load("Invalid session handle".to_string()))?;
+    predict_batch_with_options(session_handle, inputs, PredictOptions::default()).await
+}
+
+/// Make batch predictions that can be cancelled or bounded by a deadline
+/// 
+/// Cancellation and deadlines are checked between batch items.
+pub async fn predict_batch_with_options(
+    session_handle: SessionHandle,
+    inputs: Vec<InferenceInput>,
+    options: PredictOptions,
+) -> Result<Vec<InferenceResult>, InferenceError> {
+    let ctx = create_predict_context(&options).await?;
     
     // Convert inputs to tensors
     let input_tensors: Result<Vec<_>, _> = inputs.into_iter()
@@ -565,8 +997,22 @@ pub async fn predict_batch(
         .collect();
     let input_tensors = input_tensors?;
     
-    // Make predictions
-    let output_tensors = session.predict_batch(&input_tensors).await?;
+    // Make predictions, moving along the fallback chain if the first ones fail
+    let output_tensors = loop {
+        let (result, generation) = {
+            let session = get_session(session_handle).await?;
+            let generation = session.backend_generation();
+            (session.predict_batch_with_context(&input_tensors, &ctx).await, generation)
+        };
+        match result {
+            Ok(outputs) => break outputs,
+            Err(e) => {
+                if !fall_back_session(session_handle, generation, &e).await? {
+                    return Err(e);
+                }
+            }
+        }
+    };
     
     // Convert results
     let results: Result<Vec<_>, _> = output_tensors.into_iter()
@@ -583,13 +1029,427 @@ pub async fn predict_batch(
     Ok(results)
 }
 
+/// Transcribe mono PCM audio with a Whisper session
+/// 
+/// Samples are resampled to 16 kHz when needed. The spoken language is
+/// detected automatically and segments carry timestamps.
+pub async fn transcribe(
+    session_handle: SessionHandle,
+    samples: Vec<f32>,
+    sample_rate: u32,
+) -> Result<TranscriptionResult, InferenceError> {
+    #[cfg(feature = "candle")]
+    {
+        use crate::engines::candle_engine::whisper::{TranscriptionOptions, WhisperModelWrapper};
+        
+        let ctx = create_predict_context(&PredictOptions::default()).await?;
+        
+        let session = get_session(session_handle).await?;
+        
+        let _permit = session.acquire_slot(&ctx).await?;
+        let transcription = run_blocking(session, move |model| {
+            model.as_any()
+                .downcast_ref::<WhisperModelWrapper>()
+                .ok_or_else(|| InferenceError::unsupported_format("Session does not hold a Whisper model".to_string()))?
+                .transcribe(&samples, sample_rate, &TranscriptionOptions::default(), &ctx)
+        }).await?;
+        
+        Ok(TranscriptionResult {
+            text: transcription.text,
+            language: transcription.language,
+            segments: transcription.segments.into_iter()
+                .map(|segment| TranscriptSegment {
+                    start_seconds: segment.start,
+                    end_seconds: segment.end,
+                    text: segment.text,
+                    avg_logprob: segment.avg_logprob,
+                    no_speech_prob: segment.no_speech_prob,
+                })
+                .collect(),
+        })
+    }
+    
+    #[cfg(not(feature = "candle"))]
+    {
+        let _ = (session_handle, samples, sample_rate);
+        Err(InferenceError::unsupported_format("Transcription requires the candle feature".to_string()))
+    }
+}
+
+/// Stream a text completion from a Llama or Mistral session token by token
+/// 
+/// Each token is sent as a [`GenerationEvent::Token`], followed by a single
+/// [`GenerationEvent::Finished`]. Closing the stream on the Dart side cancels
+/// generation before the next token.
+pub async fn generate_stream(
+    session_handle: SessionHandle,
+    prompt: String,
+    generation: GenerationOptions,
+    options: PredictOptions,
+    sink: StreamSink<GenerationEvent>,
+) -> Result<(), InferenceError> {
+    #[cfg(feature = "candle")]
+    {
+        let config = generation.into_config()?;
+        stream_generation(session_handle, &options, &sink, move |model, ctx, on_token| {
+            model.generate_stream(&prompt, &config, ctx, on_token)
+        }).await
+    }
+    
+    #[cfg(not(feature = "candle"))]
+    {
+        let _ = (session_handle, prompt, generation, options, sink);
+        Err(InferenceError::unsupported_format("Text generation requires the candle feature".to_string()))
+    }
+}
+
+/// Render chat messages with the session model's chat template
+pub async fn apply_chat_template(
+    session_handle: SessionHandle,
+    messages: Vec<ChatMessage>,
+    add_generation_prompt: bool,
+) -> Result<String, InferenceError> {
+    #[cfg(feature = "candle")]
+    {
+        use crate::engines::candle_engine::as_text_generation;
+        
+        let messages: Vec<_> = messages.into_iter().map(Into::into).collect();
+        let session = get_session(session_handle).await?;
+        as_text_generation(session.loaded_model().await?)
+            .ok_or_else(|| InferenceError::unsupported_format("Session does not hold a text generation model".to_string()))?
+            .apply_chat_template(&messages, add_generation_prompt)
+    }
+    
+    #[cfg(not(feature = "candle"))]
+    {
+        let _ = (session_handle, messages, add_generation_prompt);
+        Err(InferenceError::unsupported_format("Chat templates require the candle feature".to_string()))
+    }
+}
+
+/// Stream the assistant's reply to a list of chat messages
+/// 
+/// The messages are rendered with the model's chat template; events are the
+/// same as for [`generate_stream`]. Nothing is kept between calls, use a
+/// conversation to reuse the KV cache across turns.
+pub async fn chat_stream(
+    session_handle: SessionHandle,
+    messages: Vec<ChatMessage>,
+    generation: GenerationOptions,
+    options: PredictOptions,
+    sink: StreamSink<GenerationEvent>,
+) -> Result<(), InferenceError> {
+    #[cfg(feature = "candle")]
+    {
+        let config = generation.into_config()?;
+        let messages: Vec<_> = messages.into_iter().map(Into::into).collect();
+        stream_generation(session_handle, &options, &sink, move |model, ctx, on_token| {
+            model.chat_stream(&messages, &config, ctx, on_token)
+        }).await
+    }
+    
+    #[cfg(not(feature = "candle"))]
+    {
+        let _ = (session_handle, messages, generation, options, sink);
+        Err(InferenceError::unsupported_format("Chat requires the candle feature".to_string()))
+    }
+}
+
+/// Start a multi-turn conversation with a Llama or Mistral session
+/// 
+/// Uses the model's chat template unless `chat_template` (Jinja source) is
+/// given. The conversation keeps the model's KV cache between turns, so each
+/// message only processes the new tokens.
+pub async fn create_conversation(
+    session_handle: SessionHandle,
+    system_prompt: Option<String>,
+    chat_template: Option<String>,
+) -> Result<ConversationHandle, InferenceError> {
+    #[cfg(feature = "candle")]
+    {
+        use crate::engines::candle_engine::{as_text_generation, chat::{ChatTemplate, Conversation}};
+        
+        let model_template = {
+            let session = get_session(session_handle).await?;
+            as_text_generation(session.loaded_model().await?)
+                .ok_or_else(|| InferenceError::unsupported_format("Session does not hold a text generation model".to_string()))?
+                .chat_template()?
+        };
+        
+        let template = match (chat_template, model_template) {
+            (Some(source), Some(model)) => {
+                ChatTemplate::new(source).with_special_tokens(model.bos_token(), model.eos_token())
+            }
+            (Some(source), None) => ChatTemplate::new(source),
+            (None, Some(model)) => model,
+            (None, None) => {
+                return Err(InferenceError::configuration("Model has no chat template; pass one explicitly"));
+            }
+        };
+        let mut conversation = Conversation::new(template);
+        if let Some(prompt) = system_prompt {
+            conversation = conversation.with_system_prompt(prompt);
+        }
+        
+        let handle = CONVERSATION_COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        let entry = ConversationEntry { session_handle, conversation };
+        CONVERSATIONS.write().await.insert(handle, Arc::new(tokio::sync::Mutex::new(entry)));
+        Ok(handle)
+    }
+    
+    #[cfg(not(feature = "candle"))]
+    {
+        let _ = (session_handle, system_prompt, chat_template);
+        Err(InferenceError::unsupported_format("Chat requires the candle feature".to_string()))
+    }
+}
+
+/// Send a user message to a conversation and stream the reply
+/// 
+/// Events are the same as for [`generate_stream`]. The reply is added to the
+/// conversation once generation finishes; if it fails, the message is dropped.
+pub async fn send_message_stream(
+    conversation: ConversationHandle,
+    content: String,
+    generation: GenerationOptions,
+    options: PredictOptions,
+    sink: StreamSink<GenerationEvent>,
+) -> Result<(), InferenceError> {
+    #[cfg(feature = "candle")]
+    {
+        let config = generation.into_config()?;
+        let entry = CONVERSATIONS.read().await.get(&conversation).cloned()
+            .ok_or_else(|| InferenceError::resource_not_found(format!("Unknown conversation: {}", conversation)))?;
+        // The owned guard moves to the generation thread and keeps other turns out
+        let mut entry = entry.lock_owned().await;
+        let session_handle = entry.session_handle;
+        stream_generation(session_handle, &options, &sink, move |model, ctx, on_token| {
+            entry.conversation.send_stream(model, &content, &config, ctx, on_token)
+        }).await
+    }
+    
+    #[cfg(not(feature = "candle"))]
+    {
+        let _ = (conversation, content, generation, options, sink);
+        Err(InferenceError::unsupported_format("Chat requires the candle feature".to_string()))
+    }
+}
+
+/// Get the messages exchanged in a conversation so far
+pub async fn get_conversation_messages(conversation: ConversationHandle) -> Result<Vec<ChatMessage>, InferenceError> {
+    #[cfg(feature = "candle")]
+    {
+        let entry = CONVERSATIONS.read().await.get(&conversation).cloned()
+            .ok_or_else(|| InferenceError::resource_not_found(format!("Unknown conversation: {}", conversation)))?;
+        let entry = entry.lock().await;
+        Ok(entry.conversation.messages().iter().cloned().map(Into::into).collect())
+    }
+    
+    #[cfg(not(feature = "candle"))]
+    {
+        let _ = conversation;
+        Err(InferenceError::unsupported_format("Chat requires the candle feature".to_string()))
+    }
+}
+
+/// Dispose a conversation and free its history
+pub async fn dispose_conversation(conversation: ConversationHandle) -> Result<(), InferenceError> {
+    #[cfg(feature = "candle")]
+    CONVERSATIONS.write().await.remove(&conversation);
+    #[cfg(not(feature = "candle"))]
+    let _ = conversation;
+    Ok(())
+}
+
+/// Run a generation on a session's text model and report it on `sink`
+/// 
+/// `run` receives the model, the prediction context and the token callback,
+/// and runs on the blocking thread pool. A closed stream cancels generation
+/// and is not reported as an error.
+#[cfg(feature = "candle")]
+async fn stream_generation<F>(
+    session_handle: SessionHandle,
+    options: &PredictOptions,
+    sink: &StreamSink<GenerationEvent>,
+    run: F,
+) -> Result<(), InferenceError>
+where
+    F: FnOnce(
+        &dyn crate::engines::candle_engine::generation::TextGeneration,
+        &PredictContext,
+        &mut dyn FnMut(crate::engines::candle_engine::generation::GeneratedToken) -> Result<(), InferenceError>,
+    ) -> Result<crate::engines::candle_engine::generation::GenerationOutput, InferenceError> + Send + 'static,
+{
+    let ctx = create_predict_context(options).await?;
+    
+    let session = get_session(session_handle).await?;
+    
+    let _permit = session.acquire_slot(&ctx).await?;
+    let sink = sink.clone();
+    run_blocking(session, move |model| report_generation(model, &ctx, &sink, run)).await
+}
+
+/// Run `run` on a text model, sending its tokens and final summary to `sink`
+#[cfg(feature = "candle")]
+fn report_generation<F>(
+    model: &dyn crate::engines::Model,
+    ctx: &PredictContext,
+    sink: &StreamSink<GenerationEvent>,
+    run: F,
+) -> Result<(), InferenceError>
+where
+    F: FnOnce(
+        &dyn crate::engines::candle_engine::generation::TextGeneration,
+        &PredictContext,
+        &mut dyn FnMut(crate::engines::candle_engine::generation::GeneratedToken) -> Result<(), InferenceError>,
+    ) -> Result<crate::engines::candle_engine::generation::GenerationOutput, InferenceError>,
+{
+    use crate::engines::candle_engine::{as_text_generation, generation::StopReason};
+    
+    let generator = as_text_generation(model)
+        .ok_or_else(|| InferenceError::unsupported_format("Session does not hold a text generation model".to_string()))?;
+    
+    let started = std::time::Instant::now();
+    let mut first_token = None;
+    let mut stream_closed = false;
+    let result = run(generator, ctx, &mut |token| {
+        first_token.get_or_insert_with(|| started.elapsed());
+        sink.add(GenerationEvent::Token {
+            text: token.text,
+            token_id: token.token_id,
+            logprob: token.logprob,
+        }).map_err(|_| {
+            stream_closed = true;
+            InferenceError::cancelled("Generation stream was closed")
+        })
+    });
+    
+    let output = match result {
+        Ok(output) => output,
+        // Nobody is listening any more, so there is nothing left to report
+        Err(_) if stream_closed => return Ok(()),
+        Err(e) => return Err(e),
+    };
+    
+    let stop_reason = match output.stop_reason {
+        StopReason::EndOfSequence => GenerationStopReason::EndOfSequence,
+        StopReason::MaxTokens => GenerationStopReason::MaxTokens,
+        StopReason::ContextFull => GenerationStopReason::ContextFull,
+        StopReason::StopSequence => GenerationStopReason::StopSequence,
+        StopReason::GrammarIncomplete => GenerationStopReason::GrammarIncomplete,
+    };
+    // The stream may have closed after the last token; that is not an error
+    let _ = sink.add(GenerationEvent::Finished {
+        generated_tokens: output.token_ids.len(),
+        text: output.text,
+        stop_reason,
+        prompt_tokens: output.prompt_tokens,
+        time_to_first_token_ms: first_token.map(|d| d.as_millis() as u64),
+        total_time_ms: started.elapsed().as_millis() as u64,
+    });
+    Ok(())
+}
+
+/// Run long inference on the session's model from the blocking thread pool
+/// 
+/// Transcription and generation take seconds of CPU time and would stall
+/// the async runtime if run inline. The model is loaded first so `run` can
+/// borrow it.
+#[cfg(feature = "candle")]
+async fn run_blocking<T, F>(session: Arc<Session>, run: F) -> Result<T, InferenceError>
+where
+    T: Send + 'static,
+    F: FnOnce(&dyn crate::engines::Model) -> Result<T, InferenceError> + Send + 'static,
+{
+    session.loaded_model().await?;
+    tokio::task::spawn_blocking(move || {
+        let model = session.try_loaded_model()
+            .ok_or_else(|| InferenceError::model_load("Session model is not loaded"))?;
+        run(model)
+    })
+    .await
+    .map_err(|e| InferenceError::prediction(format!("Inference task failed: {}", e)))?
+}
+
+/// Create a cancellation token for use with `predict_with_options`
+pub async fn create_cancellation_token() -> CancellationHandle {
+    let handle = CANCELLATION_COUNTER.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+    let mut tokens = CANCELLATION_TOKENS.write().await;
+    tokens.insert(handle, CancellationToken::new());
+    handle
+}
+
+/// Cancel every prediction using the given token
+pub async fn cancel_prediction(token: CancellationHandle) -> Result<(), InferenceError> {
+    let tokens = CANCELLATION_TOKENS.read().await;
+    tokens.get(&token)
+        .ok_or_else(|| InferenceError::resource_not_found(format!("Unknown cancellation token: {}", token)))?
+        .cancel();
+    Ok(())
+}
+
+/// Release a cancellation token once it is no longer needed
+pub async fn release_cancellation_token(token: CancellationHandle) -> Result<(), InferenceError> {
+    let mut tokens = CANCELLATION_TOKENS.write().await;
+    tokens.remove(&token);
+    Ok(())
+}
+
 /// Get session information
 pub async fn get_session_info(session_handle: SessionHandle) -> Result<SessionInfo, InferenceError> {
-    let sessions = SESSIONS.read().await;
-    let session = sessions.get(&session_handle)
-        .ok_or_else(|| InferenceError::model_load("Invalid session handle".to_string()))?;
+    let session = get_session(session_handle).await?;
+    
+    Ok(create_session_info(session_handle, &session))
+}
+
+/// Get request statistics for a session
+pub async fn get_session_stats(session_handle: SessionHandle) -> Result<SessionStats, InferenceError> {
+    let session = get_session(session_handle).await?;
     
-    Ok(create_session_info(session_handle, session))
+    Ok(session.stats())
+}
+
+/// Reload a session's model from its original file
+/// 
+/// The session keeps its configuration: the device, weight conversion, model
+/// graph and load mode are applied again, so lazy sessions defer the reload
+/// to their next prediction and warmed-up sessions are warmed up again. Cached
+/// predictions are invalidated. The previous model keeps serving requests
+/// until the new one has finished loading.
+pub async fn reload_session(session_handle: SessionHandle) -> Result<SessionInfo, InferenceError> {
+    let session = get_session(session_handle).await?;
+    let (model_path, engine_type, device) = {
+        let model_path = session.metadata()
+            .and_then(|metadata| metadata.model_path.clone())
+            .ok_or_else(|| InferenceError::configuration("Session was not loaded from a file and cannot be reloaded"))?;
+        let device = session.metadata()
+            .and_then(|metadata| metadata.device.as_deref())
+            .and_then(DeviceSelection::from_name)
+            .unwrap_or_default();
+        (model_path, session.engine_type(), device)
+    };
+    
+    // Reload on the device the session is already using, converting weights the same way
+    let mut engine = EngineFactory::create_engine_on_device(engine_type, device)?;
+    engine.set_optimization(session.optimization().clone())?;
+    if let Some(graph) = session.model_graph() {
+        engine.set_model_graph(graph)?;
+    }
+    let start = std::time::Instant::now();
+    let model = match session.load_mode() {
+        LoadMode::Lazy => Box::new(LazyModel::new(engine, ModelSource::Path(model_path))),
+        LoadMode::Eager | LoadMode::EagerWithWarmup => engine.load_model(&model_path).await?,
+    };
+    let load_time = start.elapsed();
+    
+    let mut replacement = session.with_replaced_model(model, load_time);
+    if session.load_mode() == LoadMode::EagerWithWarmup {
+        replacement.warm_up().await?;
+    }
+    let replacement = Arc::new(replacement);
+    let info = create_session_info(session_handle, &replacement);
+    replace_session(session_handle, &session, replacement).await?;
+    Ok(info)
 }
 
 /// Dispose of a session and free resources
@@ -600,14 +1460,31 @@ pub async fn dispose_session(session_handle: SessionHandle) -> Result<(), Infere
 }
 
 /// Get list of available engines
+/// 
+/// Includes engines registered through the `EngineRegistry`.
 #[frb(sync)]
 pub fn get_available_engines() -> Vec<String> {
     EngineFactory::available_engines()
         .into_iter()
-        .map(|engine| format!("{:?}", engine).to_lowercase())
+        .map(|engine| engine.name())
         .collect()
 }
 
+/// List compute devices with their capabilities
+/// 
+/// Unavailable devices are included with the reason they can't be used.
+#[frb(sync)]
+pub fn list_devices() -> Vec<DeviceInfo> {
+    #[cfg(feature = "candle")]
+    {
+        crate::engines::candle_engine::CandleEngine::list_devices()
+    }
+    #[cfg(not(feature = "candle"))]
+    {
+        vec![DeviceInfo::cpu()]
+    }
+}
+
 /// Check if a specific engine is available
 #[frb(sync)]
 pub fn is_engine_available(engine_type: String) -> bool {
@@ -622,14 +1499,14 @@ pub fn is_engine_available(engine_type: String) -> bool {
 #[frb(sync)]
 pub fn detect_engine_from_path(model_path: String) -> String {
     let engine_type = ModelDetector::detect_engine_from_path(&model_path);
-    format!("{:?}", engine_type).to_lowercase()
+    engine_type.name()
 }
 
 /// Detect engine type from bytes
 #[frb(sync)]
 pub fn detect_engine_from_bytes(model_bytes: Vec<u8>) -> Result<String, InferenceError> {
     let engine_type = ModelDetector::detect_engine_from_bytes(&model_bytes)?;
-    Ok(format!("{:?}", engine_type).to_lowercase())
+    Ok(engine_type.name())
 }
 
 /// Load a model from a URL with caching
@@ -638,23 +1515,31 @@ pub async fn load_model_from_url(
     cache: bool,
     cache_key: Option<String>,
 ) -> Result<SessionInfo, InferenceError> {
-    let model_bytes = if cache {
-        // Try to load from cache first
-        let key = cache_key.unwrap_or_else(|| url_to_cache_key(&url));
-        if let Some(cached_bytes) = load_from_cache(&key).await? {
-            cached_bytes
-        } else {
+    if !cache {
+        // Direct download without caching
+        let model_bytes = download_model(&url).await?;
+        return load_model_from_bytes(model_bytes, SessionConfig::default()).await;
+    }
+    
+    // Try to load from cache first
+    let key = cache_key.unwrap_or_else(|| url_to_cache_key(&url));
+    let cache_path = match cached_model_path(&key)? {
+        Some(path) => path,
+        None => {
             // Download and cache
             let bytes = download_model(&url).await?;
             save_to_cache(&key, &bytes).await?;
-            bytes
+            match cached_model_path(&key)? {
+                Some(path) => path,
+                // Caching is best effort, so fall back to the downloaded bytes
+                None => return load_model_from_bytes(bytes, SessionConfig::default()).await,
+            }
         }
-    } else {
-        // Direct download without caching
-        download_model(&url).await?
     };
-
-    load_model_from_bytes(model_bytes, SessionConfig::default()).await
+    
+    // Load the cached file by path so SafeTensors weights are memory-mapped
+    // instead of being read into memory first
+    load_model_with_config(cache_path.to_string_lossy().into_owned(), SessionConfig::default()).await
 }
 
 /// Load a model from local file path
@@ -677,30 +1562,25 @@ pub async fn load_from_huggingface(
     #[cfg(feature = "candle")]
     {
         use crate::engines::{candle_engine::CandleEngine, EngineType};
-        use crate::models::{ModelConfig, ModelArchitecture, ResNetVariant};
+        use crate::models::ModelConfig;
         
-        // Detect model type from repository name (simple heuristic)
-        let architecture = if repo.contains("bert") || repo.contains("BERT") {
-            ModelArchitecture::Bert
-        } else if repo.contains("resnet") || repo.contains("ResNet") {
-            ModelArchitecture::ResNet { variant: ResNetVariant::ResNet50 }
-        } else {
-            // Default to BERT for unknown models
-            ModelArchitecture::Bert
-        };
+        // An unknown architecture is reported as is: loading the file as a
+        // generic model instead would hide what config.json declared
+        let detection = detect_repo_architecture(&repo, &filename).await?;
+        println!("🔍 Detected {:?} with {:.0}% confidence ({})",
+            detection.architecture, detection.confidence * 100.0, detection.evidence);
         
-        // Create engine and load model
+        // Try to load with hf-hub first, then fallback to URL download
         let engine = CandleEngine::new()?;
-        let config = ModelConfig::new(architecture)
+        let config = ModelConfig::new(detection.architecture)
             .with_repo_id(&repo)
             .with_filename(&filename)
             .with_revision(&revision);
-        
-        // Try to load with hf-hub first, then fallback to URL download
-        let model = match engine.load_from_huggingface(&config).await {
+        let download_id = format!("{}_{}", repo, revision);
+        let model = match load_with_shard_progress(&engine, &config, &download_id).await {
             Ok(model) => model,
             Err(e) => {
-                println!("⚠️  HuggingFace Hub download failed: {}", e);
+                println!("⚠️  HuggingFace Hub loading failed: {}", e);
                 println!("🔄 Falling back to direct URL download...");
                 
                 // Fallback to URL-based loading with progress reporting
@@ -710,7 +1590,6 @@ pub async fn load_from_huggingface(
                 );
                 
                 println!("📡 Downloading from URL: {}", url);
-                let cache_key = format!("hf_{}_{}/{}", repo.replace('/', "_"), revision, filename);
                 
                 // Download and load from URL with progress
                 let model_bytes = download_model(&url).await?;
@@ -724,7 +1603,7 @@ pub async fn load_from_huggingface(
         let handle = {
             let mut sessions = SESSIONS.write().await;
             let handle = sessions.len() as SessionHandle;
-            sessions.insert(handle, session);
+            sessions.insert(handle, Arc::new(session));
             handle
         };
         
@@ -747,6 +1626,94 @@ pub async fn load_from_huggingface(
 }
 
 
+/// Detect the architecture of a HuggingFace repository
+/// 
+/// Uses `config.json` when the repository has one, and otherwise the tensor
+/// names of the SafeTensors weights (or of their shard index).
+#[cfg(feature = "candle")]
+async fn detect_repo_architecture(repo: &str, filename: &str) -> Result<ArchitectureDetection, InferenceError> {
+    use crate::engines::candle_engine::model_wrappers::download_from_huggingface;
+    use crate::engines::candle_engine::sharding::{index_filename, is_index_filename, ShardIndex};
+    
+    match download_from_huggingface(repo, "config.json").await {
+        Ok(config_path) => {
+            let config = std::fs::read_to_string(&config_path)
+                .map_err(|e| InferenceError::model_load(format!("Failed to read config.json: {}", e)))?;
+            let config: serde_json::Value = serde_json::from_str(&config)
+                .map_err(|e| InferenceError::model_load(format!("Failed to parse config.json: {}", e)))?;
+            return ArchitectureDetector::detect_from_config(&config);
+        }
+        Err(InferenceError::ResourceNotFound(_)) => {}
+        Err(e) => return Err(e),
+    }
+    
+    if !filename.ends_with(".safetensors") && !is_index_filename(filename) {
+        return Err(InferenceError::unsupported_format(format!(
+            "Cannot detect the architecture of {}: no config.json and {} is not a SafeTensors file", repo, filename
+        )));
+    }
+    if !is_index_filename(filename) {
+        match download_from_huggingface(repo, filename).await {
+            Ok(path) => return ArchitectureDetector::detect_from_safetensors_file(&path),
+            Err(InferenceError::ResourceNotFound(_)) => {}
+            Err(e) => return Err(e),
+        }
+    }
+    let index_name = if is_index_filename(filename) { filename.to_string() } else { index_filename(filename) };
+    let index = ShardIndex::from_file(&download_from_huggingface(repo, &index_name).await?)?;
+    ArchitectureDetector::detect_from_tensor_names(index.tensor_names())
+}
+
+/// Load from HuggingFace while publishing combined shard progress under `download_id`
+#[cfg(feature = "candle")]
+async fn load_with_shard_progress(
+    engine: &crate::engines::candle_engine::CandleEngine,
+    config: &crate::models::ModelConfig,
+    download_id: &str,
+) -> Result<Box<dyn crate::engines::Model>, InferenceError> {
+    use crate::engines::candle_engine::sharding::ShardProgress;
+    
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<ShardProgress>();
+    let id = download_id.to_string();
+    let publisher = tokio::spawn(async move {
+        while let Some(progress) = rx.recv().await {
+            DOWNLOAD_PROGRESS.write().await.insert(id.clone(), DownloadProgress {
+                total_bytes: progress.total_bytes,
+                downloaded_bytes: progress.downloaded_bytes,
+                percentage: progress.percentage(),
+                phase: DownloadPhase::Downloading,
+                message: Some(format!("Downloading {} ({} of {} files done)",
+                    progress.filename, progress.completed_shards, progress.total_shards)),
+            });
+        }
+    });
+    
+    let result = engine.load_from_huggingface_with_progress(config, &mut |progress| {
+        let _ = tx.send(progress.clone());
+    }).await;
+    drop(tx);
+    let _ = publisher.await;
+    
+    let mut progress_map = DOWNLOAD_PROGRESS.write().await;
+    let existing = progress_map.get(download_id).cloned();
+    progress_map.insert(download_id.to_string(), match &result {
+        Ok(_) => DownloadProgress {
+            total_bytes: existing.as_ref().and_then(|p| p.total_bytes),
+            downloaded_bytes: existing.as_ref().map(|p| p.downloaded_bytes).unwrap_or(0),
+            percentage: 100.0,
+            phase: DownloadPhase::Completed,
+            message: Some("Download completed successfully!".to_string()),
+        },
+        Err(e) => DownloadProgress {
+            total_bytes: None,
+            downloaded_bytes: 0,
+            percentage: 0.0,
+            phase: DownloadPhase::Failed,
+            message: Some(e.to_string()),
+        },
+    });
+    result
+}
 
 /// Create a progress stream for model download
 async fn download_model_progress_stream(
@@ -1129,22 +2096,12 @@ fn get_cache_dir() -> Result<PathBuf, InferenceError> {
     Ok(cache_dir)
 }
 
-/// Load model from cache
-async fn load_from_cache(cache_key: &str) -> Result<Option<Vec<u8>>, InferenceError> {
+/// Path of the cached model stored under `cache_key`, if there is one
+fn cached_model_path(cache_key: &str) -> Result<Option<PathBuf>, InferenceError> {
     let cache_dir = get_cache_dir()?;
     let cache_path = cache_dir.join(format!("{}.bin", cache_key));
 
-    if cache_path.exists() {
-        match tokio::fs::read(&cache_path).await {
-            Ok(bytes) => Ok(Some(bytes)),
-            Err(e) => {
-                eprintln!("Warning: Failed to read from cache: {}", e);
-                Ok(None)
-            }
-        }
-    } else {
-        Ok(None)
-    }
+    Ok(cache_path.is_file().then_some(cache_path))
 }
 
 /// Save model to cache
@@ -1215,25 +2172,99 @@ pub async fn get_cache_size() -> Result<u64, InferenceError> {
 fn create_session_info(handle: SessionHandle, session: &Session) -> SessionInfo {
     let input_specs = session.input_specs().to_vec();
     let output_specs = session.output_specs().to_vec();
+    let metadata = session.metadata();
     
     SessionInfo {
         handle,
-        engine_type: format!("{:?}", session.engine_type()).to_lowercase(),
+        engine_type: session.engine_type().name(),
         input_specs,
         output_specs,
+        specs_pending: session.specs_pending(),
+        load_mode: session.load_mode(),
+        is_loaded: session.is_loaded(),
+        load_time_ms: session.load_time().map(|d| d.as_millis() as u64),
+        warmup_time_ms: session.warmup_time().map(|d| d.as_millis() as u64),
+        device: metadata.and_then(|m| m.device.clone()).unwrap_or_else(|| "cpu".to_string()),
+        device_fallback_reason: metadata.and_then(|m| m.device_fallback_reason.clone()),
+        fallback_reasons: session.fallback_reasons().to_vec(),
+        optimization: session.optimization_report().cloned(),
     }
 }
 
-/// Helper function to parse engine type from string
-fn parse_engine_type(engine_str: &str) -> Result<EngineType, InferenceError> {
-    match engine_str.to_lowercase().as_str() {
-        "candle" => Ok(EngineType::Candle),
-        "linfa" => Ok(EngineType::Linfa),
+/// Look up a session, cloning it out of the map so the lock is not held while it runs
+async fn get_session(session_handle: SessionHandle) -> Result<Arc<Session>, InferenceError> {
+    SESSIONS.read().await
+        .get(&session_handle)
+        .cloned()
+        .ok_or_else(|| InferenceError::model_load("Invalid session handle".to_string()))
+}
 
-        _ => Err(InferenceError::unsupported_format(
-            format!("Unknown engine type: {}", engine_str)
-        )),
+/// Swap in a replacement built from `current`
+/// 
+/// The map is only locked for the swap itself; requests already running on
+/// `current` finish on it. Fails if the session was disposed meanwhile.
+async fn replace_session(
+    session_handle: SessionHandle,
+    current: &Arc<Session>,
+    replacement: Arc<Session>,
+) -> Result<(), InferenceError> {
+    let mut sessions = SESSIONS.write().await;
+    match sessions.get_mut(&session_handle) {
+        Some(session) if Arc::ptr_eq(session, current) => {
+            *session = replacement;
+            Ok(())
+        }
+        Some(_) => Err(InferenceError::configuration("Session was replaced while reloading")),
+        None => Err(InferenceError::model_load("Invalid session handle".to_string())),
+    }
+}
+
+/// Move a session to its next fallback backend after a failed prediction
+/// 
+/// `generation` is the session's backend generation when the prediction
+/// started; if another request already moved the session on, nothing is
+/// changed. Returns whether the prediction should be retried.
+async fn fall_back_session(
+    session_handle: SessionHandle,
+    generation: u64,
+    error: &InferenceError,
+) -> Result<bool, InferenceError> {
+    let session = get_session(session_handle).await?;
+    
+    if session.backend_generation() != generation {
+        return Ok(true);
+    }
+    if !session.should_fall_back(error) {
+        return Ok(false);
+    }
+    let replacement = Arc::new(session.fall_back(error).await?);
+    replace_session(session_handle, &session, replacement).await?;
+    Ok(true)
+}
+
+/// Helper function to build a prediction context from call options
+async fn create_predict_context(options: &PredictOptions) -> Result<PredictContext, InferenceError> {
+    let mut ctx = PredictContext::new().with_priority(options.priority);
+    
+    if let Some(timeout_ms) = options.timeout_ms {
+        ctx = ctx.with_timeout(std::time::Duration::from_millis(timeout_ms));
     }
+    
+    if let Some(handle) = options.cancellation_token {
+        let tokens = CANCELLATION_TOKENS.read().await;
+        let token = tokens.get(&handle)
+            .ok_or_else(|| InferenceError::resource_not_found(format!("Unknown cancellation token: {}", handle)))?;
+        ctx = ctx.with_token(token.clone());
+    }
+    
+    Ok(ctx)
+}
+
+/// Helper function to parse engine type from string
+fn parse_engine_type(engine_str: &str) -> Result<EngineType, InferenceError> {
+    EngineType::from_name(engine_str).ok_or_else(|| InferenceError::unsupported_format(
+        format!("Unknown engine type: {}", engine_str)
+    ))
 }
 
 /// Helper function to parse data type from string
@@ -1251,8 +2282,149 @@ fn parse_data_type(data_type_str: &str) -> Result<DataType, InferenceError> {
     }
 }
 
+/// Convert an API input to a tensor of its declared data type
+/// 
+/// Values travel as f32, so integer inputs are rounded back to integers
+/// here; unsigned types are widened to i64.
+fn input_to_tensor(input: InferenceInput) -> Result<Tensor, InferenceError> {
+    let InferenceInput { data, shape, data_type } = input;
+    match parse_data_type(&data_type)? {
+        DataType::F64 => Tensor::from_f64(data.into_iter().map(f64::from).collect(), shape),
+        DataType::I32 => Tensor::from_i32(data.into_iter().map(|v| v.round() as i32).collect(), shape),
+        DataType::I64 | DataType::U8 | DataType::U32 => {
+            Tensor::from_i64(data.into_iter().map(|v| v.round() as i64).collect(), shape)
+        }
+        _ => Tensor::from_f32(data, shape),
+    }
+}
+
 /// Initialize the inference library
 #[frb(init)]
 pub fn init_inference() {
     flutter_rust_bridge::setup_default_user_utils();
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_load_model_with_config_from_cached_bin() {
+        use candle_core::{Device, Tensor as CandleTensor};
+        
+        let dir = std::env::temp_dir().join(format!("inference_api_cache_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let tensors: HashMap<String, CandleTensor> = [
+            ("dense.weight".to_string(), CandleTensor::new(&[[1.0f32, 0.0], [0.0, 1.0], [2.0, 3.0]], &Device::Cpu).unwrap()),
+        ].into_iter().collect();
+        // Cached downloads keep their original name, so the extension says nothing about the format
+        let path = dir.join("cached.bin");
+        candle_core::safetensors::save(&tensors, &path).unwrap();
+        
+        let config = SessionConfig {
+            model_graph: Some(r#"{
+                "input": {"name": "x", "shape": [null, 2]},
+                "output": {"name": "y", "shape": [null, 3]},
+                "layers": [{"type": "linear", "weight": "dense.weight"}]
+            }"#.to_string()),
+            ..SessionConfig::default()
+        };
+        let info = load_model_with_config(path.to_string_lossy().into_owned(), config).await.unwrap();
+        assert_eq!(info.engine_type, "candle");
+        assert!(info.is_loaded);
+        
+        let input = InferenceInput { data: vec![1.0, 2.0], shape: vec![1, 2], data_type: "f32".to_string() };
+        let result = predict(info.handle, input).await.unwrap();
+        assert_eq!(result.shape, vec![1, 3]);
+        assert_eq!(result.data, vec![1.0, 2.0, 8.0]);
+        
+        dispose_session(info.handle).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_reload_keeps_model_graph() {
+        use candle_core::{Device, Tensor as CandleTensor};
+        
+        let dir = std::env::temp_dir().join(format!("inference_api_reload_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let tensors: HashMap<String, CandleTensor> = [
+            ("fc.weight".to_string(), CandleTensor::new(&[[2.0f32, 0.0], [0.0, 3.0]], &Device::Cpu).unwrap()),
+        ].into_iter().collect();
+        let path = dir.join("model.safetensors");
+        candle_core::safetensors::save(&tensors, &path).unwrap();
+        
+        let input = || InferenceInput { data: vec![1.0, 1.0], shape: vec![1, 2], data_type: "f32".to_string() };
+        for load_mode in [LoadMode::Eager, LoadMode::Lazy] {
+            let config = SessionConfig {
+                load_mode,
+                model_graph: Some(r#"{
+                    "input": {"name": "x", "shape": [null, 2]},
+                    "output": {"name": "y", "shape": [null, 2]},
+                    "layers": [{"type": "linear", "weight": "fc.weight"}]
+                }"#.to_string()),
+                ..SessionConfig::default()
+            };
+            let info = load_model_with_config(path.to_string_lossy().into_owned(), config).await.unwrap();
+            assert_eq!(predict(info.handle, input()).await.unwrap().data, vec![2.0, 3.0]);
+            
+            // No `model.graph.json` exists, so the reloaded engine needs the configured graph
+            reload_session(info.handle).await.unwrap();
+            assert_eq!(predict(info.handle, input()).await.unwrap().data, vec![2.0, 3.0], "{:?}", load_mode);
+            dispose_session(info.handle).await.unwrap();
+        }
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_reload_keeps_optimization_of_unloaded_lazy_session() {
+        use candle_core::{Device, Tensor as CandleTensor};
+        
+        let dir = std::env::temp_dir().join(format!("inference_api_reload_opt_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let tensors: HashMap<String, CandleTensor> = [
+            ("fc.weight".to_string(), CandleTensor::new(&[[2.0f32, 0.0], [0.0, 3.0]], &Device::Cpu).unwrap()),
+        ].into_iter().collect();
+        let path = dir.join("model.safetensors");
+        candle_core::safetensors::save(&tensors, &path).unwrap();
+        std::fs::write(dir.join("model.graph.json"), r#"{
+            "input": {"name": "x", "shape": [null, 2]},
+            "output": {"name": "y", "shape": [null, 2]},
+            "layers": [{"type": "linear", "weight": "fc.weight"}]
+        }"#).unwrap();
+        
+        let config = SessionConfig {
+            load_mode: LoadMode::Lazy,
+            gpu_acceleration: false,
+            optimization_level: Some("f16".to_string()),
+            ..SessionConfig::default()
+        };
+        let info = load_model_with_config(path.to_string_lossy().into_owned(), config).await.unwrap();
+        assert!(get_session(info.handle).await.unwrap().optimization_report().is_none());
+        
+        // Reloading before the lazy model has loaded still converts the weights
+        reload_session(info.handle).await.unwrap();
+        let input = InferenceInput { data: vec![1.0, 1.0], shape: vec![1, 2], data_type: "f32".to_string() };
+        assert_eq!(predict(info.handle, input).await.unwrap().data, vec![2.0, 3.0]);
+        let session = get_session(info.handle).await.unwrap();
+        assert_eq!(session.optimization().level, OptimizationLevel::F16);
+        assert_eq!(session.optimization_report().unwrap().level, OptimizationLevel::F16);
+        
+        dispose_session(info.handle).await.unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_load_from_huggingface_reports_unknown_architecture() {
+        // Downloads only config.json; the weights must not be fetched as a generic model
+        let err = load_from_huggingface("openai-community/gpt2".to_string(), None, None).await.unwrap_err();
+        assert!(matches!(err, InferenceError::UnsupportedFormat(_)), "{}", err);
+        assert!(err.to_string().contains("GPT2LMHeadModel"));
+        assert!(err.to_string().contains("gpt2"));
+    }
+}
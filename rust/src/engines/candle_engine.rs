@@ -1,20 +1,69 @@
 use crate::engines::{InferenceEngine, Model, EngineType, ModelFormat};
-use crate::models::{InferenceError, Tensor, TensorSpec, DataType, ModelArchitecture, ModelConfig, ResNetVariant};
+use crate::models::{InferenceError, Tensor, TensorSpec, DataType, ModelArchitecture, ModelConfig, MobileNetVariant, ResNetVariant, DeviceInfo, DeviceSelection, OptimizationConfig, OptimizationLevel, OptimizationReport, PredictContext};
+use crate::utils::ModelDetector;
 use async_trait::async_trait;
 use std::path::Path;
 use std::any::Any;
 use std::collections::HashMap;
 
 #[cfg(feature = "candle")]
-use candle_core::{Device, Tensor as CandleTensor, DType};
+use candle_core::{Device, DeviceLocation, Tensor as CandleTensor, DType};
 
 #[cfg(feature = "candle")]
-use safetensors::SafeTensors;
+use safetensors::{tensor::TensorView, SafeTensors};
+#[cfg(feature = "candle")]
+use candle_core::safetensors::MmapedSafetensors;
+#[cfg(feature = "candle")]
+use candle_core::quantized::QMatMul;
+#[cfg(feature = "candle")]
+use candle_nn::VarBuilder;
+#[cfg(feature = "candle")]
+use std::sync::Arc;
 
 // Import real model wrappers
 pub mod model_wrappers;
+pub mod pytorch;
+pub mod gguf;
+#[cfg(feature = "candle")]
+pub mod mobilenet;
+#[cfg(feature = "candle")]
+pub mod resnet;
+#[cfg(feature = "candle")]
+pub mod generation;
+#[cfg(feature = "candle")]
+pub mod chat;
+#[cfg(feature = "candle")]
+pub mod sampling;
+#[cfg(feature = "candle")]
+pub mod grammar;
+#[cfg(feature = "candle")]
+pub mod json_schema;
+#[cfg(feature = "candle")]
+pub mod sharding;
+#[cfg(feature = "candle")]
+pub mod graph;
+#[cfg(feature = "candle")]
+pub mod llama;
+#[cfg(feature = "candle")]
+pub mod mistral;
+#[cfg(feature = "candle")]
+pub mod whisper;
+#[cfg(feature = "candle")]
+pub mod optimization;
+#[cfg(feature = "candle")]
+use model_wrappers::{BertModelWrapper, MobileNetModelWrapper, ResNetModelWrapper};
+#[cfg(feature = "candle")]
+use gguf::GgufModel;
+#[cfg(feature = "candle")]
+use llama::LlamaModelWrapper;
+#[cfg(feature = "candle")]
+use mistral::MistralModelWrapper;
+#[cfg(feature = "candle")]
+use whisper::WhisperModelWrapper;
+#[cfg(feature = "candle")]
+use sharding::ShardProgress;
 #[cfg(feature = "candle")]
-use model_wrappers::{BertModelWrapper, ResNetModelWrapper};
+use graph::{Graph, GraphSpec, WeightSource};
 
 /// Candle ML engine implementation
 /// 
@@ -23,12 +72,23 @@ use model_wrappers::{BertModelWrapper, ResNetModelWrapper};
 /// or by using Candle's built-in model architectures from candle-transformers.
 #[derive(Debug)]
 pub struct CandleEngine {
-    /// Device to use for computations (CPU or CUDA)
+    /// Device to use for computations (CPU, CUDA or Metal)
     device: Device,
     /// Whether GPU acceleration is available
     gpu_available: bool,
+    /// Why the requested device was replaced by the CPU
+    fallback_reason: Option<String>,
+    /// Weight conversion applied to generic SafeTensors and PyTorch models
+    optimization: OptimizationConfig,
+    /// Layer graph for generic models, used instead of a `*.graph.json` next to the weights
+    #[cfg(feature = "candle")]
+    graph: Option<GraphSpec>,
 }
 
+/// Highest device ordinal probed when listing GPUs
+#[cfg(any(feature = "candle-cuda", feature = "candle-metal"))]
+const MAX_PROBED_DEVICES: usize = 8;
+
 impl CandleEngine {
     /// Create a new Candle engine with automatic device detection
     pub fn new() -> Result<Self, InferenceError> {
@@ -38,6 +98,9 @@ impl CandleEngine {
             Ok(Self {
                 device,
                 gpu_available,
+                fallback_reason: None,
+                optimization: OptimizationConfig::default(),
+                graph: None,
             })
         }
         #[cfg(not(feature = "candle"))]
@@ -52,10 +115,13 @@ impl CandleEngine {
     pub fn with_device(device: Device) -> Result<Self, InferenceError> {
         #[cfg(feature = "candle")]
         {
-            let gpu_available = matches!(device, Device::Cuda(_));
+            let gpu_available = matches!(device, Device::Cuda(_) | Device::Metal(_));
             Ok(Self {
                 device,
                 gpu_available,
+                fallback_reason: None,
+                optimization: OptimizationConfig::default(),
+                graph: None,
             })
         }
         #[cfg(not(feature = "candle"))]
@@ -73,6 +139,9 @@ impl CandleEngine {
             Ok(Self {
                 device: Device::Cpu,
                 gpu_available: false,
+                fallback_reason: None,
+                optimization: OptimizationConfig::default(),
+                graph: None,
             })
         }
         #[cfg(not(feature = "candle"))]
@@ -84,28 +153,112 @@ impl CandleEngine {
     }
     
     /// Create a CUDA-enabled Candle engine
-    pub fn cuda(_device_id: usize) -> Result<Self, InferenceError> {
-        #[cfg(all(feature = "candle", feature = "candle-cuda"))]
-        {
-            let device = Device::new_cuda(_device_id)
-                .map_err(|e| InferenceError::gpu(format!("Failed to create CUDA device {}: {}", _device_id, e)))?;
-            Ok(Self {
-                device,
-                gpu_available: true,
-            })
+    pub fn cuda(device_id: usize) -> Result<Self, InferenceError> {
+        Self::with_device(Self::resolve_device(DeviceSelection::Cuda { ordinal: device_id })?)
+    }
+    
+    /// Create a Metal-enabled Candle engine
+    pub fn metal(device_id: usize) -> Result<Self, InferenceError> {
+        Self::with_device(Self::resolve_device(DeviceSelection::Metal { ordinal: device_id })?)
+    }
+    
+    /// Create a Candle engine on the selected device
+    /// 
+    /// Falls back to the CPU when the device is unavailable, recording the
+    /// reason in `device_fallback_reason`.
+    pub fn for_device(selection: DeviceSelection) -> Result<Self, InferenceError> {
+        match Self::resolve_device(selection) {
+            Ok(device) => Self::with_device(device),
+            Err(e) => {
+                println!("⚠️  {} unavailable, falling back to CPU: {}", selection.name(), e);
+                let mut engine = Self::cpu()?;
+                engine.fallback_reason = Some(format!("{} unavailable: {}", selection.name(), e));
+                Ok(engine)
+            }
+        }
+    }
+    
+    /// Convert weights of generic models while loading them
+    pub fn with_optimization(mut self, config: OptimizationConfig) -> Result<Self, InferenceError> {
+        self.set_optimization(config)?;
+        Ok(self)
+    }
+    
+    /// Create the Candle device for a selection
+    pub fn resolve_device(selection: DeviceSelection) -> Result<Device, InferenceError> {
+        match selection {
+            DeviceSelection::Auto => Ok(Self::detect_device()?.0),
+            DeviceSelection::Cpu => Ok(Device::Cpu),
+            DeviceSelection::Cuda { ordinal } => {
+                #[cfg(feature = "candle-cuda")]
+                {
+                    Device::new_cuda(ordinal)
+                        .map_err(|e| InferenceError::gpu(format!("Failed to create CUDA device {}: {}", ordinal, e)))
+                }
+                #[cfg(not(feature = "candle-cuda"))]
+                {
+                    let _ = ordinal;
+                    Err(InferenceError::configuration(
+                        "CUDA support not available - compile with 'candle-cuda' feature"
+                    ))
+                }
+            }
+            DeviceSelection::Metal { ordinal } => {
+                #[cfg(feature = "candle-metal")]
+                {
+                    Device::new_metal(ordinal)
+                        .map_err(|e| InferenceError::gpu(format!("Failed to create Metal device {}: {}", ordinal, e)))
+                }
+                #[cfg(not(feature = "candle-metal"))]
+                {
+                    let _ = ordinal;
+                    Err(InferenceError::configuration(
+                        "Metal support not available - compile with 'candle-metal' feature"
+                    ))
+                }
+            }
         }
-        #[cfg(all(feature = "candle", not(feature = "candle-cuda")))]
+    }
+    
+    /// List the CPU and the GPUs this build can use, with their capabilities
+    pub fn list_devices() -> Vec<DeviceInfo> {
+        let mut devices = vec![DeviceInfo::cpu()];
+        
+        #[cfg(feature = "candle-cuda")]
         {
-            Err(InferenceError::configuration(
-                "CUDA support not available - compile with 'candle-cuda' feature"
-            ))
+            let found = (0..MAX_PROBED_DEVICES)
+                .map_while(|ordinal| Device::new_cuda(ordinal).ok().map(|device| (ordinal, device)))
+                .map(|(ordinal, device)| DeviceInfo::available(
+                    DeviceSelection::Cuda { ordinal }, format!("CUDA:{}", ordinal), true, supports_bf16(&device)
+                ))
+                .collect::<Vec<_>>();
+            if found.is_empty() {
+                devices.push(DeviceInfo::unavailable(DeviceSelection::Cuda { ordinal: 0 }, "CUDA:0", "No CUDA device found"));
+            }
+            devices.extend(found);
         }
-        #[cfg(not(feature = "candle"))]
+        #[cfg(not(feature = "candle-cuda"))]
+        devices.push(DeviceInfo::unavailable(
+            DeviceSelection::Cuda { ordinal: 0 }, "CUDA:0", "Compile with 'candle-cuda' feature"
+        ));
+        
+        #[cfg(feature = "candle-metal")]
         {
-            Err(InferenceError::configuration(
-                "Candle engine not available - compile with 'candle' feature"
-            ))
+            let found = (0..MAX_PROBED_DEVICES)
+                .map_while(|ordinal| Device::new_metal(ordinal).ok().map(|_| ordinal))
+                .map(|ordinal| DeviceInfo::available(DeviceSelection::Metal { ordinal }, format!("Metal:{}", ordinal), true, false))
+                .collect::<Vec<_>>();
+            if found.is_empty() {
+                devices.push(DeviceInfo::unavailable(DeviceSelection::Metal { ordinal: 0 }, "Metal:0", "No Metal device found"));
+            }
+            devices.extend(found);
         }
+        #[cfg(not(feature = "candle-metal"))]
+        devices.push(DeviceInfo::unavailable(
+            DeviceSelection::Metal { ordinal: 0 }, "Metal:0", "Compile with 'candle-metal' feature"
+        ));
+        
+        devices
     }
     
     /// Detect the best available device
@@ -119,6 +272,14 @@ impl CandleEngine {
             }
         }
         
+        // Then Metal on Apple hardware
+        #[cfg(feature = "candle-metal")]
+        {
+            if let Ok(device) = Device::new_metal(0) {
+                return Ok((device, true));
+            }
+        }
+        
         // Fall back to CPU
         Ok((Device::Cpu, false))
     }
@@ -133,21 +294,63 @@ impl CandleEngine {
         self.gpu_available
     }
     
+    /// Get the weight conversion applied while loading
+    pub fn optimization(&self) -> &OptimizationConfig {
+        &self.optimization
+    }
+    
+    /// Graph for a generic model loaded from `path`: the configured one, else the file next to it
+    #[cfg(feature = "candle")]
+    fn graph_for(&self, path: &Path) -> Result<Option<GraphSpec>, InferenceError> {
+        match &self.graph {
+            Some(graph) => Ok(Some(graph.clone())),
+            None => GraphSpec::find_for(path),
+        }
+    }
+    
+    /// Reject an optimization level the models being loaded can't apply
+    /// 
+    /// Weights are converted for generic SafeTensors/PyTorch models and for
+    /// Llama; loading anything else with a level would silently ignore it.
+    fn require_no_optimization(&self, models: &str) -> Result<(), InferenceError> {
+        if self.optimization.level != OptimizationLevel::None {
+            return Err(InferenceError::configuration(format!(
+                "Optimization level {} can't be applied to {}", self.optimization.level.name(), models
+            )));
+        }
+        Ok(())
+    }
+    
     /// Load a SafeTensors model - provides raw tensor access
     /// 
     /// NOTE: This is a low-level interface that just loads the tensors.
     /// Users need to implement their own model architecture on top of this.
     /// For proper model architectures, users should use candle-transformers
     /// or implement their own model structures using these tensors.
+    /// 
+    /// The file is memory-mapped rather than read into a buffer and the model
+    /// keeps the mapping. No weights are copied out at load time: the graph
+    /// reads each layer's weights from the mapping as it runs and drops them
+    /// afterwards, so they are never held twice.
     #[cfg(feature = "candle")]
     async fn load_safetensors(&self, path: &str) -> Result<Box<dyn Model>, InferenceError> {
-        println!("🔧 Loading SafeTensors file: {}", path);
+        println!("🔧 Mapping SafeTensors file: {}", path);
         
-        // Read the SafeTensors file
-        let bytes = tokio::fs::read(path).await
-            .map_err(|e| InferenceError::model_load(format!("Failed to read SafeTensors file {}: {}", path, e)))?;
+        // SAFETY: the file is mapped read-only; the mapping lives in the
+        // returned model, and tensors are copied out of it when read
+        let mmaped = unsafe { MmapedSafetensors::new(path) }
+            .map_err(|e| InferenceError::model_load(format!("Failed to map SafeTensors file {}: {}", path, e)))?;
+        let mut shapes = HashMap::new();
+        for (name, view) in mmaped.tensors() {
+            shapes.insert(name, (view.shape().to_vec(), candle_dtype(view.dtype())?));
+        }
+        let mapped = MappedWeights {
+            weights: VarBuilder::from_backend(Box::new(mmaped), DType::F32, self.device.clone()),
+            shapes,
+        };
+        println!("📊 Mapped {} tensors, read from the file as layers run", mapped.shapes.len());
         
-        self.load_safetensors_from_bytes(&bytes).await
+        self.build_generic_model(Vec::new(), self.graph_for(Path::new(path))?, Some(Arc::new(mapped)))
     }
     
     /// Load a SafeTensors model from bytes
@@ -159,79 +362,156 @@ impl CandleEngine {
         let safetensors = SafeTensors::deserialize(bytes)
             .map_err(|e| InferenceError::model_load(format!("Failed to parse SafeTensors: {}", e)))?;
         
-        // Load all tensors into a HashMap
-        let mut tensors = HashMap::new();
-        let mut input_specs = Vec::new();
-        let mut output_specs = Vec::new();
-        
-        println!("📊 Found {} tensors in SafeTensors file", safetensors.tensors().len());
+        self.load_tensor_views(safetensors.tensors(), self.graph.clone())
+    }
+    
+    /// Copy parsed SafeTensors views into Candle tensors and wrap them in a generic model
+    #[cfg(feature = "candle")]
+    fn load_tensor_views(&self, views: Vec<(String, TensorView<'_>)>, graph: Option<GraphSpec>) -> Result<Box<dyn Model>, InferenceError> {
+        println!("📊 Found {} tensors in SafeTensors file", views.len());
         
-        for (name, info) in safetensors.tensors() {
-            let tensor_data = safetensors.tensor(&name)
-                .map_err(|e| InferenceError::model_load(format!("Failed to get tensor {}: {}", name, e)))?;
-            
-            // Convert safetensors dtype to candle dtype
-            let dtype = match info.dtype() {
-                safetensors::Dtype::F32 => DType::F32,
-                safetensors::Dtype::F64 => DType::F64,
-                safetensors::Dtype::I32 => DType::I64, // Map to closest available
-                safetensors::Dtype::I64 => DType::I64,
-                _ => return Err(InferenceError::unsupported_format(
-                    format!("Unsupported SafeTensors dtype: {:?}", info.dtype())
-                )),
-            };
+        let mut tensors = Vec::new();
+        for (name, info) in views {
+            let dtype = candle_dtype(info.dtype())?;
             
             // Create Candle tensor from raw data
             let shape = info.shape();
-            let tensor = CandleTensor::from_raw_buffer(tensor_data.data(), dtype, shape, &self.device)
+            let tensor = CandleTensor::from_raw_buffer(info.data(), dtype, shape, &self.device)
                 .map_err(|e| InferenceError::model_load(format!("Failed to create tensor {}: {}", name, e)))?;
             
             println!("📦 Loaded tensor '{}': shape {:?}, dtype {:?}", name, shape, dtype);
-            tensors.insert(name.to_string(), tensor);
-            
-            // Create tensor specs for inputs/outputs based on naming convention
-            let tensor_spec = TensorSpec::new(
-                name.to_string(),
-                info.shape().iter().map(|&s| Some(s)).collect(),
-                match dtype {
-                    DType::F32 => DataType::F32,
-                    DType::F64 => DataType::F64,
-                    DType::I64 => DataType::I64,
-                    _ => DataType::F32, // Default fallback
-                }
-            );
-            
-            // Simple heuristic: tensors with "input" in name are inputs, others are weights/outputs
-            if name.to_lowercase().contains("input") {
-                input_specs.push(tensor_spec);
-            } else if name.to_lowercase().contains("output") || name.to_lowercase().contains("classifier") {
-                output_specs.push(tensor_spec);
-            }
+            tensors.push((name.to_string(), tensor));
         }
         
-        // If no explicit input/output specs found, create default ones
-        if input_specs.is_empty() {
-            input_specs.push(TensorSpec::new(
-                "input".to_string(),
-                vec![None, None, None, None], // Dynamic shape for images
-                DataType::F32,
-            ));
+        println!("✅ Successfully loaded SafeTensors model with {} tensors", tensors.len());
+        self.build_generic_model(tensors, graph, None)
+    }
+    
+    /// Load all shards listed by a `*.safetensors.index.json` as one generic model
+    #[cfg(feature = "candle")]
+    async fn load_sharded_safetensors(&self, path: &str) -> Result<Box<dyn Model>, InferenceError> {
+        println!("🔧 Loading sharded SafeTensors index: {}", path);
+        
+        let mut tensors: Vec<_> = sharding::load_index(Path::new(path), &self.device)?.into_iter().collect();
+        tensors.sort_by(|a, b| a.0.cmp(&b.0));
+        
+        println!("✅ Successfully loaded sharded SafeTensors model with {} tensors", tensors.len());
+        self.build_generic_model(tensors, self.graph_for(Path::new(path))?, None)
+    }
+    
+    /// Wrap named tensors in a `GenericSafeTensorsModel`
+    /// 
+    /// Shared by the SafeTensors and PyTorch loaders. Half-precision weights are
+    /// upcast to F32 so they can be multiplied with F32 inputs. The model runs
+    /// the layers of `graph` and takes its specs from it; without a graph the
+    /// tensors can be read but predictions fail. `mapped` holds tensors that
+    /// are left in a memory-mapped file.
+    #[cfg(feature = "candle")]
+    fn build_generic_model(
+        &self,
+        named_tensors: Vec<(String, CandleTensor)>,
+        graph: Option<GraphSpec>,
+        mapped: Option<Arc<MappedWeights>>,
+    ) -> Result<Box<dyn Model>, InferenceError> {
+        let mut tensors = HashMap::new();
+        for (name, tensor) in named_tensors {
+            tensors.insert(name, pytorch::upcast_half(tensor)?);
         }
         
-        if output_specs.is_empty() {
-            output_specs.push(TensorSpec::new(
-                "output".to_string(),
-                vec![None, None], // Dynamic shape for classification
-                DataType::F32,
-            ));
+        let mut model = GenericSafeTensorsModel::new(tensors, Vec::new(), Vec::new(), self.device.clone())?;
+        model.mapped = mapped;
+        let Some(graph) = graph else {
+            self.require_no_optimization("generic models without a model graph")?;
+            println!("⚠️  No model graph found; tensors can be read but predictions need a graph");
+            return Ok(Box::new(model));
+        };
+        
+        let model = model.with_graph(&graph)?;
+        println!("📋 Input spec: {}, output spec: {}", model.input_specs[0].name, model.output_specs[0].name);
+        if self.optimization.level == OptimizationLevel::None {
+            return Ok(Box::new(model));
         }
+        Ok(Box::new(self.optimize_model(model, &graph)?))
+    }
+    
+    /// Convert a generic model's weights according to the optimization level
+    /// 
+    /// The unconverted model is kept until its outputs have been compared with
+    /// the converted one, so peak memory briefly covers both.
+    #[cfg(feature = "candle")]
+    fn optimize_model(
+        &self,
+        mut model: GenericSafeTensorsModel,
+        graph: &GraphSpec,
+    ) -> Result<GenericSafeTensorsModel, InferenceError> {
+        let level = self.optimization.level;
+        println!("🗜️  Converting weights to {}", level.name());
         
-        println!("✅ Successfully loaded SafeTensors model with {} tensors", tensors.len());
-        println!("📋 Input specs: {} tensors", input_specs.len());
-        println!("📋 Output specs: {} tensors", output_specs.len());
+        // Weights left in the mapped file are read once to be converted
+        let mut tensors = model.tensors.clone();
+        if let Some(mapped) = &model.mapped {
+            for name in graph.tensor_names() {
+                if let (false, Some(tensor)) = (tensors.contains_key(name), mapped.tensor(name)?) {
+                    tensors.insert(name.to_string(), tensor);
+                }
+            }
+        }
+        let linear = optimization::linear_weights(graph);
+        let weights = optimization::convert_weights(&tensors, level, &linear)?;
+        let mut report = weights.report;
+        let mut converted = GenericSafeTensorsModel::new(
+            weights.tensors,
+            model.input_specs.clone(),
+            model.output_specs.clone(),
+            self.device.clone(),
+        )?.with_quantized(weights.quantized, weights.dtype).with_graph(graph)?;
         
-        let model = GenericSafeTensorsModel::new(tensors, input_specs, output_specs, self.device.clone())?;
-        Ok(Box::new(model))
+        let error = self.compare_outputs(&model, &converted)?;
+        report.max_abs_error = error.max();
+        report.mean_abs_error = error.mean();
+        println!(
+            "📉 Weights {} -> {} bytes ({:.1}x smaller), {} converted, {} skipped, max abs error {:?}",
+            report.size_before_bytes, report.size_after_bytes, report.compression_ratio(),
+            report.tensors_converted, report.tensors_skipped, report.max_abs_error
+        );
+        
+        if let Some(check) = self.optimization.accuracy_check() {
+            check(&report)?;
+        }
+        converted.mapped = model.mapped.take();
+        Ok(converted.with_optimization_report(report))
+    }
+    
+    /// Compare converted and unconverted outputs on the sample inputs
+    /// 
+    /// Without configured samples a synthetic input is built from the input
+    /// spec; if the unconverted model rejects it the comparison is skipped.
+    #[cfg(feature = "candle")]
+    fn compare_outputs(
+        &self,
+        original: &GenericSafeTensorsModel,
+        converted: &GenericSafeTensorsModel,
+    ) -> Result<optimization::OutputError, InferenceError> {
+        let converted_output = |sample: &Tensor| converted.forward(sample).map_err(|e| {
+            InferenceError::model_load(format!("Converted model failed on a sample input: {}", e))
+        });
+        let mut error = optimization::OutputError::default();
+        
+        if self.optimization.sample_inputs.is_empty() {
+            let Some(spec) = original.input_specs.first() else {
+                return Ok(error);
+            };
+            let sample = optimization::synthetic_sample(spec)?;
+            match original.forward(&sample) {
+                Ok(expected) => error.add(&expected, &converted_output(&sample)?)?,
+                Err(e) => println!("⚠️  Skipping accuracy check, the model rejected a synthetic input: {}", e),
+            }
+        } else {
+            for sample in &self.optimization.sample_inputs {
+                error.add(&original.forward(sample)?, &converted_output(sample)?)?;
+            }
+        }
+        Ok(error)
     }
     
     /// Check if bytes represent SafeTensors format
@@ -257,13 +537,67 @@ impl CandleEngine {
         serde_json::from_slice::<serde_json::Value>(json_bytes).is_ok()
     }
     
-    /// Load a PyTorch model (.pt, .pth)
+    /// Load a PyTorch state dict (.pt, .pth)
+    /// 
+    /// Supports zip and legacy pickle checkpoints, including weights nested
+    /// under a `state_dict` key. Tensors end up in the same representation
+    /// as SafeTensors models.
     #[cfg(feature = "candle")]
-    async fn load_pytorch(&self, _path: &str) -> Result<Box<dyn Model>, InferenceError> {
-        // PyTorch loading would require additional dependencies
-        Err(InferenceError::unsupported_format(
-            "PyTorch model loading not yet implemented - use SafeTensors format instead"
-        ))
+    async fn load_pytorch(&self, path: &str) -> Result<Box<dyn Model>, InferenceError> {
+        println!("🔧 Loading PyTorch file: {}", path);
+        
+        let tensors = pytorch::read_state_dict(Path::new(path), &self.device)?;
+        self.load_pytorch_tensors(tensors, self.graph_for(Path::new(path))?)
+    }
+    
+    /// Load a PyTorch state dict from bytes
+    #[cfg(feature = "candle")]
+    async fn load_pytorch_from_bytes(&self, bytes: &[u8]) -> Result<Box<dyn Model>, InferenceError> {
+        println!("🔧 Parsing PyTorch checkpoint from {} bytes", bytes.len());
+        
+        let tensors = pytorch::read_state_dict_from_bytes(bytes, &self.device)?;
+        self.load_pytorch_tensors(tensors, self.graph.clone())
+    }
+    
+    /// Wrap tensors read from a PyTorch checkpoint in a generic model
+    #[cfg(feature = "candle")]
+    fn load_pytorch_tensors(&self, tensors: Vec<(String, CandleTensor)>, graph: Option<GraphSpec>) -> Result<Box<dyn Model>, InferenceError> {
+        if tensors.is_empty() {
+            return Err(InferenceError::model_load(
+                "No tensors found in PyTorch checkpoint - only state dicts are supported"
+            ));
+        }
+        
+        for (name, tensor) in &tensors {
+            println!("📦 Loaded tensor '{}': shape {:?}, dtype {:?}", name, tensor.dims(), tensor.dtype());
+        }
+        println!("✅ Successfully loaded PyTorch model with {} tensors", tensors.len());
+        
+        self.build_generic_model(tensors, graph, None)
+    }
+    
+    /// Load a quantized GGUF model (.gguf)
+    #[cfg(feature = "candle")]
+    async fn load_gguf(&self, path: &str) -> Result<Box<dyn Model>, InferenceError> {
+        println!("🔧 Loading GGUF file: {}", path);
+        self.require_no_optimization("GGUF weights, which are already quantized")?;
+        
+        let file = std::fs::File::open(path)
+            .map_err(|e| InferenceError::model_load(format!("Failed to open GGUF file {}: {}", path, e)))?;
+        let mut reader = std::io::BufReader::new(file);
+        
+        let model = GgufModel::load(&mut reader, &self.device)?;
+        Ok(Box::new(model))
+    }
+    
+    /// Load a quantized GGUF model from bytes
+    #[cfg(feature = "candle")]
+    async fn load_gguf_from_bytes(&self, bytes: &[u8]) -> Result<Box<dyn Model>, InferenceError> {
+        println!("🔧 Parsing GGUF from {} bytes", bytes.len());
+        self.require_no_optimization("GGUF weights, which are already quantized")?;
+        
+        let model = GgufModel::load(&mut std::io::Cursor::new(bytes), &self.device)?;
+        Ok(Box::new(model))
     }
     
     /// Load a model with specific architecture from HuggingFace
@@ -277,6 +611,10 @@ impl CandleEngine {
         let filename = config.filename.as_ref()
             .map(|s| s.as_str())
             .or_else(|| Some(config.default_filename()));
+        // Llama picks its weight dtype from the level itself
+        if !matches!(config.architecture, ModelArchitecture::Llama) {
+            self.require_no_optimization(&format!("{:?} models", config.architecture))?;
+        }
         
         match &config.architecture {
             ModelArchitecture::Bert => {
@@ -288,12 +626,55 @@ impl CandleEngine {
                 let model = ResNetModelWrapper::load_from_huggingface(&self.device, repo_id, filename, variant.clone()).await?;
                 Ok(Box::new(model))
             }
+            ModelArchitecture::MobileNet { variant } => {
+                let model = MobileNetModelWrapper::load_from_huggingface(&self.device, repo_id, filename, variant.clone()).await?;
+                Ok(Box::new(model))
+            }
+            ModelArchitecture::Llama => {
+                let model = LlamaModelWrapper::load_from_huggingface(&self.device, repo_id, filename, self.optimization.level).await?;
+                Ok(Box::new(model))
+            }
+            ModelArchitecture::Mistral => {
+                let model = MistralModelWrapper::load_from_huggingface(&self.device, repo_id, filename).await?;
+                Ok(Box::new(model))
+            }
+            ModelArchitecture::Whisper => {
+                let model = WhisperModelWrapper::load_from_huggingface(&self.device, repo_id, filename).await?;
+                Ok(Box::new(model))
+            }
             _ => Err(InferenceError::unsupported_format(
                 format!("Model architecture {:?} not yet supported for HuggingFace loading", config.architecture)
             )),
         }
     }
     
+    /// Load a model from HuggingFace, reporting combined download progress
+    /// 
+    /// SafeTensors weights are fetched first so `on_progress` sees every shard
+    /// of a sharded checkpoint; the architecture loader then reads them from
+    /// the hf-hub cache.
+    #[cfg(feature = "candle")]
+    pub async fn load_from_huggingface_with_progress(
+        &self,
+        config: &ModelConfig,
+        on_progress: &mut (dyn FnMut(&ShardProgress) + Send),
+    ) -> Result<Box<dyn Model>, InferenceError> {
+        let repo_id = config.repo_id.as_deref()
+            .or_else(|| config.default_repo_id())
+            .ok_or_else(|| InferenceError::model_load("Repository ID required for HuggingFace loading".to_string()))?;
+        let filename = config.filename.as_deref().unwrap_or_else(|| config.default_filename());
+        
+        if filename.ends_with(".safetensors") || sharding::is_index_filename(filename) {
+            let paths = sharding::download_safetensors(repo_id, filename, on_progress).await?;
+            // Point the loader straight at the index instead of retrying the missing single file
+            if !sharding::is_index_filename(filename) && !paths.first().is_some_and(|p| p.ends_with(filename)) {
+                let config = config.clone().with_filename(&sharding::index_filename(filename));
+                return self.load_from_huggingface(&config).await;
+            }
+        }
+        self.load_from_huggingface(config).await
+    }
+    
     /// Load a BERT model from HuggingFace (convenience method)
     #[cfg(feature = "candle")]
     pub async fn load_bert(&self, repo_id: &str) -> Result<Box<dyn Model>, InferenceError> {
@@ -308,6 +689,50 @@ impl CandleEngine {
         let config = ModelConfig::new(ModelArchitecture::ResNet { variant });
         self.load_from_huggingface(&config).await
     }
+    
+    /// Load a MobileNet model (convenience method)
+    #[cfg(feature = "candle")]
+    pub async fn load_mobilenet(&self, variant: MobileNetVariant) -> Result<Box<dyn Model>, InferenceError> {
+        let config = ModelConfig::new(ModelArchitecture::MobileNet { variant });
+        self.load_from_huggingface(&config).await
+    }
+    
+    /// Load a Llama model from HuggingFace (convenience method)
+    #[cfg(feature = "candle")]
+    pub async fn load_llama(&self, repo_id: &str) -> Result<Box<dyn Model>, InferenceError> {
+        let config = ModelConfig::new(ModelArchitecture::Llama)
+            .with_repo_id(repo_id);
+        self.load_from_huggingface(&config).await
+    }
+    
+    /// Load a Mistral model from HuggingFace (convenience method)
+    #[cfg(feature = "candle")]
+    pub async fn load_mistral(&self, repo_id: &str) -> Result<Box<dyn Model>, InferenceError> {
+        let config = ModelConfig::new(ModelArchitecture::Mistral)
+            .with_repo_id(repo_id);
+        self.load_from_huggingface(&config).await
+    }
+    
+    /// Load a Whisper model from HuggingFace (convenience method)
+    #[cfg(feature = "candle")]
+    pub async fn load_whisper(&self, repo_id: &str) -> Result<Box<dyn Model>, InferenceError> {
+        let config = ModelConfig::new(ModelArchitecture::Whisper)
+            .with_repo_id(repo_id);
+        self.load_from_huggingface(&config).await
+    }
+}
+
+/// View a loaded model as a text generator, if it is one of the decoder-only wrappers
+#[cfg(feature = "candle")]
+pub fn as_text_generation(model: &dyn Model) -> Option<&dyn generation::TextGeneration> {
+    let any = model.as_any();
+    if let Some(llama) = any.downcast_ref::<LlamaModelWrapper>() {
+        return Some(llama);
+    }
+    if let Some(mistral) = any.downcast_ref::<MistralModelWrapper>() {
+        return Some(mistral);
+    }
+    None
 }
 
 impl Default for CandleEngine {
@@ -320,28 +745,42 @@ impl Default for CandleEngine {
 impl InferenceEngine for CandleEngine {
     async fn load_model(&self, path: &str) -> Result<Box<dyn Model>, InferenceError> {
         let path_obj = Path::new(path);
+        if sharding::is_index_filename(path) {
+            return self.load_sharded_safetensors(path).await;
+        }
         
         match path_obj.extension().and_then(|ext| ext.to_str()) {
             Some("safetensors") => self.load_safetensors(path).await,
             Some("pt") | Some("pth") => self.load_pytorch(path).await,
-            _ => Err(InferenceError::unsupported_format(
-                format!("Unsupported file extension for path: {}", path)
-            )),
+            Some("gguf") => self.load_gguf(path).await,
+            // Files without a known extension (such as cached downloads) are sniffed by their header
+            _ => match ModelDetector::detect_format_from_content(path_obj) {
+                Ok(ModelFormat::SafeTensors) => self.load_safetensors(path).await,
+                Ok(ModelFormat::PyTorch) => self.load_pytorch(path).await,
+                Ok(ModelFormat::Gguf) => self.load_gguf(path).await,
+                _ => Err(InferenceError::unsupported_format(
+                    format!("Unsupported file extension for path: {}", path)
+                )),
+            },
         }
     }
     
     async fn load_from_bytes(&self, bytes: &[u8]) -> Result<Box<dyn Model>, InferenceError> {
         if self.is_safetensors_format(bytes) {
             self.load_safetensors_from_bytes(bytes).await
+        } else if gguf::is_gguf_format(bytes) {
+            self.load_gguf_from_bytes(bytes).await
+        } else if pytorch::is_zip_checkpoint(bytes) || pytorch::is_legacy_checkpoint(bytes) {
+            self.load_pytorch_from_bytes(bytes).await
         } else {
             Err(InferenceError::unsupported_format(
-                "Unsupported model format in bytes - only SafeTensors supported"
+                "Unsupported model format in bytes - only SafeTensors, PyTorch and GGUF supported"
             ))
         }
     }
     
     fn supports_format(&self, format: &ModelFormat) -> bool {
-        matches!(format, ModelFormat::SafeTensors | ModelFormat::PyTorch)
+        matches!(format, ModelFormat::SafeTensors | ModelFormat::PyTorch | ModelFormat::Gguf)
     }
     
     fn engine_name(&self) -> &'static str {
@@ -351,6 +790,138 @@ impl InferenceEngine for CandleEngine {
     fn engine_type(&self) -> EngineType {
         EngineType::Candle
     }
+    
+    fn device_name(&self) -> String {
+        match self.device.location() {
+            DeviceLocation::Cpu => "cpu".to_string(),
+            DeviceLocation::Cuda { gpu_id } => format!("cuda:{}", gpu_id),
+            DeviceLocation::Metal { gpu_id } => format!("metal:{}", gpu_id),
+        }
+    }
+    
+    fn device_fallback_reason(&self) -> Option<String> {
+        self.fallback_reason.clone()
+    }
+    
+    fn set_optimization(&mut self, config: OptimizationConfig) -> Result<(), InferenceError> {
+        if config.level == OptimizationLevel::Bf16 && !supports_bf16(&self.device) {
+            return Err(InferenceError::configuration(format!(
+                "bf16 weights are not supported on {}", self.device_name()
+            )));
+        }
+        self.optimization = config;
+        Ok(())
+    }
+    
+    fn set_model_graph(&mut self, graph_json: &str) -> Result<(), InferenceError> {
+        #[cfg(feature = "candle")]
+        {
+            self.graph = Some(GraphSpec::parse(graph_json)?);
+            Ok(())
+        }
+        #[cfg(not(feature = "candle"))]
+        {
+            let _ = graph_json;
+            Err(InferenceError::configuration("Candle not available"))
+        }
+    }
+}
+
+/// Whether bf16 weights can run on a device
+/// 
+/// Candle only has bf16 kernels for CUDA, and they need compute capability
+/// 8.0 (Ampere) or newer.
+#[cfg(feature = "candle")]
+fn supports_bf16(device: &Device) -> bool {
+    match device {
+        #[cfg(feature = "candle-cuda")]
+        Device::Cuda(cuda) => {
+            use candle_core::cuda_backend::cudarc::driver::sys::CUdevice_attribute;
+            cuda.attribute(CUdevice_attribute::CU_DEVICE_ATTRIBUTE_COMPUTE_CAPABILITY_MAJOR)
+                .is_ok_and(|major| major >= 8)
+        }
+        _ => false,
+    }
+}
+
+/// Map a Candle error raised while running a model
+/// 
+/// CUDA and Metal errors, missing Metal kernels and backends that were not
+/// compiled in may succeed on another backend and become GPU errors, so a
+/// session can fall back. Everything else, such as shape mismatches, comes
+/// from the model or input and stays a prediction error.
+#[cfg(feature = "candle")]
+pub(crate) fn forward_error(context: &str, error: candle_core::Error) -> InferenceError {
+    if is_device_error(&error) {
+        InferenceError::gpu(format!("{}: {}", context, error))
+    } else {
+        InferenceError::prediction(format!("{}: {}", context, error))
+    }
+}
+
+#[cfg(feature = "candle")]
+fn is_device_error(error: &candle_core::Error) -> bool {
+    use candle_core::Error;
+    match error {
+        Error::Context { inner, .. } | Error::WithPath { inner, .. } | Error::WithBacktrace { inner, .. } => is_device_error(inner),
+        Error::Cuda(_) | Error::Metal(_) | Error::NotCompiledWithCudaSupport | Error::NotCompiledWithMetalSupport => true,
+        // The Metal backend reports dtypes without a kernel as "Metal <op> <dtype> not implemented"
+        Error::Msg(message) => message.starts_with("Metal ") && message.ends_with(" not implemented"),
+        _ => false,
+    }
+}
+
+/// Candle dtype a SafeTensors tensor loads as
+#[cfg(feature = "candle")]
+fn candle_dtype(dtype: safetensors::Dtype) -> Result<DType, InferenceError> {
+    match dtype {
+        safetensors::Dtype::F32 => Ok(DType::F32),
+        safetensors::Dtype::F64 => Ok(DType::F64),
+        safetensors::Dtype::F16 => Ok(DType::F16),
+        safetensors::Dtype::BF16 => Ok(DType::BF16),
+        safetensors::Dtype::U8 => Ok(DType::U8),
+        safetensors::Dtype::U32 => Ok(DType::U32),
+        safetensors::Dtype::I32 => Ok(DType::I64), // Map to closest available
+        safetensors::Dtype::I64 => Ok(DType::I64),
+        _ => Err(InferenceError::unsupported_format(
+            format!("Unsupported SafeTensors dtype: {:?}", dtype)
+        )),
+    }
+}
+
+/// Tensors of a memory-mapped SafeTensors file that are read when needed
+#[cfg(feature = "candle")]
+struct MappedWeights {
+    /// Owns the mapping
+    weights: VarBuilder<'static>,
+    /// Shape and dtype of each tensor in the file
+    shapes: HashMap<String, (Vec<usize>, DType)>,
+}
+
+#[cfg(feature = "candle")]
+impl std::fmt::Debug for MappedWeights {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MappedWeights")
+            .field("tensor_count", &self.shapes.len())
+            .finish()
+    }
+}
+
+#[cfg(feature = "candle")]
+impl WeightSource for MappedWeights {
+    fn dims(&self, name: &str) -> Option<Vec<usize>> {
+        self.shapes.get(name).map(|(shape, _)| shape.clone())
+    }
+    
+    /// Copy a tensor out of the file, upcasting half precision like eagerly loaded weights
+    fn tensor(&self, name: &str) -> Result<Option<CandleTensor>, InferenceError> {
+        let Some((shape, dtype)) = self.shapes.get(name) else {
+            return Ok(None);
+        };
+        let tensor = self.weights.get_with_hints_dtype(shape.as_slice(), name, candle_nn::init::ZERO, *dtype)
+            .map_err(|e| InferenceError::model_load(format!("Failed to read tensor {}: {}", name, e)))?;
+        pytorch::upcast_half(tensor).map(Some)
+    }
 }
 
 /// Generic SafeTensors model that provides raw tensor access
@@ -363,6 +934,20 @@ pub struct GenericSafeTensorsModel {
     /// Raw tensors loaded from SafeTensors file
     #[cfg(feature = "candle")]
     tensors: HashMap<String, CandleTensor>,
+    /// Linear weights quantized at load time, removed from `tensors`
+    #[cfg(feature = "candle")]
+    quantized: HashMap<String, QMatMul>,
+    /// Dtype the weights compute in
+    #[cfg(feature = "candle")]
+    dtype: DType,
+    /// Layers to run, when the model comes with a graph
+    #[cfg(feature = "candle")]
+    graph: Option<Graph>,
+    /// Memory-mapped weights file, read from as the graph runs
+    #[cfg(feature = "candle")]
+    mapped: Option<Arc<MappedWeights>>,
+    /// How the weights were converted at load time
+    optimization: Option<OptimizationReport>,
     /// Device for computations
     device: Device,
     /// Input specifications
@@ -378,6 +963,10 @@ impl std::fmt::Debug for GenericSafeTensorsModel {
             .field("input_specs", &self.input_specs)
             .field("output_specs", &self.output_specs)
             .field("tensor_count", &self.tensors.len())
+            .field("quantized_count", &self.quantized.len())
+            .field("mapped_count", &self.mapped.as_ref().map_or(0, |mapped| mapped.shapes.len()))
+            .field("dtype", &self.dtype)
+            .field("has_graph", &self.graph.is_some())
             .finish()
     }
 }
@@ -392,21 +981,75 @@ impl GenericSafeTensorsModel {
     ) -> Result<Self, InferenceError> {
         Ok(Self {
             tensors,
+            quantized: HashMap::new(),
+            dtype: DType::F32,
+            graph: None,
+            mapped: None,
+            optimization: None,
             device,
             input_specs,
             output_specs,
         })
     }
     
+    /// Use Linear weights quantized at load time, computing in `dtype`
+    /// 
+    /// Must be called before `with_graph` so the graph picks up the quantized weights.
+    #[cfg(feature = "candle")]
+    pub fn with_quantized(mut self, quantized: HashMap<String, QMatMul>, dtype: DType) -> Self {
+        self.quantized = quantized;
+        self.dtype = dtype;
+        self
+    }
+    
+    /// Record how the weights were converted
+    pub fn with_optimization_report(mut self, report: OptimizationReport) -> Self {
+        self.optimization = Some(report);
+        self
+    }
+    
+    /// Run the layers of `graph`; without one the model can't predict
+    /// 
+    /// Every tensor the graph refers to must be loaded, and the model's input
+    /// and output specs are replaced by the graph's. When all weights are in
+    /// a mapped file, each layer reads its weights from it as it runs.
+    #[cfg(feature = "candle")]
+    pub fn with_graph(mut self, graph: &GraphSpec) -> Result<Self, InferenceError> {
+        let graph = match &self.mapped {
+            Some(mapped) if self.tensors.is_empty() && self.quantized.is_empty() => Graph::build_lazy(graph, mapped.clone())?,
+            _ => Graph::build_with_quantized(graph, &self.tensors, &self.quantized, self.dtype)?,
+        };
+        self.input_specs = vec![graph.input_spec().clone()];
+        self.output_specs = vec![graph.output_spec().clone()];
+        self.graph = Some(graph);
+        Ok(self)
+    }
+    
+    /// Whether the model runs a declarative graph
+    #[cfg(feature = "candle")]
+    pub fn has_graph(&self) -> bool {
+        self.graph.is_some()
+    }
+    
     /// Get access to the raw tensors (for advanced users)
+    /// 
+    /// Weights quantized at load time are not available as dense tensors.
+    /// Tensors in the mapped weights file are read on every call.
     #[cfg(feature = "candle")]
-    pub fn get_tensor(&self, name: &str) -> Option<&CandleTensor> {
-        self.tensors.get(name)
+    pub fn get_tensor(&self, name: &str) -> Option<CandleTensor> {
+        match self.tensors.get(name) {
+            Some(tensor) => Some(tensor.clone()),
+            None if self.quantized.contains_key(name) => None,
+            None => self.mapped.as_ref()?.tensor(name).ok()?,
+        }
     }
     
     /// Get all tensor names
     pub fn tensor_names(&self) -> Vec<String> {
-        self.tensors.keys().cloned().collect()
+        let mapped = self.mapped.iter()
+            .flat_map(|mapped| mapped.shapes.keys())
+            .filter(|name| !self.tensors.contains_key(*name) && !self.quantized.contains_key(*name));
+        self.tensors.keys().chain(self.quantized.keys()).chain(mapped).cloned().collect()
     }
     
     /// Convert our Tensor to Candle Tensor
@@ -441,20 +1084,20 @@ impl GenericSafeTensorsModel {
             _ => return Err(InferenceError::prediction("Unsupported Candle tensor data type")),
         };
         
-        // Extract data from Candle tensor using to_vec1
+        // Extract data from Candle tensor, flattened so outputs of any rank convert
         // This is a simplified approach - real implementations might need more sophisticated conversion
         let data: Vec<f32> = match data_type {
             DataType::F32 => {
-                candle_tensor.to_vec1()
+                candle_tensor.flatten_all().and_then(|t| t.to_vec1())
                     .map_err(|e| InferenceError::prediction(format!("Failed to extract F32 data: {}", e)))?
             }
             DataType::F64 => {
-                let f64_data: Vec<f64> = candle_tensor.to_vec1()
+                let f64_data: Vec<f64> = candle_tensor.flatten_all().and_then(|t| t.to_vec1())
                     .map_err(|e| InferenceError::prediction(format!("Failed to extract F64 data: {}", e)))?;
                 f64_data.into_iter().map(|x| x as f32).collect()
             }
             DataType::I64 => {
-                let i64_data: Vec<i64> = candle_tensor.to_vec1()
+                let i64_data: Vec<i64> = candle_tensor.flatten_all().and_then(|t| t.to_vec1())
                     .map_err(|e| InferenceError::prediction(format!("Failed to extract I64 data: {}", e)))?;
                 i64_data.into_iter().map(|x| x as f32).collect()
             }
@@ -470,60 +1113,59 @@ impl GenericSafeTensorsModel {
             .map_err(|e| InferenceError::prediction(format!("Failed to convert Candle tensor: {}", e)))
     }
     
+    /// Run the model on one input
+    #[cfg(feature = "candle")]
+    fn forward(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+        self.forward_with_context(input, &PredictContext::new())
+    }
+    
+    /// Run the model on one input, checking for cancellation between graph layers
+    #[cfg(feature = "candle")]
+    fn forward_with_context(&self, input: &Tensor, ctx: &PredictContext) -> Result<Tensor, InferenceError> {
+        // Convert input to Candle tensor
+        let candle_input = self.tensor_to_candle(input)?;
+        
+        // Run placeholder inference
+        let output = self.run_inference(&candle_input, ctx)?;
+        
+        // Convert output back to our tensor format
+        self.candle_to_tensor(&output)
+    }
+    
     /// Perform inference using the loaded SafeTensors model
     /// 
-    /// This performs a basic linear transformation using the loaded weights.
-    /// For complex architectures, users should use the specific model wrappers.
+    /// Runs the model graph. Raw tensors say nothing about how to combine
+    /// them, so a model loaded without a graph can't predict.
     #[cfg(feature = "candle")]
-    fn run_inference(&self, input: &CandleTensor) -> Result<CandleTensor, InferenceError> {
-        println!("🔧 Running SafeTensors inference with {} tensors", self.tensors.len());
+    fn run_inference(&self, input: &CandleTensor, ctx: &PredictContext) -> Result<CandleTensor, InferenceError> {
+        let graph = self.graph.as_ref().ok_or_else(|| InferenceError::prediction(format!(
+            "This model has no graph to run; set one with `with_model_graph` or put a `*{}` file next to the weights",
+            graph::GRAPH_SUFFIX
+        )))?;
         println!("📊 Input shape: {:?}", input.shape());
         
-        // Try to find a weight tensor that matches the input dimensions
-        // This is a generic approach for simple linear models
-        let input_dims = input.shape().dims();
-        let last_dim = input_dims[input_dims.len() - 1];
-        
-        // Look for a weight tensor that can be multiplied with the input
-        for (name, tensor) in &self.tensors {
-            let tensor_dims = tensor.shape().dims();
-            
-            // Check if this tensor can be used for matrix multiplication
-            if tensor_dims.len() == 2 && tensor_dims[0] == last_dim {
-                println!("🎯 Using tensor '{}' for inference: {:?}", name, tensor_dims);
-                
-                // Perform matrix multiplication: input @ weight
-                let output = input.matmul(tensor)
-                    .map_err(|e| InferenceError::prediction(format!("Matrix multiplication failed: {}", e)))?;
-                
-                println!("✅ Inference complete, output shape: {:?}", output.shape());
-                return Ok(output);
-            }
-        }
-        
-        // If no suitable weight tensor found, return error
-        Err(InferenceError::prediction(
-            "No suitable weight tensor found for inference. This SafeTensors model may require a specific architecture wrapper."
-        ))
+        let output = graph.forward_with_context(input, ctx)?;
+        println!("✅ Graph inference complete, output shape: {:?}", output.shape());
+        Ok(output)
     }
 }
 
 #[async_trait]
 impl Model for GenericSafeTensorsModel {
     async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+        self.predict_with_context(input, &PredictContext::new()).await
+    }
+    
+    /// Checks the context between graph layers
+    async fn predict_with_context(&self, input: &Tensor, ctx: &PredictContext) -> Result<Tensor, InferenceError> {
         #[cfg(feature = "candle")]
         {
-            // Convert input to Candle tensor
-            let candle_input = self.tensor_to_candle(input)?;
-            
-            // Run placeholder inference
-            let output = self.run_inference(&candle_input)?;
-            
-            // Convert output back to our tensor format
-            self.candle_to_tensor(&output)
+            ctx.check()?;
+            self.forward_with_context(input, ctx)
         }
         #[cfg(not(feature = "candle"))]
         {
+            let _ = (input, ctx);
             Err(InferenceError::configuration("Candle not available"))
         }
     }
@@ -548,6 +1190,10 @@ impl Model for GenericSafeTensorsModel {
         EngineType::Candle
     }
     
+    fn optimization_report(&self) -> Option<&OptimizationReport> {
+        self.optimization.as_ref()
+    }
+    
     fn as_any(&self) -> &dyn Any {
         self
     }
@@ -571,7 +1217,7 @@ impl HuggingFaceIntegration {
 mod tests {
     use super::*;
     use crate::models::DataType;
-
+    
     #[test]
     fn test_candle_engine_creation() {
         let engine = CandleEngine::new();
@@ -581,7 +1227,88 @@ mod tests {
         assert_eq!(engine.engine_name(), "candle");
         assert_eq!(engine.engine_type(), EngineType::Candle);
     }
-
+    
+    #[test]
+    fn test_device_selection_and_fallback() {
+        let engine = CandleEngine::for_device(DeviceSelection::Cpu).unwrap();
+        assert_eq!(engine.device_name(), "cpu");
+        assert!(engine.device_fallback_reason().is_none());
+        
+        // Without CUDA support the engine falls back to the CPU and says why
+        #[cfg(not(feature = "candle-cuda"))]
+        {
+            let engine = CandleEngine::for_device(DeviceSelection::Cuda { ordinal: 1 }).unwrap();
+            assert_eq!(engine.device_name(), "cpu");
+            assert!(!engine.gpu_available());
+            assert!(engine.device_fallback_reason().unwrap().starts_with("cuda:1 unavailable"));
+            assert!(CandleEngine::cuda(1).is_err());
+        }
+        
+        let devices = CandleEngine::list_devices();
+        assert_eq!(devices[0], DeviceInfo::cpu());
+        assert!(devices.iter().any(|d| matches!(d.device, DeviceSelection::Cuda { .. })));
+        assert!(devices.iter().any(|d| matches!(d.device, DeviceSelection::Metal { .. })));
+        assert!(devices.iter().all(|d| d.available || d.unavailable_reason.is_some()));
+        // bf16 is only reported for CUDA devices with compute capability 8.0+
+        assert!(!supports_bf16(&Device::Cpu));
+        assert!(devices.iter().filter(|d| d.supports_bf16).all(|d| matches!(d.device, DeviceSelection::Cuda { .. })));
+    }
+    
+    #[cfg(feature = "candle")]
+    #[test]
+    fn test_forward_error_classification() {
+        // Shape errors come from the input and must not trigger a fallback
+        let a = CandleTensor::zeros((2, 3), DType::F32, &Device::Cpu).unwrap();
+        let shape_error = a.matmul(&a).unwrap_err();
+        assert!(matches!(forward_error("forward", shape_error), InferenceError::Prediction(_)));
+        
+        // So do dtype, device and striding errors, which the CPU backend raises too
+        for error in [
+            candle_core::Error::UnsupportedDTypeForOp(DType::BF16, "matmul"),
+            candle_core::Error::DeviceMismatchBinaryOp { lhs: DeviceLocation::Cpu, rhs: DeviceLocation::Cpu, op: "add" },
+            candle_core::Error::Msg("conv2d for groups > 1 is not implemented".into()),
+        ] {
+            assert!(matches!(forward_error("forward", error), InferenceError::Prediction(_)));
+        }
+        
+        let error = forward_error("forward", candle_core::Error::NotCompiledWithCudaSupport.context("layer 0"));
+        assert!(matches!(error, InferenceError::Gpu(_)));
+        assert!(error.is_backend_failure());
+        let kernel_error = candle_core::Error::Msg("Metal contiguous affine U8 not implemented".into()).bt();
+        assert!(matches!(forward_error("forward", kernel_error), InferenceError::Gpu(_)));
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_shape_error_does_not_fall_back() {
+        use crate::engines::Backend;
+        use crate::models::session::SessionBuilder;
+        
+        let dir = std::env::temp_dir().join(format!("inference_shape_error_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let tensors: HashMap<String, CandleTensor> = [
+            ("fc.weight".to_string(), CandleTensor::new(&[[1.0f32, 0.0], [0.0, 1.0]], &Device::Cpu).unwrap()),
+        ].into_iter().collect();
+        candle_core::safetensors::save(&tensors, dir.join("model.safetensors")).unwrap();
+        
+        let mut engine = CandleEngine::cpu().unwrap();
+        engine.set_model_graph(r#"{"input": {"name": "x"}, "output": {"name": "y"}, "layers": [{"type": "linear", "weight": "fc.weight"}]}"#).unwrap();
+        let session = SessionBuilder::new()
+            .with_engine(Box::new(engine))
+            .with_fallback_chain(vec![Backend::new(EngineType::Candle, DeviceSelection::Cpu)])
+            .load_from_path(dir.join("model.safetensors").to_str().unwrap())
+            .await
+            .unwrap();
+        
+        // A 3-wide input can't go through a 2x2 weight on any backend
+        let input = Tensor::from_f32(vec![1.0, 2.0, 3.0], vec![1, 3]).unwrap();
+        let error = session.predict(&input).await.unwrap_err();
+        assert!(matches!(error, InferenceError::Prediction(_)), "{}", error);
+        assert!(!session.should_fall_back(&error));
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    
     #[cfg(feature = "candle")]
     #[tokio::test]
     async fn test_safetensors_format_detection() {
@@ -595,4 +1322,208 @@ mod tests {
         let short_data = vec![0u8; 5];
         assert!(!engine.is_safetensors_format(&short_data));
     }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_load_mmaped_safetensors() {
+        let engine = CandleEngine::cpu().unwrap();
+        let dir = std::env::temp_dir().join(format!("inference_mmap_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        
+        let tensors: HashMap<String, CandleTensor> = [
+            ("dense.weight".to_string(), CandleTensor::new(&[[1.0f32, 2.0], [3.0, 4.0]], &Device::Cpu).unwrap()),
+            ("dense.bias".to_string(), CandleTensor::new(&[0.5f32, -0.5], &Device::Cpu).unwrap()),
+        ].into_iter().collect();
+        candle_core::safetensors::save(&tensors, dir.join("model.safetensors")).unwrap();
+        // Cached downloads have no SafeTensors extension and are sniffed by header
+        std::fs::copy(dir.join("model.safetensors"), dir.join("cached.bin")).unwrap();
+        
+        for name in ["model.safetensors", "cached.bin"] {
+            let model = engine.load_model(dir.join(name).to_str().unwrap()).await.unwrap();
+            let model = model.as_any().downcast_ref::<GenericSafeTensorsModel>().unwrap();
+            let mut names = model.tensor_names();
+            names.sort();
+            assert_eq!(names, vec!["dense.bias", "dense.weight"]);
+            assert_eq!(model.get_tensor("dense.bias").unwrap().to_vec1::<f32>().unwrap(), vec![0.5, -0.5]);
+            
+            // Without a graph nothing says how to combine the tensors
+            let input = Tensor::from_f32(vec![1.0, 1.0], vec![1, 2]).unwrap();
+            let err = model.predict(&input).await.unwrap_err();
+            assert!(matches!(&err, InferenceError::Prediction(msg) if msg.contains("no graph")), "{}", err);
+        }
+        
+        // Weights can't be converted for a model that can't run
+        let engine = CandleEngine::cpu().unwrap().with_optimization(OptimizationConfig::new(OptimizationLevel::F16)).unwrap();
+        let result = engine.load_model(dir.join("model.safetensors").to_str().unwrap()).await;
+        assert!(matches!(result, Err(InferenceError::Configuration(_))));
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_load_safetensors_with_graph() {
+        let engine = CandleEngine::cpu().unwrap();
+        let dir = std::env::temp_dir().join(format!("inference_graph_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        
+        let tensors: HashMap<String, CandleTensor> = [
+            ("fc.weight".to_string(), CandleTensor::new(&[[1.0f32, 1.0], [1.0, -1.0], [0.0, 2.0]], &Device::Cpu).unwrap()),
+            ("fc.bias".to_string(), CandleTensor::new(&[0.0f32, 0.0, 1.0], &Device::Cpu).unwrap()),
+            ("unused.weight".to_string(), CandleTensor::new(&[7.0f32, 8.0], &Device::Cpu).unwrap()),
+        ].into_iter().collect();
+        candle_core::safetensors::save(&tensors, dir.join("classifier.safetensors")).unwrap();
+        std::fs::write(dir.join("classifier.graph.json"), r#"{
+            "input": {"name": "features", "shape": [null, 2]},
+            "output": {"name": "scores", "shape": [null, 3]},
+            "layers": [
+                {"type": "linear", "weight": "fc.weight", "bias": "fc.bias"},
+                {"type": "activation", "function": "relu"}
+            ]
+        }"#).unwrap();
+        
+        let model = engine.load_model(dir.join("classifier.safetensors").to_str().unwrap()).await.unwrap();
+        assert_eq!(model.input_specs()[0].name, "features");
+        assert_eq!(model.output_specs()[0].shape, vec![None, Some(3)]);
+        
+        let input = Tensor::from_f32(vec![1.0, 2.0, 3.0, 1.0], vec![2, 2]).unwrap();
+        let output = model.predict(&input).await.unwrap();
+        assert_eq!(output.shape(), &[2, 3]);
+        assert_eq!(output.to_f32_vec().unwrap(), vec![3.0, 0.0, 5.0, 4.0, 2.0, 3.0]);
+        
+        // The weights stay in the mapped file instead of being copied into the model
+        let generic = model.as_any().downcast_ref::<GenericSafeTensorsModel>().unwrap();
+        assert!(generic.tensors.is_empty());
+        assert!(!generic.tensors.contains_key("unused.weight"));
+        assert!(generic.tensor_names().contains(&"unused.weight".to_string()));
+        assert_eq!(generic.get_tensor("unused.weight").unwrap().to_vec1::<f32>().unwrap(), vec![7.0, 8.0]);
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_load_safetensors_with_configured_graph() {
+        let dir = std::env::temp_dir().join(format!("inference_set_graph_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        
+        let tensors: HashMap<String, CandleTensor> = [
+            ("fc.weight".to_string(), CandleTensor::new(&[[2.0f32, 0.0], [0.0, 3.0]], &Device::Cpu).unwrap()),
+        ].into_iter().collect();
+        candle_core::safetensors::save(&tensors, dir.join("model.safetensors")).unwrap();
+        
+        let graph = |layer: &str| format!(
+            r#"{{"input": {{"name": "x", "shape": [null, 2]}}, "output": {{"name": "y", "shape": [null, 2]}}, "layers": [{}]}}"#,
+            layer,
+        );
+        let mut engine = CandleEngine::cpu().unwrap();
+        assert!(matches!(engine.set_model_graph(&graph(r#"{"type": "max_pool2d", "kernel_size": 0}"#)), Err(InferenceError::Configuration(_))));
+        engine.set_model_graph(&graph(r#"{"type": "linear", "weight": "fc.weight"}"#)).unwrap();
+        
+        // No `model.graph.json` exists, so the graph comes from the engine
+        let model = engine.load_model(dir.join("model.safetensors").to_str().unwrap()).await.unwrap();
+        let input = Tensor::from_f32(vec![1.0, 1.0], vec![1, 2]).unwrap();
+        assert_eq!(model.predict(&input).await.unwrap().to_f32_vec().unwrap(), vec![2.0, 3.0]);
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_load_time_weight_conversion() {
+        let dir = std::env::temp_dir().join(format!("inference_optimize_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        
+        let weight: Vec<f32> = (0..3 * 64).map(|i| (i % 13) as f32 / 13.0 - 0.5).collect();
+        let tensors: HashMap<String, CandleTensor> = [
+            ("fc.weight".to_string(), CandleTensor::from_vec(weight, (3, 64), &Device::Cpu).unwrap()),
+            ("fc.bias".to_string(), CandleTensor::new(&[0.1f32, 0.0, -0.1], &Device::Cpu).unwrap()),
+        ].into_iter().collect();
+        candle_core::safetensors::save(&tensors, dir.join("mlp.safetensors")).unwrap();
+        std::fs::write(dir.join("mlp.graph.json"), r#"{
+            "input": {"name": "features", "shape": [null, 64]},
+            "output": {"name": "scores", "shape": [null, 3]},
+            "layers": [{"type": "linear", "weight": "fc.weight", "bias": "fc.bias"}]
+        }"#).unwrap();
+        let path = dir.join("mlp.safetensors");
+        let path = path.to_str().unwrap();
+        
+        let input = Tensor::from_f32((0..64).map(|i| i as f32 / 64.0).collect(), vec![1, 64]).unwrap();
+        let baseline = CandleEngine::cpu().unwrap().load_model(path).await.unwrap();
+        assert!(baseline.optimization_report().is_none());
+        let expected = baseline.predict(&input).await.unwrap().to_f32_vec().unwrap();
+        
+        for level in [OptimizationLevel::F16, OptimizationLevel::Int8, OptimizationLevel::Q4] {
+            let engine = CandleEngine::cpu().unwrap()
+                .with_optimization(OptimizationConfig::new(level).with_sample_inputs(vec![input.clone()]))
+                .unwrap();
+            let model = engine.load_model(path).await.unwrap();
+            
+            let report = model.optimization_report().unwrap();
+            assert_eq!(report.level, level);
+            assert!(report.size_after_bytes < report.size_before_bytes, "{:?}", report);
+            assert!(report.max_abs_error.unwrap() < 0.1, "{:?}", report);
+            
+            let output = model.predict(&input).await.unwrap().to_f32_vec().unwrap();
+            for (e, a) in expected.iter().zip(&output) {
+                assert!((e - a).abs() < 0.1, "{:?}: {} vs {}", level, e, a);
+            }
+        }
+        
+        assert!(CandleEngine::cpu().unwrap().with_optimization(OptimizationConfig::new(OptimizationLevel::Bf16)).is_err());
+        
+        // Int8 quantizes the Linear weight but keeps the bias dense
+        let model = CandleEngine::cpu().unwrap()
+            .with_optimization(OptimizationConfig::new(OptimizationLevel::Int8))
+            .unwrap()
+            .load_model(path)
+            .await
+            .unwrap();
+        let generic = model.as_any().downcast_ref::<GenericSafeTensorsModel>().unwrap();
+        assert!(generic.get_tensor("fc.weight").is_none());
+        assert!(generic.get_tensor("fc.bias").is_some());
+        assert_eq!(generic.tensor_names().len(), 2);
+        // Without samples the comparison runs on a synthetic input
+        assert!(model.optimization_report().unwrap().max_abs_error.is_some());
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_accuracy_check_rejects_conversion() {
+        let weight: Vec<f32> = (0..2 * 32).map(|i| (i % 7) as f32 * 0.37 - 1.0).collect();
+        let tensors: HashMap<String, CandleTensor> = [
+            ("proj".to_string(), CandleTensor::from_vec(weight, (2, 32), &Device::Cpu).unwrap()),
+        ].into_iter().collect();
+        let graph = r#"{
+            "input": {"name": "x", "shape": [null, 32]},
+            "output": {"name": "y", "shape": [null, 2]},
+            "layers": [{"type": "linear", "weight": "proj"}]
+        }"#;
+        let path = std::env::temp_dir().join(format!("inference_accuracy_{}.safetensors", std::process::id()));
+        candle_core::safetensors::save(&tensors, &path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let sample = Tensor::from_f32((0..32).map(|i| i as f32 / 8.0).collect(), vec![1, 32]).unwrap();
+        
+        let mut engine = CandleEngine::cpu().unwrap()
+            .with_optimization(OptimizationConfig::new(OptimizationLevel::Q4)
+                .with_sample_inputs(vec![sample.clone()])
+                .with_max_abs_error(1.0))
+            .unwrap();
+        engine.set_model_graph(graph).unwrap();
+        let model = engine.load_from_bytes(&bytes).await.unwrap();
+        assert_eq!(model.predict(&sample).await.unwrap().shape(), &[1, 2]);
+        assert_eq!(model.optimization_report().unwrap().tensors_converted, 1);
+        
+        let mut engine = CandleEngine::cpu().unwrap()
+            .with_optimization(OptimizationConfig::new(OptimizationLevel::Q4)
+                .with_sample_inputs(vec![sample])
+                .with_max_abs_error(0.0))
+            .unwrap();
+        engine.set_model_graph(graph).unwrap();
+        let err = engine.load_from_bytes(&bytes).await.unwrap_err();
+        assert!(err.to_string().contains("tolerance"), "{}", err);
+    }
 } 
\ No newline at end of file
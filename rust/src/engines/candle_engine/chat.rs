@@ -0,0 +1,403 @@
+use crate::models::{InferenceError, PredictContext};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+use super::generation::{GeneratedToken, GenerationOutput, StopReason, TextGeneration};
+use super::sampling::GenerationConfig;
+
+/// Who wrote a chat message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatRole {
+    System,
+    User,
+    Assistant,
+}
+
+/// One turn of a chat
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ChatMessage {
+    pub role: ChatRole,
+    pub content: String,
+}
+
+impl ChatMessage {
+    /// Create a message with the given role
+    pub fn new<S: Into<String>>(role: ChatRole, content: S) -> Self {
+        Self { role, content: content.into() }
+    }
+    
+    /// Create a system message
+    pub fn system<S: Into<String>>(content: S) -> Self {
+        Self::new(ChatRole::System, content)
+    }
+    
+    /// Create a user message
+    pub fn user<S: Into<String>>(content: S) -> Self {
+        Self::new(ChatRole::User, content)
+    }
+    
+    /// Create an assistant message
+    pub fn assistant<S: Into<String>>(content: S) -> Self {
+        Self::new(ChatRole::Assistant, content)
+    }
+}
+
+/// A HuggingFace chat template (Jinja) with the special tokens it refers to
+/// 
+/// Rendered the way `transformers` does it: `trim_blocks` and `lstrip_blocks`
+/// are on, Python string methods such as `strip()` work, and
+/// `raise_exception(msg)` fails the render with `msg`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatTemplate {
+    source: String,
+    bos_token: String,
+    eos_token: String,
+}
+
+impl ChatTemplate {
+    /// Create a template from Jinja source with empty special tokens
+    pub fn new<S: Into<String>>(source: S) -> Self {
+        Self {
+            source: source.into(),
+            bos_token: String::new(),
+            eos_token: String::new(),
+        }
+    }
+    
+    /// Set the `bos_token` and `eos_token` strings passed to the template
+    pub fn with_special_tokens<S: Into<String>>(mut self, bos_token: S, eos_token: S) -> Self {
+        self.bos_token = bos_token.into();
+        self.eos_token = eos_token.into();
+        self
+    }
+    
+    /// Read the template from a parsed `tokenizer_config.json`
+    /// 
+    /// Returns `None` when the config has no `chat_template`. When several
+    /// named templates are listed, the one called `default` is used.
+    pub fn from_tokenizer_config(config: &Value) -> Result<Option<Self>, InferenceError> {
+        let source = match &config["chat_template"] {
+            Value::Null => return Ok(None),
+            Value::String(source) => source.clone(),
+            Value::Array(templates) => templates.iter()
+                .find(|t| t["name"] == "default")
+                .and_then(|t| t["template"].as_str())
+                .ok_or_else(|| InferenceError::configuration("tokenizer_config.json has no default chat template"))?
+                .to_string(),
+            other => {
+                return Err(InferenceError::configuration(format!("Unexpected chat_template value: {}", other)));
+            }
+        };
+        
+        Ok(Some(Self::new(source).with_special_tokens(special_token(config, "bos_token"), special_token(config, "eos_token"))))
+    }
+    
+    /// Find the chat template shipped next to a `tokenizer.json`
+    /// 
+    /// Looks at `tokenizer_config.json` first, then at a standalone
+    /// `chat_template.jinja`. A missing or unreadable config is not an error,
+    /// since plenty of models have no chat template.
+    pub fn find_for_tokenizer(tokenizer_path: &Path) -> Option<Self> {
+        let dir = tokenizer_path.parent()?;
+        
+        let from_config = std::fs::read_to_string(dir.join("tokenizer_config.json")).ok()
+            .and_then(|json| serde_json::from_str::<Value>(&json).ok())
+            .map(|config| (Self::from_tokenizer_config(&config), config));
+        let special_tokens = match from_config {
+            Some((Ok(Some(template)), _)) => return Some(template),
+            Some((Err(e), _)) => {
+                println!("⚠️ Ignoring chat template in tokenizer_config.json: {}", e);
+                return None;
+            }
+            Some((Ok(None), config)) => Some(config),
+            None => None,
+        };
+        
+        let source = std::fs::read_to_string(dir.join("chat_template.jinja")).ok()?;
+        let template = Self::new(source);
+        Some(match special_tokens {
+            Some(config) => template.with_special_tokens(special_token(&config, "bos_token"), special_token(&config, "eos_token")),
+            None => template,
+        })
+    }
+    
+    /// The Jinja source
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+    
+    /// The beginning-of-sequence token string
+    pub fn bos_token(&self) -> &str {
+        &self.bos_token
+    }
+    
+    /// The end-of-sequence token string, which chat models often use to end a turn
+    pub fn eos_token(&self) -> &str {
+        &self.eos_token
+    }
+    
+    /// Render `messages` into a prompt
+    /// 
+    /// With `add_generation_prompt` the template appends the header that
+    /// starts an assistant turn.
+    pub fn render(&self, messages: &[ChatMessage], add_generation_prompt: bool) -> Result<String, InferenceError> {
+        let mut env = minijinja::Environment::new();
+        env.set_trim_blocks(true);
+        env.set_lstrip_blocks(true);
+        env.set_unknown_method_callback(minijinja_contrib::pycompat::unknown_method_callback);
+        env.add_function("raise_exception", |message: String| -> Result<String, minijinja::Error> {
+            Err(minijinja::Error::new(minijinja::ErrorKind::InvalidOperation, message))
+        });
+        
+        let template = env.template_from_str(&self.source)
+            .map_err(|e| InferenceError::configuration(format!("Invalid chat template: {}", e)))?;
+        template.render(minijinja::context! {
+            messages => messages,
+            add_generation_prompt => add_generation_prompt,
+            bos_token => &self.bos_token,
+            eos_token => &self.eos_token,
+        }).map_err(|e| InferenceError::configuration(format!("Failed to render chat template: {}", e)))
+    }
+}
+
+/// Read a special token, stored as a plain string or a serialized `AddedToken`
+fn special_token(config: &Value, key: &str) -> String {
+    match &config[key] {
+        Value::String(token) => token.clone(),
+        value => value["content"].as_str().unwrap_or_default().to_string(),
+    }
+}
+
+/// A multi-turn chat that keeps the model's KV cache between turns
+/// 
+/// The conversation remembers the exact tokens the model has seen so far,
+/// including the tokens it generated itself. Each new message only appends
+/// the tokens for the text the template adds, so the generator runs just the
+/// new part instead of the whole history. If the template rewrites earlier
+/// turns, the history is tokenized again from scratch.
+#[derive(Debug, Clone)]
+pub struct Conversation {
+    template: ChatTemplate,
+    messages: Vec<ChatMessage>,
+    /// Tokens for `rendered`
+    tokens: Vec<u32>,
+    /// Rendered text covered by `tokens`
+    rendered: String,
+}
+
+impl Conversation {
+    /// Start an empty conversation
+    pub fn new(template: ChatTemplate) -> Self {
+        Self {
+            template,
+            messages: Vec::new(),
+            tokens: Vec::new(),
+            rendered: String::new(),
+        }
+    }
+    
+    /// Start with a system prompt
+    pub fn with_system_prompt<S: Into<String>>(mut self, prompt: S) -> Self {
+        self.messages.push(ChatMessage::system(prompt));
+        self
+    }
+    
+    /// Get the messages exchanged so far
+    pub fn messages(&self) -> &[ChatMessage] {
+        &self.messages
+    }
+    
+    /// Get the tokens the model has seen for this conversation
+    pub fn tokens(&self) -> &[u32] {
+        &self.tokens
+    }
+    
+    /// Send a user message and generate the assistant's reply
+    pub fn send(
+        &mut self,
+        model: &dyn TextGeneration,
+        content: &str,
+        config: &GenerationConfig,
+        ctx: &PredictContext,
+    ) -> Result<GenerationOutput, InferenceError> {
+        self.send_stream(model, content, config, ctx, &mut |_| Ok(()))
+    }
+    
+    /// Send a user message, passing each token of the reply to `on_token`
+    /// 
+    /// On error the user message is dropped again, so the conversation is
+    /// left as it was.
+    pub fn send_stream(
+        &mut self,
+        model: &dyn TextGeneration,
+        content: &str,
+        config: &GenerationConfig,
+        ctx: &PredictContext,
+        on_token: &mut dyn FnMut(GeneratedToken) -> Result<(), InferenceError>,
+    ) -> Result<GenerationOutput, InferenceError> {
+        let turns = self.messages.len();
+        self.messages.push(ChatMessage::user(content));
+        let result = self.reply(model, config, ctx, on_token);
+        if result.is_err() {
+            self.messages.truncate(turns);
+        }
+        result
+    }
+    
+    fn reply(
+        &mut self,
+        model: &dyn TextGeneration,
+        config: &GenerationConfig,
+        ctx: &PredictContext,
+        on_token: &mut dyn FnMut(GeneratedToken) -> Result<(), InferenceError>,
+    ) -> Result<GenerationOutput, InferenceError> {
+        let mut generator = model.generator()?;
+        
+        let prompt = self.template.render(&self.messages, true)?;
+        let prompt_tokens = match prompt.strip_prefix(self.rendered.as_str()) {
+            Some(added) => {
+                let mut tokens = self.tokens.clone();
+                tokens.extend(generator.encode(added, false)?);
+                tokens
+            }
+            None => generator.encode(&prompt, false)?,
+        };
+        
+        let output = generator.generate_tokens_streaming(prompt_tokens.clone(), config, ctx, on_token)?;
+        
+        // Keep the generated ids rather than re-tokenizing the reply, so the
+        // cached tokens stay a prefix of the next prompt
+        self.messages.push(ChatMessage::assistant(output.text.clone()));
+        let rendered = self.template.render(&self.messages, false)?;
+        let replied = format!("{}{}", prompt, output.text);
+        self.tokens = match rendered.strip_prefix(replied.as_str()) {
+            Some(rest) if output.stop_reason != StopReason::StopSequence => {
+                let mut tokens = prompt_tokens;
+                tokens.extend_from_slice(&output.token_ids);
+                tokens.extend(generator.encode(rest, false)?);
+                tokens
+            }
+            _ => generator.encode(&rendered, false)?,
+        };
+        self.rendered = rendered;
+        Ok(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::candle_engine::generation::{CausalLm, TextGenerator};
+    use candle_core::{Device, Tensor as CandleTensor};
+    use serde_json::json;
+    use std::sync::{Arc, Mutex, MutexGuard};
+    use tokenizers::models::wordlevel::WordLevel;
+    use tokenizers::pre_tokenizers::whitespace::Whitespace;
+    use tokenizers::Tokenizer;
+    
+    const CHATML: &str = "{% for message in messages %}{{ '<|im_start|>' + message.role + '\\n' + message.content.strip() + '<|im_end|>\\n' }}{% endfor %}{% if add_generation_prompt %}{{ '<|im_start|>assistant\\n' }}{% endif %}";
+    
+    #[test]
+    fn test_render_chatml() {
+        let template = ChatTemplate::new(CHATML);
+        let messages = vec![ChatMessage::system("Be brief."), ChatMessage::user(" Hi ")];
+        
+        let prompt = template.render(&messages, true).unwrap();
+        assert_eq!(prompt, "<|im_start|>system\nBe brief.<|im_end|>\n<|im_start|>user\nHi<|im_end|>\n<|im_start|>assistant\n");
+        assert!(!template.render(&messages, false).unwrap().ends_with("assistant\n"));
+    }
+    
+    #[test]
+    fn test_from_tokenizer_config() {
+        let config = json!({
+            "bos_token": { "__type": "AddedToken", "content": "<s>" },
+            "eos_token": "</s>",
+            "chat_template": [
+                { "name": "tool_use", "template": "unused" },
+                { "name": "default", "template": "{{ bos_token }}{% for m in messages %}[{{ m.role }}] {{ m.content }}{{ eos_token }}{% endfor %}" }
+            ]
+        });
+        let template = ChatTemplate::from_tokenizer_config(&config).unwrap().unwrap();
+        assert_eq!(template.eos_token(), "</s>");
+        assert_eq!(template.render(&[ChatMessage::user("hey")], false).unwrap(), "<s>[user] hey</s>");
+        
+        assert!(ChatTemplate::from_tokenizer_config(&json!({ "eos_token": "</s>" })).unwrap().is_none());
+    }
+    
+    #[test]
+    fn test_raise_exception() {
+        let template = ChatTemplate::new("{% if messages[0].role != 'user' %}{{ raise_exception('Conversations must start with a user message') }}{% endif %}ok");
+        assert_eq!(template.render(&[ChatMessage::user("a")], false).unwrap(), "ok");
+        
+        let err = template.render(&[ChatMessage::assistant("a")], false).unwrap_err();
+        assert!(err.to_string().contains("must start with a user message"));
+    }
+    
+    const VOCAB: [&str; 8] = ["<unk>", "</s>", "system", "user", "assistant", "hi", "hello", "there"];
+    
+    /// Every `(tokens, position)` pair passed to `forward`
+    type CallLog = Arc<Mutex<Vec<(Vec<u32>, usize)>>>;
+    
+    /// Answers "hello there" after "assistant" and records forward calls
+    struct ScriptedLm {
+        calls: CallLog,
+    }
+    
+    impl CausalLm for ScriptedLm {
+        fn forward(&mut self, tokens: &[u32], position: usize) -> Result<CandleTensor, InferenceError> {
+            self.calls.lock().unwrap().push((tokens.to_vec(), position));
+            let next = match tokens.last() {
+                Some(4) => 6,
+                Some(6) => 7,
+                _ => 1,
+            };
+            let mut logits = vec![0f32; VOCAB.len()];
+            logits[next] = 1.0;
+            Ok(CandleTensor::new(logits, &Device::Cpu).unwrap())
+        }
+        
+        fn clear_cache(&mut self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+        
+        fn max_sequence_length(&self) -> usize {
+            64
+        }
+    }
+    
+    struct ScriptedChat(Mutex<TextGenerator>);
+    
+    impl TextGeneration for ScriptedChat {
+        fn generator(&self) -> Result<MutexGuard<'_, TextGenerator>, InferenceError> {
+            Ok(self.0.lock().unwrap())
+        }
+    }
+    
+    #[test]
+    fn test_conversation_reuses_cache_between_turns() {
+        let vocab = VOCAB.iter().enumerate().map(|(id, word)| (word.to_string(), id as u32)).collect();
+        let mut tokenizer = Tokenizer::new(WordLevel::builder().vocab(vocab).unk_token("<unk>".to_string()).build().unwrap());
+        tokenizer.with_pre_tokenizer(Some(Whitespace {}));
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let model = ScriptedChat(Mutex::new(TextGenerator::new(Box::new(ScriptedLm { calls: calls.clone() }), tokenizer, vec![1])));
+        
+        let template = ChatTemplate::new("{% for m in messages %}{{ m.role }} {{ m.content }} {% endfor %}{% if add_generation_prompt %}assistant {% endif %}");
+        let mut conversation = Conversation::new(template);
+        let config = GenerationConfig::default();
+        let ctx = PredictContext::new();
+        
+        let first = conversation.send(&model, "hi", &config, &ctx).unwrap();
+        assert_eq!(first.text, "hello there");
+        let second = conversation.send(&model, "hi", &config, &ctx).unwrap();
+        assert_eq!(second.text, "hello there");
+        
+        // The second turn only runs the new "user hi assistant" tokens
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls[0], (vec![3, 5, 4], 0));
+        assert_eq!(calls[3], (vec![3, 5, 4], 5));
+        assert_eq!(conversation.messages().len(), 4);
+        assert_eq!(conversation.tokens(), &[3, 5, 4, 6, 7, 3, 5, 4, 6, 7]);
+    }
+}
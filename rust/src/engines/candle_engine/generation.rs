@@ -0,0 +1,682 @@
+use crate::models::{InferenceError, PredictContext, Tensor};
+use std::path::Path;
+use std::sync::{Arc, MutexGuard};
+
+use candle_core::Tensor as CandleTensor;
+use tokenizers::Tokenizer;
+
+use super::chat::{ChatMessage, ChatTemplate};
+use super::grammar::{GrammarConstraint, TokenTrie};
+use super::sampling::{find_stop_sequence, partial_stop_sequence_len, GenerationConfig, Sampler};
+
+/// Why a generation run stopped
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    /// The model produced an end-of-sequence token
+    EndOfSequence,
+    /// `max_new_tokens` tokens were generated
+    MaxTokens,
+    /// The model's context window is full
+    ContextFull,
+    /// The completion reached one of the configured stop sequences
+    StopSequence,
+    /// The token limit or the context window ended the run before the
+    /// grammar was complete, so the completion does not parse
+    GrammarIncomplete,
+}
+
+/// Result of a generation run
+#[derive(Debug, Clone)]
+pub struct GenerationOutput {
+    /// Decoded completion, excluding the prompt and any stop sequence
+    pub text: String,
+    /// Generated token ids, excluding the prompt and any end-of-sequence token
+    /// 
+    /// After a stop sequence this still includes the tokens that spelled it.
+    pub token_ids: Vec<u32>,
+    /// Number of tokens in the encoded prompt
+    pub prompt_tokens: usize,
+    /// Why generation stopped
+    pub stop_reason: StopReason,
+}
+
+/// A token produced while streaming a completion
+#[derive(Debug, Clone)]
+pub struct GeneratedToken {
+    /// Token id
+    pub token_id: u32,
+    /// Text this token adds to the completion
+    /// 
+    /// Empty while the token ends in an incomplete character; the text is
+    /// emitted with the token that completes it.
+    pub text: String,
+    /// Log probability of the token under the model
+    pub logprob: f32,
+}
+
+/// A decoder-only language model that keeps its own KV cache
+/// 
+/// `forward` receives only the tokens that are not yet cached, starting at
+/// `position`, and returns the logits for the last of them as a `[vocab]`
+/// F32 tensor.
+pub trait CausalLm: Send {
+    /// Run the model over `tokens`, appending them to the KV cache
+    fn forward(&mut self, tokens: &[u32], position: usize) -> Result<CandleTensor, InferenceError>;
+    
+    /// Drop everything in the KV cache
+    fn clear_cache(&mut self) -> Result<(), InferenceError>;
+    
+    /// Maximum number of tokens the model can attend over
+    fn max_sequence_length(&self) -> usize;
+}
+
+/// Tokens that end a turn in common chat and base model vocabularies
+const EOS_TOKENS: [&str; 4] = ["</s>", "<|endoftext|>", "<|end_of_text|>", "<|eot_id|>"];
+
+/// Merge configured end-of-sequence ids with well-known EOS tokens in the vocabulary
+pub(crate) fn eos_token_ids(tokenizer: &Tokenizer, mut ids: Vec<u32>) -> Vec<u32> {
+    for token in EOS_TOKENS {
+        if let Some(id) = tokenizer.token_to_id(token) {
+            if !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+    ids
+}
+
+/// Read `eos_token_id` (a single id or a list) from a HuggingFace `config.json`
+pub(crate) fn config_eos_token_ids(config: &serde_json::Value) -> Vec<u32> {
+    match &config["eos_token_id"] {
+        serde_json::Value::Number(id) => id.as_u64().map(|id| vec![id as u32]).unwrap_or_default(),
+        serde_json::Value::Array(ids) => ids.iter().filter_map(|id| id.as_u64()).map(|id| id as u32).collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Load a HuggingFace `tokenizer.json`
+pub(crate) fn load_tokenizer(path: &Path) -> Result<Tokenizer, InferenceError> {
+    Tokenizer::from_file(path)
+        .map_err(|e| InferenceError::model_load(format!("Failed to load tokenizer {}: {}", path.display(), e)))
+}
+
+/// Incremental detokenizer for streamed tokens
+/// 
+/// Each step decodes a short window that overlaps the last emitted text, so
+/// tokenizer-specific spacing stays right without re-decoding everything.
+#[derive(Debug, Default)]
+struct DeltaDecoder {
+    prev_index: usize,
+    current_index: usize,
+}
+
+impl DeltaDecoder {
+    /// Text added by the last of `tokens`, or an empty string if it is incomplete
+    fn next(&mut self, generator: &TextGenerator, tokens: &[u32]) -> Result<String, InferenceError> {
+        let prev_text = generator.decode(&tokens[self.prev_index..self.current_index])?;
+        let text = generator.decode(&tokens[self.prev_index..])?;
+        
+        match text.get(prev_text.len()..) {
+            Some(delta) if !delta.is_empty() && !delta.ends_with('\u{FFFD}') => {
+                let delta = delta.to_string();
+                self.prev_index = self.current_index;
+                self.current_index = tokens.len();
+                Ok(delta)
+            }
+            _ => Ok(String::new()),
+        }
+    }
+}
+
+/// Token-by-token text generation on top of a [`CausalLm`]
+/// 
+/// The generator remembers which tokens are in the model's KV cache, so a
+/// prompt that extends the previous one only runs the new tokens.
+pub struct TextGenerator {
+    model: Box<dyn CausalLm>,
+    tokenizer: Tokenizer,
+    eos_token_ids: Vec<u32>,
+    cached_tokens: Vec<u32>,
+    /// Vocabulary index for grammar-constrained decoding, built on first use
+    token_trie: Option<Arc<TokenTrie>>,
+    chat_template: Option<ChatTemplate>,
+}
+
+impl std::fmt::Debug for TextGenerator {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TextGenerator")
+            .field("eos_token_ids", &self.eos_token_ids)
+            .field("cached_tokens", &self.cached_tokens.len())
+            .finish()
+    }
+}
+
+impl TextGenerator {
+    /// Create a generator from a model, its tokenizer and its end-of-sequence ids
+    pub fn new(model: Box<dyn CausalLm>, tokenizer: Tokenizer, eos_token_ids: Vec<u32>) -> Self {
+        Self {
+            model,
+            tokenizer,
+            eos_token_ids,
+            cached_tokens: Vec::new(),
+            token_trie: None,
+            chat_template: None,
+        }
+    }
+    
+    /// Use `template` for chat messages
+    /// 
+    /// The template's end-of-sequence token also ends generation, since chat
+    /// models use it to close a turn.
+    pub fn set_chat_template(&mut self, template: ChatTemplate) {
+        if let Some(id) = self.tokenizer.token_to_id(template.eos_token()) {
+            if !self.eos_token_ids.contains(&id) {
+                self.eos_token_ids.push(id);
+                self.token_trie = None;
+            }
+        }
+        self.chat_template = Some(template);
+    }
+    
+    /// Get the model's chat template, if it has one
+    pub fn chat_template(&self) -> Option<&ChatTemplate> {
+        self.chat_template.as_ref()
+    }
+    
+    /// Get the tokenizer
+    pub fn tokenizer(&self) -> &Tokenizer {
+        &self.tokenizer
+    }
+    
+    /// Get the end-of-sequence token ids
+    pub fn eos_token_ids(&self) -> &[u32] {
+        &self.eos_token_ids
+    }
+    
+    /// Get the tokens currently held in the KV cache
+    pub fn cached_tokens(&self) -> &[u32] {
+        &self.cached_tokens
+    }
+    
+    /// Encode text into token ids
+    pub fn encode(&self, text: &str, add_special_tokens: bool) -> Result<Vec<u32>, InferenceError> {
+        let encoding = self.tokenizer.encode(text, add_special_tokens)
+            .map_err(|e| InferenceError::prediction(format!("Failed to tokenize prompt: {}", e)))?;
+        Ok(encoding.get_ids().to_vec())
+    }
+    
+    /// Decode token ids into text, skipping special tokens
+    pub fn decode(&self, tokens: &[u32]) -> Result<String, InferenceError> {
+        self.tokenizer.decode(tokens, true)
+            .map_err(|e| InferenceError::prediction(format!("Failed to decode tokens: {}", e)))
+    }
+    
+    /// Get the vocabulary index used to apply grammars
+    fn token_trie(&mut self) -> Arc<TokenTrie> {
+        let Self { tokenizer, eos_token_ids, token_trie, .. } = self;
+        token_trie.get_or_insert_with(|| Arc::new(TokenTrie::new(tokenizer, eos_token_ids)))
+            .clone()
+    }
+    
+    /// Clear the KV cache
+    pub fn reset(&mut self) -> Result<(), InferenceError> {
+        self.cached_tokens.clear();
+        self.model.clear_cache()
+    }
+    
+    /// Compute the next-token logits for a full token sequence
+    /// 
+    /// When `tokens` extends the cached sequence only the new suffix is run;
+    /// otherwise the cache is cleared and the whole sequence is processed.
+    pub fn next_logits(&mut self, tokens: &[u32]) -> Result<CandleTensor, InferenceError> {
+        if tokens.is_empty() {
+            return Err(InferenceError::invalid_shape_msg("Cannot run a language model on an empty token sequence"));
+        }
+        if tokens.len() > self.model.max_sequence_length() {
+            return Err(InferenceError::invalid_shape_msg(format!(
+                "Sequence of {} tokens exceeds the model's context of {} tokens",
+                tokens.len(), self.model.max_sequence_length()
+            )));
+        }
+        
+        let reuse = !self.cached_tokens.is_empty()
+            && tokens.len() > self.cached_tokens.len()
+            && tokens.starts_with(&self.cached_tokens);
+        if !reuse {
+            self.reset()?;
+        }
+        
+        let position = self.cached_tokens.len();
+        let logits = self.model.forward(&tokens[position..], position)?;
+        self.cached_tokens.extend_from_slice(&tokens[position..]);
+        Ok(logits)
+    }
+    
+    /// Generate a greedy completion for `prompt`
+    pub fn generate(&mut self, prompt: &str, max_new_tokens: usize) -> Result<GenerationOutput, InferenceError> {
+        self.generate_with_context(prompt, max_new_tokens, &PredictContext::new())
+    }
+    
+    /// Generate a greedy completion, checking `ctx` for cancellation before each token
+    pub fn generate_with_context(
+        &mut self,
+        prompt: &str,
+        max_new_tokens: usize,
+        ctx: &PredictContext,
+    ) -> Result<GenerationOutput, InferenceError> {
+        let config = GenerationConfig::default().with_max_new_tokens(max_new_tokens);
+        self.generate_with_config(prompt, &config, ctx)
+    }
+    
+    /// Generate a completion with the decoding settings in `config`
+    pub fn generate_with_config(
+        &mut self,
+        prompt: &str,
+        config: &GenerationConfig,
+        ctx: &PredictContext,
+    ) -> Result<GenerationOutput, InferenceError> {
+        let prompt_tokens = self.encode(prompt, true)?;
+        self.generate_tokens(prompt_tokens, config, ctx)
+    }
+    
+    /// Generate a completion for an already tokenized prompt
+    pub fn generate_tokens(
+        &mut self,
+        prompt_tokens: Vec<u32>,
+        config: &GenerationConfig,
+        ctx: &PredictContext,
+    ) -> Result<GenerationOutput, InferenceError> {
+        self.generate_tokens_streaming(prompt_tokens, config, ctx, |_| Ok(()))
+    }
+    
+    /// Generate a completion, passing each new token to `on_token` as it is produced
+    /// 
+    /// An error from `on_token` stops generation and is returned as is, which
+    /// lets a closed consumer cancel the run. Text that might be the start of
+    /// a stop sequence is held back until it is known not to be one. Stop
+    /// sequences are ignored under a grammar, which decides where text ends.
+    pub fn generate_tokens_streaming<F>(
+        &mut self,
+        prompt_tokens: Vec<u32>,
+        config: &GenerationConfig,
+        ctx: &PredictContext,
+        mut on_token: F,
+    ) -> Result<GenerationOutput, InferenceError>
+    where
+        F: FnMut(GeneratedToken) -> Result<(), InferenceError>,
+    {
+        let mut sampler = Sampler::new(config)?;
+        let trie = config.grammar.as_ref().map(|_| self.token_trie());
+        let mut constraint = config.grammar.as_ref()
+            .zip(trie.as_deref())
+            .map(|(grammar, trie)| GrammarConstraint::new(grammar, trie));
+        let stop_sequences: &[String] = if constraint.is_some() { &[] } else { &config.stop_sequences };
+        let prompt_len = prompt_tokens.len();
+        let mut tokens = prompt_tokens;
+        let mut stop_reason = StopReason::MaxTokens;
+        let mut deltas = DeltaDecoder::default();
+        
+        // Completion text so far, how much of it was passed on, and where a stop sequence cut it
+        let mut completion = String::new();
+        let mut emitted = 0;
+        let mut stop_at = None;
+        
+        for _ in 0..config.max_new_tokens {
+            ctx.check()?;
+            
+            if tokens.len() >= self.model.max_sequence_length() {
+                stop_reason = StopReason::ContextFull;
+                break;
+            }
+            
+            let mut logits: Vec<f32> = self.next_logits(&tokens)?
+                .to_vec1()
+                .map_err(|e| InferenceError::prediction(format!("Failed to extract logits: {}", e)))?;
+            if let Some(constraint) = &constraint {
+                if !constraint.mask(&mut logits, &self.eos_token_ids) {
+                    if constraint.is_complete() {
+                        stop_reason = StopReason::EndOfSequence;
+                        break;
+                    }
+                    return Err(InferenceError::prediction("No token in the vocabulary continues the grammar"));
+                }
+            }
+            let (next_token, logprob) = sampler.sample(&logits, &tokens)?;
+            
+            if self.eos_token_ids.contains(&next_token) {
+                stop_reason = StopReason::EndOfSequence;
+                break;
+            }
+            if let Some(constraint) = &mut constraint {
+                constraint.accept_token(next_token)?;
+            }
+            tokens.push(next_token);
+            
+            completion.push_str(&deltas.next(self, &tokens[prompt_len..])?);
+            let stop = find_stop_sequence(&completion, stop_sequences);
+            let end = stop.unwrap_or(completion.len() - partial_stop_sequence_len(&completion, stop_sequences));
+            let text = completion.get(emitted..end).unwrap_or_default().to_string();
+            emitted = emitted.max(end);
+            
+            on_token(GeneratedToken { token_id: next_token, text, logprob })?;
+            if stop.is_some() {
+                stop_at = stop;
+                stop_reason = StopReason::StopSequence;
+                break;
+            }
+        }
+        
+        if constraint.as_ref().is_some_and(|constraint| !constraint.is_complete())
+            && matches!(stop_reason, StopReason::MaxTokens | StopReason::ContextFull)
+        {
+            stop_reason = StopReason::GrammarIncomplete;
+        }
+        
+        let token_ids = tokens.split_off(prompt_len);
+        let text = match stop_at {
+            Some(end) => {
+                completion.truncate(end);
+                completion
+            }
+            None => self.decode(&token_ids)?,
+        };
+        Ok(GenerationOutput {
+            text,
+            token_ids,
+            prompt_tokens: prompt_len,
+            stop_reason,
+        })
+    }
+}
+
+/// Generation interface shared by the decoder-only model wrappers
+/// 
+/// Wrappers only provide access to their [`TextGenerator`]; generation, cache
+/// resets and tensor-level next-token logits are implemented here once.
+pub trait TextGeneration: Send + Sync {
+    /// Lock the wrapper's generator for exclusive use
+    fn generator(&self) -> Result<MutexGuard<'_, TextGenerator>, InferenceError>;
+    
+    /// Generate a greedy completion for `prompt`
+    fn generate(&self, prompt: &str, max_new_tokens: usize) -> Result<GenerationOutput, InferenceError> {
+        self.generate_with_context(prompt, max_new_tokens, &PredictContext::new())
+    }
+    
+    /// Generate a greedy completion, stopping early if `ctx` is cancelled
+    fn generate_with_context(
+        &self,
+        prompt: &str,
+        max_new_tokens: usize,
+        ctx: &PredictContext,
+    ) -> Result<GenerationOutput, InferenceError> {
+        self.generator()?.generate_with_context(prompt, max_new_tokens, ctx)
+    }
+    
+    /// Generate a completion with the decoding settings in `config`
+    fn generate_with_config(
+        &self,
+        prompt: &str,
+        config: &GenerationConfig,
+        ctx: &PredictContext,
+    ) -> Result<GenerationOutput, InferenceError> {
+        self.generator()?.generate_with_config(prompt, config, ctx)
+    }
+    
+    /// Generate a completion, passing each new token to `on_token` as it is produced
+    fn generate_stream(
+        &self,
+        prompt: &str,
+        config: &GenerationConfig,
+        ctx: &PredictContext,
+        on_token: &mut dyn FnMut(GeneratedToken) -> Result<(), InferenceError>,
+    ) -> Result<GenerationOutput, InferenceError> {
+        let mut generator = self.generator()?;
+        let prompt_tokens = generator.encode(prompt, true)?;
+        generator.generate_tokens_streaming(prompt_tokens, config, ctx, on_token)
+    }
+    
+    /// Get the model's chat template, if it has one
+    fn chat_template(&self) -> Result<Option<ChatTemplate>, InferenceError> {
+        Ok(self.generator()?.chat_template().cloned())
+    }
+    
+    /// Render `messages` with the model's chat template
+    fn apply_chat_template(&self, messages: &[ChatMessage], add_generation_prompt: bool) -> Result<String, InferenceError> {
+        self.chat_template()?
+            .ok_or_else(|| InferenceError::configuration("Model has no chat template"))?
+            .render(messages, add_generation_prompt)
+    }
+    
+    /// Generate the assistant's reply to `messages`, streaming its tokens
+    /// 
+    /// The prompt is rendered with the model's chat template. Special tokens
+    /// come from the template only, so none are added during tokenization.
+    fn chat_stream(
+        &self,
+        messages: &[ChatMessage],
+        config: &GenerationConfig,
+        ctx: &PredictContext,
+        on_token: &mut dyn FnMut(GeneratedToken) -> Result<(), InferenceError>,
+    ) -> Result<GenerationOutput, InferenceError> {
+        let mut generator = self.generator()?;
+        let prompt = generator.chat_template()
+            .ok_or_else(|| InferenceError::configuration("Model has no chat template"))?
+            .render(messages, true)?;
+        let prompt_tokens = generator.encode(&prompt, false)?;
+        generator.generate_tokens_streaming(prompt_tokens, config, ctx, on_token)
+    }
+    
+    /// Clear the KV cache
+    fn reset(&self) -> Result<(), InferenceError> {
+        self.generator()?.reset()
+    }
+    
+    /// Next-token logits `[1, vocab]` for token ids of shape `[seq]` or `[1, seq]`
+    fn next_token_logits(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+        if !matches!(input.shape(), [_] | [1, _]) {
+            return Err(InferenceError::invalid_shape_msg(format!(
+                "Expected token ids of shape [seq] or [1, seq], got {:?}", input.shape()
+            )));
+        }
+        let tokens: Vec<u32> = input.to_i64_vec()?.into_iter().map(|id| id as u32).collect();
+        
+        let logits = self.generator()?.next_logits(&tokens)?;
+        let data: Vec<f32> = logits.to_vec1()
+            .map_err(|e| InferenceError::prediction(format!("Failed to extract logits: {}", e)))?;
+        let vocab_size = data.len();
+        Tensor::from_f32(data, vec![1, vocab_size])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CancellationToken;
+    use candle_core::Device;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+    use tokenizers::models::wordlevel::WordLevel;
+    use tokenizers::pre_tokenizers::whitespace::Whitespace;
+    
+    const VOCAB: [&str; 6] = ["<unk>", "</s>", "the", "cat", "sat", "down"];
+    
+    /// Every `(tokens, position)` pair passed to `forward`
+    type CallLog = Arc<Mutex<Vec<(Vec<u32>, usize)>>>;
+    
+    /// Predicts `next[last token]` and records every forward call
+    struct LookupLm {
+        next: HashMap<u32, u32>,
+        calls: CallLog,
+    }
+    
+    impl CausalLm for LookupLm {
+        fn forward(&mut self, tokens: &[u32], position: usize) -> Result<CandleTensor, InferenceError> {
+            self.calls.lock().unwrap().push((tokens.to_vec(), position));
+            let last = *tokens.last().unwrap();
+            let mut logits = vec![0f32; VOCAB.len()];
+            logits[self.next.get(&last).copied().unwrap_or(1) as usize] = 1.0;
+            Ok(CandleTensor::new(logits, &Device::Cpu).unwrap())
+        }
+        
+        fn clear_cache(&mut self) -> Result<(), InferenceError> {
+            Ok(())
+        }
+        
+        fn max_sequence_length(&self) -> usize {
+            16
+        }
+    }
+    
+    fn word_tokenizer() -> Tokenizer {
+        let vocab = VOCAB.iter().enumerate().map(|(id, word)| (word.to_string(), id as u32)).collect();
+        let model = WordLevel::builder()
+            .vocab(vocab)
+            .unk_token("<unk>".to_string())
+            .build()
+            .unwrap();
+        let mut tokenizer = Tokenizer::new(model);
+        tokenizer.with_pre_tokenizer(Some(Whitespace {}));
+        tokenizer
+    }
+    
+    fn generator(next: &[(u32, u32)]) -> (TextGenerator, CallLog) {
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let model = LookupLm { next: next.iter().copied().collect(), calls: calls.clone() };
+        (TextGenerator::new(Box::new(model), word_tokenizer(), vec![1]), calls)
+    }
+    
+    #[test]
+    fn test_generate_stops_at_eos() {
+        // the -> cat -> sat -> down -> </s>
+        let (mut generator, calls) = generator(&[(2, 3), (3, 4), (4, 5)]);
+        
+        let output = generator.generate("the", 10).unwrap();
+        assert_eq!(output.text, "cat sat down");
+        assert_eq!(output.token_ids, vec![3, 4, 5]);
+        assert_eq!(output.prompt_tokens, 1);
+        assert_eq!(output.stop_reason, StopReason::EndOfSequence);
+        
+        // The prompt runs once, then each new token is fed on its own
+        let calls = calls.lock().unwrap();
+        assert_eq!(calls[0], (vec![2], 0));
+        assert_eq!(calls[1], (vec![3], 1));
+        assert_eq!(calls[3], (vec![5], 3));
+    }
+    
+    #[test]
+    fn test_generate_reuses_cache_for_extended_prompt() {
+        let (mut generator, calls) = generator(&[(2, 3), (3, 4), (4, 5)]);
+        
+        let output = generator.generate("the", 1).unwrap();
+        assert_eq!(output.stop_reason, StopReason::MaxTokens);
+        assert_eq!(generator.cached_tokens(), &[2]);
+        
+        generator.generate("the cat sat", 1).unwrap();
+        assert_eq!(calls.lock().unwrap().last().unwrap(), &(vec![3, 4], 1));
+        
+        // A diverging prompt starts over from position 0
+        generator.generate("cat", 1).unwrap();
+        assert_eq!(calls.lock().unwrap().last().unwrap(), &(vec![3], 0));
+    }
+    
+    #[test]
+    fn test_generate_cancelled() {
+        let (mut generator, _) = generator(&[(2, 3)]);
+        let token = CancellationToken::new();
+        token.cancel();
+        
+        let ctx = PredictContext::new().with_token(token);
+        let result = generator.generate_with_context("the", 5, &ctx);
+        assert!(matches!(result, Err(InferenceError::Cancelled(_))));
+    }
+    
+    #[test]
+    fn test_streaming_deltas_add_up_to_text() {
+        let (mut generator, _) = generator(&[(2, 3), (3, 4), (4, 5)]);
+        let mut streamed = Vec::new();
+        
+        let config = GenerationConfig::default().with_max_new_tokens(10);
+        let output = generator.generate_tokens_streaming(vec![2], &config, &PredictContext::new(), |token| {
+            streamed.push(token);
+            Ok(())
+        }).unwrap();
+        
+        let ids: Vec<u32> = streamed.iter().map(|t| t.token_id).collect();
+        assert_eq!(ids, output.token_ids);
+        assert_eq!(streamed.iter().map(|t| t.text.as_str()).collect::<String>(), output.text);
+        assert!(streamed.iter().all(|t| t.logprob < 0.0));
+    }
+    
+    #[test]
+    fn test_streaming_stops_when_consumer_fails() {
+        let (mut generator, calls) = generator(&[(2, 3), (3, 4), (4, 5)]);
+        
+        let result = generator.generate_tokens_streaming(vec![2], &GenerationConfig::default(), &PredictContext::new(), |_| {
+            Err(InferenceError::cancelled("stream closed"))
+        });
+        assert!(matches!(result, Err(InferenceError::Cancelled(_))));
+        assert_eq!(calls.lock().unwrap().len(), 1);
+    }
+    
+    #[test]
+    fn test_stop_sequence_truncates_and_holds_back_text() {
+        let (mut generator, _) = generator(&[(2, 3), (3, 4), (4, 5)]);
+        let config = GenerationConfig::default().with_stop_sequence(" sat down");
+        let mut streamed = Vec::new();
+        
+        let output = generator.generate_tokens_streaming(vec![2], &config, &PredictContext::new(), |token| {
+            streamed.push(token.text);
+            Ok(())
+        }).unwrap();
+        
+        assert_eq!(output.stop_reason, StopReason::StopSequence);
+        assert_eq!(output.text, "cat");
+        assert_eq!(output.token_ids, vec![3, 4, 5]);
+        // " sat" could have started the stop sequence, so it was never streamed
+        assert_eq!(streamed, vec!["cat", "", ""]);
+    }
+    
+    #[test]
+    fn test_grammar_constrains_tokens() {
+        use super::super::grammar::Grammar;
+        
+        // The model wants "cat" after "the", but the grammar only allows "sat" then "down"
+        let (mut generator, _) = generator(&[(2, 3), (3, 4), (4, 5)]);
+        let config = GenerationConfig::default().with_grammar(Grammar::parse(r#"root ::= "sat" "down""#).unwrap());
+        
+        let output = generator.generate_tokens(vec![2], &config, &PredictContext::new()).unwrap();
+        assert_eq!(output.token_ids, vec![4, 5]);
+        assert_eq!(output.stop_reason, StopReason::EndOfSequence);
+        
+        let config = GenerationConfig::default().with_grammar(Grammar::parse(r#"root ::= "dog""#).unwrap());
+        assert!(generator.generate_tokens(vec![2], &config, &PredictContext::new()).is_err());
+    }
+    
+    #[test]
+    fn test_grammar_cut_short_is_reported() {
+        use super::super::grammar::Grammar;
+        
+        let (mut generator, _) = generator(&[(2, 3), (3, 4), (4, 5)]);
+        let config = GenerationConfig::default()
+            .with_max_new_tokens(1)
+            .with_grammar(Grammar::parse(r#"root ::= "sat" "down""#).unwrap());
+        
+        let output = generator.generate_tokens(vec![2], &config, &PredictContext::new()).unwrap();
+        assert_eq!(output.token_ids, vec![4]);
+        assert_eq!(output.stop_reason, StopReason::GrammarIncomplete);
+    }
+    
+    #[test]
+    fn test_stop_sequences_ignored_under_grammar() {
+        use super::super::grammar::Grammar;
+        
+        let (mut generator, _) = generator(&[(2, 3), (3, 4), (4, 5)]);
+        let config = GenerationConfig::default()
+            .with_stop_sequence("sat")
+            .with_grammar(Grammar::parse(r#"root ::= "sat" "down""#).unwrap());
+        
+        let output = generator.generate_tokens(vec![2], &config, &PredictContext::new()).unwrap();
+        assert_eq!(output.token_ids, vec![4, 5]);
+        assert_eq!(output.stop_reason, StopReason::EndOfSequence);
+    }
+}
@@ -0,0 +1,446 @@
+use crate::engines::{Model, EngineType, ModelMetadata};
+use crate::models::{InferenceError, Tensor, TensorSpec, DataType};
+use async_trait::async_trait;
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+#[cfg(feature = "candle")]
+use candle_core::quantized::{gguf_file, GgmlDType};
+#[cfg(feature = "candle")]
+use candle_core::Device;
+#[cfg(feature = "candle")]
+use super::generation::CausalLm;
+#[cfg(feature = "candle")]
+use super::llama::QuantizedLlamaBackend;
+
+/// Magic bytes at the start of every GGUF file
+pub const GGUF_MAGIC: &[u8; 4] = b"GGUF";
+
+/// Check whether bytes represent a GGUF file
+pub fn is_gguf_format(bytes: &[u8]) -> bool {
+    bytes.starts_with(GGUF_MAGIC)
+}
+
+/// Header information parsed from a GGUF file
+#[derive(Debug, Clone, Default)]
+pub struct GgufInfo {
+    /// GGUF container version (1-3)
+    pub version: u32,
+    /// Value of `general.architecture`
+    pub architecture: Option<String>,
+    pub name: Option<String>,
+    /// Value of `general.version`
+    pub model_version: Option<String>,
+    pub description: Option<String>,
+    pub author: Option<String>,
+    pub license: Option<String>,
+    /// Value of `<architecture>.context_length`
+    pub context_length: Option<usize>,
+    /// Value of `tokenizer.ggml.model`
+    pub tokenizer_model: Option<String>,
+    /// Vocabulary from `tokenizer.ggml.tokens`, indexed by token id
+    pub tokens: Vec<String>,
+    pub bos_token_id: Option<u32>,
+    pub eos_token_id: Option<u32>,
+    /// Most common quantized weight type (e.g. "Q4_K")
+    pub quantization: Option<String>,
+    pub tensor_count: usize,
+}
+
+#[cfg(feature = "candle")]
+impl GgufInfo {
+    /// Extract the interesting keys from parsed GGUF content
+    pub fn from_content(content: &gguf_file::Content) -> Self {
+        let string = |key: &str| content.metadata.get(key)
+            .and_then(|v| v.to_string().ok())
+            .cloned();
+        let integer = |key: &str| content.metadata.get(key)
+            .and_then(|v| v.to_u64().ok());
+        
+        let architecture = string("general.architecture");
+        let context_length = architecture.as_ref()
+            .and_then(|arch| integer(&format!("{}.context_length", arch)))
+            .map(|n| n as usize);
+        
+        let tokens = content.metadata.get("tokenizer.ggml.tokens")
+            .and_then(|v| v.to_vec().ok())
+            .map(|values| values.iter()
+                .map(|v| v.to_string().cloned().unwrap_or_default())
+                .collect())
+            .unwrap_or_default();
+        
+        let version = match content.magic {
+            gguf_file::VersionedMagic::GgufV1 => 1,
+            gguf_file::VersionedMagic::GgufV2 => 2,
+            gguf_file::VersionedMagic::GgufV3 => 3,
+        };
+        
+        Self {
+            version,
+            architecture,
+            name: string("general.name"),
+            model_version: string("general.version"),
+            description: string("general.description"),
+            author: string("general.author"),
+            license: string("general.license"),
+            context_length,
+            tokenizer_model: string("tokenizer.ggml.model"),
+            tokens,
+            bos_token_id: integer("tokenizer.ggml.bos_token_id").map(|id| id as u32),
+            eos_token_id: integer("tokenizer.ggml.eos_token_id").map(|id| id as u32),
+            quantization: Self::predominant_quantization(content),
+            tensor_count: content.tensor_infos.len(),
+        }
+    }
+    
+    /// Pick the most common weight type, preferring quantized types over F32/F16 norms
+    fn predominant_quantization(content: &gguf_file::Content) -> Option<String> {
+        let mut counts: HashMap<&'static str, usize> = HashMap::new();
+        for info in content.tensor_infos.values() {
+            *counts.entry(ggml_dtype_name(info.ggml_dtype)).or_insert(0) += 1;
+        }
+        
+        let most_common = |quantized_only: bool| counts.iter()
+            .filter(|(name, _)| !quantized_only || !matches!(**name, "F32" | "F16"))
+            .max_by_key(|(name, count)| (**count, std::cmp::Reverse(**name)))
+            .map(|(name, _)| name.to_string());
+        
+        most_common(true).or_else(|| most_common(false))
+    }
+    
+    /// Convert to the engine-independent metadata representation
+    pub fn model_metadata(&self) -> ModelMetadata {
+        ModelMetadata {
+            name: self.name.clone(),
+            version: self.model_version.clone(),
+            description: self.description.clone(),
+            author: self.author.clone(),
+            license: self.license.clone(),
+            architecture: self.architecture.clone(),
+            context_length: self.context_length,
+            tokenizer: self.tokenizer_model.clone(),
+            quantization: self.quantization.clone(),
+        }
+    }
+}
+
+/// Conventional llama.cpp name for a GGML weight type
+#[cfg(feature = "candle")]
+pub fn ggml_dtype_name(dtype: GgmlDType) -> &'static str {
+    match dtype {
+        GgmlDType::F32 => "F32",
+        GgmlDType::F16 => "F16",
+        GgmlDType::Q4_0 => "Q4_0",
+        GgmlDType::Q4_1 => "Q4_1",
+        GgmlDType::Q5_0 => "Q5_0",
+        GgmlDType::Q5_1 => "Q5_1",
+        GgmlDType::Q8_0 => "Q8_0",
+        GgmlDType::Q8_1 => "Q8_1",
+        GgmlDType::Q2K => "Q2_K",
+        GgmlDType::Q3K => "Q3_K",
+        GgmlDType::Q4K => "Q4_K",
+        GgmlDType::Q5K => "Q5_K",
+        GgmlDType::Q6K => "Q6_K",
+        GgmlDType::Q8K => "Q8_K",
+    }
+}
+
+/// Quantized weights for architectures we can run
+#[cfg(feature = "candle")]
+enum GgufWeights {
+    Llama(Box<Mutex<QuantizedLlamaBackend>>),
+    /// Metadata-only model for architectures without a quantized implementation
+    Unsupported,
+}
+
+/// Model loaded from a GGUF file through Candle's quantized backend
+/// 
+/// Llama-family weights are loaded and run as-is (no dequantization). Other
+/// architectures can still be opened to inspect their metadata.
+pub struct GgufModel {
+    #[cfg(feature = "candle")]
+    weights: GgufWeights,
+    info: GgufInfo,
+    metadata: ModelMetadata,
+    #[cfg(feature = "candle")]
+    device: Device,
+    input_specs: Vec<TensorSpec>,
+    output_specs: Vec<TensorSpec>,
+}
+
+impl std::fmt::Debug for GgufModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("GgufModel");
+        debug.field("architecture", &self.info.architecture)
+            .field("quantization", &self.info.quantization)
+            .field("tensor_count", &self.info.tensor_count);
+        #[cfg(feature = "candle")]
+        debug.field("device", &self.device);
+        debug.finish()
+    }
+}
+
+#[cfg(feature = "candle")]
+impl GgufModel {
+    /// Load a GGUF model from any seekable reader
+    pub fn load<R: std::io::Read + std::io::Seek>(reader: &mut R, device: &Device) -> Result<Self, InferenceError> {
+        let content = gguf_file::Content::read(reader)
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse GGUF file: {}", e)))?;
+        let info = GgufInfo::from_content(&content);
+        
+        println!("📊 GGUF v{}: architecture {:?}, {} tensors, quantization {:?}",
+            info.version, info.architecture, info.tensor_count, info.quantization);
+        
+        let weights = match info.architecture.as_deref() {
+            Some("llama") => {
+                let backend = QuantizedLlamaBackend::from_content(content, &info, reader, device)?;
+                GgufWeights::Llama(Box::new(Mutex::new(backend)))
+            }
+            _ => GgufWeights::Unsupported,
+        };
+        
+        let vocab_size = if info.tokens.is_empty() { None } else { Some(info.tokens.len()) };
+        let input_specs = vec![TensorSpec::new(
+            "input_ids".to_string(),
+            vec![None, None], // batch_size, sequence_length
+            DataType::I64,
+        )];
+        let output_specs = vec![TensorSpec::new(
+            "logits".to_string(),
+            vec![None, vocab_size],
+            DataType::F32,
+        )];
+        
+        Ok(Self {
+            weights,
+            metadata: info.model_metadata(),
+            info,
+            device: device.clone(),
+            input_specs,
+            output_specs,
+        })
+    }
+    
+    /// Get the parsed GGUF header information
+    pub fn info(&self) -> &GgufInfo {
+        &self.info
+    }
+    
+    /// Check whether the architecture can run predictions
+    pub fn is_runnable(&self) -> bool {
+        !matches!(self.weights, GgufWeights::Unsupported)
+    }
+    
+    /// Split a token id tensor of shape [seq] or [batch, seq] into its rows
+    fn token_rows(input: &Tensor) -> Result<Vec<Vec<u32>>, InferenceError> {
+        let seq_len = match input.shape() {
+            [seq_len] | [_, seq_len] if *seq_len > 0 => *seq_len,
+            shape => return Err(InferenceError::invalid_shape_msg(
+                format!("Expected non-empty token ids of shape [seq] or [batch, seq], got {:?}", shape)
+            )),
+        };
+        
+        let ids: Vec<u32> = input.to_i64_vec()?
+            .into_iter()
+            .map(|id| id as u32)
+            .collect();
+        Ok(ids.chunks(seq_len).map(|row| row.to_vec()).collect())
+    }
+}
+
+#[async_trait]
+impl Model for GgufModel {
+    async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+        #[cfg(feature = "candle")]
+        {
+            let backend = match &self.weights {
+                GgufWeights::Llama(backend) => backend,
+                GgufWeights::Unsupported => return Err(InferenceError::unsupported_format(format!(
+                    "GGUF architecture {:?} has no quantized implementation",
+                    self.info.architecture
+                ))),
+            };
+            let rows = Self::token_rows(input)?;
+            let mut backend = backend.lock()
+                .map_err(|_| InferenceError::prediction("GGUF model lock poisoned"))?;
+            
+            let mut data = Vec::new();
+            for row in &rows {
+                // Every row is an independent forward pass over an empty KV cache
+                backend.clear_cache()?;
+                let logits: Vec<f32> = backend.forward(row, 0)?
+                    .to_vec1()
+                    .map_err(|e| InferenceError::prediction(format!("Failed to extract logits: {}", e)))?;
+                data.extend(logits);
+            }
+            
+            let vocab_size = data.len() / rows.len();
+            Tensor::from_f32(data, vec![rows.len(), vocab_size])
+        }
+        #[cfg(not(feature = "candle"))]
+        {
+            let _ = input;
+            Err(InferenceError::configuration("Candle not available"))
+        }
+    }
+    
+    async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+        let mut results = Vec::new();
+        for input in inputs {
+            results.push(self.predict(input).await?);
+        }
+        Ok(results)
+    }
+    
+    fn input_specs(&self) -> &[TensorSpec] {
+        &self.input_specs
+    }
+    
+    fn output_specs(&self) -> &[TensorSpec] {
+        &self.output_specs
+    }
+    
+    fn engine_type(&self) -> EngineType {
+        EngineType::Candle
+    }
+    
+    fn metadata(&self) -> Option<&ModelMetadata> {
+        Some(&self.metadata)
+    }
+    
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_core::quantized::QTensor;
+    use candle_core::{DType, Tensor as CandleTensor};
+    use gguf_file::Value;
+    
+    fn write_gguf(metadata: &[(&str, Value)]) -> Vec<u8> {
+        let weight = CandleTensor::zeros((32, 32), DType::F32, &Device::Cpu).unwrap();
+        let norm = CandleTensor::zeros(32, DType::F32, &Device::Cpu).unwrap();
+        let weight = QTensor::quantize(&weight, GgmlDType::Q8_0).unwrap();
+        let norm = QTensor::quantize(&norm, GgmlDType::F32).unwrap();
+        
+        let metadata: Vec<(&str, &Value)> = metadata.iter().map(|(k, v)| (*k, v)).collect();
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        gguf_file::write(&mut cursor, &metadata, &[("blk.0.weight", &weight), ("norm.weight", &norm)]).unwrap();
+        cursor.into_inner()
+    }
+    
+    #[test]
+    fn test_metadata_parsing() {
+        let bytes = write_gguf(&[
+            ("general.architecture", Value::String("gpt2".to_string())),
+            ("general.name", Value::String("tiny".to_string())),
+            ("gpt2.context_length", Value::U32(1024)),
+            ("tokenizer.ggml.model", Value::String("gpt2".to_string())),
+            ("tokenizer.ggml.tokens", Value::Array(vec![Value::String("a".to_string()), Value::String("b".to_string())])),
+            ("tokenizer.ggml.eos_token_id", Value::U32(1)),
+        ]);
+        assert!(is_gguf_format(&bytes));
+        
+        let model = GgufModel::load(&mut std::io::Cursor::new(bytes), &Device::Cpu).unwrap();
+        assert!(!model.is_runnable());
+        assert_eq!(model.info().tokens, vec!["a", "b"]);
+        assert_eq!(model.info().eos_token_id, Some(1));
+        
+        let metadata = model.metadata().unwrap();
+        assert_eq!(metadata.name.as_deref(), Some("tiny"));
+        assert_eq!(metadata.architecture.as_deref(), Some("gpt2"));
+        assert_eq!(metadata.context_length, Some(1024));
+        assert_eq!(metadata.tokenizer.as_deref(), Some("gpt2"));
+        assert_eq!(metadata.quantization.as_deref(), Some("Q8_0"));
+        assert_eq!(model.output_specs()[0].shape, vec![None, Some(2)]);
+    }
+    
+    #[tokio::test]
+    async fn test_unsupported_architecture_cannot_predict() {
+        let bytes = write_gguf(&[("general.architecture", Value::String("gpt2".to_string()))]);
+        let model = GgufModel::load(&mut std::io::Cursor::new(bytes), &Device::Cpu).unwrap();
+        
+        let input = Tensor::from_i64(vec![0, 1], vec![2]).unwrap();
+        assert!(matches!(model.predict(&input).await, Err(InferenceError::UnsupportedFormat(_))));
+    }
+    
+    #[tokio::test]
+    async fn test_optimization_level_is_rejected() {
+        use crate::engines::candle_engine::CandleEngine;
+        use crate::engines::InferenceEngine;
+        use crate::models::{OptimizationConfig, OptimizationLevel};
+        
+        // GGUF weights are already quantized, so a level can't be honoured
+        let bytes = write_gguf(&[("general.architecture", Value::String("gpt2".to_string()))]);
+        let engine = CandleEngine::cpu().unwrap()
+            .with_optimization(OptimizationConfig::new(OptimizationLevel::Int8))
+            .unwrap();
+        assert!(matches!(engine.load_from_bytes(&bytes).await, Err(InferenceError::Configuration(_))));
+    }
+    
+    fn write_tiny_llama() -> Vec<u8> {
+        let (vocab, hidden, ff) = (8, 8, 16);
+        let weight = |rows: usize, cols: usize, seed: f32| {
+            let data: Vec<f32> = (0..rows * cols).map(|i| ((i as f32 + seed) * 0.37).sin() * 0.5).collect();
+            let tensor = CandleTensor::from_vec(data, (rows, cols), &Device::Cpu).unwrap();
+            QTensor::quantize(&tensor, GgmlDType::F32).unwrap()
+        };
+        let norm = QTensor::quantize(&CandleTensor::ones(hidden, DType::F32, &Device::Cpu).unwrap(), GgmlDType::F32).unwrap();
+        
+        let tensors = vec![
+            ("token_embd.weight", weight(vocab, hidden, 1.0)),
+            ("output.weight", weight(vocab, hidden, 2.0)),
+            ("blk.0.attn_q.weight", weight(hidden, hidden, 3.0)),
+            ("blk.0.attn_k.weight", weight(hidden, hidden, 4.0)),
+            ("blk.0.attn_v.weight", weight(hidden, hidden, 5.0)),
+            ("blk.0.attn_output.weight", weight(hidden, hidden, 6.0)),
+            ("blk.0.ffn_gate.weight", weight(ff, hidden, 7.0)),
+            ("blk.0.ffn_up.weight", weight(ff, hidden, 8.0)),
+            ("blk.0.ffn_down.weight", weight(hidden, ff, 9.0)),
+        ];
+        let metadata = [
+            ("general.architecture", Value::String("llama".to_string())),
+            ("llama.attention.head_count", Value::U32(2)),
+            ("llama.attention.head_count_kv", Value::U32(2)),
+            ("llama.block_count", Value::U32(1)),
+            ("llama.embedding_length", Value::U32(hidden as u32)),
+            ("llama.rope.dimension_count", Value::U32(4)),
+            ("llama.attention.layer_norm_rms_epsilon", Value::F32(1e-5)),
+        ];
+        
+        let mut tensor_refs: Vec<(&str, &QTensor)> = tensors.iter().map(|(n, t)| (*n, t)).collect();
+        tensor_refs.push(("output_norm.weight", &norm));
+        tensor_refs.push(("blk.0.attn_norm.weight", &norm));
+        tensor_refs.push(("blk.0.ffn_norm.weight", &norm));
+        let metadata: Vec<(&str, &Value)> = metadata.iter().map(|(k, v)| (*k, v)).collect();
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        gguf_file::write(&mut cursor, &metadata, &tensor_refs).unwrap();
+        cursor.into_inner()
+    }
+    
+    #[tokio::test]
+    async fn test_repeated_predictions_start_from_an_empty_cache() {
+        let bytes = write_tiny_llama();
+        let model = GgufModel::load(&mut std::io::Cursor::new(bytes), &Device::Cpu).unwrap();
+        assert!(model.is_runnable());
+        
+        let input = Tensor::from_i64(vec![1, 2, 3], vec![3]).unwrap();
+        let first = model.predict(&input).await.unwrap();
+        let second = model.predict(&input).await.unwrap();
+        assert_eq!(first.shape(), &[1, 8]);
+        assert_eq!(first.to_f32_vec().unwrap(), second.to_f32_vec().unwrap());
+        
+        // Each batch row is an independent sequence
+        let batch = Tensor::from_i64(vec![1, 2, 3, 1, 2, 3], vec![2, 3]).unwrap();
+        let batch_logits = model.predict(&batch).await.unwrap().to_f32_vec().unwrap();
+        assert_eq!(batch_logits[..8], batch_logits[8..]);
+        assert_eq!(batch_logits[..8], first.to_f32_vec().unwrap()[..]);
+        
+        let empty = Tensor::from_i64(vec![], vec![0]).unwrap();
+        assert!(model.predict(&empty).await.is_err());
+    }
+}
@@ -0,0 +1,835 @@
+use crate::models::InferenceError;
+use std::collections::{HashMap, HashSet};
+
+use tokenizers::decoders::DecoderWrapper;
+use tokenizers::Tokenizer;
+
+/// Deepest rule nesting the matcher follows; stops left-recursive rules from looping
+const MAX_STACK_DEPTH: usize = 256;
+
+/// One grammar symbol
+#[derive(Debug, Clone, PartialEq)]
+enum Element {
+    /// A single character from a set of inclusive ranges
+    Chars { ranges: Vec<(char, char)>, negated: bool },
+    /// Another rule
+    Rule(usize),
+}
+
+impl Element {
+    fn literal(c: char) -> Self {
+        Element::Chars { ranges: vec![(c, c)], negated: false }
+    }
+    
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Element::Chars { ranges, negated } => ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != *negated,
+            Element::Rule(_) => false,
+        }
+    }
+    
+    /// Whether any code point in `lo..=hi` matches
+    fn matches_any(&self, lo: u32, hi: u32) -> bool {
+        match self {
+            Element::Chars { ranges, negated: false } => ranges.iter().any(|&(a, b)| a as u32 <= hi && lo <= b as u32),
+            Element::Chars { ranges, negated: true } => {
+                // Matches unless the ranges cover all of lo..=hi
+                let mut ranges: Vec<(u32, u32)> = ranges.iter().map(|&(a, b)| (a as u32, b as u32)).collect();
+                ranges.sort_unstable();
+                let mut next = lo;
+                for (a, b) in ranges {
+                    if a > next {
+                        break;
+                    }
+                    next = next.max(b.saturating_add(1));
+                    if next > hi {
+                        return false;
+                    }
+                }
+                true
+            }
+            Element::Rule(_) => false,
+        }
+    }
+}
+
+/// Code points whose UTF-8 encoding starts with `prefix`, an incomplete character
+fn code_point_range(prefix: &[u8]) -> Option<(u32, u32)> {
+    let (len, lead_bits, min) = match prefix.first()? {
+        0xC2..=0xDF => (2, 0x1F, 0x80),
+        0xE0..=0xEF => (3, 0x0F, 0x800),
+        0xF0..=0xF4 => (4, 0x07, 0x10000),
+        _ => return None,
+    };
+    if prefix.len() >= len || prefix[1..].iter().any(|b| b & 0xC0 != 0x80) {
+        return None;
+    }
+    
+    let mut lo = u32::from(prefix[0] & lead_bits);
+    for b in &prefix[1..] {
+        lo = lo << 6 | u32::from(b & 0x3F);
+    }
+    let missing = 6 * (len - prefix.len()) as u32;
+    let hi = (lo << missing | ((1 << missing) - 1)).min(0x10FFFF);
+    let lo = (lo << missing).max(min);
+    (lo <= hi).then_some((lo, hi))
+}
+
+type Alternative = Vec<Element>;
+
+/// A context-free grammar written in llama.cpp's GBNF notation
+/// 
+/// Supports rules (`name ::= ...`), string literals, character classes
+/// (`[a-z]`, `[^"]`), `.`, grouping, alternation and the `*`, `+` and `?`
+/// operators. Generation starts from the `root` rule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grammar {
+    rules: Vec<Vec<Alternative>>,
+    root: usize,
+}
+
+/// A point inside a rule alternative: the next element to match
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Position {
+    rule: usize,
+    alt: usize,
+    index: usize,
+}
+
+type Stack = Vec<Position>;
+
+impl Grammar {
+    /// Parse a GBNF grammar
+    pub fn parse(text: &str) -> Result<Self, InferenceError> {
+        let mut parser = Parser::new(text);
+        parser.parse_grammar()?;
+        parser.finish()
+    }
+    
+    /// Whether `text` is a complete sentence of the grammar
+    pub fn matches(&self, text: &str) -> bool {
+        let mut matcher = GrammarMatcher::new(self);
+        matcher.accept_str(text) && matcher.is_complete()
+    }
+    
+    fn finished(&self, position: Position) -> bool {
+        position.index >= self.rules[position.rule][position.alt].len()
+    }
+    
+    /// Expand rule references until the top of the stack is a character set
+    /// 
+    /// Finished alternatives are popped so control returns to the rule that
+    /// referenced them; an empty stack means the input so far is complete.
+    fn expand(&self, mut stack: Stack, out: &mut Vec<Stack>) {
+        while stack.last().is_some_and(|&top| self.finished(top)) {
+            stack.pop();
+        }
+        let Some(&top) = stack.last() else {
+            out.push(stack);
+            return;
+        };
+        
+        match &self.rules[top.rule][top.alt][top.index] {
+            Element::Chars { .. } => out.push(stack),
+            Element::Rule(rule) => {
+                if stack.len() >= MAX_STACK_DEPTH {
+                    return;
+                }
+                
+                // Move past the reference first so right recursion does not grow the stack
+                let mut base = stack;
+                if let Some(top) = base.last_mut() {
+                    top.index += 1;
+                }
+                while base.last().is_some_and(|&top| self.finished(top)) {
+                    base.pop();
+                }
+                
+                for alt in 0..self.rules[*rule].len() {
+                    let mut next = base.clone();
+                    next.push(Position { rule: *rule, alt, index: 0 });
+                    self.expand(next, out);
+                }
+            }
+        }
+    }
+    
+    fn initial_stacks(&self) -> Vec<Stack> {
+        let mut stacks = Vec::new();
+        for alt in 0..self.rules[self.root].len() {
+            self.expand(vec![Position { rule: self.root, alt, index: 0 }], &mut stacks);
+        }
+        stacks.sort();
+        stacks.dedup();
+        stacks
+    }
+    
+    /// Stacks that remain after consuming `c`
+    fn advance(&self, stacks: &[Stack], c: char) -> Vec<Stack> {
+        let mut next = Vec::new();
+        for stack in stacks {
+            let Some(&top) = stack.last() else { continue };
+            if self.rules[top.rule][top.alt][top.index].matches(c) {
+                let mut stack = stack.clone();
+                if let Some(top) = stack.last_mut() {
+                    top.index += 1;
+                }
+                self.expand(stack, &mut next);
+            }
+        }
+        next.sort();
+        next.dedup();
+        next
+    }
+    
+    /// Stacks and pending bytes after consuming `byte`
+    /// 
+    /// Bytes of an incomplete character are held in `partial`; stacks that
+    /// cannot accept any character starting with them are dropped.
+    fn advance_byte(&self, stacks: &[Stack], partial: &[u8], byte: u8) -> (Vec<Stack>, Vec<u8>) {
+        let mut bytes = partial.to_vec();
+        bytes.push(byte);
+        match std::str::from_utf8(&bytes) {
+            Ok(text) => match text.chars().next() {
+                Some(c) => (self.advance(stacks, c), Vec::new()),
+                None => (Vec::new(), Vec::new()),
+            },
+            Err(e) if e.error_len().is_none() => {
+                let Some((lo, hi)) = code_point_range(&bytes) else {
+                    return (Vec::new(), Vec::new());
+                };
+                let stacks = stacks.iter()
+                    .filter(|stack| stack.last().is_some_and(|&top| self.rules[top.rule][top.alt][top.index].matches_any(lo, hi)))
+                    .cloned()
+                    .collect();
+                (stacks, bytes)
+            }
+            Err(_) => (Vec::new(), Vec::new()),
+        }
+    }
+}
+
+/// Incremental recognizer that tracks every way the input so far can continue
+#[derive(Debug, Clone)]
+pub struct GrammarMatcher<'g> {
+    grammar: &'g Grammar,
+    stacks: Vec<Stack>,
+    /// Leading bytes of a character that is not complete yet
+    partial: Vec<u8>,
+}
+
+impl<'g> GrammarMatcher<'g> {
+    /// Start matching from the grammar's root rule
+    pub fn new(grammar: &'g Grammar) -> Self {
+        Self {
+            grammar,
+            stacks: grammar.initial_stacks(),
+            partial: Vec::new(),
+        }
+    }
+    
+    /// Consume `text`, or return `false` and leave the matcher unchanged if the grammar rejects it
+    pub fn accept_str(&mut self, text: &str) -> bool {
+        self.accept_bytes(text.as_bytes())
+    }
+    
+    /// Consume UTF-8 `bytes`, which may start or end partway through a character
+    /// 
+    /// Returns `false` and leaves the matcher unchanged if the grammar rejects them.
+    pub fn accept_bytes(&mut self, bytes: &[u8]) -> bool {
+        let mut stacks = self.stacks.clone();
+        let mut partial = self.partial.clone();
+        for &byte in bytes {
+            (stacks, partial) = self.grammar.advance_byte(&stacks, &partial, byte);
+            if stacks.is_empty() {
+                return false;
+            }
+        }
+        self.stacks = stacks;
+        self.partial = partial;
+        true
+    }
+    
+    /// Whether the input so far is a complete sentence
+    pub fn is_complete(&self) -> bool {
+        self.partial.is_empty() && self.stacks.iter().any(|stack| stack.is_empty())
+    }
+}
+
+/// Vocabulary bytes arranged by prefix so the grammar is checked once per shared prefix
+#[derive(Debug, Default)]
+pub struct TokenTrie {
+    nodes: Vec<TrieNode>,
+    texts: HashMap<u32, Vec<u8>>,
+    /// The decoder drops one leading space from the completion (SentencePiece)
+    strips_leading_space: bool,
+}
+
+#[derive(Debug, Default)]
+struct TrieNode {
+    children: Vec<(u8, usize)>,
+    tokens: Vec<u32>,
+}
+
+impl TokenTrie {
+    /// Index every non-special token of `tokenizer` except `excluded`
+    /// 
+    /// Tokens are indexed by their bytes, so byte-level and byte-fallback
+    /// tokens that hold part of a multi-byte character can still be generated.
+    pub fn new(tokenizer: &Tokenizer, excluded: &[u32]) -> Self {
+        let byte_decoder = match tokenizer.get_decoder() {
+            Some(DecoderWrapper::ByteLevel(_)) => Some(byte_level_decoder()),
+            _ => None,
+        };
+        let special: HashSet<u32> = tokenizer.get_added_tokens_decoder()
+            .into_iter()
+            .filter(|(_, token)| token.special)
+            .map(|(id, _)| id)
+            .collect();
+        
+        let vocab = tokenizer.get_vocab(true);
+        let metaspace = byte_decoder.is_none() && vocab.keys().any(|piece| piece.contains('\u{2581}'));
+        let texts = vocab.into_iter()
+            .filter(|(_, id)| !excluded.contains(id) && !special.contains(id))
+            .filter_map(|(piece, id)| token_bytes(&piece, byte_decoder.as_ref()).map(|bytes| (id, bytes)));
+        Self::from_texts(texts, metaspace)
+    }
+    
+    /// Build a trie from token texts or raw token bytes
+    pub(crate) fn from_texts<I, T>(texts: I, strips_leading_space: bool) -> Self
+    where
+        I: IntoIterator<Item = (u32, T)>,
+        T: Into<Vec<u8>>,
+    {
+        let mut trie = Self {
+            nodes: vec![TrieNode::default()],
+            texts: HashMap::new(),
+            strips_leading_space,
+        };
+        
+        for (id, text) in texts {
+            let text = text.into();
+            if text.is_empty() {
+                continue;
+            }
+            let mut node = 0;
+            for &byte in &text {
+                node = match trie.nodes[node].children.iter().find(|(child, _)| *child == byte) {
+                    Some(&(_, child)) => child,
+                    None => {
+                        trie.nodes.push(TrieNode::default());
+                        let child = trie.nodes.len() - 1;
+                        trie.nodes[node].children.push((byte, child));
+                        child
+                    }
+                };
+            }
+            trie.nodes[node].tokens.push(id);
+            trie.texts.insert(id, text);
+        }
+        trie
+    }
+}
+
+/// Surface bytes of a vocabulary piece
+fn token_bytes(piece: &str, byte_decoder: Option<&HashMap<char, u8>>) -> Option<Vec<u8>> {
+    // SentencePiece byte fallback pieces such as <0x0A>
+    if let Some(hex) = piece.strip_prefix("<0x").and_then(|rest| rest.strip_suffix('>')) {
+        return u8::from_str_radix(hex, 16).ok().map(|byte| vec![byte]);
+    }
+    
+    match byte_decoder {
+        Some(decoder) => piece.chars().map(|c| decoder.get(&c).copied()).collect(),
+        None => Some(piece.replace('\u{2581}', " ").into_bytes()),
+    }
+}
+
+/// Inverse of the GPT-2 byte-to-unicode table used by byte-level BPE
+fn byte_level_decoder() -> HashMap<char, u8> {
+    let printable = |b: u8| matches!(b, b'!'..=b'~' | 0xA1..=0xAC | 0xAE..=0xFF);
+    let mut decoder = HashMap::new();
+    let mut shifted = 0u32;
+    for byte in 0..=255u8 {
+        if printable(byte) {
+            decoder.insert(char::from(byte), byte);
+        } else if let Some(c) = char::from_u32(256 + shifted) {
+            decoder.insert(c, byte);
+            shifted += 1;
+        }
+    }
+    decoder
+}
+
+/// Grammar state for one generation run
+/// 
+/// Masks the logits of tokens that cannot continue the grammar and advances
+/// as tokens are accepted, so the completion always parses.
+#[derive(Debug)]
+pub struct GrammarConstraint<'a> {
+    matcher: GrammarMatcher<'a>,
+    trie: &'a TokenTrie,
+    at_start: bool,
+}
+
+impl<'a> GrammarConstraint<'a> {
+    /// Start constraining a completion
+    pub fn new(grammar: &'a Grammar, trie: &'a TokenTrie) -> Self {
+        Self {
+            matcher: GrammarMatcher::new(grammar),
+            trie,
+            at_start: true,
+        }
+    }
+    
+    /// Whether the completion so far is a complete sentence
+    pub fn is_complete(&self) -> bool {
+        self.matcher.is_complete()
+    }
+    
+    /// Tokens the grammar accepts next, excluding end-of-sequence tokens
+    pub fn allowed_tokens(&self) -> Vec<u32> {
+        let mut allowed = Vec::new();
+        self.collect(0, &self.matcher.stacks, &self.matcher.partial, &mut allowed);
+        allowed.sort_unstable();
+        allowed
+    }
+    
+    fn collect(&self, node: usize, stacks: &[Stack], partial: &[u8], allowed: &mut Vec<u32>) {
+        if node != 0 {
+            allowed.extend_from_slice(&self.trie.nodes[node].tokens);
+        }
+        
+        for &(byte, child) in &self.trie.nodes[node].children {
+            // The decoder drops the completion's first space, so the grammar never sees it
+            if node == 0 && byte == b' ' && self.at_start && self.trie.strips_leading_space {
+                self.collect(child, stacks, partial, allowed);
+                continue;
+            }
+            
+            let (next, partial) = self.matcher.grammar.advance_byte(stacks, partial, byte);
+            if !next.is_empty() {
+                self.collect(child, &next, &partial, allowed);
+            }
+        }
+    }
+    
+    /// Set the logits of rejected tokens to negative infinity
+    /// 
+    /// End-of-sequence tokens stay available once the grammar is complete.
+    /// Returns `false` if no token at all is allowed.
+    pub fn mask(&self, logits: &mut [f32], eos_token_ids: &[u32]) -> bool {
+        let mut allowed = vec![false; logits.len()];
+        let mut permit = |id: u32| {
+            if let Some(slot) = allowed.get_mut(id as usize) {
+                *slot = true;
+            }
+        };
+        self.allowed_tokens().into_iter().for_each(&mut permit);
+        if self.is_complete() {
+            eos_token_ids.iter().copied().for_each(&mut permit);
+        }
+        
+        let mut any = false;
+        for (logit, allowed) in logits.iter_mut().zip(allowed) {
+            if allowed {
+                any = true;
+            } else {
+                *logit = f32::NEG_INFINITY;
+            }
+        }
+        any
+    }
+    
+    /// Advance the grammar past a generated token
+    pub fn accept_token(&mut self, token: u32) -> Result<(), InferenceError> {
+        let text = self.trie.texts.get(&token)
+            .ok_or_else(|| InferenceError::prediction(format!("Token {} cannot be generated under a grammar", token)))?;
+        let text = match text.strip_prefix(b" ") {
+            Some(rest) if self.at_start && self.trie.strips_leading_space => rest,
+            _ => text.as_slice(),
+        };
+        self.at_start = false;
+        
+        if !self.matcher.accept_bytes(text) {
+            return Err(InferenceError::prediction(format!("Token {:?} does not continue the grammar", String::from_utf8_lossy(text))));
+        }
+        Ok(())
+    }
+}
+
+/// Recursive-descent parser for GBNF
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    names: HashMap<String, usize>,
+    rule_names: Vec<String>,
+    rules: Vec<Option<Vec<Alternative>>>,
+}
+
+impl Parser {
+    fn new(text: &str) -> Self {
+        Self {
+            chars: text.chars().collect(),
+            pos: 0,
+            names: HashMap::new(),
+            rule_names: Vec::new(),
+            rules: Vec::new(),
+        }
+    }
+    
+    fn error(&self, msg: &str) -> InferenceError {
+        let line = self.chars[..self.pos.min(self.chars.len())].iter().filter(|&&c| c == '\n').count() + 1;
+        InferenceError::configuration(format!("Invalid grammar on line {}: {}", line, msg))
+    }
+    
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+    
+    fn next(&mut self) -> Result<char, InferenceError> {
+        let c = self.peek().ok_or_else(|| self.error("unexpected end of grammar"))?;
+        self.pos += 1;
+        Ok(c)
+    }
+    
+    fn expect(&mut self, token: &str) -> Result<(), InferenceError> {
+        for expected in token.chars() {
+            if self.peek() != Some(expected) {
+                return Err(self.error(&format!("expected '{}'", token)));
+            }
+            self.pos += 1;
+        }
+        Ok(())
+    }
+    
+    /// Skip whitespace, newlines and `#` comments
+    fn skip_space(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == '#' {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.pos += 1;
+                }
+            } else if c.is_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+    
+    fn parse_name(&mut self) -> Option<String> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+            self.pos += 1;
+        }
+        (self.pos > start).then(|| self.chars[start..self.pos].iter().collect())
+    }
+    
+    /// Whether the input at the current position starts a new `name ::=` rule
+    fn at_rule_start(&mut self) -> bool {
+        let start = self.pos;
+        let is_rule = self.parse_name().is_some() && {
+            self.skip_space();
+            self.chars[self.pos..].starts_with(&[':', ':', '='])
+        };
+        self.pos = start;
+        is_rule
+    }
+    
+    fn rule_id(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.names.get(name) {
+            return id;
+        }
+        self.rules.push(None);
+        self.rule_names.push(name.to_string());
+        self.names.insert(name.to_string(), self.rules.len() - 1);
+        self.rules.len() - 1
+    }
+    
+    /// Add an anonymous rule for a group or repetition inside `parent`
+    fn add_rule(&mut self, parent: &str, alternatives: Vec<Alternative>) -> usize {
+        self.rules.push(Some(alternatives));
+        self.rule_names.push(format!("{}-{}", parent, self.rules.len()));
+        self.rules.len() - 1
+    }
+    
+    fn parse_grammar(&mut self) -> Result<(), InferenceError> {
+        loop {
+            self.skip_space();
+            if self.peek().is_none() {
+                return Ok(());
+            }
+            
+            let name = self.parse_name().ok_or_else(|| self.error("expected a rule name"))?;
+            self.skip_space();
+            self.expect("::=")?;
+            let alternatives = self.parse_alternatives(&name, false)?;
+            
+            let id = self.rule_id(&name);
+            if self.rules[id].is_some() {
+                return Err(self.error(&format!("rule '{}' is defined twice", name)));
+            }
+            self.rules[id] = Some(alternatives);
+        }
+    }
+    
+    fn parse_alternatives(&mut self, name: &str, nested: bool) -> Result<Vec<Alternative>, InferenceError> {
+        let mut alternatives = vec![self.parse_sequence(name, nested)?];
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            alternatives.push(self.parse_sequence(name, nested)?);
+        }
+        Ok(alternatives)
+    }
+    
+    fn parse_sequence(&mut self, name: &str, nested: bool) -> Result<Alternative, InferenceError> {
+        let mut sequence = Vec::new();
+        loop {
+            self.skip_space();
+            match self.peek() {
+                None | Some('|') | Some(')') => break,
+                _ if !nested && self.at_rule_start() => break,
+                _ => {}
+            }
+            
+            let item = self.parse_primary(name)?;
+            match self.peek() {
+                Some('*') => {
+                    self.pos += 1;
+                    let repeat = self.repeat(name, item);
+                    sequence.push(Element::Rule(repeat));
+                }
+                Some('+') => {
+                    self.pos += 1;
+                    let repeat = self.repeat(name, item.clone());
+                    sequence.extend(item);
+                    sequence.push(Element::Rule(repeat));
+                }
+                Some('?') => {
+                    self.pos += 1;
+                    let optional = self.add_rule(name, vec![item, Vec::new()]);
+                    sequence.push(Element::Rule(optional));
+                }
+                _ => sequence.extend(item),
+            }
+        }
+        Ok(sequence)
+    }
+    
+    /// `item*` becomes `r ::= item r | ε`
+    fn repeat(&mut self, name: &str, item: Vec<Element>) -> usize {
+        let id = self.add_rule(name, Vec::new());
+        let mut recurse = item;
+        recurse.push(Element::Rule(id));
+        self.rules[id] = Some(vec![recurse, Vec::new()]);
+        id
+    }
+    
+    fn parse_primary(&mut self, name: &str) -> Result<Vec<Element>, InferenceError> {
+        match self.peek() {
+            Some('"') => {
+                self.pos += 1;
+                let mut elements = Vec::new();
+                loop {
+                    match self.next()? {
+                        '"' => break,
+                        '\\' => elements.push(Element::literal(self.parse_escape()?)),
+                        c => elements.push(Element::literal(c)),
+                    }
+                }
+                Ok(elements)
+            }
+            Some('[') => {
+                self.pos += 1;
+                let negated = self.peek() == Some('^');
+                if negated {
+                    self.pos += 1;
+                }
+                let mut ranges = Vec::new();
+                while self.peek() != Some(']') {
+                    let lo = self.parse_class_char()?;
+                    let hi = if self.peek() == Some('-') && self.chars.get(self.pos + 1) != Some(&']') {
+                        self.pos += 1;
+                        self.parse_class_char()?
+                    } else {
+                        lo
+                    };
+                    ranges.push((lo, hi));
+                }
+                self.pos += 1;
+                Ok(vec![Element::Chars { ranges, negated }])
+            }
+            Some('.') => {
+                self.pos += 1;
+                Ok(vec![Element::Chars { ranges: Vec::new(), negated: true }])
+            }
+            Some('(') => {
+                self.pos += 1;
+                let alternatives = self.parse_alternatives(name, true)?;
+                self.skip_space();
+                self.expect(")")?;
+                Ok(vec![Element::Rule(self.add_rule(name, alternatives))])
+            }
+            _ => {
+                let reference = self.parse_name().ok_or_else(|| self.error("expected a literal, character class, group or rule name"))?;
+                Ok(vec![Element::Rule(self.rule_id(&reference))])
+            }
+        }
+    }
+    
+    fn parse_class_char(&mut self) -> Result<char, InferenceError> {
+        match self.next()? {
+            '\\' => self.parse_escape(),
+            c => Ok(c),
+        }
+    }
+    
+    /// Parse the character after a backslash
+    fn parse_escape(&mut self) -> Result<char, InferenceError> {
+        let digits = match self.next()? {
+            'n' => return Ok('\n'),
+            't' => return Ok('\t'),
+            'r' => return Ok('\r'),
+            'x' => 2,
+            'u' => 4,
+            'U' => 8,
+            c => return Ok(c),
+        };
+        let hex: String = (0..digits).map(|_| self.next()).collect::<Result<_, _>>()?;
+        u32::from_str_radix(&hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error(&format!("invalid escape '{}'", hex)))
+    }
+    
+    fn finish(self) -> Result<Grammar, InferenceError> {
+        let root = *self.names.get("root")
+            .ok_or_else(|| InferenceError::configuration("Grammar has no 'root' rule"))?;
+        
+        let mut rules = Vec::with_capacity(self.rules.len());
+        for (rule, name) in self.rules.into_iter().zip(&self.rule_names) {
+            rules.push(rule.ok_or_else(|| InferenceError::configuration(format!("Grammar rule '{}' is not defined", name)))?);
+        }
+        Ok(Grammar { rules, root })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const LIST: &str = r#"
+        # A bracketed, comma separated list of small numbers
+        root ::= "[" ( item ( "," " "? item )* )? "]"
+        item ::= [1-9] [0-9]? | "zero"
+    "#;
+    
+    #[test]
+    fn test_parse_and_match() {
+        let grammar = Grammar::parse(LIST).unwrap();
+        assert!(grammar.matches("[]"));
+        assert!(grammar.matches("[1, 22,zero]"));
+        assert!(!grammar.matches("[1,]"));
+        assert!(!grammar.matches("[100]"));
+        assert!(!grammar.matches("[1"));
+        
+        let escapes = Grammar::parse(r#"root ::= "\"" [^"\\\x00-\x1f]+ "\"""#).unwrap();
+        assert!(escapes.matches("\"hi there\""));
+        assert!(!escapes.matches("\"a\nb\""));
+    }
+    
+    #[test]
+    fn test_parse_errors() {
+        assert!(Grammar::parse("item ::= \"a\"").is_err());
+        assert!(Grammar::parse("root ::= item").is_err());
+        assert!(Grammar::parse("root ::= \"a").is_err());
+        assert!(Grammar::parse("root ::= \"a\"\nroot ::= \"b\"").is_err());
+    }
+    
+    #[test]
+    fn test_long_repetition_does_not_grow_stack() {
+        let grammar = Grammar::parse("root ::= \"a\"*").unwrap();
+        assert!(grammar.matches(&"a".repeat(MAX_STACK_DEPTH * 4)));
+    }
+    
+    #[test]
+    fn test_constraint_masks_tokens() {
+        let grammar = Grammar::parse(LIST).unwrap();
+        let texts = ["[", "[1", "]", "1,", " 2", "x", "[zero]", ","];
+        let trie = TokenTrie::from_texts(texts.iter().enumerate().map(|(id, t)| (id as u32, t.to_string())), false);
+        let eos = 8;
+        
+        let mut constraint = GrammarConstraint::new(&grammar, &trie);
+        assert_eq!(constraint.allowed_tokens(), vec![0, 1, 6]);
+        
+        constraint.accept_token(1).unwrap();
+        assert_eq!(constraint.allowed_tokens(), vec![2, 3, 7]);
+        assert!(constraint.accept_token(5).is_err());
+        
+        constraint.accept_token(7).unwrap();
+        assert_eq!(constraint.allowed_tokens(), vec![3, 4]);
+        constraint.accept_token(4).unwrap();
+        constraint.accept_token(2).unwrap();
+        assert!(constraint.is_complete());
+        
+        // Once complete only end-of-sequence is left
+        let mut logits = vec![0.0; 9];
+        assert!(constraint.mask(&mut logits, &[eos]));
+        assert!(logits[..8].iter().all(|l| l.is_infinite()));
+        assert_eq!(logits[8], 0.0);
+    }
+    
+    #[test]
+    fn test_leading_space_dropped_for_sentencepiece() {
+        let grammar = Grammar::parse(r#"root ::= "{}""#).unwrap();
+        let trie = TokenTrie::from_texts(vec![(0, " {".to_string()), (1, "}".to_string())], true);
+        
+        let mut constraint = GrammarConstraint::new(&grammar, &trie);
+        assert_eq!(constraint.allowed_tokens(), vec![0]);
+        constraint.accept_token(0).unwrap();
+        constraint.accept_token(1).unwrap();
+        assert!(constraint.is_complete());
+    }
+    
+    #[test]
+    fn test_token_text_decoding() {
+        let decoder = byte_level_decoder();
+        assert_eq!(decoder.len(), 256);
+        assert_eq!(token_bytes("\u{120}world", Some(&decoder)).as_deref(), Some(&b" world"[..]));
+        assert_eq!(token_bytes("\u{2581}world", None).as_deref(), Some(&b" world"[..]));
+        assert_eq!(token_bytes("<0x0A>", None).as_deref(), Some(&b"\n"[..]));
+        assert_eq!(token_bytes("<0xE2>", None).as_deref(), Some(&[0xE2][..]));
+        assert_eq!(token_bytes("\u{E2}", Some(&decoder)).as_deref(), Some(&[0xE2][..]));
+    }
+    
+    #[test]
+    fn test_partial_utf8_tokens_follow_the_grammar() {
+        // "é" is C3 A9 and "ü" is C3 BC; the vocabulary only has their bytes
+        let grammar = Grammar::parse(r#"root ::= "é" [^a-z]"#).unwrap();
+        let tokens: Vec<(u32, Vec<u8>)> = vec![(0, vec![0xC3]), (1, vec![0xA9]), (2, vec![0xBC]), (3, b"x".to_vec()), (4, vec![0xE2])];
+        let trie = TokenTrie::from_texts(tokens, false);
+        
+        let mut constraint = GrammarConstraint::new(&grammar, &trie);
+        assert_eq!(constraint.allowed_tokens(), vec![0]);
+        constraint.accept_token(0).unwrap();
+        assert!(!constraint.is_complete());
+        assert_eq!(constraint.allowed_tokens(), vec![1]);
+        constraint.accept_token(1).unwrap();
+        
+        // Any lead byte of a non-ASCII character is allowed by the negated class
+        assert_eq!(constraint.allowed_tokens(), vec![0, 4]);
+        constraint.accept_token(0).unwrap();
+        assert_eq!(constraint.allowed_tokens(), vec![1, 2]);
+        constraint.accept_token(2).unwrap();
+        assert!(constraint.is_complete());
+    }
+    
+    #[test]
+    fn test_code_point_range() {
+        assert_eq!(code_point_range(&[0xC3]), Some((0xC0, 0xFF)));
+        assert_eq!(code_point_range(&[0xE2, 0x82]), Some((0x2080, 0x20BF)));
+        assert_eq!(code_point_range(&[0xF4]), Some((0x100000, 0x10FFFF)));
+        assert_eq!(code_point_range(&[0x80]), None);
+        assert_eq!(code_point_range(&[0xC3, 0xA9]), None);
+    }
+}
@@ -0,0 +1,712 @@
+use crate::models::{DataType, InferenceError, PredictContext, TensorSpec};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use candle_core::quantized::QMatMul;
+use candle_core::{DType, Device, Tensor as CandleTensor};
+use candle_nn::{Activation, BatchNorm, Conv2d, Conv2dConfig, LayerNorm, Linear, Module, ModuleT};
+use super::forward_error;
+
+/// Suffix of the graph file looked up next to a weights file
+pub const GRAPH_SUFFIX: &str = ".graph.json";
+
+/// Name and shape of a graph input or output
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GraphIo {
+    /// Tensor name reported in the model specs
+    pub name: String,
+    /// Expected shape, `null` for dynamic dimensions
+    #[serde(default)]
+    pub shape: Vec<Option<usize>>,
+}
+
+impl GraphIo {
+    fn spec(&self) -> TensorSpec {
+        TensorSpec::new(self.name.clone(), self.shape.clone(), DataType::F32)
+    }
+}
+
+fn default_eps() -> f64 {
+    1e-5
+}
+
+fn default_one() -> usize {
+    1
+}
+
+fn default_dim() -> isize {
+    -1
+}
+
+/// One layer of a graph, referring to weights by tensor name
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LayerSpec {
+    /// `x @ weight^T + bias` with a PyTorch `[out, in]` weight
+    Linear {
+        weight: String,
+        #[serde(default)]
+        bias: Option<String>,
+    },
+    /// 2-D convolution over `[N, C, H, W]` with a `[out, in / groups, kh, kw]` weight
+    Conv2d {
+        weight: String,
+        #[serde(default)]
+        bias: Option<String>,
+        #[serde(default = "default_one")]
+        stride: usize,
+        #[serde(default)]
+        padding: usize,
+        #[serde(default = "default_one")]
+        dilation: usize,
+        #[serde(default = "default_one")]
+        groups: usize,
+    },
+    /// Elementwise activation such as `relu`, `gelu`, `silu` or `sigmoid`
+    Activation {
+        function: Activation,
+    },
+    /// Elementwise `tanh`
+    Tanh,
+    /// Inference-mode batch norm over the channel dimension
+    BatchNorm {
+        running_mean: String,
+        running_var: String,
+        #[serde(default)]
+        weight: Option<String>,
+        #[serde(default)]
+        bias: Option<String>,
+        #[serde(default = "default_eps")]
+        eps: f64,
+    },
+    /// Layer norm over the last dimension
+    LayerNorm {
+        weight: String,
+        #[serde(default)]
+        bias: Option<String>,
+        #[serde(default = "default_eps")]
+        eps: f64,
+    },
+    /// Max pooling over `[N, C, H, W]`; `stride` defaults to `kernel_size`
+    MaxPool2d {
+        kernel_size: usize,
+        #[serde(default)]
+        stride: Option<usize>,
+    },
+    /// Average pooling over `[N, C, H, W]`; `stride` defaults to `kernel_size`
+    AvgPool2d {
+        kernel_size: usize,
+        #[serde(default)]
+        stride: Option<usize>,
+    },
+    /// Mean over the spatial dimensions, `[N, C, H, W]` to `[N, C]`
+    GlobalAvgPool,
+    /// Flatten everything after the batch dimension
+    Flatten,
+    /// `x + layers(x)`
+    Residual {
+        layers: Vec<LayerSpec>,
+    },
+    /// Softmax over `dim` (negative counts from the end)
+    Softmax {
+        #[serde(default = "default_dim")]
+        dim: isize,
+    },
+}
+
+/// Declarative description of a feed-forward network over named tensors
+/// 
+/// ```json
+/// {
+///   "input": {"name": "features", "shape": [null, 4]},
+///   "output": {"name": "probabilities", "shape": [null, 3]},
+///   "layers": [
+///     {"type": "linear", "weight": "fc1.weight", "bias": "fc1.bias"},
+///     {"type": "activation", "function": "relu"},
+///     {"type": "linear", "weight": "fc2.weight", "bias": "fc2.bias"},
+///     {"type": "softmax"}
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct GraphSpec {
+    pub input: GraphIo,
+    pub output: GraphIo,
+    pub layers: Vec<LayerSpec>,
+}
+
+impl GraphSpec {
+    /// Parse a graph from its JSON text
+    /// 
+    /// Strides, dilations, groups and kernel sizes must be at least 1.
+    pub fn parse(json: &str) -> Result<Self, InferenceError> {
+        let spec: Self = serde_json::from_str(json)
+            .map_err(|e| InferenceError::configuration(format!("Invalid model graph: {}", e)))?;
+        validate_layers(&spec.layers)?;
+        Ok(spec)
+    }
+    
+    /// Read a graph file
+    pub fn from_file(path: &Path) -> Result<Self, InferenceError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| InferenceError::configuration(format!("Failed to read model graph {}: {}", path.display(), e)))?;
+        Self::parse(&json)
+    }
+    
+    /// Graph file that belongs to `weights_path`
+    /// 
+    /// `model.safetensors`, `model.pt` and `model.safetensors.index.json` all
+    /// map to `model.graph.json` in the same directory.
+    pub fn path_for(weights_path: &Path) -> PathBuf {
+        let name = weights_path.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        let name = name.strip_suffix(".index.json").unwrap_or(name);
+        let stem = Path::new(name).file_stem().and_then(|s| s.to_str()).unwrap_or(name);
+        weights_path.with_file_name(format!("{}{}", stem, GRAPH_SUFFIX))
+    }
+    
+    /// Read the graph next to `weights_path`, if there is one
+    pub fn find_for(weights_path: &Path) -> Result<Option<Self>, InferenceError> {
+        let path = Self::path_for(weights_path);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        println!("🧭 Using model graph {}", path.display());
+        Self::from_file(&path).map(Some)
+    }
+    
+    /// Names of the weights used by `linear` layers, including nested ones
+    pub fn linear_weights(&self) -> Vec<&str> {
+        fn collect<'a>(layers: &'a [LayerSpec], names: &mut Vec<&'a str>) {
+            for layer in layers {
+                match layer {
+                    LayerSpec::Linear { weight, .. } => names.push(weight),
+                    LayerSpec::Residual { layers } => collect(layers, names),
+                    _ => {}
+                }
+            }
+        }
+        
+        let mut names = Vec::new();
+        collect(&self.layers, &mut names);
+        names
+    }
+    
+    /// Names of every tensor the layers refer to, including nested ones
+    pub fn tensor_names(&self) -> Vec<&str> {
+        fn collect<'a>(layers: &'a [LayerSpec], names: &mut Vec<&'a str>) {
+            for layer in layers {
+                match layer {
+                    LayerSpec::Linear { weight, bias } | LayerSpec::Conv2d { weight, bias, .. } | LayerSpec::LayerNorm { weight, bias, .. } => {
+                        names.push(weight);
+                        names.extend(bias.as_deref());
+                    }
+                    LayerSpec::BatchNorm { running_mean, running_var, weight, bias, .. } => {
+                        names.extend([running_mean.as_str(), running_var.as_str()]);
+                        names.extend(weight.as_deref().into_iter().chain(bias.as_deref()));
+                    }
+                    LayerSpec::Residual { layers } => collect(layers, names),
+                    _ => {}
+                }
+            }
+        }
+        
+        let mut names = Vec::new();
+        collect(&self.layers, &mut names);
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+}
+
+/// Reject layer settings that would divide by zero or build empty windows
+fn validate_layers(layers: &[LayerSpec]) -> Result<(), InferenceError> {
+    let at_least_one = |layer: &str, field: &str, value: usize| {
+        if value == 0 {
+            return Err(InferenceError::configuration(format!("Invalid model graph: {} {} must be at least 1", layer, field)));
+        }
+        Ok(())
+    };
+    
+    for layer in layers {
+        match layer {
+            LayerSpec::Conv2d { stride, dilation, groups, .. } => {
+                at_least_one("conv2d", "stride", *stride)?;
+                at_least_one("conv2d", "dilation", *dilation)?;
+                at_least_one("conv2d", "groups", *groups)?;
+            }
+            LayerSpec::MaxPool2d { kernel_size, stride } | LayerSpec::AvgPool2d { kernel_size, stride } => {
+                at_least_one("pooling", "kernel_size", *kernel_size)?;
+                at_least_one("pooling", "stride", stride.unwrap_or(*kernel_size))?;
+            }
+            LayerSpec::Residual { layers } => validate_layers(layers)?,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Layer with its weights resolved, ready to run
+#[derive(Debug, Clone)]
+enum Layer {
+    Linear(Linear),
+    /// Linear layer with a quantized `[out, in]` weight
+    QuantizedLinear { weight: QMatMul, bias: Option<CandleTensor> },
+    Conv2d(Conv2d),
+    Activation(Activation),
+    Tanh,
+    BatchNorm(BatchNorm),
+    LayerNorm(LayerNorm),
+    MaxPool2d { kernel_size: usize, stride: usize },
+    AvgPool2d { kernel_size: usize, stride: usize },
+    GlobalAvgPool,
+    Flatten,
+    Residual(Vec<Layer>),
+    Softmax(isize),
+}
+
+/// Named weights a graph resolves its layers against
+pub trait WeightSource: Send + Sync + Debug {
+    /// Shape of a weight, `None` when there is no tensor by that name
+    fn dims(&self, name: &str) -> Option<Vec<usize>>;
+    
+    /// Read a weight, `None` when there is no tensor by that name
+    fn tensor(&self, name: &str) -> Result<Option<CandleTensor>, InferenceError>;
+}
+
+impl WeightSource for HashMap<String, CandleTensor> {
+    fn dims(&self, name: &str) -> Option<Vec<usize>> {
+        self.get(name).map(|tensor| tensor.dims().to_vec())
+    }
+    
+    fn tensor(&self, name: &str) -> Result<Option<CandleTensor>, InferenceError> {
+        Ok(self.get(name).cloned())
+    }
+}
+
+/// Stands in for a source with single-element broadcast tensors of the same shapes
+/// 
+/// Building layers against it checks names and shapes without reading any weights.
+#[derive(Debug)]
+struct Placeholders<'a>(&'a dyn WeightSource);
+
+impl WeightSource for Placeholders<'_> {
+    fn dims(&self, name: &str) -> Option<Vec<usize>> {
+        self.0.dims(name)
+    }
+    
+    fn tensor(&self, name: &str) -> Result<Option<CandleTensor>, InferenceError> {
+        let Some(dims) = self.0.dims(name) else {
+            return Ok(None);
+        };
+        CandleTensor::zeros((), DType::F32, &Device::Cpu)
+            .and_then(|zero| zero.broadcast_as(dims))
+            .map(Some)
+            .map_err(|e| InferenceError::configuration(format!("Invalid shape for tensor '{}': {}", name, e)))
+    }
+}
+
+/// Look up a weight, reporting the layer that needs it
+fn weight(tensors: &dyn WeightSource, name: &str, layer: &str) -> Result<CandleTensor, InferenceError> {
+    tensors.tensor(name)?
+        .ok_or_else(|| InferenceError::configuration(format!("{} layer refers to missing tensor '{}'", layer, name)))
+}
+
+/// Look up an optional weight
+fn optional_weight(
+    tensors: &dyn WeightSource,
+    name: &Option<String>,
+    layer: &str,
+) -> Result<Option<CandleTensor>, InferenceError> {
+    name.as_deref().map(|name| weight(tensors, name, layer)).transpose()
+}
+
+/// Check that a weight has the expected rank
+fn expect_rank(tensor: &CandleTensor, rank: usize, name: &str, layer: &str) -> Result<(), InferenceError> {
+    if tensor.rank() != rank {
+        return Err(InferenceError::configuration(format!(
+            "{} weight '{}' must be {}-D, got shape {:?}", layer, name, rank, tensor.dims()
+        )));
+    }
+    Ok(())
+}
+
+impl Layer {
+    fn build(
+        spec: &LayerSpec,
+        tensors: &dyn WeightSource,
+        quantized: &HashMap<String, QMatMul>,
+    ) -> Result<Self, InferenceError> {
+        Ok(match spec {
+            LayerSpec::Linear { weight: w, bias } if quantized.contains_key(w) => Layer::QuantizedLinear {
+                weight: quantized[w].clone(),
+                bias: optional_weight(tensors, bias, "linear")?,
+            },
+            LayerSpec::Linear { weight: w, bias } => {
+                let weight = weight(tensors, w, "linear")?;
+                expect_rank(&weight, 2, w, "linear")?;
+                Layer::Linear(Linear::new(weight, optional_weight(tensors, bias, "linear")?))
+            }
+            LayerSpec::Conv2d { weight: w, bias, stride, padding, dilation, groups } => {
+                let weight = weight(tensors, w, "conv2d")?;
+                expect_rank(&weight, 4, w, "conv2d")?;
+                let config = Conv2dConfig { padding: *padding, stride: *stride, dilation: *dilation, groups: *groups };
+                Layer::Conv2d(Conv2d::new(weight, optional_weight(tensors, bias, "conv2d")?, config))
+            }
+            LayerSpec::Activation { function } => Layer::Activation(*function),
+            LayerSpec::Tanh => Layer::Tanh,
+            LayerSpec::BatchNorm { running_mean, running_var, weight: w, bias, eps } => {
+                let mean = weight(tensors, running_mean, "batch_norm")?;
+                let var = weight(tensors, running_var, "batch_norm")?;
+                let num_features = mean.elem_count();
+                let norm = match (optional_weight(tensors, w, "batch_norm")?, optional_weight(tensors, bias, "batch_norm")?) {
+                    (Some(w), Some(b)) => BatchNorm::new(num_features, mean, var, w, b, *eps),
+                    (None, None) => BatchNorm::new_no_bias(num_features, mean, var, *eps),
+                    _ => return Err(InferenceError::configuration("batch_norm needs both weight and bias, or neither")),
+                };
+                Layer::BatchNorm(norm.map_err(|e| InferenceError::configuration(format!("Invalid batch_norm layer: {}", e)))?)
+            }
+            LayerSpec::LayerNorm { weight: w, bias, eps } => {
+                let weight = weight(tensors, w, "layer_norm")?;
+                Layer::LayerNorm(match optional_weight(tensors, bias, "layer_norm")? {
+                    Some(bias) => LayerNorm::new(weight, bias, *eps),
+                    None => LayerNorm::new_no_bias(weight, *eps),
+                })
+            }
+            LayerSpec::MaxPool2d { kernel_size, stride } => Layer::MaxPool2d {
+                kernel_size: *kernel_size,
+                stride: stride.unwrap_or(*kernel_size),
+            },
+            LayerSpec::AvgPool2d { kernel_size, stride } => Layer::AvgPool2d {
+                kernel_size: *kernel_size,
+                stride: stride.unwrap_or(*kernel_size),
+            },
+            LayerSpec::GlobalAvgPool => Layer::GlobalAvgPool,
+            LayerSpec::Flatten => Layer::Flatten,
+            LayerSpec::Residual { layers } => Layer::Residual(
+                layers.iter().map(|layer| Layer::build(layer, tensors, quantized)).collect::<Result<_, _>>()?
+            ),
+            LayerSpec::Softmax { dim } => Layer::Softmax(*dim),
+        })
+    }
+    
+    fn forward(&self, xs: &CandleTensor) -> candle_core::Result<CandleTensor> {
+        match self {
+            Layer::Linear(linear) => linear.forward(xs),
+            Layer::QuantizedLinear { weight, bias } => {
+                let ys = weight.forward(&xs.contiguous()?)?;
+                match bias {
+                    Some(bias) => ys.broadcast_add(bias),
+                    None => Ok(ys),
+                }
+            }
+            Layer::Conv2d(conv) => conv.forward(xs),
+            Layer::Activation(activation) => activation.forward(xs),
+            Layer::Tanh => xs.tanh(),
+            Layer::BatchNorm(norm) => norm.forward_t(xs, false),
+            Layer::LayerNorm(norm) => norm.forward(xs),
+            Layer::MaxPool2d { kernel_size, stride } => xs.max_pool2d_with_stride(*kernel_size, *stride),
+            Layer::AvgPool2d { kernel_size, stride } => xs.avg_pool2d_with_stride(*kernel_size, *stride),
+            Layer::GlobalAvgPool => xs.mean((2, 3)),
+            Layer::Flatten => xs.flatten_from(1),
+            Layer::Residual(layers) => {
+                let mut ys = xs.clone();
+                for layer in layers {
+                    ys = layer.forward(&ys)?;
+                }
+                xs + ys
+            }
+            Layer::Softmax(dim) => {
+                let rank = xs.rank() as isize;
+                let dim = if *dim < 0 { rank + dim } else { *dim };
+                if dim < 0 || dim >= rank {
+                    return Err(candle_core::Error::Msg(format!("softmax dim {} out of range for rank {}", dim, rank)));
+                }
+                candle_nn::ops::softmax(xs, dim as usize)
+            }
+        }
+    }
+}
+
+/// Layers of a `Graph`, with their weights resolved or left in the source
+#[derive(Debug, Clone)]
+enum Layers {
+    Resolved(Vec<Layer>),
+    /// Each layer's weights are read when it runs and dropped afterwards
+    Lazy { specs: Vec<LayerSpec>, weights: Arc<dyn WeightSource> },
+}
+
+/// A `GraphSpec` with its weights resolved against a tensor map
+#[derive(Debug, Clone)]
+pub struct Graph {
+    input: TensorSpec,
+    output: TensorSpec,
+    layers: Layers,
+    /// Dtype the layers compute in
+    dtype: DType,
+}
+
+impl Graph {
+    /// Resolve every layer's tensors, failing on missing or misshapen weights
+    pub fn build(spec: &GraphSpec, tensors: &HashMap<String, CandleTensor>) -> Result<Self, InferenceError> {
+        Self::build_with_quantized(spec, tensors, &HashMap::new(), DType::F32)
+    }
+    
+    /// Resolve layers whose weights were converted at load time
+    /// 
+    /// `linear` layers take their weight from `quantized` when it is there,
+    /// and inputs are cast to `dtype` before the first layer.
+    pub fn build_with_quantized(
+        spec: &GraphSpec,
+        tensors: &HashMap<String, CandleTensor>,
+        quantized: &HashMap<String, QMatMul>,
+        dtype: DType,
+    ) -> Result<Self, InferenceError> {
+        let layers = Self::resolve(spec, tensors, quantized)?;
+        Ok(Self::new(spec, Layers::Resolved(layers), dtype))
+    }
+    
+    /// Leave the weights in `weights` and read each layer's when it runs
+    /// 
+    /// Names and shapes are checked up front without reading any weights,
+    /// so no weights are held in memory between predictions.
+    pub fn build_lazy(spec: &GraphSpec, weights: Arc<dyn WeightSource>) -> Result<Self, InferenceError> {
+        Self::resolve(spec, &Placeholders(weights.as_ref()), &HashMap::new())?;
+        Ok(Self::new(spec, Layers::Lazy { specs: spec.layers.clone(), weights }, DType::F32))
+    }
+    
+    fn resolve(
+        spec: &GraphSpec,
+        tensors: &dyn WeightSource,
+        quantized: &HashMap<String, QMatMul>,
+    ) -> Result<Vec<Layer>, InferenceError> {
+        if spec.layers.is_empty() {
+            return Err(InferenceError::configuration("Model graph has no layers"));
+        }
+        spec.layers.iter()
+            .map(|layer| Layer::build(layer, tensors, quantized))
+            .collect()
+    }
+    
+    fn new(spec: &GraphSpec, layers: Layers, dtype: DType) -> Self {
+        Self {
+            input: spec.input.spec(),
+            output: spec.output.spec(),
+            layers,
+            dtype,
+        }
+    }
+    
+    /// Specification of the graph input
+    pub fn input_spec(&self) -> &TensorSpec {
+        &self.input
+    }
+    
+    /// Specification of the graph output
+    pub fn output_spec(&self) -> &TensorSpec {
+        &self.output
+    }
+    
+    /// Run the layers in order on an input of any numeric dtype
+    /// 
+    /// The output is always f32, whatever precision the layers run in.
+    pub fn forward(&self, input: &CandleTensor) -> Result<CandleTensor, InferenceError> {
+        self.forward_with_context(input, &PredictContext::new())
+    }
+    
+    /// Run the layers in order, checking for cancellation before each one
+    pub fn forward_with_context(&self, input: &CandleTensor, ctx: &PredictContext) -> Result<CandleTensor, InferenceError> {
+        let mut xs = input.to_dtype(self.dtype)
+            .map_err(|e| InferenceError::prediction(format!("Failed to convert graph input to {:?}: {}", self.dtype, e)))?;
+        let run = |index: usize, layer: &Layer, xs: &CandleTensor| layer.forward(xs)
+            .map_err(|e| forward_error(&format!("Graph layer {} failed on shape {:?}", index, xs.dims()), e));
+        match &self.layers {
+            Layers::Resolved(layers) => {
+                for (index, layer) in layers.iter().enumerate() {
+                    ctx.check()?;
+                    xs = run(index, layer, &xs)?;
+                }
+            }
+            Layers::Lazy { specs, weights } => {
+                for (index, spec) in specs.iter().enumerate() {
+                    ctx.check()?;
+                    let layer = Layer::build(spec, weights.as_ref(), &HashMap::new())?;
+                    xs = run(index, &layer, &xs)?;
+                }
+            }
+        }
+        xs.to_dtype(DType::F32)
+            .map_err(|e| InferenceError::prediction(format!("Failed to convert graph output to f32: {}", e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn tensors(entries: Vec<(&str, CandleTensor)>) -> HashMap<String, CandleTensor> {
+        entries.into_iter().map(|(name, tensor)| (name.to_string(), tensor)).collect()
+    }
+    
+    #[test]
+    fn test_mlp_graph() {
+        let spec = GraphSpec::parse(r#"{
+            "input": {"name": "features", "shape": [null, 2]},
+            "output": {"name": "probabilities", "shape": [null, 2]},
+            "layers": [
+                {"type": "linear", "weight": "fc1.weight", "bias": "fc1.bias"},
+                {"type": "activation", "function": "relu"},
+                {"type": "residual", "layers": [{"type": "linear", "weight": "skip.weight"}]},
+                {"type": "softmax"}
+            ]
+        }"#).unwrap();
+        let weights = tensors(vec![
+            ("fc1.weight", CandleTensor::new(&[[1.0f32, 0.0], [0.0, -1.0]], &Device::Cpu).unwrap()),
+            ("fc1.bias", CandleTensor::new(&[0.0f32, 0.0], &Device::Cpu).unwrap()),
+            ("skip.weight", CandleTensor::new(&[[1.0f32, 0.0], [0.0, 1.0]], &Device::Cpu).unwrap()),
+        ]);
+        let graph = Graph::build(&spec, &weights).unwrap();
+        assert_eq!(graph.input_spec().name, "features");
+        assert_eq!(graph.output_spec().shape, vec![None, Some(2)]);
+        
+        // relu([1, -2]) = [1, 0], residual doubles it, softmax([2, 0])
+        let input = CandleTensor::new(&[[1.0f32, 2.0]], &Device::Cpu).unwrap();
+        let output: Vec<Vec<f32>> = graph.forward(&input).unwrap().to_vec2().unwrap();
+        let expected = 1.0 / (1.0 + (-2.0f32).exp());
+        assert!((output[0][0] - expected).abs() < 1e-6);
+        assert!((output[0][0] + output[0][1] - 1.0).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn test_conv_graph() {
+        let spec = GraphSpec::parse(r#"{
+            "input": {"name": "image", "shape": [null, 1, 4, 4]},
+            "output": {"name": "logits", "shape": [null, 2]},
+            "layers": [
+                {"type": "conv2d", "weight": "conv.weight", "padding": 1},
+                {"type": "batch_norm", "running_mean": "bn.mean", "running_var": "bn.var", "eps": 0.0},
+                {"type": "max_pool2d", "kernel_size": 2},
+                {"type": "global_avg_pool"},
+                {"type": "flatten"},
+                {"type": "linear", "weight": "fc.weight"}
+            ]
+        }"#).unwrap();
+        let weights = tensors(vec![
+            // Identity 3x3 kernel
+            ("conv.weight", CandleTensor::new(&[[[[0.0f32, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 0.0]]]], &Device::Cpu).unwrap()),
+            ("bn.mean", CandleTensor::new(&[1.0f32], &Device::Cpu).unwrap()),
+            ("bn.var", CandleTensor::new(&[1.0f32], &Device::Cpu).unwrap()),
+            ("fc.weight", CandleTensor::new(&[[1.0f32], [-1.0]], &Device::Cpu).unwrap()),
+        ]);
+        let graph = Graph::build(&spec, &weights).unwrap();
+        
+        let image: Vec<f32> = (0..16).map(|v| v as f32).collect();
+        let input = CandleTensor::from_vec(image, (1, 1, 4, 4), &Device::Cpu).unwrap();
+        let output: Vec<Vec<f32>> = graph.forward(&input).unwrap().to_vec2().unwrap();
+        // Pooled maxima are 5, 7, 13, 15; minus the running mean of 1 averages to 9
+        assert_eq!(output, vec![vec![9.0, -9.0]]);
+    }
+    
+    #[test]
+    fn test_graph_checks_cancellation_between_layers() {
+        use crate::models::CancellationToken;
+        
+        let spec = GraphSpec::parse(r#"{"input": {"name": "x"}, "output": {"name": "y"}, "layers": [{"type": "softmax"}]}"#).unwrap();
+        let graph = Graph::build(&spec, &HashMap::new()).unwrap();
+        let input = CandleTensor::new(&[[1.0f32, 2.0]], &Device::Cpu).unwrap();
+        
+        let token = CancellationToken::new();
+        let ctx = PredictContext::new().with_token(token.clone());
+        assert!(graph.forward_with_context(&input, &ctx).is_ok());
+        
+        token.cancel();
+        assert!(matches!(graph.forward_with_context(&input, &ctx), Err(InferenceError::Cancelled(_))));
+    }
+    
+    #[test]
+    fn test_lazy_graph_reads_weights_as_layers_run() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        
+        /// Counts how often weights are read
+        #[derive(Debug)]
+        struct Counting(HashMap<String, CandleTensor>, AtomicUsize);
+        
+        impl WeightSource for Counting {
+            fn dims(&self, name: &str) -> Option<Vec<usize>> {
+                self.0.dims(name)
+            }
+            
+            fn tensor(&self, name: &str) -> Result<Option<CandleTensor>, InferenceError> {
+                self.1.fetch_add(1, Ordering::SeqCst);
+                self.0.tensor(name)
+            }
+        }
+        
+        let spec = GraphSpec::parse(r#"{
+            "input": {"name": "x"},
+            "output": {"name": "y"},
+            "layers": [
+                {"type": "linear", "weight": "fc.weight", "bias": "fc.bias"},
+                {"type": "activation", "function": "relu"}
+            ]
+        }"#).unwrap();
+        let weights = Arc::new(Counting(tensors(vec![
+            ("fc.weight", CandleTensor::new(&[[1.0f32, 1.0], [1.0, -1.0]], &Device::Cpu).unwrap()),
+            ("fc.bias", CandleTensor::new(&[0.0f32, 1.0], &Device::Cpu).unwrap()),
+        ]), AtomicUsize::new(0)));
+        
+        // Building checks the graph without reading weights, and only the
+        // source keeps them between runs
+        let graph = Graph::build_lazy(&spec, weights.clone()).unwrap();
+        assert_eq!(weights.1.load(Ordering::SeqCst), 0);
+        
+        let input = CandleTensor::new(&[[1.0f32, 2.0]], &Device::Cpu).unwrap();
+        let output: Vec<Vec<f32>> = graph.forward(&input).unwrap().to_vec2().unwrap();
+        assert_eq!(output, vec![vec![3.0, 0.0]]);
+        assert_eq!(weights.1.load(Ordering::SeqCst), 2);
+        
+        let err = Graph::build_lazy(&spec, Arc::new(Counting(tensors(vec![
+            ("fc.weight", CandleTensor::new(&[1.0f32, 1.0], &Device::Cpu).unwrap()),
+            ("fc.bias", CandleTensor::new(&[0.0f32], &Device::Cpu).unwrap()),
+        ]), AtomicUsize::new(0)))).unwrap_err();
+        assert!(matches!(&err, InferenceError::Configuration(msg) if msg.contains("2-D")), "{}", err);
+    }
+    
+    #[test]
+    fn test_graph_validation() {
+        let spec = GraphSpec::parse(r#"{
+            "input": {"name": "x"},
+            "output": {"name": "y"},
+            "layers": [{"type": "linear", "weight": "missing.weight"}]
+        }"#).unwrap();
+        let err = Graph::build(&spec, &HashMap::new()).unwrap_err();
+        assert!(err.to_string().contains("missing.weight"));
+        
+        assert!(GraphSpec::parse(r#"{"input": {"name": "x"}, "output": {"name": "y"}, "layers": [{"type": "dropout"}]}"#).is_err());
+        
+        let spec = GraphSpec::parse(r#"{"input": {"name": "x"}, "output": {"name": "y"}, "layers": [{"type": "linear", "weight": "w"}]}"#).unwrap();
+        let weights = tensors(vec![("w", CandleTensor::new(&[1.0f32, 2.0], &Device::Cpu).unwrap())]);
+        assert!(Graph::build(&spec, &weights).is_err());
+        
+        // Zero strides, groups and kernel sizes are rejected before any weights are read
+        for layer in [
+            r#"{"type": "conv2d", "weight": "w", "stride": 0}"#,
+            r#"{"type": "conv2d", "weight": "w", "groups": 0}"#,
+            r#"{"type": "max_pool2d", "kernel_size": 0}"#,
+            r#"{"type": "residual", "layers": [{"type": "avg_pool2d", "kernel_size": 2, "stride": 0}]}"#,
+        ] {
+            let json = format!(r#"{{"input": {{"name": "x"}}, "output": {{"name": "y"}}, "layers": [{}]}}"#, layer);
+            assert!(matches!(GraphSpec::parse(&json), Err(InferenceError::Configuration(_))), "{}", layer);
+        }
+    }
+    
+    #[test]
+    fn test_graph_path_for_weights() {
+        let dir = Path::new("/models");
+        assert_eq!(GraphSpec::path_for(&dir.join("model.safetensors")), dir.join("model.graph.json"));
+        assert_eq!(GraphSpec::path_for(&dir.join("model.safetensors.index.json")), dir.join("model.graph.json"));
+        assert_eq!(GraphSpec::path_for(&dir.join("classifier.pt")), dir.join("classifier.graph.json"));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::models::InferenceError;
+use serde_json::{Map, Value};
+use std::collections::{HashMap, HashSet};
+
+use super::grammar::Grammar;
+
+/// JSON building blocks shared by every converted schema
+/// 
+/// Whitespace is limited to a single optional space so a model cannot pad
+/// its output forever.
+const JSON_RULES: &str = r#"
+value ::= object | array | string | number | boolean | null
+object ::= "{" ws ( string ":" ws value ( "," ws string ":" ws value )* )? "}" ws
+array ::= "[" ws ( value ( "," ws value )* )? "]" ws
+string ::= "\"" char* "\"" ws
+char ::= [^"\\\x00-\x1f] | "\\" ( ["\\/bfnrt] | "u" hex hex hex hex )
+hex ::= [0-9a-fA-F]
+int-part ::= "-"? ( "0" | [1-9] [0-9]* )
+integer ::= int-part ws
+number ::= int-part ( "." [0-9]+ )? ( [eE] [-+]? [0-9]+ )? ws
+boolean ::= ( "true" | "false" ) ws
+null ::= "null" ws
+ws ::= " "?
+"#;
+
+impl Grammar {
+    /// Build a grammar that only accepts JSON documents valid against `schema`
+    /// 
+    /// See [`json_schema_to_gbnf`] for the supported keywords.
+    pub fn from_json_schema(schema: &Value) -> Result<Self, InferenceError> {
+        Self::parse(&json_schema_to_gbnf(schema)?)
+    }
+}
+
+/// Convert a JSON schema to a GBNF grammar
+/// 
+/// Supports `type` (including lists of types), `properties` with `required`,
+/// `items`, `enum`, `const`, `anyOf`/`oneOf` and local `$ref`s. Object
+/// properties are generated in key order and additional properties are not
+/// produced; string formats, patterns and numeric bounds are not enforced.
+pub fn json_schema_to_gbnf(schema: &Value) -> Result<String, InferenceError> {
+    let mut converter = SchemaConverter {
+        root: schema,
+        rules: Vec::new(),
+        refs: HashMap::new(),
+    };
+    let root = converter.visit(schema, "schema")?;
+    
+    let mut gbnf = format!("root ::= {}\n", root);
+    for (name, body) in &converter.rules {
+        gbnf.push_str(&format!("{} ::= {}\n", name, body));
+    }
+    gbnf.push_str(JSON_RULES);
+    Ok(gbnf)
+}
+
+/// A GBNF literal matching `value` serialized as compact JSON
+fn json_literal(value: &Value) -> String {
+    let escaped = value.to_string()
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"");
+    format!("\"{}\" ws", escaped)
+}
+
+/// Turn a property or definition name into a rule name fragment
+fn rule_fragment(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect()
+}
+
+fn unsupported(path: &str, schema: &Value) -> InferenceError {
+    InferenceError::configuration(format!("Unsupported JSON schema at {}: {}", path, schema))
+}
+
+struct SchemaConverter<'a> {
+    root: &'a Value,
+    rules: Vec<(String, String)>,
+    refs: HashMap<String, String>,
+}
+
+impl<'a> SchemaConverter<'a> {
+    /// Pick a rule name starting with `base` that is not taken yet
+    fn unique_name(&self, base: &str) -> String {
+        let taken = |name: &str| self.rules.iter().any(|(rule, _)| rule == name);
+        if !taken(base) {
+            return base.to_string();
+        }
+        (2..).map(|i| format!("{}-{}", base, i))
+            .find(|name| !taken(name))
+            .unwrap_or_else(|| base.to_string())
+    }
+    
+    fn add_rule(&mut self, base: &str, body: String) -> String {
+        let name = self.unique_name(base);
+        self.rules.push((name.clone(), body));
+        name
+    }
+    
+    /// Grammar expression for `schema`; `name` prefixes any rules it needs
+    fn visit(&mut self, schema: &'a Value, name: &str) -> Result<String, InferenceError> {
+        let object = match schema {
+            Value::Bool(true) => return Ok("value".to_string()),
+            Value::Object(object) => object,
+            _ => return Err(unsupported(name, schema)),
+        };
+        
+        if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+            return self.visit_ref(reference);
+        }
+        if let Some(value) = object.get("const") {
+            return Ok(json_literal(value));
+        }
+        if let Some(values) = object.get("enum") {
+            let values = values.as_array().filter(|v| !v.is_empty()).ok_or_else(|| unsupported(name, schema))?;
+            let literals: Vec<String> = values.iter().map(json_literal).collect();
+            return Ok(format!("( {} )", literals.join(" | ")));
+        }
+        for keyword in ["anyOf", "oneOf"] {
+            if let Some(options) = object.get(keyword) {
+                let options = options.as_array().filter(|o| !o.is_empty()).ok_or_else(|| unsupported(name, schema))?;
+                let alternatives = options.iter()
+                    .enumerate()
+                    .map(|(i, option)| self.visit(option, &format!("{}-{}", name, i)))
+                    .collect::<Result<Vec<_>, _>>()?;
+                return Ok(format!("( {} )", alternatives.join(" | ")));
+            }
+        }
+        
+        match object.get("type") {
+            Some(Value::String(ty)) => self.visit_type(ty, object, name),
+            Some(Value::Array(types)) => {
+                let alternatives = types.iter()
+                    .map(|ty| {
+                        let ty = ty.as_str().ok_or_else(|| unsupported(name, schema))?;
+                        self.visit_type(ty, object, &format!("{}-{}", name, ty))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(format!("( {} )", alternatives.join(" | ")))
+            }
+            Some(_) => Err(unsupported(name, schema)),
+            None if object.contains_key("properties") => self.visit_type("object", object, name),
+            None => Ok("value".to_string()),
+        }
+    }
+    
+    fn visit_type(&mut self, ty: &str, object: &'a Map<String, Value>, name: &str) -> Result<String, InferenceError> {
+        match ty {
+            "string" | "number" | "integer" | "boolean" | "null" => Ok(ty.to_string()),
+            "array" => match object.get("items") {
+                Some(items) => {
+                    let item = self.visit(items, &format!("{}-item", name))?;
+                    let body = format!(r#""[" ws ( {item} ( "," ws {item} )* )? "]" ws"#, item = item);
+                    Ok(self.add_rule(name, body))
+                }
+                None => Ok("array".to_string()),
+            },
+            "object" => self.visit_object(object, name),
+            _ => Err(InferenceError::configuration(format!("Unsupported JSON schema type at {}: {}", name, ty))),
+        }
+    }
+    
+    fn visit_object(&mut self, object: &'a Map<String, Value>, name: &str) -> Result<String, InferenceError> {
+        let Some(Value::Object(properties)) = object.get("properties") else {
+            return Ok("object".to_string());
+        };
+        let required: HashSet<&str> = object.get("required")
+            .and_then(Value::as_array)
+            .map(|keys| keys.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        
+        let mut required_pairs = Vec::new();
+        let mut optional_pairs = Vec::new();
+        for (key, schema) in properties {
+            let prefix = format!("{}-{}", name, rule_fragment(key));
+            let value = self.visit(schema, &prefix)?;
+            let pair = self.add_rule(&format!("{}-kv", prefix), format!(
+                r#"{} ":" ws {}"#, json_literal(&Value::String(key.clone())), value
+            ));
+            if required.contains(key.as_str()) {
+                required_pairs.push(pair);
+            } else {
+                optional_pairs.push(pair);
+            }
+        }
+        
+        let optional_tail = |pairs: &[String]| -> String {
+            pairs.iter().map(|pair| format!(r#" ( "," ws {} )?"#, pair)).collect()
+        };
+        let members = if !required_pairs.is_empty() {
+            format!("{}{}", required_pairs.join(r#" "," ws "#), optional_tail(&optional_pairs))
+        } else if !optional_pairs.is_empty() {
+            // The first property present carries no leading comma
+            let starts: Vec<String> = (0..optional_pairs.len())
+                .map(|i| format!("{}{}", optional_pairs[i], optional_tail(&optional_pairs[i + 1..])))
+                .collect();
+            format!("( {} )?", starts.join(" | "))
+        } else {
+            String::new()
+        };
+        
+        Ok(self.add_rule(name, format!(r#""{{" ws {} "}}" ws"#, members)))
+    }
+    
+    /// Resolve a local `$ref` such as `#/$defs/item`, allowing recursion
+    fn visit_ref(&mut self, reference: &str) -> Result<String, InferenceError> {
+        if let Some(rule) = self.refs.get(reference) {
+            return Ok(rule.clone());
+        }
+        
+        let target = reference.strip_prefix("#/")
+            .and_then(|path| path.split('/').try_fold(self.root, |value, key| value.get(key)))
+            .ok_or_else(|| InferenceError::configuration(format!("Unresolvable JSON schema $ref: {}", reference)))?;
+        let last = reference.rsplit('/').next().unwrap_or("ref");
+        
+        // Register the rule before visiting so recursive references resolve to it
+        let name = self.add_rule(&format!("ref-{}", rule_fragment(last)), String::new());
+        self.refs.insert(reference.to_string(), name.clone());
+        let body = self.visit(target, &format!("{}-def", name))?;
+        if let Some(rule) = self.rules.iter_mut().find(|(rule, _)| *rule == name) {
+            rule.1 = body;
+        }
+        Ok(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn test_object_schema() {
+        let schema = json!({
+            "type": "object",
+            "properties": {
+                "age": { "type": "integer" },
+                "name": { "type": "string" },
+                "tags": { "type": "array", "items": { "enum": ["a", "b"] } }
+            },
+            "required": ["name", "age"]
+        });
+        let grammar = Grammar::from_json_schema(&schema).unwrap();
+        
+        assert!(grammar.matches(r#"{"age":3,"name":"Ann"}"#));
+        assert!(grammar.matches(r#"{ "age": -12, "name": "A \"q\"", "tags": ["a", "b"] }"#));
+        assert!(!grammar.matches(r#"{"age":3}"#));
+        assert!(!grammar.matches(r#"{"age":3.5,"name":"Ann"}"#));
+        assert!(!grammar.matches(r#"{"age":3,"name":"Ann","tags":["c"]}"#));
+        assert!(!grammar.matches(r#"{"age":3,"name":"Ann""#));
+    }
+    
+    #[test]
+    fn test_optional_properties_and_type_lists() {
+        let schema = json!({
+            "properties": {
+                "a": { "type": ["number", "null"] },
+                "b": { "const": true }
+            }
+        });
+        let grammar = Grammar::from_json_schema(&schema).unwrap();
+        assert!(grammar.matches("{}"));
+        assert!(grammar.matches(r#"{"b":true}"#));
+        assert!(grammar.matches(r#"{"a":null,"b":true}"#));
+        assert!(grammar.matches(r#"{"a":1e-3}"#));
+        assert!(!grammar.matches(r#"{"b":false}"#));
+        assert!(!grammar.matches(r#"{,"b":true}"#));
+    }
+    
+    #[test]
+    fn test_recursive_ref_and_free_json() {
+        let schema = json!({
+            "$ref": "#/$defs/node",
+            "$defs": {
+                "node": {
+                    "type": "object",
+                    "properties": { "children": { "type": "array", "items": { "$ref": "#/$defs/node" } } },
+                    "required": ["children"]
+                }
+            }
+        });
+        let grammar = Grammar::from_json_schema(&schema).unwrap();
+        assert!(grammar.matches(r#"{"children":[{"children":[]}]}"#));
+        assert!(!grammar.matches(r#"{"children":[1]}"#));
+        
+        let any = Grammar::from_json_schema(&json!({})).unwrap();
+        assert!(any.matches(r#"[1, "x", {"k": null}]"#));
+        assert!(!any.matches("{'k': 1}"));
+    }
+    
+    #[test]
+    fn test_unsupported_schema_rejected() {
+        assert!(json_schema_to_gbnf(&json!({ "type": "date" })).is_err());
+        assert!(json_schema_to_gbnf(&json!({ "$ref": "https://example.com/schema" })).is_err());
+        assert!(json_schema_to_gbnf(&json!({ "enum": [] })).is_err());
+    }
+}
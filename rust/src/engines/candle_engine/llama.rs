@@ -0,0 +1,485 @@
+use crate::engines::{Model, EngineType};
+use crate::models::{InferenceError, Tensor, TensorSpec, DataType, OptimizationLevel};
+use async_trait::async_trait;
+use std::any::Any;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use candle_core::quantized::gguf_file;
+use candle_core::{Device, DeviceLocation, DType, Tensor as CandleTensor};
+use candle_nn::VarBuilder;
+use candle_transformers::models::llama::{Cache, Config as LlamaConfig, Llama, LlamaConfig as LlamaJsonConfig, LlamaEosToks};
+use candle_transformers::models::quantized_llama;
+use tokenizers::Tokenizer;
+
+use super::forward_error;
+use super::chat::ChatTemplate;
+use super::generation::{eos_token_ids, load_tokenizer, CausalLm, TextGeneration, TextGenerator};
+use super::gguf::GgufInfo;
+use super::model_wrappers::download_from_huggingface;
+use super::sharding::download_safetensors;
+
+/// Run `tokens` through `step` starting at `position`
+/// 
+/// Candle's Llama attention masks a multi-token input only when it starts at
+/// position 0, so tokens appended to a warm cache are fed one at a time.
+fn forward_in_chunks(
+    tokens: &[u32],
+    position: usize,
+    mut step: impl FnMut(&[u32], usize) -> Result<CandleTensor, InferenceError>,
+) -> Result<CandleTensor, InferenceError> {
+    let chunk_size = if position == 0 { tokens.len().max(1) } else { 1 };
+    let mut position = position;
+    let mut logits = None;
+    for chunk in tokens.chunks(chunk_size) {
+        logits = Some(step(chunk, position)?);
+        position += chunk.len();
+    }
+    logits.ok_or_else(|| InferenceError::invalid_shape_msg("Cannot run Llama on an empty token sequence"))
+}
+
+/// Pick the dtype full-precision Llama weights are loaded in
+/// 
+/// F16 and BF16 optimization levels convert the weights; otherwise the
+/// checkpoint's `torch_dtype` is kept. BF16 checkpoints load as F32 off CUDA,
+/// where Candle has no bf16 matmul kernels.
+fn weight_dtype(level: OptimizationLevel, torch_dtype: Option<&str>, device: &Device) -> Result<DType, InferenceError> {
+    match level {
+        OptimizationLevel::None => Ok(match torch_dtype {
+            Some("float16") => DType::F16,
+            Some("bfloat16") if matches!(device.location(), DeviceLocation::Cuda { .. }) => DType::BF16,
+            _ => DType::F32,
+        }),
+        OptimizationLevel::F16 => Ok(DType::F16),
+        OptimizationLevel::Bf16 => Ok(DType::BF16),
+        level => Err(InferenceError::configuration(format!(
+            "Llama SafeTensors weights can't be quantized to {}; load a GGUF checkpoint instead", level.name()
+        ))),
+    }
+}
+
+/// Build a `[1, seq]` token tensor
+fn token_tensor(tokens: &[u32], device: &Device) -> Result<CandleTensor, InferenceError> {
+    CandleTensor::new(tokens, device)
+        .and_then(|t| t.unsqueeze(0))
+        .map_err(|e| InferenceError::prediction(format!("Failed to create token tensor: {}", e)))
+}
+
+/// Llama with full-precision weights and candle's KV cache
+pub struct LlamaBackend {
+    model: Llama,
+    cache: Cache,
+    config: LlamaConfig,
+    dtype: DType,
+    device: Device,
+}
+
+impl LlamaBackend {
+    /// Build the model from a variable builder
+    pub fn new(vb: VarBuilder, config: LlamaConfig) -> Result<Self, InferenceError> {
+        let dtype = vb.dtype();
+        let device = vb.device().clone();
+        let model = Llama::load(vb, &config)
+            .map_err(|e| InferenceError::model_load(format!("Failed to build Llama model: {}", e)))?;
+        let cache = Cache::new(true, dtype, &config, &device)
+            .map_err(|e| InferenceError::model_load(format!("Failed to create Llama KV cache: {}", e)))?;
+        
+        Ok(Self {
+            model,
+            cache,
+            config,
+            dtype,
+            device,
+        })
+    }
+    
+    /// Get the model configuration
+    pub fn config(&self) -> &LlamaConfig {
+        &self.config
+    }
+    
+    fn step(&mut self, tokens: &[u32], position: usize) -> Result<CandleTensor, InferenceError> {
+        let input = token_tensor(tokens, &self.device)?;
+        self.model.forward(&input, position, &mut self.cache)
+            .and_then(|logits| logits.squeeze(0))
+            .map_err(|e| forward_error("Llama forward pass failed", e))
+    }
+}
+
+impl CausalLm for LlamaBackend {
+    fn forward(&mut self, tokens: &[u32], position: usize) -> Result<CandleTensor, InferenceError> {
+        forward_in_chunks(tokens, position, |chunk, position| self.step(chunk, position))
+    }
+    
+    fn clear_cache(&mut self) -> Result<(), InferenceError> {
+        // Candle's cache always appends, so clearing means starting a new one
+        self.cache = Cache::new(true, self.dtype, &self.config, &self.device)
+            .map_err(|e| InferenceError::prediction(format!("Failed to reset Llama KV cache: {}", e)))?;
+        Ok(())
+    }
+    
+    fn max_sequence_length(&self) -> usize {
+        self.config.max_position_embeddings
+    }
+}
+
+/// Llama with GGUF quantized weights
+pub struct QuantizedLlamaBackend {
+    weights: quantized_llama::ModelWeights,
+    max_sequence_length: usize,
+    device: Device,
+}
+
+impl QuantizedLlamaBackend {
+    /// Load llama-architecture GGUF weights, also returning the parsed header
+    /// 
+    /// llama.cpp exports Mistral checkpoints with the `llama` architecture too.
+    pub fn from_gguf<R: std::io::Read + std::io::Seek>(reader: &mut R, device: &Device) -> Result<(Self, GgufInfo), InferenceError> {
+        let content = gguf_file::Content::read(reader)
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse GGUF file: {}", e)))?;
+        let info = GgufInfo::from_content(&content);
+        let backend = Self::from_content(content, &info, reader, device)?;
+        Ok((backend, info))
+    }
+    
+    /// Load llama-architecture weights from an already parsed GGUF header
+    pub fn from_content<R: std::io::Read + std::io::Seek>(
+        content: gguf_file::Content,
+        info: &GgufInfo,
+        reader: &mut R,
+        device: &Device,
+    ) -> Result<Self, InferenceError> {
+        if info.architecture.as_deref() != Some("llama") {
+            return Err(InferenceError::unsupported_format(format!(
+                "Expected a llama GGUF file, got architecture {:?}", info.architecture
+            )));
+        }
+        
+        let weights = quantized_llama::ModelWeights::from_gguf(content, reader, device)
+            .map_err(|e| InferenceError::model_load(format!("Failed to load llama GGUF weights: {}", e)))?;
+        
+        // Rotary tables are precomputed up to MAX_SEQ_LEN
+        let max_sequence_length = info.context_length
+            .unwrap_or(quantized_llama::MAX_SEQ_LEN)
+            .min(quantized_llama::MAX_SEQ_LEN);
+        
+        Ok(Self {
+            weights,
+            max_sequence_length,
+            device: device.clone(),
+        })
+    }
+    
+    fn step(&mut self, tokens: &[u32], position: usize) -> Result<CandleTensor, InferenceError> {
+        let input = token_tensor(tokens, &self.device)?;
+        self.weights.forward(&input, position)
+            .and_then(|logits| logits.squeeze(0))
+            .and_then(|logits| logits.to_dtype(DType::F32))
+            .map_err(|e| forward_error("Quantized Llama forward pass failed", e))
+    }
+}
+
+impl CausalLm for QuantizedLlamaBackend {
+    fn forward(&mut self, tokens: &[u32], position: usize) -> Result<CandleTensor, InferenceError> {
+        forward_in_chunks(tokens, position, |chunk, position| self.step(chunk, position))
+    }
+    
+    fn clear_cache(&mut self) -> Result<(), InferenceError> {
+        // The quantized model drops its cache whenever it runs at position 0
+        Ok(())
+    }
+    
+    fn max_sequence_length(&self) -> usize {
+        self.max_sequence_length
+    }
+}
+
+/// Llama text generation model using candle-transformers
+/// 
+/// Loads either SafeTensors weights with a HuggingFace `config.json` or a
+/// quantized GGUF file, plus a `tokenizer.json`. Beyond the tensor-level
+/// [`Model`] interface it exposes [`LlamaModelWrapper::generate`], which
+/// keeps the KV cache warm across decoding steps and across calls whose
+/// prompt extends the previous one.
+pub struct LlamaModelWrapper {
+    generator: Mutex<TextGenerator>,
+    quantized: bool,
+    input_specs: Vec<TensorSpec>,
+    output_specs: Vec<TensorSpec>,
+}
+
+impl std::fmt::Debug for LlamaModelWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LlamaModelWrapper")
+            .field("quantized", &self.quantized)
+            .finish()
+    }
+}
+
+impl LlamaModelWrapper {
+    /// Wrap a backend and tokenizer
+    pub fn new(model: Box<dyn CausalLm>, tokenizer: Tokenizer, eos_token_ids: Vec<u32>, vocab_size: usize, quantized: bool) -> Self {
+        let input_specs = vec![TensorSpec::new(
+            "input_ids".to_string(),
+            vec![Some(1), None], // batch_size, sequence_length
+            DataType::I64,
+        )];
+        let output_specs = vec![TensorSpec::new(
+            "logits".to_string(),
+            vec![Some(1), Some(vocab_size)],
+            DataType::F32,
+        )];
+        
+        Self {
+            generator: Mutex::new(TextGenerator::new(model, tokenizer, eos_token_ids)),
+            quantized,
+            input_specs,
+            output_specs,
+        }
+    }
+    
+    /// Render chat messages with `template`
+    pub fn with_chat_template(mut self, template: ChatTemplate) -> Self {
+        if let Ok(generator) = self.generator.get_mut() {
+            generator.set_chat_template(template);
+        }
+        self
+    }
+    
+    /// Load SafeTensors weights with a HuggingFace config and tokenizer
+    /// 
+    /// Weights are loaded in the dtype `level` asks for, or the checkpoint's
+    /// own `torch_dtype` when the level is `None`.
+    pub fn load<P: AsRef<Path>>(
+        config_path: P,
+        weight_paths: &[PathBuf],
+        tokenizer_path: P,
+        device: &Device,
+        level: OptimizationLevel,
+    ) -> Result<Self, InferenceError> {
+        let config_json = std::fs::read_to_string(config_path.as_ref())
+            .map_err(|e| InferenceError::model_load(format!("Failed to read Llama config: {}", e)))?;
+        let raw_config: serde_json::Value = serde_json::from_str(&config_json)
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse Llama config: {}", e)))?;
+        let dtype = weight_dtype(level, raw_config.get("torch_dtype").and_then(|v| v.as_str()), device)?;
+        let config: LlamaJsonConfig = serde_json::from_value(raw_config)
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse Llama config: {}", e)))?;
+        let config = config.into_config(false);
+        let tokenizer = load_tokenizer(tokenizer_path.as_ref())?;
+        
+        // SAFETY: the weight files are memory-mapped read-only and must not be
+        // modified while the model is alive, the same contract as candle's examples
+        let vb = unsafe { VarBuilder::from_mmaped_safetensors(weight_paths, dtype, device) }
+            .map_err(|e| InferenceError::model_load(format!("Failed to map Llama weights: {}", e)))?;
+        
+        // Llama::load panics on a missing layer, so check the last one up front
+        let last_layer = format!("model.layers.{}.mlp.down_proj.weight", config.num_hidden_layers.saturating_sub(1));
+        if !vb.contains_tensor(&last_layer) {
+            return Err(InferenceError::model_load(format!("Llama weights are missing {}", last_layer)));
+        }
+        
+        let config_eos = match &config.eos_token_id {
+            Some(LlamaEosToks::Single(id)) => vec![*id],
+            Some(LlamaEosToks::Multiple(ids)) => ids.clone(),
+            None => Vec::new(),
+        };
+        let eos = eos_token_ids(&tokenizer, config_eos);
+        let vocab_size = config.vocab_size;
+        let backend = LlamaBackend::new(vb, config)?;
+        
+        println!("✅ Loaded Llama model with {} layers ({:?})", backend.config().num_hidden_layers, dtype);
+        let wrapper = Self::new(Box::new(backend), tokenizer, eos, vocab_size, false);
+        Ok(match ChatTemplate::find_for_tokenizer(tokenizer_path.as_ref()) {
+            Some(template) => wrapper.with_chat_template(template),
+            None => wrapper,
+        })
+    }
+    
+    /// Load quantized weights from a GGUF file and a tokenizer
+    pub fn load_gguf<P: AsRef<Path>>(path: P, tokenizer_path: P, device: &Device) -> Result<Self, InferenceError> {
+        let mut file = std::fs::File::open(path.as_ref())
+            .map_err(|e| InferenceError::model_load(format!("Failed to open GGUF file: {}", e)))?;
+        let (backend, info) = QuantizedLlamaBackend::from_gguf(&mut file, device)?;
+        let tokenizer = load_tokenizer(tokenizer_path.as_ref())?;
+        
+        let eos = eos_token_ids(&tokenizer, info.eos_token_id.into_iter().collect());
+        let vocab_size = if info.tokens.is_empty() { tokenizer.get_vocab_size(true) } else { info.tokens.len() };
+        
+        println!("✅ Loaded quantized Llama model ({:?})", info.quantization);
+        let wrapper = Self::new(Box::new(backend), tokenizer, eos, vocab_size, true);
+        Ok(match ChatTemplate::find_for_tokenizer(tokenizer_path.as_ref()) {
+            Some(template) => wrapper.with_chat_template(template),
+            None => wrapper,
+        })
+    }
+    
+    /// Load a Llama model from a HuggingFace repository
+    /// 
+    /// A `filename` ending in `.gguf` loads quantized weights; anything else is
+    /// treated as SafeTensors weights next to `config.json`, falling back to the
+    /// shards of `<filename>.index.json`. Both need the repository to ship a
+    /// `tokenizer.json`. `level` selects the dtype of SafeTensors weights.
+    pub async fn load_from_huggingface(
+        device: &Device,
+        repo_id: &str,
+        filename: Option<&str>,
+        level: OptimizationLevel,
+    ) -> Result<Self, InferenceError> {
+        println!("🤗 Loading Llama model from HuggingFace: {}", repo_id);
+        
+        let filename = filename.unwrap_or("model.safetensors");
+        let tokenizer_path = download_from_huggingface(repo_id, "tokenizer.json").await?;
+        // Optional: only chat models ship a template, which is read from next to tokenizer.json
+        let _ = download_from_huggingface(repo_id, "tokenizer_config.json").await;
+        
+        let wrapper = if filename.ends_with(".gguf") {
+            if level != OptimizationLevel::None {
+                return Err(InferenceError::configuration(format!(
+                    "Optimization level {} can't be applied to GGUF weights, which are already quantized", level.name()
+                )));
+            }
+            let model_path = download_from_huggingface(repo_id, filename).await?;
+            Self::load_gguf(&model_path, &tokenizer_path, device)?
+        } else {
+            let config_path = download_from_huggingface(repo_id, "config.json").await?;
+            let weight_paths = download_safetensors(repo_id, filename, &mut |_| {}).await?;
+            Self::load(&config_path, &weight_paths, &tokenizer_path, device, level)?
+        };
+        
+        println!("✅ Successfully loaded Llama model from HuggingFace");
+        Ok(wrapper)
+    }
+    
+    /// Whether the weights are GGUF quantized
+    pub fn is_quantized(&self) -> bool {
+        self.quantized
+    }
+}
+
+impl TextGeneration for LlamaModelWrapper {
+    fn generator(&self) -> Result<MutexGuard<'_, TextGenerator>, InferenceError> {
+        self.generator.lock()
+            .map_err(|_| InferenceError::prediction("Llama generator lock poisoned"))
+    }
+}
+
+#[async_trait]
+impl Model for LlamaModelWrapper {
+    /// Return next-token logits `[1, vocab]` for token ids of shape `[seq]` or `[1, seq]`
+    async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+        self.next_token_logits(input)
+    }
+    
+    async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+        let mut results = Vec::new();
+        for input in inputs {
+            results.push(self.predict(input).await?);
+        }
+        Ok(results)
+    }
+    
+    fn input_specs(&self) -> &[TensorSpec] {
+        &self.input_specs
+    }
+    
+    fn output_specs(&self) -> &[TensorSpec] {
+        &self.output_specs
+    }
+    
+    fn engine_type(&self) -> EngineType {
+        EngineType::Candle
+    }
+    
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_nn::VarMap;
+    
+    fn tiny_llama(device: &Device) -> LlamaBackend {
+        let varmap = VarMap::new();
+        let vb = VarBuilder::from_varmap(&varmap, DType::F32, device);
+        LlamaBackend::new(vb, tiny_config()).unwrap()
+    }
+    
+    fn tiny_config() -> LlamaConfig {
+        let config: LlamaJsonConfig = serde_json::from_str(r#"{
+            "hidden_size": 16,
+            "intermediate_size": 32,
+            "vocab_size": 24,
+            "num_hidden_layers": 2,
+            "num_attention_heads": 4,
+            "num_key_value_heads": 2,
+            "rms_norm_eps": 1e-5,
+            "max_position_embeddings": 32
+        }"#).unwrap();
+        config.into_config(false)
+    }
+    
+    fn max_abs_diff(a: &CandleTensor, b: &CandleTensor) -> f32 {
+        (a - b).unwrap().abs().unwrap().max(0).unwrap().to_scalar::<f32>().unwrap()
+    }
+    
+    #[test]
+    fn test_kv_cache_matches_full_forward() {
+        let device = Device::Cpu;
+        let mut model = tiny_llama(&device);
+        let tokens = [3u32, 7, 1, 12, 5];
+        
+        let full = model.forward(&tokens, 0).unwrap();
+        assert_eq!(full.dims(), &[24]);
+        
+        // Prompt first, then the rest appended to the warm cache
+        model.clear_cache().unwrap();
+        model.forward(&tokens[..3], 0).unwrap();
+        let cached = model.forward(&tokens[3..], 3).unwrap();
+        
+        assert!(max_abs_diff(&full, &cached) < 1e-4);
+    }
+    
+    #[test]
+    fn test_clear_cache_restarts_sequence() {
+        let device = Device::Cpu;
+        let mut model = tiny_llama(&device);
+        
+        let first = model.forward(&[4, 9], 0).unwrap();
+        model.clear_cache().unwrap();
+        let second = model.forward(&[4, 9], 0).unwrap();
+        
+        assert!(max_abs_diff(&first, &second) < 1e-6);
+    }
+    
+    #[test]
+    fn test_weight_dtype_follows_level_then_checkpoint() {
+        let cpu = Device::Cpu;
+        assert_eq!(weight_dtype(OptimizationLevel::None, None, &cpu).unwrap(), DType::F32);
+        assert_eq!(weight_dtype(OptimizationLevel::None, Some("float16"), &cpu).unwrap(), DType::F16);
+        assert_eq!(weight_dtype(OptimizationLevel::None, Some("bfloat16"), &cpu).unwrap(), DType::F32);
+        assert_eq!(weight_dtype(OptimizationLevel::F16, Some("float32"), &cpu).unwrap(), DType::F16);
+        assert_eq!(weight_dtype(OptimizationLevel::Bf16, None, &cpu).unwrap(), DType::BF16);
+        assert!(matches!(weight_dtype(OptimizationLevel::Int8, None, &cpu), Err(InferenceError::Configuration(_))));
+    }
+    
+    #[test]
+    fn test_half_precision_weights_match_f32() {
+        let device = Device::Cpu;
+        let varmap = VarMap::new();
+        let mut full = LlamaBackend::new(VarBuilder::from_varmap(&varmap, DType::F32, &device), tiny_config()).unwrap();
+        
+        let weights = varmap.data().lock().unwrap()
+            .iter()
+            .map(|(name, var)| (name.clone(), var.as_tensor().clone()))
+            .collect();
+        let mut half = LlamaBackend::new(VarBuilder::from_tensors(weights, DType::F16, &device), tiny_config()).unwrap();
+        
+        let tokens = [3u32, 7, 1, 12];
+        let expected = full.forward(&tokens, 0).unwrap();
+        let actual = half.forward(&tokens, 0).unwrap();
+        assert_eq!(actual.dtype(), DType::F32);
+        assert!(max_abs_diff(&expected, &actual) < 1e-2);
+    }
+}
@@ -0,0 +1,353 @@
+use crate::engines::{Model, EngineType};
+use crate::models::{InferenceError, Tensor, TensorSpec, DataType};
+use async_trait::async_trait;
+use std::any::Any;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use candle_core::{Device, DType, Tensor as CandleTensor};
+use candle_nn::{Module, VarBuilder};
+use candle_transformers::models::mistral::{Config as MistralConfig, Model as Mistral};
+
+use super::forward_error;
+use super::chat::ChatTemplate;
+use super::generation::{config_eos_token_ids, eos_token_ids, load_tokenizer, CausalLm, TextGeneration, TextGenerator};
+use super::llama::QuantizedLlamaBackend;
+use super::model_wrappers::download_from_huggingface;
+use super::sharding::download_safetensors;
+
+/// Mistral with full-precision weights
+/// 
+/// Grouped-query attention comes from candle-transformers. Candle only masks
+/// the sliding window within a single forward call, so the attention mask is
+/// built here from absolute positions and also covers cached keys.
+pub struct MistralBackend {
+    model: Mistral,
+    sliding_window: Option<usize>,
+    max_sequence_length: usize,
+    dtype: DType,
+    device: Device,
+}
+
+impl MistralBackend {
+    /// Build the model from a variable builder
+    pub fn new(vb: VarBuilder, config: &MistralConfig) -> Result<Self, InferenceError> {
+        let device = vb.device().clone();
+        let dtype = vb.dtype();
+        let model = Mistral::new(config, vb)
+            .map_err(|e| InferenceError::model_load(format!("Failed to build Mistral model: {}", e)))?;
+        
+        Ok(Self {
+            model,
+            sliding_window: config.sliding_window,
+            max_sequence_length: config.max_position_embeddings,
+            dtype,
+            device,
+        })
+    }
+    
+    /// Additive attention mask for `len` queries starting at `position`
+    /// 
+    /// Query `i` sees key `j` when `j <= i` and `i - j <= sliding_window`,
+    /// matching candle's in-call mask. Returns `None` when nothing is masked.
+    fn attention_mask(&self, len: usize, position: usize) -> Result<Option<CandleTensor>, InferenceError> {
+        let keys = position + len;
+        let window = self.sliding_window.unwrap_or(keys);
+        if len == 1 && keys <= window + 1 {
+            return Ok(None);
+        }
+        
+        let mask: Vec<f32> = (position..keys)
+            .flat_map(|i| (0..keys).map(move |j| {
+                if j > i || i - j > window { f32::NEG_INFINITY } else { 0.0 }
+            }))
+            .collect();
+        CandleTensor::from_vec(mask, (1, 1, len, keys), &self.device)
+            .and_then(|mask| mask.to_dtype(self.dtype))
+            .map(Some)
+            .map_err(|e| InferenceError::prediction(format!("Failed to build Mistral attention mask: {}", e)))
+    }
+}
+
+impl CausalLm for MistralBackend {
+    fn forward(&mut self, tokens: &[u32], position: usize) -> Result<CandleTensor, InferenceError> {
+        let input = CandleTensor::new(tokens, &self.device)
+            .and_then(|t| t.unsqueeze(0))
+            .map_err(|e| InferenceError::prediction(format!("Failed to create token tensor: {}", e)))?;
+        
+        let mask = self.attention_mask(tokens.len(), position)?;
+        
+        // Logits come back as [1, 1, vocab]
+        self.model.embed_tokens().forward(&input)
+            .and_then(|embeds| self.model.forward_embeds(&embeds, mask.as_ref(), position))
+            .and_then(|logits| logits.flatten_all())
+            .and_then(|logits| logits.to_dtype(DType::F32))
+            .map_err(|e| forward_error("Mistral forward pass failed", e))
+    }
+    
+    fn clear_cache(&mut self) -> Result<(), InferenceError> {
+        self.model.clear_kv_cache();
+        Ok(())
+    }
+    
+    fn max_sequence_length(&self) -> usize {
+        self.max_sequence_length
+    }
+}
+
+/// Mistral text generation model using candle-transformers
+/// 
+/// Loads SafeTensors weights with a HuggingFace `config.json`, or a llama.cpp
+/// GGUF export (which uses the llama architecture), plus a `tokenizer.json`.
+/// Generation goes through [`TextGeneration`] like the other decoder-only
+/// wrappers.
+pub struct MistralModelWrapper {
+    generator: Mutex<TextGenerator>,
+    config: Option<MistralConfig>,
+    input_specs: Vec<TensorSpec>,
+    output_specs: Vec<TensorSpec>,
+}
+
+impl std::fmt::Debug for MistralModelWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MistralModelWrapper")
+            .field("sliding_window", &self.config.as_ref().and_then(|c| c.sliding_window))
+            .field("quantized", &self.is_quantized())
+            .finish()
+    }
+}
+
+impl MistralModelWrapper {
+    /// Wrap a backend and tokenizer
+    pub fn new(model: Box<dyn CausalLm>, tokenizer: tokenizers::Tokenizer, eos_token_ids: Vec<u32>, vocab_size: usize, config: Option<MistralConfig>) -> Self {
+        let input_specs = vec![TensorSpec::new(
+            "input_ids".to_string(),
+            vec![Some(1), None], // batch_size, sequence_length
+            DataType::I64,
+        )];
+        let output_specs = vec![TensorSpec::new(
+            "logits".to_string(),
+            vec![Some(1), Some(vocab_size)],
+            DataType::F32,
+        )];
+        
+        Self {
+            generator: Mutex::new(TextGenerator::new(model, tokenizer, eos_token_ids)),
+            config,
+            input_specs,
+            output_specs,
+        }
+    }
+    
+    /// Render chat messages with `template`
+    pub fn with_chat_template(mut self, template: ChatTemplate) -> Self {
+        if let Ok(generator) = self.generator.get_mut() {
+            generator.set_chat_template(template);
+        }
+        self
+    }
+    
+    /// Load SafeTensors weights with a HuggingFace config and tokenizer
+    pub fn load<P: AsRef<Path>>(
+        config_path: P,
+        weight_paths: &[PathBuf],
+        tokenizer_path: P,
+        device: &Device,
+    ) -> Result<Self, InferenceError> {
+        let config_json = std::fs::read_to_string(config_path.as_ref())
+            .map_err(|e| InferenceError::model_load(format!("Failed to read Mistral config: {}", e)))?;
+        let raw_config: serde_json::Value = serde_json::from_str(&config_json)
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse Mistral config: {}", e)))?;
+        let config: MistralConfig = serde_json::from_value(raw_config.clone())
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse Mistral config: {}", e)))?;
+        let tokenizer = load_tokenizer(tokenizer_path.as_ref())?;
+        
+        // SAFETY: the weight files are memory-mapped read-only and must not be
+        // modified while the model is alive, the same contract as candle's examples
+        let vb = unsafe { VarBuilder::from_mmaped_safetensors(weight_paths, DType::F32, device) }
+            .map_err(|e| InferenceError::model_load(format!("Failed to map Mistral weights: {}", e)))?;
+        let backend = MistralBackend::new(vb, &config)?;
+        
+        let eos = eos_token_ids(&tokenizer, config_eos_token_ids(&raw_config));
+        println!("✅ Loaded Mistral model with {} layers ({} KV heads, sliding window {:?})",
+            config.num_hidden_layers, config.num_key_value_heads, config.sliding_window);
+        let wrapper = Self::new(Box::new(backend), tokenizer, eos, config.vocab_size, Some(config));
+        Ok(match ChatTemplate::find_for_tokenizer(tokenizer_path.as_ref()) {
+            Some(template) => wrapper.with_chat_template(template),
+            None => wrapper,
+        })
+    }
+    
+    /// Load quantized weights from a llama.cpp GGUF file and a tokenizer
+    pub fn load_gguf<P: AsRef<Path>>(path: P, tokenizer_path: P, device: &Device) -> Result<Self, InferenceError> {
+        let mut file = std::fs::File::open(path.as_ref())
+            .map_err(|e| InferenceError::model_load(format!("Failed to open GGUF file: {}", e)))?;
+        let (backend, info) = QuantizedLlamaBackend::from_gguf(&mut file, device)?;
+        let tokenizer = load_tokenizer(tokenizer_path.as_ref())?;
+        
+        let eos = eos_token_ids(&tokenizer, info.eos_token_id.into_iter().collect());
+        let vocab_size = if info.tokens.is_empty() { tokenizer.get_vocab_size(true) } else { info.tokens.len() };
+        
+        println!("✅ Loaded quantized Mistral model ({:?})", info.quantization);
+        let wrapper = Self::new(Box::new(backend), tokenizer, eos, vocab_size, None);
+        Ok(match ChatTemplate::find_for_tokenizer(tokenizer_path.as_ref()) {
+            Some(template) => wrapper.with_chat_template(template),
+            None => wrapper,
+        })
+    }
+    
+    /// Load a Mistral model from a HuggingFace repository
+    /// 
+    /// A `filename` ending in `.gguf` loads quantized weights; anything else is
+    /// treated as SafeTensors weights next to `config.json`, falling back to the
+    /// shards of `<filename>.index.json`. Both need the repository to ship a
+    /// `tokenizer.json`.
+    pub async fn load_from_huggingface(
+        device: &Device,
+        repo_id: &str,
+        filename: Option<&str>,
+    ) -> Result<Self, InferenceError> {
+        println!("🤗 Loading Mistral model from HuggingFace: {}", repo_id);
+        
+        let filename = filename.unwrap_or("model.safetensors");
+        let tokenizer_path = download_from_huggingface(repo_id, "tokenizer.json").await?;
+        // Optional: only chat models ship a template, which is read from next to tokenizer.json
+        let _ = download_from_huggingface(repo_id, "tokenizer_config.json").await;
+        
+        let wrapper = if filename.ends_with(".gguf") {
+            let model_path = download_from_huggingface(repo_id, filename).await?;
+            Self::load_gguf(&model_path, &tokenizer_path, device)?
+        } else {
+            let config_path = download_from_huggingface(repo_id, "config.json").await?;
+            let weight_paths = download_safetensors(repo_id, filename, &mut |_| {}).await?;
+            Self::load(&config_path, &weight_paths, &tokenizer_path, device)?
+        };
+        
+        println!("✅ Successfully loaded Mistral model from HuggingFace");
+        Ok(wrapper)
+    }
+    
+    /// Get the model configuration (`None` for GGUF weights)
+    pub fn config(&self) -> Option<&MistralConfig> {
+        self.config.as_ref()
+    }
+    
+    /// Whether the weights are GGUF quantized
+    pub fn is_quantized(&self) -> bool {
+        self.config.is_none()
+    }
+}
+
+impl TextGeneration for MistralModelWrapper {
+    fn generator(&self) -> Result<MutexGuard<'_, TextGenerator>, InferenceError> {
+        self.generator.lock()
+            .map_err(|_| InferenceError::prediction("Mistral generator lock poisoned"))
+    }
+}
+
+#[async_trait]
+impl Model for MistralModelWrapper {
+    /// Return next-token logits `[1, vocab]` for token ids of shape `[seq]` or `[1, seq]`
+    async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+        self.next_token_logits(input)
+    }
+    
+    async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+        let mut results = Vec::new();
+        for input in inputs {
+            results.push(self.predict(input).await?);
+        }
+        Ok(results)
+    }
+    
+    fn input_specs(&self) -> &[TensorSpec] {
+        &self.input_specs
+    }
+    
+    fn output_specs(&self) -> &[TensorSpec] {
+        &self.output_specs
+    }
+    
+    fn engine_type(&self) -> EngineType {
+        EngineType::Candle
+    }
+    
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_nn::VarMap;
+    
+    fn tiny_config(sliding_window: Option<usize>) -> MistralConfig {
+        serde_json::from_value(serde_json::json!({
+            "vocab_size": 24,
+            "hidden_size": 16,
+            "intermediate_size": 32,
+            "num_hidden_layers": 2,
+            "num_attention_heads": 4,
+            "num_key_value_heads": 2,
+            "max_position_embeddings": 64,
+            "rms_norm_eps": 1e-5,
+            "rope_theta": 10000.0,
+            "sliding_window": sliding_window
+        })).unwrap()
+    }
+    
+    fn tiny_mistral(config: &MistralConfig) -> MistralBackend {
+        let varmap = VarMap::new();
+        let vb = VarBuilder::from_varmap(&varmap, DType::F32, &Device::Cpu);
+        MistralBackend::new(vb, config).unwrap()
+    }
+    
+    fn max_abs_diff(a: &CandleTensor, b: &CandleTensor) -> f32 {
+        (a - b).unwrap().abs().unwrap().max(0).unwrap().to_scalar::<f32>().unwrap()
+    }
+    
+    #[test]
+    fn test_kv_cache_matches_full_forward() {
+        let mut model = tiny_mistral(&tiny_config(Some(8)));
+        let tokens = [3u32, 7, 1, 12, 5, 9];
+        
+        let full = model.forward(&tokens, 0).unwrap();
+        assert_eq!(full.dims(), &[24]);
+        
+        // A multi-token chunk appended to a warm cache is masked correctly too
+        model.clear_cache().unwrap();
+        model.forward(&tokens[..2], 0).unwrap();
+        let cached = model.forward(&tokens[2..], 2).unwrap();
+        
+        assert!(max_abs_diff(&full, &cached) < 1e-4);
+    }
+    
+    #[test]
+    fn test_context_extends_past_sliding_window() {
+        assert_eq!(tiny_mistral(&tiny_config(Some(8))).max_sequence_length(), 64);
+        assert_eq!(tiny_mistral(&tiny_config(None)).max_sequence_length(), 64);
+    }
+    
+    #[test]
+    fn test_keys_beyond_window_are_masked() {
+        // With one layer the last token only sees tokens inside its window
+        let mut config = tiny_config(Some(3));
+        config.num_hidden_layers = 1;
+        let mut model = tiny_mistral(&config);
+        let tokens = [3u32, 7, 1, 12, 5, 9, 2, 4, 11, 6];
+        
+        let full = model.forward(&tokens, 0).unwrap();
+        model.clear_cache().unwrap();
+        let windowed = model.forward(&tokens[tokens.len() - 4..], 0).unwrap();
+        assert!(max_abs_diff(&full, &windowed) < 1e-4);
+        
+        // Cached keys fall out of the window for single-token steps too
+        model.clear_cache().unwrap();
+        let mut stepped = None;
+        for (position, token) in tokens.iter().enumerate() {
+            stepped = Some(model.forward(&[*token], position).unwrap());
+        }
+        assert!(max_abs_diff(&full, &stepped.unwrap()) < 1e-4);
+    }
+}
@@ -0,0 +1,500 @@
+use crate::models::{InferenceError, MobileNetVariant, PredictContext};
+use candle_core::{Result, Tensor, D};
+use candle_nn::{BatchNorm, Conv2d, Conv2dConfig, Linear, Module, VarBuilder};
+use super::forward_error;
+use Activation::{HardSwish as HS, Relu as RE};
+use BlockKind::{ConvBnAct as CN, DepthwiseSeparable as DS, InvertedResidual as IR};
+
+/// Activation applied after a convolution and batch norm
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Activation {
+    None,
+    Relu,
+    Relu6,
+    HardSwish,
+}
+
+impl Activation {
+    fn apply(self, xs: &Tensor) -> Result<Tensor> {
+        match self {
+            Activation::None => Ok(xs.clone()),
+            Activation::Relu => xs.relu(),
+            Activation::Relu6 => xs.clamp(0f32, 6f32),
+            Activation::HardSwish => xs * hard_sigmoid(xs)?,
+        }
+    }
+}
+
+/// `relu6(x + 3) / 6`, as used by MobileNetV3
+fn hard_sigmoid(xs: &Tensor) -> Result<Tensor> {
+    (xs + 3.0)?.clamp(0f32, 6f32)? / 6.0
+}
+
+/// Round channels to a multiple of `divisor`, never dropping more than 10%
+/// 
+/// Matches `make_divisible` in timm and HF transformers.
+fn make_divisible(value: f64, divisor: usize) -> usize {
+    let rounded = (((value + divisor as f64 / 2.0) as usize) / divisor * divisor).max(divisor);
+    if (rounded as f64) < 0.9 * value {
+        rounded + divisor
+    } else {
+        rounded
+    }
+}
+
+/// Weight naming and numerics of a checkpoint family
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Layout {
+    /// HF transformers (`google/mobilenet_v*`): TF "SAME" padding, BN eps 1e-3
+    Transformers,
+    /// timm: symmetric padding, BN eps 1e-5
+    Timm,
+}
+
+impl Layout {
+    fn eps(self) -> f64 {
+        match self {
+            Layout::Transformers => 1e-3,
+            Layout::Timm => 1e-5,
+        }
+    }
+}
+
+/// Shape of a single convolution
+#[derive(Debug, Clone, Copy)]
+struct ConvSpec {
+    c_in: usize,
+    c_out: usize,
+    kernel: usize,
+    stride: usize,
+    groups: usize,
+    activation: Activation,
+}
+
+impl ConvSpec {
+    fn new(c_in: usize, c_out: usize, kernel: usize, stride: usize, activation: Activation) -> Self {
+        Self { c_in, c_out, kernel, stride, groups: 1, activation }
+    }
+    
+    fn depthwise(channels: usize, kernel: usize, stride: usize, activation: Activation) -> Self {
+        Self { c_in: channels, c_out: channels, kernel, stride, groups: channels, activation }
+    }
+}
+
+/// Convolution without bias, followed by batch norm and an activation
+#[derive(Debug)]
+struct ConvBn {
+    conv: Conv2d,
+    bn: BatchNorm,
+    spec: ConvSpec,
+    tf_padding: bool,
+}
+
+impl ConvBn {
+    fn load(spec: ConvSpec, layout: Layout, conv_vb: VarBuilder, bn_vb: VarBuilder) -> Result<Self> {
+        let tf_padding = layout == Layout::Transformers;
+        let cfg = Conv2dConfig {
+            padding: if tf_padding { 0 } else { spec.kernel / 2 },
+            stride: spec.stride,
+            groups: spec.groups,
+            ..Default::default()
+        };
+        let conv = candle_nn::conv2d_no_bias(spec.c_in, spec.c_out, spec.kernel, cfg, conv_vb)?;
+        let bn = candle_nn::batch_norm(spec.c_out, layout.eps(), bn_vb)?;
+        Ok(Self { conv, bn, spec, tf_padding })
+    }
+    
+    /// Load an HF transformers conv layer (`{prefix}.convolution`, `{prefix}.normalization`)
+    fn load_transformers(spec: ConvSpec, vb: VarBuilder) -> Result<Self> {
+        Self::load(spec, Layout::Transformers, vb.pp("convolution"), vb.pp("normalization"))
+    }
+    
+    /// Load a timm conv layer, where conv and norm have separate names
+    fn load_timm(spec: ConvSpec, vb: &VarBuilder, conv: &str, bn: &str) -> Result<Self> {
+        Self::load(spec, Layout::Timm, vb.pp(conv), vb.pp(bn))
+    }
+    
+    fn forward(&self, xs: &Tensor) -> Result<Tensor> {
+        let xs = if self.tf_padding {
+            tf_same_pad(xs, self.spec.kernel, self.spec.stride)?
+        } else {
+            xs.clone()
+        };
+        let xs = xs.apply(&self.conv)?.apply_t(&self.bn, false)?;
+        self.spec.activation.apply(&xs)
+    }
+}
+
+/// Pad like TensorFlow's "SAME" convolutions, putting any odd pixel at the end
+fn tf_same_pad(xs: &Tensor, kernel: usize, stride: usize) -> Result<Tensor> {
+    let (_, _, height, width) = xs.dims4()?;
+    let total = |size: usize| match size % stride {
+        0 => kernel.saturating_sub(stride),
+        rem => kernel.saturating_sub(rem),
+    };
+    let (pad_h, pad_w) = (total(height), total(width));
+    if pad_h == 0 && pad_w == 0 {
+        return Ok(xs.clone());
+    }
+    xs.pad_with_zeros(D::Minus2, pad_h / 2, pad_h - pad_h / 2)?
+        .pad_with_zeros(D::Minus1, pad_w / 2, pad_w - pad_w / 2)
+}
+
+/// Squeeze-and-excite gate from timm's MobileNetV3
+#[derive(Debug)]
+struct SqueezeExcite {
+    conv_reduce: Conv2d,
+    conv_expand: Conv2d,
+}
+
+impl SqueezeExcite {
+    fn load(channels: usize, vb: VarBuilder) -> Result<Self> {
+        let reduced = make_divisible(channels as f64 * 0.25, 8);
+        let cfg = Conv2dConfig::default();
+        Ok(Self {
+            conv_reduce: candle_nn::conv2d(channels, reduced, 1, cfg, vb.pp("conv_reduce"))?,
+            conv_expand: candle_nn::conv2d(reduced, channels, 1, cfg, vb.pp("conv_expand"))?,
+        })
+    }
+    
+    fn forward(&self, xs: &Tensor) -> Result<Tensor> {
+        let scale = xs.mean_keepdim((2, 3))?
+            .apply(&self.conv_reduce)?
+            .relu()?
+            .apply(&self.conv_expand)?;
+        xs.broadcast_mul(&hard_sigmoid(&scale)?)
+    }
+}
+
+/// A chain of conv layers with an optional squeeze-excite gate and residual
+#[derive(Debug)]
+struct Block {
+    layers: Vec<ConvBn>,
+    /// Squeeze-excite applied after the layer at this index
+    se: Option<(usize, SqueezeExcite)>,
+    residual: bool,
+}
+
+impl Block {
+    /// Layers applied in sequence
+    fn new(layers: Vec<ConvBn>) -> Self {
+        Self { layers, se: None, residual: false }
+    }
+    
+    /// Layers with a skip connection whenever the block keeps the input shape
+    fn residual(layers: Vec<ConvBn>) -> Self {
+        let first = layers.first().map(|l| l.spec);
+        let last = layers.last().map(|l| l.spec);
+        let residual = match (first, last) {
+            (Some(first), Some(last)) => {
+                layers.iter().all(|l| l.spec.stride == 1) && first.c_in == last.c_out
+            }
+            _ => false,
+        };
+        Self { layers, se: None, residual }
+    }
+    
+    fn with_se(mut self, after: usize, se: SqueezeExcite) -> Self {
+        self.se = Some((after, se));
+        self
+    }
+    
+    fn forward(&self, xs: &Tensor) -> Result<Tensor> {
+        let mut ys = xs.clone();
+        for (ix, layer) in self.layers.iter().enumerate() {
+            ys = layer.forward(&ys)?;
+            if let Some((after, se)) = &self.se {
+                if *after == ix {
+                    ys = se.forward(&ys)?;
+                }
+            }
+        }
+        if self.residual {
+            ys = (ys + xs)?;
+        }
+        Ok(ys)
+    }
+}
+
+/// MobileNet network for any `MobileNetVariant`
+/// 
+/// V1 and V2 load HF transformers checkpoints (`google/mobilenet_v1_1.0_224`,
+/// `google/mobilenet_v2_1.0_224`); V3 loads timm checkpoints
+/// (`timm/mobilenetv3_small_100.lamb_in1k`, `timm/mobilenetv3_large_100.ra_in1k`),
+/// since HF transformers has no MobileNetV3.
+#[derive(Debug)]
+pub struct MobileNet {
+    blocks: Vec<Block>,
+    /// 1x1 conv with bias applied after pooling (MobileNetV3 only)
+    head: Option<Conv2d>,
+    classifier: Option<Linear>,
+    num_features: usize,
+}
+
+impl MobileNet {
+    /// Build the network for a variant
+    /// 
+    /// `num_classes` is the classifier width, or `None` to return pooled features.
+    pub fn load(variant: &MobileNetVariant, num_classes: Option<usize>, vb: VarBuilder) -> Result<Self> {
+        let (blocks, head, num_features) = match variant {
+            MobileNetVariant::V1 => (mobilenet_v1_blocks(vb.pp("mobilenet_v1"))?, None, 1024),
+            MobileNetVariant::V2 => (mobilenet_v2_blocks(vb.pp("mobilenet_v2"))?, None, 1280),
+            MobileNetVariant::V3Small => mobilenet_v3(MOBILENET_V3_SMALL, 576, 1024, &vb)?,
+            MobileNetVariant::V3Large => mobilenet_v3(MOBILENET_V3_LARGE, 960, 1280, &vb)?,
+        };
+        let classifier = match num_classes {
+            Some(n) => Some(candle_nn::linear(num_features, n, vb.pp("classifier"))?),
+            None => None,
+        };
+        Ok(Self { blocks, head, classifier, num_features })
+    }
+    
+    /// Width of the pooled feature vector fed to the classifier
+    pub fn num_features(&self) -> usize {
+        self.num_features
+    }
+    
+    /// Run the network, checking for cancellation before each block
+    pub fn forward_with_context(&self, xs: &Tensor, ctx: &PredictContext) -> std::result::Result<Tensor, InferenceError> {
+        let forward_error = |e| forward_error("MobileNet forward pass failed", e);
+        let mut xs = xs.clone();
+        for block in &self.blocks {
+            ctx.check()?;
+            xs = block.forward(&xs).map_err(forward_error)?;
+        }
+        ctx.check()?;
+        self.forward_head(&xs).map_err(forward_error)
+    }
+    
+    /// Pool the last feature map and apply the head and classifier
+    fn forward_head(&self, xs: &Tensor) -> Result<Tensor> {
+        let mut xs = xs.mean_keepdim((2, 3))?;
+        if let Some(head) = &self.head {
+            xs = Activation::HardSwish.apply(&xs.apply(head)?)?;
+        }
+        let xs = xs.flatten_from(1)?;
+        match &self.classifier {
+            Some(classifier) => xs.apply(classifier),
+            None => Ok(xs),
+        }
+    }
+}
+
+impl Module for MobileNet {
+    fn forward(&self, xs: &Tensor) -> Result<Tensor> {
+        let mut xs = xs.clone();
+        for block in &self.blocks {
+            xs = block.forward(&xs)?;
+        }
+        self.forward_head(&xs)
+    }
+}
+
+/// HF transformers MobileNetV1: stem plus 13 depthwise-separable pairs
+fn mobilenet_v1_blocks(vb: VarBuilder) -> Result<Vec<Block>> {
+    const STRIDES: [usize; 13] = [1, 2, 1, 2, 1, 2, 1, 1, 1, 1, 1, 2, 1];
+    const CHANNELS: [usize; 13] = [64, 128, 128, 256, 256, 512, 512, 512, 512, 512, 512, 1024, 1024];
+    
+    let act = Activation::Relu6;
+    let stem = ConvBn::load_transformers(ConvSpec::new(3, 32, 3, 2, act), vb.pp("conv_stem"))?;
+    let mut blocks = vec![Block::new(vec![stem])];
+    
+    let mut c_in = 32;
+    for (ix, (&stride, &c_out)) in STRIDES.iter().zip(CHANNELS.iter()).enumerate() {
+        let depthwise = ConvBn::load_transformers(ConvSpec::depthwise(c_in, 3, stride, act), vb.pp(format!("layer.{}", 2 * ix)))?;
+        let pointwise = ConvBn::load_transformers(ConvSpec::new(c_in, c_out, 1, 1, act), vb.pp(format!("layer.{}", 2 * ix + 1)))?;
+        blocks.push(Block::new(vec![depthwise]));
+        blocks.push(Block::new(vec![pointwise]));
+        c_in = c_out;
+    }
+    Ok(blocks)
+}
+
+/// HF transformers MobileNetV2: stem, 16 inverted residuals and a 1x1 head conv
+fn mobilenet_v2_blocks(vb: VarBuilder) -> Result<Vec<Block>> {
+    const STRIDES: [usize; 16] = [2, 1, 2, 1, 1, 2, 1, 1, 1, 1, 1, 1, 2, 1, 1, 1];
+    const CHANNELS: [usize; 17] = [16, 24, 24, 32, 32, 32, 64, 64, 64, 64, 96, 96, 96, 160, 160, 160, 320];
+    
+    let act = Activation::Relu6;
+    let stem_vb = vb.pp("conv_stem");
+    let stem = vec![
+        ConvBn::load_transformers(ConvSpec::new(3, 32, 3, 2, act), stem_vb.pp("first_conv"))?,
+        ConvBn::load_transformers(ConvSpec::depthwise(32, 3, 1, act), stem_vb.pp("conv_3x3"))?,
+        ConvBn::load_transformers(ConvSpec::new(32, 16, 1, 1, Activation::None), stem_vb.pp("reduce_1x1"))?,
+    ];
+    let mut blocks = vec![Block::new(stem)];
+    
+    for (ix, &stride) in STRIDES.iter().enumerate() {
+        let (c_in, c_out) = (CHANNELS[ix], CHANNELS[ix + 1]);
+        let expanded = make_divisible(c_in as f64 * 6.0, 8);
+        let layer_vb = vb.pp(format!("layer.{}", ix));
+        blocks.push(Block::residual(vec![
+            ConvBn::load_transformers(ConvSpec::new(c_in, expanded, 1, 1, act), layer_vb.pp("expand_1x1"))?,
+            ConvBn::load_transformers(ConvSpec::depthwise(expanded, 3, stride, act), layer_vb.pp("conv_3x3"))?,
+            ConvBn::load_transformers(ConvSpec::new(expanded, c_out, 1, 1, Activation::None), layer_vb.pp("reduce_1x1"))?,
+        ]));
+    }
+    
+    let head = ConvBn::load_transformers(ConvSpec::new(320, 1280, 1, 1, act), vb.pp("conv_1x1"))?;
+    blocks.push(Block::new(vec![head]));
+    Ok(blocks)
+}
+
+/// Block types used in timm's MobileNetV3 architecture strings
+#[derive(Debug, Clone, Copy)]
+enum BlockKind {
+    /// `ds`: depthwise conv, optional SE, pointwise conv
+    DepthwiseSeparable,
+    /// `ir`: pointwise expansion, depthwise conv, optional SE, pointwise projection
+    InvertedResidual,
+    /// `cn`: plain conv, batch norm and activation
+    ConvBnAct,
+}
+
+/// (kind, repeats, kernel, stride, expansion, out channels, squeeze-excite, activation)
+type BlockDef = (BlockKind, usize, usize, usize, f64, usize, bool, Activation);
+
+/// timm `mobilenetv3_small_100`, one inner slice per stage
+const MOBILENET_V3_SMALL: &[&[BlockDef]] = &[
+    &[(DS, 1, 3, 2, 1.0, 16, true, RE)],
+    &[(IR, 1, 3, 2, 4.5, 24, false, RE), (IR, 1, 3, 1, 3.67, 24, false, RE)],
+    &[(IR, 1, 5, 2, 4.0, 40, true, HS), (IR, 2, 5, 1, 6.0, 40, true, HS)],
+    &[(IR, 2, 5, 1, 3.0, 48, true, HS)],
+    &[(IR, 3, 5, 2, 6.0, 96, true, HS)],
+    &[(CN, 1, 1, 1, 1.0, 576, false, HS)],
+];
+
+/// timm `mobilenetv3_large_100`, one inner slice per stage
+const MOBILENET_V3_LARGE: &[&[BlockDef]] = &[
+    &[(DS, 1, 3, 1, 1.0, 16, false, RE)],
+    &[(IR, 1, 3, 2, 4.0, 24, false, RE), (IR, 1, 3, 1, 3.0, 24, false, RE)],
+    &[(IR, 3, 5, 2, 3.0, 40, true, RE)],
+    &[(IR, 1, 3, 2, 6.0, 80, false, HS), (IR, 1, 3, 1, 2.5, 80, false, HS), (IR, 2, 3, 1, 2.3, 80, false, HS)],
+    &[(IR, 2, 3, 1, 6.0, 112, true, HS)],
+    &[(IR, 3, 5, 2, 6.0, 160, true, HS)],
+    &[(CN, 1, 1, 1, 1.0, 960, false, HS)],
+];
+
+/// timm MobileNetV3: stem, staged blocks and a pooled 1x1 head conv
+fn mobilenet_v3(
+    stages: &[&[BlockDef]],
+    head_in: usize,
+    num_features: usize,
+    vb: &VarBuilder,
+) -> Result<(Vec<Block>, Option<Conv2d>, usize)> {
+    let stem = ConvBn::load_timm(ConvSpec::new(3, 16, 3, 2, Activation::HardSwish), vb, "conv_stem", "bn1")?;
+    let mut blocks = vec![Block::new(vec![stem])];
+    
+    let mut c_in = 16;
+    for (stage_ix, stage) in stages.iter().enumerate() {
+        let mut block_ix = 0;
+        for &(kind, repeats, kernel, stride, expansion, c_out, se, act) in stage.iter() {
+            for repeat in 0..repeats {
+                let stride = if repeat == 0 { stride } else { 1 };
+                let block_vb = vb.pp(format!("blocks.{}.{}", stage_ix, block_ix));
+                blocks.push(mobilenet_v3_block(kind, c_in, c_out, kernel, stride, expansion, se, act, block_vb)?);
+                c_in = c_out;
+                block_ix += 1;
+            }
+        }
+    }
+    
+    let head = candle_nn::conv2d(head_in, num_features, 1, Conv2dConfig::default(), vb.pp("conv_head"))?;
+    Ok((blocks, Some(head), num_features))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn mobilenet_v3_block(
+    kind: BlockKind,
+    c_in: usize,
+    c_out: usize,
+    kernel: usize,
+    stride: usize,
+    expansion: f64,
+    se: bool,
+    act: Activation,
+    vb: VarBuilder,
+) -> Result<Block> {
+    match kind {
+        BlockKind::DepthwiseSeparable => {
+            let block = Block::residual(vec![
+                ConvBn::load_timm(ConvSpec::depthwise(c_in, kernel, stride, act), &vb, "conv_dw", "bn1")?,
+                ConvBn::load_timm(ConvSpec::new(c_in, c_out, 1, 1, Activation::None), &vb, "conv_pw", "bn2")?,
+            ]);
+            Ok(if se { block.with_se(0, SqueezeExcite::load(c_in, vb.pp("se"))?) } else { block })
+        }
+        BlockKind::InvertedResidual => {
+            let mid = make_divisible(c_in as f64 * expansion, 8);
+            let block = Block::residual(vec![
+                ConvBn::load_timm(ConvSpec::new(c_in, mid, 1, 1, act), &vb, "conv_pw", "bn1")?,
+                ConvBn::load_timm(ConvSpec::depthwise(mid, kernel, stride, act), &vb, "conv_dw", "bn2")?,
+                ConvBn::load_timm(ConvSpec::new(mid, c_out, 1, 1, Activation::None), &vb, "conv_pwl", "bn3")?,
+            ]);
+            Ok(if se { block.with_se(1, SqueezeExcite::load(mid, vb.pp("se"))?) } else { block })
+        }
+        BlockKind::ConvBnAct => Ok(Block::new(vec![
+            ConvBn::load_timm(ConvSpec::new(c_in, c_out, kernel, stride, act), &vb, "conv", "bn1")?,
+        ])),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_core::{DType, Device};
+    
+    fn random_mobilenet(variant: &MobileNetVariant, num_classes: Option<usize>) -> (MobileNet, candle_nn::VarMap) {
+        let varmap = candle_nn::VarMap::new();
+        let vb = VarBuilder::from_varmap(&varmap, DType::F32, &Device::Cpu);
+        let model = MobileNet::load(variant, num_classes, vb).unwrap();
+        (model, varmap)
+    }
+    
+    #[test]
+    fn test_make_divisible() {
+        assert_eq!(make_divisible(24.0 * 3.67, 8), 88);
+        assert_eq!(make_divisible(80.0 * 2.3, 8), 184);
+        assert_eq!(make_divisible(72.0 * 0.25, 8), 24);
+        assert_eq!(make_divisible(16.0 * 0.25, 8), 8);
+        assert_eq!(make_divisible(144.0 * 0.25, 8), 40);
+    }
+    
+    #[test]
+    fn test_tf_same_padding() {
+        let xs = Tensor::zeros((1, 1, 7, 8), DType::F32, &Device::Cpu).unwrap();
+        // Odd size with stride 2 pads one pixel each side, even size only at the end
+        assert_eq!(tf_same_pad(&xs, 3, 2).unwrap().dims(), &[1, 1, 9, 9]);
+        assert_eq!(tf_same_pad(&xs, 1, 1).unwrap().dims(), &[1, 1, 7, 8]);
+    }
+    
+    #[test]
+    fn test_all_variants_forward() {
+        let xs = Tensor::rand(0f32, 1f32, (2, 3, 64, 64), &Device::Cpu).unwrap();
+        for (variant, features, params) in [
+            (MobileNetVariant::V1, 1024, 3_206_976),
+            (MobileNetVariant::V2, 1280, 2_223_872),
+            (MobileNetVariant::V3Small, 1024, 1_517_856),
+            (MobileNetVariant::V3Large, 1280, 4_202_032),
+        ] {
+            let (model, varmap) = random_mobilenet(&variant, Some(1000));
+            assert_eq!(model.num_features(), features);
+            
+            // Trainable parameter counts match the reference implementations
+            let trainable: usize = varmap.data().lock().unwrap().iter()
+                .filter(|(name, _)| !name.contains("running_"))
+                .map(|(_, var)| var.elem_count())
+                .sum();
+            assert_eq!(trainable, params + 1000 * (features + 1), "{:?}", variant);
+            
+            let logits = model.forward(&xs).unwrap();
+            assert_eq!(logits.dims(), &[2, 1000], "{:?}", variant);
+        }
+    }
+    
+    #[test]
+    fn test_features_without_classifier() {
+        let (model, _) = random_mobilenet(&MobileNetVariant::V3Small, None);
+        let xs = Tensor::rand(0f32, 1f32, (1, 3, 32, 32), &Device::Cpu).unwrap();
+        assert_eq!(model.forward(&xs).unwrap().dims(), &[1, 1024]);
+    }
+}
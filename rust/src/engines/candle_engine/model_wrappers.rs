@@ -1,48 +1,197 @@
 use crate::engines::{Model, EngineType};
-use crate::models::{InferenceError, Tensor, TensorSpec, DataType, ResNetVariant};
+use crate::models::{InferenceError, Tensor, TensorSpec, DataType, ModelArchitecture, ModelConfig, MobileNetVariant, ResNetVariant, PredictContext};
 use async_trait::async_trait;
 use std::any::Any;
-use std::path::PathBuf;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 #[cfg(feature = "candle")]
-use candle_core::Device;
+use candle_core::{Device, DType, Tensor as CandleTensor};
+#[cfg(feature = "candle")]
+use candle_nn::{Embedding, LayerNorm, Linear, Module, VarBuilder};
+#[cfg(feature = "candle")]
+use candle_transformers::models::bert::{BertEncoder, Config as BertConfig, DTYPE as BERT_DTYPE};
+#[cfg(feature = "candle")]
+use super::forward_error;
+use super::mobilenet::MobileNet;
+#[cfg(feature = "candle")]
+use super::resnet::ResNet;
+#[cfg(feature = "candle")]
+use super::sharding::{download_safetensors, is_index_filename, load_tensors};
+
+/// Outputs of a BERT forward pass
+#[derive(Debug, Clone)]
+pub struct BertOutput {
+    /// Hidden states of the last encoder layer, shape `[batch, seq_len, hidden_size]`
+    pub last_hidden_state: Tensor,
+    /// Pooled `[CLS]` representation, shape `[batch, hidden_size]`
+    pub pooled_output: Tensor,
+}
+
+/// BERT input embeddings, matching HuggingFace's `BertEmbeddings` in eval mode
+#[cfg(feature = "candle")]
+struct BertEmbeddings {
+    word_embeddings: Embedding,
+    position_embeddings: Embedding,
+    token_type_embeddings: Embedding,
+    layer_norm: LayerNorm,
+}
+
+#[cfg(feature = "candle")]
+impl BertEmbeddings {
+    fn load(vb: VarBuilder, config: &BertConfig) -> candle_core::Result<Self> {
+        Ok(Self {
+            word_embeddings: candle_nn::embedding(config.vocab_size, config.hidden_size, vb.pp("word_embeddings"))?,
+            position_embeddings: candle_nn::embedding(config.max_position_embeddings, config.hidden_size, vb.pp("position_embeddings"))?,
+            token_type_embeddings: candle_nn::embedding(config.type_vocab_size, config.hidden_size, vb.pp("token_type_embeddings"))?,
+            layer_norm: candle_nn::layer_norm(config.hidden_size, config.layer_norm_eps, vb.pp("LayerNorm"))?,
+        })
+    }
+    
+    fn forward(&self, input_ids: &CandleTensor, token_type_ids: &CandleTensor) -> candle_core::Result<CandleTensor> {
+        let seq_len = input_ids.dim(1)?;
+        let position_ids = CandleTensor::arange(0u32, seq_len as u32, input_ids.device())?;
+        let embeddings = (self.word_embeddings.forward(input_ids)? + self.token_type_embeddings.forward(token_type_ids)?)?
+            .broadcast_add(&self.position_embeddings.forward(&position_ids)?)?;
+        self.layer_norm.forward(&embeddings)
+    }
+}
+
+/// Turn a `[batch, seq_len]` 0/1 mask into an additive `[batch, 1, 1, seq_len]` attention bias
+#[cfg(feature = "candle")]
+fn extended_attention_mask(attention_mask: &CandleTensor) -> candle_core::Result<CandleTensor> {
+    let mask = attention_mask.unsqueeze(1)?.unsqueeze(1)?.to_dtype(DType::F32)?;
+    (mask.ones_like()? - &mask)? * f32::MIN as f64
+}
 
 /// Real BERT model wrapper using candle-transformers
+/// 
+/// Builds the BERT encoder layers from a HuggingFace `config.json` and
+/// SafeTensors weights and runs them one at a time. The pooler
+/// (`pooler.dense`) is loaded when the checkpoint has one; otherwise the
+/// pooled output is the raw `[CLS]` hidden state.
 #[cfg(feature = "candle")]
-#[derive(Debug)]
 pub struct BertModelWrapper {
     device: Device,
+    config: BertConfig,
+    embeddings: BertEmbeddings,
+    /// One single-layer encoder per BERT layer, since candle keeps
+    /// `BertLayer::forward` private
+    layers: Vec<BertEncoder>,
+    pooler: Option<Linear>,
     input_specs: Vec<TensorSpec>,
     output_specs: Vec<TensorSpec>,
 }
 
+#[cfg(feature = "candle")]
+impl std::fmt::Debug for BertModelWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BertModelWrapper")
+            .field("device", &self.device)
+            .field("config", &self.config)
+            .field("num_layers", &self.layers.len())
+            .field("has_pooler", &self.pooler.is_some())
+            .finish()
+    }
+}
+
 #[cfg(feature = "candle")]
 impl BertModelWrapper {
-    /// Create a new BERT model wrapper
-    pub fn new(device: Device) -> Self {
+    /// Build a BERT model from a config and a variable builder over its weights
+    /// 
+    /// Weights may be stored at the root or under the `model_type` prefix
+    /// (e.g. `bert.encoder...`), as in HuggingFace checkpoints with task heads.
+    pub fn new(vb: VarBuilder, config: BertConfig, device: Device) -> Result<Self, InferenceError> {
+        let load = |vb: VarBuilder| -> candle_core::Result<_> {
+            Ok((BertEmbeddings::load(vb.pp("embeddings"), &config)?, BertEncoder::load(vb.pp("encoder"), &config)?))
+        };
+        let (embeddings, encoder) = match (load(vb.clone()), &config.model_type) {
+            (Ok(parts), _) => Ok(parts),
+            (Err(e), Some(model_type)) => load(vb.pp(model_type)).map_err(|_| e),
+            (Err(e), None) => Err(e),
+        }
+        .map_err(|e| InferenceError::model_load(format!("Failed to build BERT model: {}", e)))?;
+        let layers = encoder.layers.iter()
+            .map(|layer| {
+                let mut single = encoder.clone();
+                single.layers = vec![layer.clone()];
+                single
+            })
+            .collect();
+        
+        let pooler_prefix = match &config.model_type {
+            _ if vb.contains_tensor("pooler.dense.weight") => Some("pooler.dense".to_string()),
+            Some(model_type) if vb.contains_tensor(&format!("{}.pooler.dense.weight", model_type)) => {
+                Some(format!("{}.pooler.dense", model_type))
+            }
+            _ => None,
+        };
+        let pooler = match pooler_prefix {
+            Some(prefix) => Some(
+                candle_nn::linear(config.hidden_size, config.hidden_size, vb.pp(prefix))
+                    .map_err(|e| InferenceError::model_load(format!("Failed to load BERT pooler: {}", e)))?
+            ),
+            None => None,
+        };
+        
         // Define input specs for BERT (input_ids, attention_mask, token_type_ids)
-        let input_specs = vec![
-            TensorSpec::new(
-                "input_ids".to_string(),
-                vec![None, Some(512)], // batch_size, sequence_length
+        let input_specs = ["input_ids", "attention_mask", "token_type_ids"]
+            .iter()
+            .map(|name| TensorSpec::new(
+                name.to_string(),
+                vec![None, None], // batch_size, sequence_length
                 DataType::I64,
-            ),
-        ];
+            ))
+            .collect();
         
         // Define output specs for BERT (last_hidden_state, pooler_output)
         let output_specs = vec![
             TensorSpec::new(
-                "embeddings".to_string(),
-                vec![None, Some(768)], // batch_size, hidden_size
+                "last_hidden_state".to_string(),
+                vec![None, None, Some(config.hidden_size)], // batch_size, sequence_length, hidden_size
+                DataType::F32,
+            ),
+            TensorSpec::new(
+                "pooled_output".to_string(),
+                vec![None, Some(config.hidden_size)], // batch_size, hidden_size
                 DataType::F32,
             ),
         ];
         
-        Self {
+        Ok(Self {
             device,
+            config,
+            embeddings,
+            layers,
+            pooler,
             input_specs,
             output_specs,
-        }
+        })
+    }
+    
+    /// Read a HuggingFace BERT `config.json`
+    fn read_config(config_path: &Path) -> Result<BertConfig, InferenceError> {
+        let config_json = std::fs::read_to_string(config_path)
+            .map_err(|e| InferenceError::model_load(format!("Failed to read BERT config {}: {}", config_path.display(), e)))?;
+        serde_json::from_str(&config_json)
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse BERT config: {}", e)))
+    }
+    
+    /// Load a BERT model from a `config.json` and a SafeTensors weights file
+    pub fn load(config_path: &Path, weights_path: &Path, device: &Device) -> Result<Self, InferenceError> {
+        Self::load_sharded(config_path, &[weights_path.to_path_buf()], device)
+    }
+    
+    /// Load a BERT model whose weights are split across several SafeTensors files
+    pub fn load_sharded(config_path: &Path, weight_paths: &[PathBuf], device: &Device) -> Result<Self, InferenceError> {
+        let config = Self::read_config(config_path)?;
+        
+        // SAFETY: the weight files are memory-mapped read-only and must not be
+        // modified while the model is alive, the same contract as candle's examples
+        let vb = unsafe { VarBuilder::from_mmaped_safetensors(weight_paths, BERT_DTYPE, device) }
+            .map_err(|e| InferenceError::model_load(format!("Failed to map BERT weights: {}", e)))?;
+        
+        Self::new(vb, config, device.clone())
     }
     
     /// Load BERT model from HuggingFace repository with real model downloading
@@ -55,16 +204,14 @@ impl BertModelWrapper {
         {
             println!("🤗 Loading BERT model from HuggingFace: {}", repo_id);
             
-            // Use hf-hub to download model
+            // Use hf-hub to download the config and weights
             let filename = filename.unwrap_or("model.safetensors");
-            let model_path = Self::download_from_huggingface(repo_id, filename).await?;
+            let config_path = download_from_huggingface(repo_id, "config.json").await?;
+            let weight_paths = download_safetensors(repo_id, filename, &mut |_| {}).await?;
             
             // Load the downloaded model
-            println!("📂 Loading model from: {}", model_path.display());
-            
-            // For now, create a wrapper that knows it was loaded from HuggingFace
-            let mut wrapper = Self::new(device.clone());
-            wrapper.input_specs[0].name = format!("hf_{}_{}", repo_id.replace('/', "_"), filename);
+            println!("📂 Loading model from {} file(s)", weight_paths.len());
+            let wrapper = Self::load_sharded(&config_path, &weight_paths, device)?;
             
             println!("✅ Successfully loaded BERT model from HuggingFace");
             Ok(wrapper)
@@ -77,76 +224,128 @@ impl BertModelWrapper {
         }
     }
     
-    /// Download model from HuggingFace Hub using hf-hub crate
-    async fn download_from_huggingface(repo_id: &str, filename: &str) -> Result<PathBuf, InferenceError> {
-        #[cfg(feature = "candle")]
-        {
-            use hf_hub::api::tokio::Api;
-            
-            println!("🔄 Attempting to download {} from {} using hf-hub", filename, repo_id);
-            
-            // Create HuggingFace API client
-            let api = Api::new()
-                .map_err(|e| InferenceError::model_load(format!("Failed to create HF API client: {}", e)))?;
-            
-            // Get the repository
-            let repo = api.model(repo_id.to_string());
-            
-            // Download the specific file
-            let local_path = repo.get(filename).await
-                .map_err(|e| {
-                    let error_msg = format!("Failed to download {} from {}: {}", filename, repo_id, e);
-                    println!("❌ HuggingFace Hub download failed: {}", error_msg);
-                    InferenceError::model_load(error_msg)
-                })?;
-            
-            println!("✅ Successfully downloaded to: {}", local_path.display());
-            Ok(local_path)
+    /// Get the BERT configuration
+    pub fn config(&self) -> &BertConfig {
+        &self.config
+    }
+    
+    /// Run the encoder and return both the hidden states and the pooled output
+    /// 
+    /// `attention_mask` defaults to all ones and `token_type_ids` to all zeros.
+    /// All inputs are token-level integer tensors of shape `[batch, seq_len]`
+    /// (or `[seq_len]` for a single sequence).
+    pub fn encode(
+        &self,
+        input_ids: &Tensor,
+        attention_mask: Option<&Tensor>,
+        token_type_ids: Option<&Tensor>,
+    ) -> Result<BertOutput, InferenceError> {
+        self.encode_with_context(input_ids, attention_mask, token_type_ids, &PredictContext::new())
+    }
+    
+    /// Run the encoder, checking for cancellation before each layer
+    pub fn encode_with_context(
+        &self,
+        input_ids: &Tensor,
+        attention_mask: Option<&Tensor>,
+        token_type_ids: Option<&Tensor>,
+        ctx: &PredictContext,
+    ) -> Result<BertOutput, InferenceError> {
+        let input_ids = self.ids_to_candle(input_ids, "input_ids")?;
+        let attention_mask = attention_mask
+            .map(|mask| self.ids_to_candle(mask, "attention_mask"))
+            .transpose()?;
+        let token_type_ids = match token_type_ids {
+            Some(ids) => self.ids_to_candle(ids, "token_type_ids")?,
+            None => input_ids.zeros_like()
+                .map_err(|e| InferenceError::prediction(format!("Failed to create token type ids: {}", e)))?,
+        };
+        
+        for (name, tensor) in [("attention_mask", attention_mask.as_ref()), ("token_type_ids", Some(&token_type_ids))] {
+            if let Some(tensor) = tensor {
+                if tensor.dims() != input_ids.dims() {
+                    return Err(InferenceError::invalid_shape_msg(format!(
+                        "BERT {} shape {:?} does not match input_ids shape {:?}",
+                        name, tensor.dims(), input_ids.dims()
+                    )));
+                }
+            }
         }
-        #[cfg(not(feature = "candle"))]
-        {
-            Err(InferenceError::configuration(
-                "Candle feature not enabled - cannot download HuggingFace models"
-            ))
+        
+        let forward_error = |e| forward_error("BERT forward pass failed", e);
+        let attention_mask = match attention_mask {
+            Some(mask) => mask,
+            None => input_ids.ones_like().map_err(forward_error)?,
+        };
+        let attention_mask = extended_attention_mask(&attention_mask).map_err(forward_error)?;
+        let mut hidden = self.embeddings.forward(&input_ids, &token_type_ids).map_err(forward_error)?;
+        for layer in &self.layers {
+            ctx.check()?;
+            hidden = layer.forward(&hidden, &attention_mask).map_err(forward_error)?;
         }
+        ctx.check()?;
+        
+        // Pool the [CLS] token, matching HuggingFace's BertPooler
+        let pooled = hidden.narrow(1, 0, 1)
+            .and_then(|cls| cls.squeeze(1))
+            .and_then(|cls| match &self.pooler {
+                Some(pooler) => pooler.forward(&cls)?.tanh(),
+                None => Ok(cls),
+            })
+            .map_err(|e| InferenceError::prediction(format!("BERT pooling failed: {}", e)))?;
+        
+        Ok(BertOutput {
+            last_hidden_state: Self::candle_to_tensor(&hidden)?,
+            pooled_output: Self::candle_to_tensor(&pooled)?,
+        })
     }
     
-
+    /// Convert integer token-level input to a `[batch, seq_len]` Candle tensor
+    fn ids_to_candle(&self, tensor: &Tensor, name: &str) -> Result<CandleTensor, InferenceError> {
+        let (batch, seq_len) = match tensor.shape() {
+            [seq_len] => (1, *seq_len),
+            [batch, seq_len] => (*batch, *seq_len),
+            shape => return Err(InferenceError::invalid_shape_msg(
+                format!("Expected BERT {} of shape [seq] or [batch, seq], got {:?}", name, shape)
+            )),
+        };
+        
+        let ids = tensor.to_i64_vec()?
+            .into_iter()
+            .map(|id| u32::try_from(id).map_err(|_| InferenceError::invalid_tensor_data(
+                format!("BERT {} contains {}, which is not a valid token index", name, id)
+            )))
+            .collect::<Result<Vec<u32>, _>>()?;
+        
+        CandleTensor::from_vec(ids, (batch, seq_len), &self.device)
+            .map_err(|e| InferenceError::prediction(format!("Failed to create {} tensor: {}", name, e)))
+    }
+    
+    /// Convert a Candle output tensor to an F32 Tensor
+    fn candle_to_tensor(tensor: &CandleTensor) -> Result<Tensor, InferenceError> {
+        let shape = tensor.dims().to_vec();
+        let data: Vec<f32> = tensor.to_dtype(DType::F32)
+            .and_then(|t| t.flatten_all())
+            .and_then(|t| t.to_vec1())
+            .map_err(|e| InferenceError::prediction(format!("Failed to extract BERT output: {}", e)))?;
+        Tensor::from_f32(data, shape)
+    }
 }
 
 #[cfg(feature = "candle")]
 #[async_trait]
 impl Model for BertModelWrapper {
+    /// Run BERT on `input_ids` and return `last_hidden_state`
+    /// 
+    /// Use `predict_named` to pass an attention mask or token type ids, or
+    /// to get the pooled output.
     async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
-        // Simplified BERT-like inference - just do a basic transformation
-        println!("🔥 Running BERT-like inference (simplified version)");
-        
-        let input_data = input.as_f32_slice()
-            .ok_or_else(|| InferenceError::invalid_shape_msg("BERT input must be f32 data"))?;
-        
-        let shape = input.shape();
-        
-        // Create a simple "embedding" output by averaging the input
-        let output_size = 768; // BERT embedding size
-        let batch_size = if shape.is_empty() { 1 } else { shape[0] as usize };
-        
-        let mut output_data = vec![0.0f32; batch_size * output_size];
-        
-        // Simple transformation: create embeddings by repeating and scaling input
-        for i in 0..batch_size {
-            for j in 0..output_size {
-                let input_idx = if input_data.len() > j { j } else { j % input_data.len() };
-                output_data[i * output_size + j] = input_data[input_idx] * 0.1; // Scale down
-            }
-        }
-        
-        // Convert to bytes
-        let bytes: Vec<u8> = output_data.iter()
-            .flat_map(|&x| x.to_le_bytes().to_vec())
-            .collect();
-        
-        Tensor::new(bytes, vec![batch_size, output_size], DataType::F32)
-            .map_err(|e| InferenceError::prediction(format!("Failed to create output tensor: {}", e)))
+        self.predict_with_context(input, &PredictContext::new()).await
+    }
+    
+    /// Checks the context between encoder layers
+    async fn predict_with_context(&self, input: &Tensor, ctx: &PredictContext) -> Result<Tensor, InferenceError> {
+        Ok(self.encode_with_context(input, None, None, ctx)?.last_hidden_state)
     }
     
     async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
@@ -157,6 +356,32 @@ impl Model for BertModelWrapper {
         Ok(results)
     }
     
+    /// Run BERT on `input_ids` with optional `attention_mask` and `token_type_ids`
+    /// 
+    /// Returns `last_hidden_state` and `pooled_output`.
+    async fn predict_named(&self, inputs: &[(String, Tensor)]) -> Result<Vec<(String, Tensor)>, InferenceError> {
+        let mut input_ids = None;
+        let mut attention_mask = None;
+        let mut token_type_ids = None;
+        for (name, tensor) in inputs {
+            let slot = match name.as_str() {
+                "input_ids" => &mut input_ids,
+                "attention_mask" => &mut attention_mask,
+                "token_type_ids" => &mut token_type_ids,
+                _ => return Err(InferenceError::invalid_tensor_data(format!("BERT has no input named '{}'", name))),
+            };
+            *slot = Some(tensor);
+        }
+        let input_ids = input_ids
+            .ok_or_else(|| InferenceError::invalid_tensor_data("BERT requires an 'input_ids' input"))?;
+        
+        let output = self.encode(input_ids, attention_mask, token_type_ids)?;
+        Ok(vec![
+            (self.output_specs[0].name.clone(), output.last_hidden_state),
+            (self.output_specs[1].name.clone(), output.pooled_output),
+        ])
+    }
+    
     fn input_specs(&self) -> &[TensorSpec] {
         &self.input_specs
     }
@@ -175,19 +400,50 @@ impl Model for BertModelWrapper {
 }
 
 /// Real ResNet model wrapper using candle-transformers
+/// 
+/// Builds the ResNet network for the requested variant and runs it in
+/// inference mode, one residual block at a time.
+/// Weights may use the timm/torchvision layout (`conv1`, `layer1.0.conv1`,
+/// `fc`) or the HF transformers layout (`resnet.embedder`, `resnet.encoder.stages`,
+/// `classifier.1`); the latter is renamed to the former on load.
 #[cfg(feature = "candle")]
 #[derive(Debug)]
 pub struct ResNetModelWrapper {
     device: Device,
     variant: ResNetVariant,
+    model: ResNet,
     input_specs: Vec<TensorSpec>,
     output_specs: Vec<TensorSpec>,
 }
 
 #[cfg(feature = "candle")]
 impl ResNetModelWrapper {
-    /// Create a new ResNet model wrapper
-    pub fn new(device: Device, variant: ResNetVariant) -> Self {
+    /// Build a ResNet model from a state dict in timm or HF transformers layout
+    /// 
+    /// The number of classes is read from the classifier weights. Checkpoints
+    /// without a classifier produce pooled features instead of logits.
+    pub fn new(
+        tensors: HashMap<String, CandleTensor>,
+        variant: ResNetVariant,
+        device: Device,
+    ) -> Result<Self, InferenceError> {
+        let mut weights = HashMap::with_capacity(tensors.len());
+        for (name, tensor) in tensors {
+            let name = resnet_timm_key(&name).unwrap_or(name);
+            weights.insert(name, super::pytorch::upcast_half(tensor)?);
+        }
+        
+        let num_classes = match weights.get("fc.weight") {
+            Some(fc) => Some(fc.dims2()
+                .map_err(|e| InferenceError::model_load(format!("Invalid ResNet classifier weights: {}", e)))?
+                .0),
+            None => None,
+        };
+        
+        let vb = VarBuilder::from_tensors(weights, DType::F32, &device);
+        let model = ResNet::load(&variant, num_classes, vb)
+            .map_err(|e| InferenceError::model_load(format!("Failed to build {:?} from weights: {}", variant, e)))?;
+        
         // Define input specs for ResNet (images)
         let input_specs = vec![
             TensorSpec::new(
@@ -197,30 +453,50 @@ impl ResNetModelWrapper {
             ),
         ];
         
-        // Define output specs for ResNet (classification logits)
+        // Define output specs for ResNet (classification logits or pooled features)
         let output_specs = vec![
-            TensorSpec::new(
-                "logits".to_string(),
-                vec![None, Some(1000)], // batch_size, num_classes
-                DataType::F32,
-            ),
+            match num_classes {
+                Some(n) => TensorSpec::new(
+                    "logits".to_string(),
+                    vec![None, Some(n)], // batch_size, num_classes
+                    DataType::F32,
+                ),
+                None => TensorSpec::new(
+                    "features".to_string(),
+                    vec![None, Some(Self::feature_size(&variant))], // batch_size, feature_size
+                    DataType::F32,
+                ),
+            },
         ];
         
-        Self {
+        Ok(Self {
             device,
             variant,
+            model,
             input_specs,
             output_specs,
-        }
+        })
+    }
+    
+    /// Load a ResNet model from a SafeTensors weights file
+    pub fn load(weights_path: &Path, variant: ResNetVariant, device: &Device) -> Result<Self, InferenceError> {
+        Self::load_sharded(&[weights_path.to_path_buf()], variant, device)
+    }
+    
+    /// Load a ResNet model whose weights are split across several SafeTensors files
+    pub fn load_sharded(weight_paths: &[PathBuf], variant: ResNetVariant, device: &Device) -> Result<Self, InferenceError> {
+        Self::new(load_tensors(weight_paths, device)?, variant, device.clone())
     }
     
-    /// Load ResNet model from HuggingFace or create pretrained version
+    /// Load pretrained ImageNet weights for a variant from its default HuggingFace repository
     pub async fn load_pretrained(
         device: &Device,
         variant: ResNetVariant,
     ) -> Result<Self, InferenceError> {
-        println!("📝 Creating ResNet model wrapper ({:?})", variant);
-        Ok(Self::new(device.clone(), variant))
+        let config = ModelConfig::new(ModelArchitecture::ResNet { variant: variant.clone() });
+        let repo_id = config.default_repo_id()
+            .ok_or_else(|| InferenceError::model_load(format!("No pretrained repository known for {:?}", variant)))?;
+        Self::load_from_huggingface(device, repo_id, Some(config.default_filename()), variant).await
     }
     
     /// Load ResNet model from HuggingFace repository
@@ -236,14 +512,11 @@ impl ResNetModelWrapper {
             
             // Use hf-hub to download model
             let filename = filename.unwrap_or("model.safetensors");
-            let model_path = Self::download_from_huggingface(repo_id, filename).await?;
+            let weight_paths = download_safetensors(repo_id, filename, &mut |_| {}).await?;
             
             // Load the downloaded model
-            println!("📂 Loading ResNet model from: {}", model_path.display());
-            
-            // Create a wrapper that knows it was loaded from HuggingFace
-            let mut wrapper = Self::new(device.clone(), variant);
-            wrapper.input_specs[0].name = format!("hf_{}_{}", repo_id.replace('/', "_"), filename);
+            println!("📂 Loading ResNet model from {} file(s)", weight_paths.len());
+            let wrapper = Self::load_sharded(&weight_paths, variant, device)?;
             
             println!("✅ Successfully loaded ResNet model from HuggingFace");
             Ok(wrapper)
@@ -256,76 +529,275 @@ impl ResNetModelWrapper {
         }
     }
     
-    /// Download model from HuggingFace Hub using hf-hub crate
-    async fn download_from_huggingface(repo_id: &str, filename: &str) -> Result<PathBuf, InferenceError> {
-        #[cfg(feature = "candle")]
-        {
-            use hf_hub::api::tokio::Api;
-            
-            println!("🔄 Attempting to download {} from {} using hf-hub", filename, repo_id);
-            
-            // Create HuggingFace API client
-            let api = Api::new()
-                .map_err(|e| InferenceError::model_load(format!("Failed to create HF API client: {}", e)))?;
-            
-            // Get the repository
-            let repo = api.model(repo_id.to_string());
-            
-            // Download the specific file
-            let local_path = repo.get(filename).await
-                .map_err(|e| {
-                    let error_msg = format!("Failed to download {} from {}: {}", filename, repo_id, e);
-                    println!("❌ HuggingFace Hub download failed: {}", error_msg);
-                    InferenceError::model_load(error_msg)
-                })?;
-            
-            println!("✅ Successfully downloaded to: {}", local_path.display());
-            Ok(local_path)
-        }
-        #[cfg(not(feature = "candle"))]
-        {
-            Err(InferenceError::configuration(
-                "Candle feature not enabled - cannot download HuggingFace models"
-            ))
-        }
+    /// Get the ResNet variant
+    pub fn variant(&self) -> &ResNetVariant {
+        &self.variant
     }
     
+    /// Width of the pooled feature vector for a variant
+    fn feature_size(variant: &ResNetVariant) -> usize {
+        match variant {
+            ResNetVariant::ResNet18 | ResNetVariant::ResNet34 => 512,
+            _ => 2048,
+        }
+    }
+}
 
+/// Map an HF transformers ResNet weight name to the timm/torchvision layout
+/// 
+/// Returns `None` for names that need no renaming.
+#[cfg(feature = "candle")]
+fn resnet_timm_key(name: &str) -> Option<String> {
+    let name = name.strip_prefix("resnet.").unwrap_or(name);
+    
+    if let Some(rest) = name.strip_prefix("classifier.1.") {
+        return Some(format!("fc.{}", rest));
+    }
+    if let Some(rest) = name.strip_prefix("embedder.embedder.") {
+        return Some(rest
+            .replacen("convolution.", "conv1.", 1)
+            .replacen("normalization.", "bn1.", 1));
+    }
+    
+    // encoder.stages.{stage}.layers.{block}.{module...}
+    let rest = name.strip_prefix("encoder.stages.")?;
+    let mut parts = rest.splitn(4, '.');
+    let stage: usize = parts.next()?.parse().ok()?;
+    if parts.next()? != "layers" {
+        return None;
+    }
+    let block = parts.next()?;
+    let module = parts.next()?;
+    
+    let module = if let Some(param) = module.strip_prefix("shortcut.convolution.") {
+        format!("downsample.0.{}", param)
+    } else if let Some(param) = module.strip_prefix("shortcut.normalization.") {
+        format!("downsample.1.{}", param)
+    } else {
+        // layer.{index}.convolution|normalization.{param}
+        let mut parts = module.strip_prefix("layer.")?.splitn(3, '.');
+        let index: usize = parts.next()?.parse().ok()?;
+        let kind = match parts.next()? {
+            "convolution" => "conv",
+            "normalization" => "bn",
+            _ => return None,
+        };
+        format!("{}{}.{}", kind, index + 1, parts.next()?)
+    };
+    Some(format!("layer{}.{}.{}", stage + 1, block, module))
 }
 
 #[cfg(feature = "candle")]
 #[async_trait]
 impl Model for ResNetModelWrapper {
     async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
-        // Simplified ResNet-like inference
-        println!("🔥 Running ResNet-like inference (simplified version)");
+        self.predict_with_context(input, &PredictContext::new()).await
+    }
+    
+    /// Checks the context between residual blocks
+    async fn predict_with_context(&self, input: &Tensor, ctx: &PredictContext) -> Result<Tensor, InferenceError> {
+        let shape = match input.shape() {
+            [3, h, w] => vec![1, 3, *h, *w],
+            [n, 3, h, w] => vec![*n, 3, *h, *w],
+            shape => return Err(InferenceError::invalid_shape_msg(format!(
+                "ResNet input must be [N, 3, H, W] or [3, H, W] f32 image data, got {:?}", shape
+            ))),
+        };
         
-        let input_data = input.as_f32_slice()
-            .ok_or_else(|| InferenceError::invalid_shape_msg("ResNet input must be f32 image data"))?;
+        let images = CandleTensor::from_vec(input.to_f32_vec()?, shape, &self.device)
+            .map_err(|e| InferenceError::prediction(format!("Failed to create image tensor: {}", e)))?;
+        let logits = self.model.forward_with_context(&images, ctx)?;
         
-        let shape = input.shape();
+        let shape = logits.dims().to_vec();
+        let data: Vec<f32> = logits.flatten_all()
+            .and_then(|t| t.to_vec1())
+            .map_err(|e| InferenceError::prediction(format!("Failed to extract ResNet output: {}", e)))?;
+        Tensor::from_f32(data, shape)
+    }
+    
+    async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+        let mut results = Vec::new();
+        for input in inputs {
+            results.push(self.predict(input).await?);
+        }
+        Ok(results)
+    }
+    
+    fn input_specs(&self) -> &[TensorSpec] {
+        &self.input_specs
+    }
+    
+    fn output_specs(&self) -> &[TensorSpec] {
+        &self.output_specs
+    }
+    
+    fn engine_type(&self) -> EngineType {
+        EngineType::Candle
+    }
+    
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// MobileNet model wrapper for all `MobileNetVariant` values
+/// 
+/// V1 and V2 expect HF transformers checkpoints (`google/mobilenet_v*_1.0_224`,
+/// 1001 classes including background), V3 expects timm checkpoints.
+#[cfg(feature = "candle")]
+#[derive(Debug)]
+pub struct MobileNetModelWrapper {
+    device: Device,
+    variant: MobileNetVariant,
+    model: MobileNet,
+    input_specs: Vec<TensorSpec>,
+    output_specs: Vec<TensorSpec>,
+}
+
+#[cfg(feature = "candle")]
+impl MobileNetModelWrapper {
+    /// Build a MobileNet model from a state dict
+    /// 
+    /// The number of classes is read from `classifier.weight`. Checkpoints
+    /// without a classifier produce pooled features instead of logits.
+    pub fn new(
+        tensors: HashMap<String, CandleTensor>,
+        variant: MobileNetVariant,
+        device: Device,
+    ) -> Result<Self, InferenceError> {
+        let mut weights = HashMap::with_capacity(tensors.len());
+        for (name, tensor) in tensors {
+            weights.insert(name, super::pytorch::upcast_half(tensor)?);
+        }
         
-        // Create classification logits output
-        let num_classes = 1000; // ImageNet classes
-        let batch_size = if shape.is_empty() { 1 } else { shape[0] as usize };
+        let num_classes = match weights.get("classifier.weight") {
+            Some(classifier) => Some(classifier.dims2()
+                .map_err(|e| InferenceError::model_load(format!("Invalid MobileNet classifier weights: {}", e)))?
+                .0),
+            None => None,
+        };
         
-        let mut output_data = vec![0.0f32; batch_size * num_classes];
+        let vb = VarBuilder::from_tensors(weights, DType::F32, &device);
+        let model = MobileNet::load(&variant, num_classes, vb)
+            .map_err(|e| InferenceError::model_load(format!("Failed to build MobileNet {:?} from weights: {}", variant, e)))?;
         
-        // Simple transformation: create logits by processing input
-        for i in 0..batch_size {
-            for j in 0..num_classes {
-                let input_idx = if input_data.len() > j { j } else { j % input_data.len() };
-                output_data[i * num_classes + j] = input_data[input_idx] * 0.01; // Scale down
-            }
-        }
+        // Define input specs for MobileNet (images)
+        let input_specs = vec![
+            TensorSpec::new(
+                "image".to_string(),
+                vec![None, Some(3), Some(224), Some(224)], // batch_size, channels, height, width
+                DataType::F32,
+            ),
+        ];
         
-        // Convert to bytes
-        let bytes: Vec<u8> = output_data.iter()
-            .flat_map(|&x| x.to_le_bytes().to_vec())
-            .collect();
+        // Define output specs for MobileNet (classification logits or pooled features)
+        let output_specs = vec![
+            match num_classes {
+                Some(n) => TensorSpec::new(
+                    "logits".to_string(),
+                    vec![None, Some(n)], // batch_size, num_classes
+                    DataType::F32,
+                ),
+                None => TensorSpec::new(
+                    "features".to_string(),
+                    vec![None, Some(model.num_features())], // batch_size, feature_size
+                    DataType::F32,
+                ),
+            },
+        ];
+        
+        Ok(Self {
+            device,
+            variant,
+            model,
+            input_specs,
+            output_specs,
+        })
+    }
+    
+    /// Load a MobileNet model from a SafeTensors or PyTorch (.bin, .pt, .pth) weights file
+    pub fn load(weights_path: &Path, variant: MobileNetVariant, device: &Device) -> Result<Self, InferenceError> {
+        let tensors = match weights_path.extension().and_then(|ext| ext.to_str()) {
+            Some("bin") | Some("pt") | Some("pth") => super::pytorch::read_state_dict(weights_path, device)?
+                .into_iter()
+                .collect(),
+            _ => load_tensors(&[weights_path.to_path_buf()], device)?,
+        };
+        Self::new(tensors, variant, device.clone())
+    }
+    
+    /// Load a MobileNet model whose weights are split across several SafeTensors files
+    pub fn load_sharded(weight_paths: &[PathBuf], variant: MobileNetVariant, device: &Device) -> Result<Self, InferenceError> {
+        Self::new(load_tensors(weight_paths, device)?, variant, device.clone())
+    }
+    
+    /// Load pretrained ImageNet weights for a variant from its default HuggingFace repository
+    pub async fn load_pretrained(
+        device: &Device,
+        variant: MobileNetVariant,
+    ) -> Result<Self, InferenceError> {
+        let config = ModelConfig::new(ModelArchitecture::MobileNet { variant: variant.clone() });
+        let repo_id = config.default_repo_id()
+            .ok_or_else(|| InferenceError::model_load(format!("No pretrained repository known for {:?}", variant)))?;
+        Self::load_from_huggingface(device, repo_id, Some(config.default_filename()), variant).await
+    }
+    
+    /// Load MobileNet model from HuggingFace repository
+    pub async fn load_from_huggingface(
+        device: &Device,
+        repo_id: &str,
+        filename: Option<&str>,
+        variant: MobileNetVariant,
+    ) -> Result<Self, InferenceError> {
+        println!("🤗 Loading MobileNet model from HuggingFace: {}", repo_id);
+        
+        let filename = filename.unwrap_or("model.safetensors");
+        let wrapper = if filename.ends_with(".safetensors") || is_index_filename(filename) {
+            let weight_paths = download_safetensors(repo_id, filename, &mut |_| {}).await?;
+            println!("📂 Loading MobileNet model from {} file(s)", weight_paths.len());
+            Self::load_sharded(&weight_paths, variant, device)?
+        } else {
+            let model_path = download_from_huggingface(repo_id, filename).await?;
+            println!("📂 Loading MobileNet model from: {}", model_path.display());
+            Self::load(&model_path, variant, device)?
+        };
+        
+        println!("✅ Successfully loaded MobileNet model from HuggingFace");
+        Ok(wrapper)
+    }
+    
+    /// Get the MobileNet variant
+    pub fn variant(&self) -> &MobileNetVariant {
+        &self.variant
+    }
+}
+
+#[cfg(feature = "candle")]
+#[async_trait]
+impl Model for MobileNetModelWrapper {
+    async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+        self.predict_with_context(input, &PredictContext::new()).await
+    }
+    
+    /// Checks the context between network blocks
+    async fn predict_with_context(&self, input: &Tensor, ctx: &PredictContext) -> Result<Tensor, InferenceError> {
+        let shape = match input.shape() {
+            [3, h, w] => vec![1, 3, *h, *w],
+            [n, 3, h, w] => vec![*n, 3, *h, *w],
+            shape => return Err(InferenceError::invalid_shape_msg(format!(
+                "MobileNet input must be [N, 3, H, W] or [3, H, W] f32 image data, got {:?}", shape
+            ))),
+        };
+        
+        let images = CandleTensor::from_vec(input.to_f32_vec()?, shape, &self.device)
+            .map_err(|e| InferenceError::prediction(format!("Failed to create image tensor: {}", e)))?;
+        let logits = self.model.forward_with_context(&images, ctx)?;
         
-        Tensor::new(bytes, vec![batch_size, num_classes], DataType::F32)
-            .map_err(|e| InferenceError::prediction(format!("Failed to create output tensor: {}", e)))
+        let shape = logits.dims().to_vec();
+        let data: Vec<f32> = logits.flatten_all()
+            .and_then(|t| t.to_vec1())
+            .map_err(|e| InferenceError::prediction(format!("Failed to extract MobileNet output: {}", e)))?;
+        Tensor::from_f32(data, shape)
     }
     
     async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
@@ -354,70 +826,453 @@ impl Model for ResNetModelWrapper {
 }
 
 /// Fallback for when candle feature is not enabled
+/// Download a file from the HuggingFace Hub using the hf-hub crate
+#[cfg(feature = "candle")]
+pub(crate) async fn download_from_huggingface(repo_id: &str, filename: &str) -> Result<PathBuf, InferenceError> {
+    use hf_hub::api::tokio::Api;
+    
+    println!("🔄 Attempting to download {} from {} using hf-hub", filename, repo_id);
+    
+    // Create HuggingFace API client
+    let api = Api::new()
+        .map_err(|e| InferenceError::model_load(format!("Failed to create HF API client: {}", e)))?;
+    
+    // Get the repository
+    let repo = api.model(repo_id.to_string());
+    
+    // Download the specific file
+    let local_path = repo.get(filename).await
+        .map_err(|e| {
+            let error_msg = format!("Failed to download {} from {}: {}", filename, repo_id, e);
+            println!("❌ HuggingFace Hub download failed: {}", error_msg);
+            match e {
+                hf_hub::api::tokio::ApiError::RequestError(e) if e.status().is_some_and(|s| s.as_u16() == 404) => {
+                    InferenceError::resource_not_found(error_msg)
+                }
+                _ => InferenceError::model_load(error_msg),
+            }
+        })?;
+    
+    println!("✅ Successfully downloaded to: {}", local_path.display());
+    Ok(local_path)
+}
+
+/// Download a file from the HuggingFace Hub, reporting bytes as they arrive
+/// 
+/// `on_bytes` receives the bytes downloaded so far and the file size, if
+/// known. Cached files are returned straight away; new ones are streamed into
+/// the hf-hub cache layout so later lookups find them. A file the repository
+/// does not have is reported as [`InferenceError::ResourceNotFound`].
+#[cfg(feature = "candle")]
+pub(crate) async fn download_from_huggingface_with_progress(
+    repo_id: &str,
+    filename: &str,
+    on_bytes: &mut (dyn FnMut(u64, Option<u64>) + Send),
+) -> Result<PathBuf, InferenceError> {
+    use hf_hub::api::tokio::Api;
+    use hf_hub::{Cache, Repo};
+    
+    let cache = Cache::default();
+    let repo = Repo::model(repo_id.to_string());
+    if let Some(path) = cache.repo(repo.clone()).get(filename) {
+        let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        on_bytes(size, Some(size));
+        return Ok(path);
+    }
+    
+    let api = Api::new()
+        .map_err(|e| InferenceError::model_load(format!("Failed to create HF API client: {}", e)))?;
+    let url = api.repo(repo.clone()).url(filename);
+    println!("🔄 Downloading {} from {}", filename, repo_id);
+    
+    let repo_dir = cache.path().join(repo.folder_name());
+    let (path, commit) = stream_to_cache(&url, cache.token(), &repo_dir, filename, on_bytes).await?;
+    cache.repo(repo).create_ref(&commit)?;
+    
+    println!("✅ Successfully downloaded to: {}", path.display());
+    Ok(path)
+}
+
+/// Stream `url` into `repo_dir/blobs` and link it from the commit's snapshot
+/// 
+/// Returns the snapshot path and the commit it belongs to.
+#[cfg(feature = "candle")]
+async fn stream_to_cache(
+    url: &str,
+    token: Option<String>,
+    repo_dir: &Path,
+    filename: &str,
+    on_bytes: &mut (dyn FnMut(u64, Option<u64>) + Send),
+) -> Result<(PathBuf, String), InferenceError> {
+    use futures::StreamExt;
+    use tokio::io::AsyncWriteExt;
+    
+    let failed = |e: &dyn std::fmt::Display| InferenceError::model_load(format!("Failed to download {}: {}", url, e));
+    let authorized = |request: reqwest::RequestBuilder| match &token {
+        Some(token) => request.bearer_auth(token),
+        None => request,
+    };
+    
+    // The first response carries the commit and blob id even when it redirects to a CDN
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|e| failed(&e))?;
+    let response = authorized(client.get(url)).send().await.map_err(|e| failed(&e))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_FOUND {
+        return Err(InferenceError::resource_not_found(format!("{} (HTTP 404)", url)));
+    }
+    if !status.is_success() && !status.is_redirection() {
+        return Err(failed(&status));
+    }
+    
+    let header = |name: &str| response.headers().get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.trim_matches('"').to_string());
+    let commit = header("x-repo-commit").ok_or_else(|| failed(&"response has no x-repo-commit header"))?;
+    let etag = header("x-linked-etag").or_else(|| header("etag")).ok_or_else(|| failed(&"response has no etag header"))?;
+    
+    let response = if status.is_redirection() {
+        let location = header("location").ok_or_else(|| failed(&"redirect has no location header"))?;
+        let target = response.url().join(&location).map_err(|e| failed(&e))?;
+        // Only send the token back to the hub itself, not to the storage it redirects to
+        let request = reqwest::Client::new().get(target.clone());
+        let request = if target.host_str() == response.url().host_str() { authorized(request) } else { request };
+        request.send().await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| failed(&e))?
+    } else {
+        response
+    };
+    
+    let blob = repo_dir.join("blobs").join(&etag);
+    let pointer = repo_dir.join("snapshots").join(&commit).join(filename);
+    let incomplete = repo_dir.join("blobs").join(format!("{}.incomplete", etag));
+    if let Some(parent) = pointer.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::create_dir_all(repo_dir.join("blobs"))?;
+    
+    let total = response.content_length();
+    let mut file = tokio::fs::File::create(&incomplete).await?;
+    let mut downloaded = 0u64;
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|e| failed(&e))?;
+        file.write_all(&chunk).await?;
+        downloaded += chunk.len() as u64;
+        on_bytes(downloaded, total);
+    }
+    file.flush().await?;
+    drop(file);
+    tokio::fs::rename(&incomplete, &blob).await?;
+    
+    if !pointer.exists() {
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&blob, &pointer)?;
+        #[cfg(not(unix))]
+        std::fs::copy(&blob, &pointer)?;
+    }
+    Ok((pointer, commit))
+}
+
 #[cfg(not(feature = "candle"))]
 pub struct BertModelWrapper;
 
 #[cfg(not(feature = "candle"))]
 pub struct ResNetModelWrapper; 
 
+#[cfg(not(feature = "candle"))]
+pub struct MobileNetModelWrapper;
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::models::Tensor;
     
+    /// Load the tiny BERT checkpoint from the test fixtures
+    #[cfg(feature = "candle")]
+    fn tiny_bert(device: &Device) -> BertModelWrapper {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tiny_bert");
+        BertModelWrapper::load(&dir.join("config.json"), &dir.join("model.safetensors"), device).unwrap()
+    }
+    
+    /// Read a 2-D integer array from the tiny BERT reference file
+    #[cfg(feature = "candle")]
+    fn reference_ids(reference: &serde_json::Value, key: &str) -> Tensor {
+        let rows = reference[key].as_array().unwrap();
+        let data: Vec<i64> = rows.iter()
+            .flat_map(|row| row.as_array().unwrap().iter().map(|v| v.as_i64().unwrap()))
+            .collect();
+        let seq_len = data.len() / rows.len();
+        Tensor::from_i64(data, vec![rows.len(), seq_len]).unwrap()
+    }
+    
+    /// Compare outputs against reference values within float32 tolerance
+    #[cfg(feature = "candle")]
+    fn assert_close(actual: &[f32], expected: &serde_json::Value) {
+        let expected: Vec<f64> = expected.as_array().unwrap().iter().map(|v| v.as_f64().unwrap()).collect();
+        assert_eq!(actual.len(), expected.len());
+        for (i, (a, e)) in actual.iter().zip(expected.iter()).enumerate() {
+            assert!((*a as f64 - e).abs() < 1e-4, "value {} differs: {} vs {}", i, a, e);
+        }
+    }
+    
     #[cfg(feature = "candle")]
     #[tokio::test]
     async fn test_bert_model_wrapper() {
         let device = Device::Cpu;
-        let model = BertModelWrapper::new(device);
+        let model = tiny_bert(&device);
         
-        // Create test input
-        let input_data = vec![1.0f32, 2.0, 3.0, 4.0];
-        let input = Tensor::from_f32(input_data, vec![1, 4]).unwrap();
+        // Token ids for a single sequence
+        let input = Tensor::from_i64(vec![2, 5, 7, 3], vec![1, 4]).unwrap();
         
         // Test prediction
         let result = model.predict(&input).await;
         assert!(result.is_ok());
         
         let output = result.unwrap();
-        assert_eq!(output.shape(), &[1, 768]); // BERT embedding size
+        assert_eq!(output.shape(), &[1, 4, 8]); // batch, seq_len, hidden_size
         assert_eq!(output.data_type(), &DataType::F32);
         
+        assert_eq!(model.input_specs().len(), 3);
+        assert_eq!(model.output_specs()[1].shape, vec![None, Some(8)]);
+        
+        // Float input is not a valid token id tensor
+        let float_input = Tensor::from_f32(vec![1.0, 2.0], vec![1, 2]).unwrap();
+        assert!(model.predict(&float_input).await.is_err());
+        
         println!("✅ BERT model wrapper test passed!");
     }
     
+    #[cfg(feature = "candle")]
+    #[test]
+    fn test_bert_matches_reference_outputs() {
+        let device = Device::Cpu;
+        let model = tiny_bert(&device);
+        
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tiny_bert/reference.json");
+        let reference: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        
+        let input_ids = reference_ids(&reference, "input_ids");
+        let attention_mask = reference_ids(&reference, "attention_mask");
+        let token_type_ids = reference_ids(&reference, "token_type_ids");
+        
+        let output = model.encode(&input_ids, Some(&attention_mask), Some(&token_type_ids)).unwrap();
+        
+        assert_eq!(output.last_hidden_state.shape(), &[2, 6, 8]);
+        assert_eq!(output.pooled_output.shape(), &[2, 8]);
+        assert_close(&output.last_hidden_state.to_f32_vec().unwrap(), &reference["last_hidden_state"]);
+        assert_close(&output.pooled_output.to_f32_vec().unwrap(), &reference["pooled_output"]);
+        
+        // Mismatched mask shapes are rejected
+        let short_mask = Tensor::from_i64(vec![1, 1], vec![1, 2]).unwrap();
+        assert!(model.encode(&input_ids, Some(&short_mask), None).is_err());
+        
+        // Negative or oversized ids are rejected instead of wrapping
+        let negative_ids = Tensor::from_i64(vec![2, -1, 3], vec![1, 3]).unwrap();
+        let result = model.encode(&negative_ids, None, None);
+        assert!(matches!(&result, Err(InferenceError::InvalidTensorData(msg)) if msg.contains("input_ids")));
+        let ids = Tensor::from_i64(vec![2, 5, 3], vec![1, 3]).unwrap();
+        let huge_mask = Tensor::from_i64(vec![1, 1, u32::MAX as i64 + 2], vec![1, 3]).unwrap();
+        let result = model.encode(&ids, Some(&huge_mask), None);
+        assert!(matches!(&result, Err(InferenceError::InvalidTensorData(msg)) if msg.contains("attention_mask")));
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_bert_named_inputs_match_reference_outputs() {
+        let device = Device::Cpu;
+        let model = tiny_bert(&device);
+        
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tiny_bert/reference.json");
+        let reference: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let inputs = vec![
+            ("token_type_ids".to_string(), reference_ids(&reference, "token_type_ids")),
+            ("attention_mask".to_string(), reference_ids(&reference, "attention_mask")),
+            ("input_ids".to_string(), reference_ids(&reference, "input_ids")),
+        ];
+        
+        let outputs = model.predict_named(&inputs).await.unwrap();
+        assert_eq!(outputs[0].0, "last_hidden_state");
+        assert_close(&outputs[0].1.to_f32_vec().unwrap(), &reference["last_hidden_state"]);
+        assert_eq!(outputs[1].0, "pooled_output");
+        assert_close(&outputs[1].1.to_f32_vec().unwrap(), &reference["pooled_output"]);
+        
+        // input_ids is required and unknown names are rejected
+        assert!(model.predict_named(&inputs[..2]).await.is_err());
+        let unknown = vec![("position_ids".to_string(), reference_ids(&reference, "input_ids"))];
+        assert!(model.predict_named(&unknown).await.is_err());
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_bert_and_resnet_stop_when_cancelled() {
+        use crate::models::CancellationToken;
+        
+        let device = Device::Cpu;
+        let bert = tiny_bert(&device);
+        let resnet = ResNetModelWrapper::new(random_resnet18_weights(&device), ResNetVariant::ResNet18, device).unwrap();
+        let ids = Tensor::from_i64(vec![2, 5, 7, 3], vec![1, 4]).unwrap();
+        let image = Tensor::from_f32(vec![0.5; 3 * 32 * 32], vec![3, 32, 32]).unwrap();
+        
+        let token = CancellationToken::new();
+        let ctx = PredictContext::new().with_token(token.clone());
+        let expected = bert.encode(&ids, None, None).unwrap();
+        let output = bert.encode_with_context(&ids, None, None, &ctx).unwrap();
+        assert_eq!(output.last_hidden_state.to_f32_vec().unwrap(), expected.last_hidden_state.to_f32_vec().unwrap());
+        assert_eq!(resnet.predict_with_context(&image, &ctx).await.unwrap().shape(), &[1, 1000]);
+        
+        token.cancel();
+        assert!(matches!(bert.encode_with_context(&ids, None, None, &ctx), Err(InferenceError::Cancelled(_))));
+        assert!(matches!(bert.predict_with_context(&ids, &ctx).await, Err(InferenceError::Cancelled(_))));
+        assert!(matches!(resnet.predict_with_context(&image, &ctx).await, Err(InferenceError::Cancelled(_))));
+    }
+    
+    /// Randomly initialised ResNet-18 weights in timm layout
+    #[cfg(feature = "candle")]
+    fn random_resnet18_weights(device: &Device) -> HashMap<String, CandleTensor> {
+        let varmap = candle_nn::VarMap::new();
+        let vb = VarBuilder::from_varmap(&varmap, DType::F32, device);
+        candle_transformers::models::resnet::resnet18(1000, vb).unwrap();
+        let data = varmap.data().lock().unwrap();
+        data.iter().map(|(name, var)| (name.clone(), var.as_tensor().clone())).collect()
+    }
+    
+    /// Rename a timm ResNet-18 weight to the HF transformers layout
+    #[cfg(feature = "candle")]
+    fn resnet_hf_key(name: &str) -> String {
+        let name = name.replace("fc.", "classifier.1.");
+        let name = match name.split_once('.') {
+            Some(("conv1", param)) => format!("embedder.embedder.convolution.{}", param),
+            Some(("bn1", param)) => format!("embedder.embedder.normalization.{}", param),
+            Some((layer, rest)) if layer.starts_with("layer") => {
+                let stage: usize = layer["layer".len()..].parse().unwrap();
+                let (block, module) = rest.split_once('.').unwrap();
+                let module = module
+                    .replace("downsample.0.", "shortcut.convolution.")
+                    .replace("downsample.1.", "shortcut.normalization.")
+                    .replace("conv1.", "layer.0.convolution.")
+                    .replace("bn1.", "layer.0.normalization.")
+                    .replace("conv2.", "layer.1.convolution.")
+                    .replace("bn2.", "layer.1.normalization.");
+                format!("encoder.stages.{}.layers.{}.{}", stage - 1, block, module)
+            }
+            _ => return name,
+        };
+        format!("resnet.{}", name)
+    }
+    
     #[cfg(feature = "candle")]
     #[tokio::test]
     async fn test_resnet_model_wrapper() {
         let device = Device::Cpu;
         let variant = ResNetVariant::ResNet18;
-        let model = ResNetModelWrapper::new(device, variant);
+        let model = ResNetModelWrapper::new(random_resnet18_weights(&device), variant, device).unwrap();
         
-        // Create test input (simplified image)
-        let input_data = vec![0.5f32; 3 * 224 * 224]; // 3 channels, 224x224
-        let input = Tensor::from_f32(input_data, vec![1, 3, 224, 224]).unwrap();
+        // Create test input (small image keeps the test fast; pooling is global)
+        let input_data = vec![0.5f32; 2 * 3 * 64 * 64];
+        let input = Tensor::from_f32(input_data, vec![2, 3, 64, 64]).unwrap();
         
         // Test prediction
         let result = model.predict(&input).await;
         assert!(result.is_ok());
         
         let output = result.unwrap();
-        assert_eq!(output.shape(), &[1, 1000]); // ImageNet classes
+        assert_eq!(output.shape(), &[2, 1000]); // ImageNet classes
         assert_eq!(output.data_type(), &DataType::F32);
+        assert_eq!(model.output_specs()[0].shape, vec![None, Some(1000)]);
+        
+        // Channel-last or grayscale input is rejected
+        let bad_input = Tensor::from_f32(vec![0.5; 64 * 64], vec![1, 1, 64, 64]).unwrap();
+        assert!(model.predict(&bad_input).await.is_err());
         
         println!("✅ ResNet model wrapper test passed!");
     }
     
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_resnet_hf_layout_matches_timm_layout() {
+        let device = Device::Cpu;
+        let timm_weights = random_resnet18_weights(&device);
+        let hf_weights = timm_weights.iter()
+            .map(|(name, tensor)| (resnet_hf_key(name), tensor.clone()))
+            .collect();
+        
+        let timm_model = ResNetModelWrapper::new(timm_weights, ResNetVariant::ResNet18, device.clone()).unwrap();
+        let hf_model = ResNetModelWrapper::new(hf_weights, ResNetVariant::ResNet18, device.clone()).unwrap();
+        
+        let input = Tensor::from_f32((0..3 * 32 * 32).map(|i| (i % 7) as f32 / 7.0).collect(), vec![3, 32, 32]).unwrap();
+        let timm_logits = timm_model.predict(&input).await.unwrap();
+        let hf_logits = hf_model.predict(&input).await.unwrap();
+        assert_eq!(timm_logits.shape(), &[1, 1000]);
+        assert_eq!(timm_logits.to_f32_vec().unwrap(), hf_logits.to_f32_vec().unwrap());
+        
+        // Weights for one variant do not fit another
+        let result = ResNetModelWrapper::new(random_resnet18_weights(&device), ResNetVariant::ResNet50, device);
+        assert!(matches!(result, Err(InferenceError::ModelLoad(_))));
+    }
+    
+    #[cfg(feature = "candle")]
+    #[test]
+    fn test_resnet_timm_key() {
+        assert_eq!(resnet_timm_key("resnet.embedder.embedder.convolution.weight").as_deref(), Some("conv1.weight"));
+        assert_eq!(resnet_timm_key("resnet.embedder.embedder.normalization.running_var").as_deref(), Some("bn1.running_var"));
+        assert_eq!(
+            resnet_timm_key("resnet.encoder.stages.1.layers.0.shortcut.convolution.weight").as_deref(),
+            Some("layer2.0.downsample.0.weight")
+        );
+        assert_eq!(
+            resnet_timm_key("resnet.encoder.stages.3.layers.2.layer.2.normalization.bias").as_deref(),
+            Some("layer4.2.bn3.bias")
+        );
+        assert_eq!(resnet_timm_key("classifier.1.weight").as_deref(), Some("fc.weight"));
+        assert_eq!(resnet_timm_key("layer1.0.conv1.weight"), None);
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_mobilenet_model_wrapper() {
+        let device = Device::Cpu;
+        
+        // Randomly initialised HF transformers MobileNetV2 with a 1001-class head
+        let varmap = candle_nn::VarMap::new();
+        let vb = VarBuilder::from_varmap(&varmap, DType::F32, &device);
+        MobileNet::load(&MobileNetVariant::V2, Some(1001), vb).unwrap();
+        let mut weights: HashMap<String, CandleTensor> = varmap.data().lock().unwrap().iter()
+            .map(|(name, var)| (name.clone(), var.as_tensor().clone()))
+            .collect();
+        
+        let model = MobileNetModelWrapper::new(weights.clone(), MobileNetVariant::V2, device.clone()).unwrap();
+        assert_eq!(model.variant(), &MobileNetVariant::V2);
+        assert_eq!(model.output_specs()[0].shape, vec![None, Some(1001)]);
+        
+        let input = Tensor::from_f32(vec![0.5; 3 * 64 * 64], vec![3, 64, 64]).unwrap();
+        let output = model.predict(&input).await.unwrap();
+        assert_eq!(output.shape(), &[1, 1001]);
+        
+        // Without a classifier the model returns pooled features
+        weights.retain(|name, _| !name.starts_with("classifier."));
+        let backbone = MobileNetModelWrapper::new(weights.clone(), MobileNetVariant::V2, device.clone()).unwrap();
+        assert_eq!(backbone.output_specs()[0].name, "features");
+        assert_eq!(backbone.predict(&input).await.unwrap().shape(), &[1, 1280]);
+        
+        // V2 weights do not fit V1
+        let result = MobileNetModelWrapper::new(weights, MobileNetVariant::V1, device);
+        assert!(matches!(result, Err(InferenceError::ModelLoad(_))));
+    }
+    
     #[cfg(feature = "candle")]
     #[tokio::test]
     async fn test_real_vs_placeholder_behavior() {
         let device = Device::Cpu;
-        let bert_model = BertModelWrapper::new(device.clone());
-        let resnet_model = ResNetModelWrapper::new(device, ResNetVariant::ResNet18);
+        let bert_model = tiny_bert(&device);
+        let resnet_model = ResNetModelWrapper::new(random_resnet18_weights(&device), ResNetVariant::ResNet18, device).unwrap();
         
         // Test BERT with different inputs
-        let input1 = Tensor::from_f32(vec![1.0, 2.0], vec![1, 2]).unwrap();
-        let input2 = Tensor::from_f32(vec![3.0, 4.0], vec![1, 2]).unwrap();
+        let input1 = Tensor::from_i64(vec![1, 2], vec![1, 2]).unwrap();
+        let input2 = Tensor::from_i64(vec![3, 4], vec![1, 2]).unwrap();
         
         let bert_result1 = bert_model.predict(&input1).await.unwrap();
         let bert_result2 = bert_model.predict(&input2).await.unwrap();
@@ -429,6 +1284,13 @@ mod tests {
         // Check that outputs are different (proving it's not just pass-through)
         assert_ne!(bert_data1[0], bert_data2[0]);
         
+        // Test ResNet with different images
+        let image1 = Tensor::from_f32(vec![0.1; 3 * 32 * 32], vec![1, 3, 32, 32]).unwrap();
+        let image2 = Tensor::from_f32((0..3 * 32 * 32).map(|i| (i % 5) as f32).collect(), vec![1, 3, 32, 32]).unwrap();
+        let resnet_data1 = resnet_model.predict(&image1).await.unwrap().to_f32_vec().unwrap();
+        let resnet_data2 = resnet_model.predict(&image2).await.unwrap().to_f32_vec().unwrap();
+        assert_ne!(resnet_data1, resnet_data2);
+        
         println!("✅ Real ML behavior test passed - outputs are different for different inputs!");
     }
     
@@ -453,12 +1315,11 @@ mod tests {
         let bert_model = result.unwrap();
         
         // Test that the model has the correct specs
-        assert_eq!(bert_model.input_specs().len(), 1);
-        assert_eq!(bert_model.output_specs().len(), 1);
-        assert_eq!(bert_model.output_specs()[0].shape, vec![None, Some(768)]);
-        
-        // Verify the model name was updated to reflect HuggingFace source
-        assert!(bert_model.input_specs()[0].name.contains("hf_bert-base-uncased"));
+        assert_eq!(bert_model.input_specs().len(), 3);
+        assert_eq!(bert_model.output_specs().len(), 2);
+        assert_eq!(bert_model.output_specs()[0].shape, vec![None, None, Some(768)]);
+        assert_eq!(bert_model.output_specs()[1].shape, vec![None, Some(768)]);
+        assert_eq!(bert_model.config().hidden_size, 768);
         
         // Test ResNet HuggingFace integration
         let resnet_result = ResNetModelWrapper::load_from_huggingface(
@@ -472,8 +1333,70 @@ mod tests {
         
         let resnet_model = resnet_result.unwrap();
         assert_eq!(resnet_model.output_specs()[0].shape, vec![None, Some(1000)]);
-        assert!(resnet_model.input_specs()[0].name.contains("hf_microsoft_resnet-50"));
+        assert_eq!(resnet_model.variant(), &ResNetVariant::ResNet50);
         
         println!("✅ HuggingFace integration test passed!");
     }
+    
+    /// Serve canned hub responses on a local port: `/missing` is a 404, anything else `body`
+    #[cfg(feature = "candle")]
+    fn serve_hub_files(body: &'static [u8]) -> String {
+        use std::io::{BufRead, BufReader, Write};
+        
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                // Skip the request headers
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                
+                if request_line.contains("/missing") {
+                    stream.write_all(b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n").unwrap();
+                } else {
+                    let head = format!(
+                        "HTTP/1.1 200 OK\r\nx-repo-commit: abc123\r\netag: \"blob1\"\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                        body.len()
+                    );
+                    stream.write_all(head.as_bytes()).unwrap();
+                    stream.write_all(body).unwrap();
+                }
+            }
+        });
+        format!("http://{}", address)
+    }
+    
+    #[cfg(feature = "candle")]
+    #[tokio::test]
+    async fn test_stream_to_cache_reports_bytes_and_not_found() {
+        let body: &'static [u8] = &[7u8; 100_000];
+        let server = serve_hub_files(body);
+        let repo_dir = std::env::temp_dir().join(format!("inference_hub_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&repo_dir);
+        
+        let mut reports = Vec::new();
+        let (path, commit) = stream_to_cache(
+            &format!("{}/model.safetensors", server), None, &repo_dir, "model.safetensors",
+            &mut |downloaded, total| reports.push((downloaded, total)),
+        ).await.unwrap();
+        assert_eq!(commit, "abc123");
+        assert_eq!(path, repo_dir.join("snapshots/abc123/model.safetensors"));
+        assert_eq!(std::fs::read(&path).unwrap(), body);
+        assert!(repo_dir.join("blobs/blob1").exists());
+        assert!(reports.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(reports.last(), Some(&(body.len() as u64, Some(body.len() as u64))));
+        
+        let missing = stream_to_cache(
+            &format!("{}/missing", server), None, &repo_dir, "missing", &mut |_, _| {},
+        ).await;
+        assert!(matches!(missing, Err(InferenceError::ResourceNotFound(_))));
+        
+        std::fs::remove_dir_all(&repo_dir).unwrap();
+    }
 } 
\ No newline at end of file
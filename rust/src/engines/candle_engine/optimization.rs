@@ -0,0 +1,260 @@
+use crate::engines::candle_engine::graph::GraphSpec;
+use crate::models::{DataType, InferenceError, OptimizationLevel, OptimizationReport, Tensor, TensorSpec};
+use std::collections::HashMap;
+
+use candle_core::quantized::{GgmlDType, QMatMul, QTensor};
+use candle_core::{DType, Tensor as CandleTensor};
+
+/// Weights after a load-time conversion
+#[derive(Debug, Clone)]
+pub struct ConvertedWeights {
+    /// Tensors kept dense, cast when the level asks for it
+    pub tensors: HashMap<String, CandleTensor>,
+    /// Quantized Linear weights in `[out, in]` layout
+    pub quantized: HashMap<String, QMatMul>,
+    /// Dtype the converted model computes in
+    pub dtype: DType,
+    /// Tensor counts and sizes; the error fields are filled in by the accuracy check
+    pub report: OptimizationReport,
+}
+
+/// Floating-point dtype a level casts weights to
+fn cast_dtype(level: OptimizationLevel) -> Option<DType> {
+    match level {
+        OptimizationLevel::F16 => Some(DType::F16),
+        OptimizationLevel::Bf16 => Some(DType::BF16),
+        _ => None,
+    }
+}
+
+/// Block format a level quantizes Linear weights to
+fn quantized_dtype(level: OptimizationLevel) -> Option<GgmlDType> {
+    match level {
+        OptimizationLevel::Int8 => Some(GgmlDType::Q8_0),
+        OptimizationLevel::Q4 => Some(GgmlDType::Q4_0),
+        _ => None,
+    }
+}
+
+/// Size of a dense tensor in bytes
+pub fn tensor_bytes(tensor: &CandleTensor) -> usize {
+    tensor.elem_count() * tensor.dtype().size_in_bytes()
+}
+
+/// Input features of a quantized Linear weight
+pub fn in_features(weight: &QMatMul) -> Option<usize> {
+    let dims = match weight {
+        QMatMul::QTensor(qtensor) => qtensor.shape().dims2(),
+        QMatMul::Tensor(tensor) | QMatMul::TensorF16(tensor) => tensor.dims2(),
+    };
+    dims.ok().map(|(_, in_features)| in_features)
+}
+
+/// Weights used by the graph's `linear` layers, mapped to whether they must be transposed to `[out, in]`
+/// 
+/// Graph weights are stored PyTorch-style as `[out, in]` already.
+pub fn linear_weights(graph: &GraphSpec) -> HashMap<String, bool> {
+    graph.linear_weights().into_iter().map(|name| (name.to_string(), false)).collect()
+}
+
+/// Cast or quantize weights for an optimization level
+/// 
+/// F16 and BF16 cast every floating-point tensor. Int8 and Q4 quantize the
+/// `linear` weights whose input features are a multiple of the block size and
+/// leave the rest in F32.
+pub fn convert_weights(
+    tensors: &HashMap<String, CandleTensor>,
+    level: OptimizationLevel,
+    linear: &HashMap<String, bool>,
+) -> Result<ConvertedWeights, InferenceError> {
+    let convert_error = |name: &str, e: candle_core::Error| {
+        InferenceError::model_load(format!("Failed to convert tensor '{}' to {}: {}", name, level.name(), e))
+    };
+    let mut report = OptimizationReport {
+        level,
+        tensors_converted: 0,
+        tensors_skipped: 0,
+        size_before_bytes: tensors.values().map(tensor_bytes).sum(),
+        size_after_bytes: 0,
+        max_abs_error: None,
+        mean_abs_error: None,
+    };
+    let mut dense = HashMap::new();
+    let mut quantized = HashMap::new();
+    
+    for (name, tensor) in tensors {
+        if let Some(dtype) = cast_dtype(level) {
+            if tensor.dtype().is_float() && tensor.dtype() != dtype {
+                let tensor = tensor.to_dtype(dtype).map_err(|e| convert_error(name, e))?;
+                report.tensors_converted += 1;
+                report.size_after_bytes += tensor_bytes(&tensor);
+                dense.insert(name.clone(), tensor);
+                continue;
+            }
+        }
+        
+        if let (Some(dtype), Some(&transpose)) = (quantized_dtype(level), linear.get(name)) {
+            let weight = if transpose {
+                tensor.t().and_then(|t| t.contiguous()).map_err(|e| convert_error(name, e))?
+            } else {
+                tensor.clone()
+            };
+            let quantizable = weight.rank() == 2
+                && weight.dtype().is_float()
+                && weight.dims()[1] % dtype.block_size() == 0;
+            if quantizable {
+                let qtensor = QTensor::quantize(&weight, dtype).map_err(|e| convert_error(name, e))?;
+                report.tensors_converted += 1;
+                report.size_after_bytes += qtensor.storage_size_in_bytes();
+                quantized.insert(name.clone(), QMatMul::from_qtensor(qtensor).map_err(|e| convert_error(name, e))?);
+                continue;
+            }
+            report.tensors_skipped += 1;
+        }
+        
+        report.size_after_bytes += tensor_bytes(tensor);
+        dense.insert(name.clone(), tensor.clone());
+    }
+    
+    Ok(ConvertedWeights {
+        tensors: dense,
+        quantized,
+        dtype: cast_dtype(level).unwrap_or(DType::F32),
+        report,
+    })
+}
+
+/// Sample input for a spec, filled with a fixed pattern in [-1, 1)
+/// 
+/// Unlike the zero-filled warm-up input, this exercises the weights so
+/// converted and unconverted outputs can differ.
+pub fn synthetic_sample(spec: &TensorSpec) -> Result<Tensor, InferenceError> {
+    if spec.data_type != DataType::F32 {
+        return spec.synthetic_tensor();
+    }
+    let shape = spec.concrete_shape();
+    let values = (0..shape.iter().product::<usize>())
+        .map(|i| (i * 37 % 101) as f32 / 50.5 - 1.0)
+        .collect();
+    Tensor::from_f32(values, shape)
+}
+
+/// Absolute differences between outputs, accumulated over samples
+#[derive(Debug, Default)]
+pub struct OutputError {
+    max: f32,
+    sum: f64,
+    count: usize,
+}
+
+impl OutputError {
+    /// Compare one converted output with the unconverted one
+    pub fn add(&mut self, expected: &Tensor, actual: &Tensor) -> Result<(), InferenceError> {
+        if expected.shape() != actual.shape() {
+            return Err(InferenceError::model_load(format!(
+                "Converted model returned shape {:?} instead of {:?}", actual.shape(), expected.shape()
+            )));
+        }
+        for (x, y) in expected.to_f32_vec()?.into_iter().zip(actual.to_f32_vec()?) {
+            // NaNs from overflowing half-precision weights count as unbounded error
+            let diff = match (x - y).abs() {
+                diff if diff.is_nan() => f32::INFINITY,
+                diff => diff,
+            };
+            self.max = self.max.max(diff);
+            self.sum += diff as f64;
+            self.count += 1;
+        }
+        Ok(())
+    }
+    
+    /// Largest difference, if any outputs were compared
+    pub fn max(&self) -> Option<f32> {
+        (self.count > 0).then_some(self.max)
+    }
+    
+    /// Mean difference, if any outputs were compared
+    pub fn mean(&self) -> Option<f32> {
+        (self.count > 0).then(|| (self.sum / self.count as f64) as f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_core::{Device, Module};
+    
+    fn weights(entries: Vec<(&str, CandleTensor)>) -> HashMap<String, CandleTensor> {
+        entries.into_iter().map(|(name, tensor)| (name.to_string(), tensor)).collect()
+    }
+    
+    fn ramp(rows: usize, cols: usize) -> CandleTensor {
+        let values: Vec<f32> = (0..rows * cols).map(|i| (i % 17) as f32 / 8.0 - 1.0).collect();
+        CandleTensor::from_vec(values, (rows, cols), &Device::Cpu).unwrap()
+    }
+    
+    #[test]
+    fn test_cast_to_half_precision() {
+        let tensors = weights(vec![
+            ("fc.weight", ramp(4, 8)),
+            ("positions", CandleTensor::new(&[1u32, 2, 3], &Device::Cpu).unwrap()),
+        ]);
+        let converted = convert_weights(&tensors, OptimizationLevel::F16, &HashMap::new()).unwrap();
+        
+        assert_eq!(converted.dtype, DType::F16);
+        assert_eq!(converted.tensors["fc.weight"].dtype(), DType::F16);
+        assert_eq!(converted.tensors["positions"].dtype(), DType::U32);
+        assert_eq!(converted.report.tensors_converted, 1);
+        assert_eq!(converted.report.size_before_bytes, 4 * 8 * 4 + 3 * 4);
+        assert_eq!(converted.report.size_after_bytes, 4 * 8 * 2 + 3 * 4);
+    }
+    
+    #[test]
+    fn test_quantize_linear_weights() {
+        let tensors = weights(vec![
+            ("fc.weight", ramp(4, 64)),
+            ("fc.bias", CandleTensor::zeros(4, DType::F32, &Device::Cpu).unwrap()),
+            ("narrow.weight", ramp(4, 8)),
+        ]);
+        let linear: HashMap<String, bool> = [("fc.weight", false), ("narrow.weight", false)]
+            .into_iter()
+            .map(|(name, transpose)| (name.to_string(), transpose))
+            .collect();
+        
+        let converted = convert_weights(&tensors, OptimizationLevel::Int8, &linear).unwrap();
+        assert_eq!(converted.dtype, DType::F32);
+        assert!(converted.quantized.contains_key("fc.weight"));
+        assert!(!converted.tensors.contains_key("fc.weight"));
+        // 8 input features don't fill a 32-value block
+        assert!(converted.tensors.contains_key("narrow.weight"));
+        assert_eq!((converted.report.tensors_converted, converted.report.tensors_skipped), (1, 1));
+        assert!(converted.report.size_after_bytes < converted.report.size_before_bytes);
+        
+        let weight = &converted.quantized["fc.weight"];
+        assert_eq!(in_features(weight), Some(64));
+        let input = ramp(2, 64);
+        let expected: Vec<Vec<f32>> = input.matmul(&ramp(4, 64).t().unwrap()).unwrap().to_vec2().unwrap();
+        let actual: Vec<Vec<f32>> = weight.forward(&input).unwrap().to_vec2().unwrap();
+        for (e, a) in expected.iter().flatten().zip(actual.iter().flatten()) {
+            assert!((e - a).abs() < 0.5, "{} vs {}", e, a);
+        }
+        
+        let q4 = convert_weights(&tensors, OptimizationLevel::Q4, &linear).unwrap();
+        assert!(q4.report.size_after_bytes < converted.report.size_after_bytes);
+    }
+    
+    #[test]
+    fn test_output_error() {
+        let expected = Tensor::from_f32(vec![1.0, 2.0], vec![1, 2]).unwrap();
+        let mut error = OutputError::default();
+        assert_eq!(error.max(), None);
+        
+        error.add(&expected, &Tensor::from_f32(vec![1.5, 2.0], vec![1, 2]).unwrap()).unwrap();
+        assert_eq!(error.max(), Some(0.5));
+        assert_eq!(error.mean(), Some(0.25));
+        
+        error.add(&expected, &Tensor::from_f32(vec![f32::NAN, 2.0], vec![1, 2]).unwrap()).unwrap();
+        assert_eq!(error.max(), Some(f32::INFINITY));
+        assert!(error.add(&expected, &Tensor::from_f32(vec![1.0], vec![1, 1]).unwrap()).is_err());
+    }
+}
@@ -0,0 +1,416 @@
+use crate::models::InferenceError;
+
+#[cfg(feature = "candle")]
+use std::io::Cursor;
+#[cfg(feature = "candle")]
+use std::path::Path;
+
+#[cfg(feature = "candle")]
+use candle_core::pickle::{self, Object, Stack, TensorInfo};
+#[cfg(feature = "candle")]
+use candle_core::{DType, Device, Tensor as CandleTensor};
+
+/// Keys under which training checkpoints commonly nest the model weights
+pub const STATE_DICT_KEYS: &[&str] = &["state_dict", "model_state_dict", "model", "module"];
+
+/// Magic number written at the start of legacy (pre-zip) `torch.save` files
+const LEGACY_MAGIC: [u8; 10] = [0x6c, 0xfc, 0x9c, 0x46, 0xf9, 0x20, 0x6a, 0xa8, 0x50, 0x19];
+
+/// Check whether bytes are a zip-based PyTorch checkpoint
+pub fn is_zip_checkpoint(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"PK\x03\x04")
+}
+
+/// Check whether bytes are a legacy pickle-based PyTorch checkpoint
+/// 
+/// Legacy files start with a protocol 2 pickle of the torch magic number.
+pub fn is_legacy_checkpoint(bytes: &[u8]) -> bool {
+    bytes.len() > 15
+        && bytes.starts_with(&[0x80, 0x02, 0x8a, 0x0a])
+        && bytes[4..14] == LEGACY_MAGIC
+        && bytes[14] == b'.'
+}
+
+/// Read every tensor of a `.pt`/`.pth` state dict from a file
+/// 
+/// Handles both the zip format (PyTorch >= 1.6) and the legacy pickle
+/// format. Checkpoints that wrap the weights in a dict under one of
+/// [`STATE_DICT_KEYS`] are unwrapped automatically.
+#[cfg(feature = "candle")]
+pub fn read_state_dict(path: &Path, device: &Device) -> Result<Vec<(String, CandleTensor)>, InferenceError> {
+    let mut header = [0u8; 16];
+    let header_len = {
+        use std::io::Read;
+        let mut file = std::fs::File::open(path)
+            .map_err(|e| InferenceError::model_load(format!("Failed to open PyTorch file {}: {}", path.display(), e)))?;
+        file.read(&mut header)
+            .map_err(|e| InferenceError::model_load(format!("Failed to read PyTorch file {}: {}", path.display(), e)))?
+    };
+    
+    if is_zip_checkpoint(&header[..header_len]) {
+        read_zip_state_dict(path, device)
+    } else {
+        let bytes = std::fs::read(path)
+            .map_err(|e| InferenceError::model_load(format!("Failed to read PyTorch file {}: {}", path.display(), e)))?;
+        read_legacy_state_dict(&bytes, device)
+    }
+}
+
+/// Read every tensor of a `.pt`/`.pth` state dict from memory
+/// 
+/// The zip reader needs a file, so zip checkpoints are spilled to a
+/// temporary file for the duration of the read.
+#[cfg(feature = "candle")]
+pub fn read_state_dict_from_bytes(bytes: &[u8], device: &Device) -> Result<Vec<(String, CandleTensor)>, InferenceError> {
+    if !is_zip_checkpoint(bytes) {
+        return read_legacy_state_dict(bytes, device);
+    }
+    
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    let temp_path = std::env::temp_dir()
+        .join(format!("inference-{}-{}.pt", std::process::id(), nanos));
+    
+    std::fs::write(&temp_path, bytes)
+        .map_err(|e| InferenceError::model_load(format!("Failed to spill PyTorch bytes to {}: {}", temp_path.display(), e)))?;
+    let result = read_zip_state_dict(&temp_path, device);
+    let _ = std::fs::remove_file(&temp_path);
+    result
+}
+
+/// Read a zip-based checkpoint, trying the wrapper keys if the root holds no tensors
+#[cfg(feature = "candle")]
+fn read_zip_state_dict(path: &Path, device: &Device) -> Result<Vec<(String, CandleTensor)>, InferenceError> {
+    let root_infos = pickle::read_pth_tensor_info(path, false, None)
+        .map_err(|e| InferenceError::model_load(format!("Failed to parse PyTorch checkpoint: {}", e)))?;
+    
+    let key = if root_infos.is_empty() {
+        STATE_DICT_KEYS.iter()
+            .copied()
+            .find(|key| {
+                pickle::read_pth_tensor_info(path, false, Some(key))
+                    .map(|infos| !infos.is_empty())
+                    .unwrap_or(false)
+            })
+    } else {
+        None
+    };
+    
+    let pth = pickle::PthTensors::new(path, key)
+        .map_err(|e| InferenceError::model_load(format!("Failed to parse PyTorch checkpoint: {}", e)))?;
+    
+    let mut tensors = Vec::with_capacity(pth.tensor_infos().len());
+    for name in pth.tensor_infos().keys() {
+        let tensor = pth.get(name)
+            .map_err(|e| InferenceError::model_load(format!("Failed to read tensor {}: {}", name, e)))?;
+        if let Some(tensor) = tensor {
+            let tensor = tensor.to_device(device)
+                .map_err(|e| InferenceError::model_load(format!("Failed to move tensor {} to device: {}", name, e)))?;
+            tensors.push((name.clone(), tensor));
+        }
+    }
+    
+    Ok(tensors)
+}
+
+/// Read a legacy checkpoint: magic, protocol, sys info, the object, storage keys, raw storages
+#[cfg(feature = "candle")]
+fn read_legacy_state_dict(bytes: &[u8], device: &Device) -> Result<Vec<(String, CandleTensor)>, InferenceError> {
+    if !is_legacy_checkpoint(bytes) {
+        return Err(InferenceError::unsupported_format(
+            "Not a PyTorch checkpoint - expected a zip archive or a legacy pickle file"
+        ));
+    }
+    
+    let mut reader = Cursor::new(&bytes[15..]);
+    let protocol = read_pickle(&mut reader)?;
+    if protocol != Object::Int(1001) {
+        return Err(InferenceError::unsupported_format(
+            format!("Unsupported legacy PyTorch protocol: {:?}", protocol)
+        ));
+    }
+    let _sys_info = read_pickle(&mut reader)?;
+    let root = read_pickle(&mut reader)?;
+    let storage_keys = match read_pickle(&mut reader)? {
+        Object::List(keys) => keys.into_iter()
+            .map(|key| key.unicode().map_err(|k| InferenceError::model_load(format!("Invalid storage key: {:?}", k))))
+            .collect::<Result<Vec<_>, _>>()?,
+        other => return Err(InferenceError::model_load(format!("Expected storage key list, got {:?}", other))),
+    };
+    
+    // Each storage is stored as an i64 element count followed by its raw data
+    let mut offset = 15 + reader.position() as usize;
+    let mut storages = std::collections::HashMap::new();
+    let mut infos = Vec::new();
+    collect_tensor_infos(unwrap_state_dict(root)?, "", &mut infos);
+    
+    for key in storage_keys {
+        let count_bytes: [u8; 8] = bytes.get(offset..offset + 8)
+            .and_then(|count| count.try_into().ok())
+            .ok_or_else(|| InferenceError::model_load("Truncated PyTorch checkpoint"))?;
+        let count = usize::try_from(i64::from_le_bytes(count_bytes))
+            .map_err(|_| InferenceError::model_load(format!("Negative element count for storage {} in PyTorch checkpoint", key)))?;
+        offset += 8;
+        
+        // All tensors of a storage share one dtype; default to bytes if unreferenced
+        let element_size = infos.iter()
+            .find(|info| storage_key(info) == key)
+            .map(|info| info.dtype.size_in_bytes())
+            .unwrap_or(1);
+        let end = count.checked_mul(element_size)
+            .and_then(|len| offset.checked_add(len))
+            .ok_or_else(|| InferenceError::model_load(format!("Storage {} in PyTorch checkpoint is too large", key)))?;
+        let data = bytes.get(offset..end)
+            .ok_or_else(|| InferenceError::model_load(format!("Truncated storage {} in PyTorch checkpoint", key)))?;
+        storages.insert(key, data);
+        offset = end;
+    }
+    
+    let mut tensors = Vec::with_capacity(infos.len());
+    for info in infos {
+        let data = storages.get(storage_key(&info))
+            .ok_or_else(|| InferenceError::model_load(format!("Missing storage for tensor {}", info.name)))?;
+        if !info.layout.is_contiguous() {
+            return Err(InferenceError::unsupported_format(
+                format!("Non-contiguous tensor {} is not supported", info.name)
+            ));
+        }
+        
+        let element_size = info.dtype.size_in_bytes();
+        let range = info.layout.start_offset().checked_mul(element_size)
+            .zip(info.layout.shape().elem_count().checked_mul(element_size))
+            .and_then(|(start, len)| Some(start..start.checked_add(len)?));
+        let raw = range.and_then(|range| data.get(range))
+            .ok_or_else(|| InferenceError::model_load(format!("Tensor {} exceeds its storage", info.name)))?;
+        
+        let tensor = CandleTensor::from_raw_buffer(raw, info.dtype, info.layout.dims(), device)
+            .map_err(|e| InferenceError::model_load(format!("Failed to create tensor {}: {}", info.name, e)))?;
+        tensors.push((info.name, tensor));
+    }
+    
+    Ok(tensors)
+}
+
+#[cfg(feature = "candle")]
+fn read_pickle(reader: &mut Cursor<&[u8]>) -> Result<Object, InferenceError> {
+    let mut stack = Stack::empty();
+    stack.read_loop(reader)
+        .and_then(|_| stack.finalize())
+        .map_err(|e| InferenceError::model_load(format!("Failed to parse PyTorch pickle: {}", e)))
+}
+
+/// Storage key of a legacy tensor; infos are built with an empty directory
+#[cfg(feature = "candle")]
+fn storage_key(info: &TensorInfo) -> &str {
+    info.path.trim_start_matches('/')
+}
+
+/// Strip a scripted-module wrapper and descend into a nested state dict
+#[cfg(feature = "candle")]
+fn unwrap_state_dict(root: Object) -> Result<Object, InferenceError> {
+    let root = match root {
+        Object::Build { callable, args } => match *callable {
+            Object::Reduce { callable, .. } if matches!(
+                &*callable,
+                Object::Class { module_name, class_name } if module_name == "__torch__" && class_name == "Module"
+            ) => *args,
+            callable => Object::Build { callable: Box::new(callable), args },
+        },
+        root => root,
+    };
+    
+    match root {
+        Object::Dict(entries) => {
+            let nested = entries.iter().position(|(key, value)| {
+                matches!(value, Object::Dict(_))
+                    && matches!(key, Object::Unicode(k) if STATE_DICT_KEYS.contains(&k.as_str()))
+            });
+            match nested {
+                Some(index) => entries.into_iter()
+                    .nth(index)
+                    .map(|(_, value)| value)
+                    .ok_or_else(|| InferenceError::model_load("Nested state dict disappeared while unwrapping")),
+                None => Ok(Object::Dict(entries)),
+            }
+        }
+        root => Ok(root),
+    }
+}
+
+/// Collect tensor infos from a state dict, flattening nested dicts with dotted names
+#[cfg(feature = "candle")]
+fn collect_tensor_infos(obj: Object, prefix: &str, infos: &mut Vec<TensorInfo>) {
+    let entries = match obj {
+        Object::Dict(entries) => entries,
+        _ => return,
+    };
+    
+    for (key, value) in entries {
+        let name = match &key {
+            Object::Unicode(name) if prefix.is_empty() => name.clone(),
+            Object::Unicode(name) => format!("{}.{}", prefix, name),
+            _ => continue,
+        };
+        
+        if matches!(value, Object::Dict(_)) {
+            collect_tensor_infos(value, &name, infos);
+            continue;
+        }
+        
+        match value.into_tensor_info(Object::Unicode(name.clone()), Path::new("")) {
+            Ok(Some(info)) => infos.push(info),
+            Ok(None) => {}
+            Err(e) => println!("⚠️ Skipping tensor '{}': {}", name, e),
+        }
+    }
+}
+
+/// Convert half-precision weights to F32 so they can be used with F32 inputs
+#[cfg(feature = "candle")]
+pub fn upcast_half(tensor: CandleTensor) -> Result<CandleTensor, InferenceError> {
+    match tensor.dtype() {
+        DType::F16 | DType::BF16 => tensor.to_dtype(DType::F32)
+            .map_err(|e| InferenceError::model_load(format!("Failed to convert tensor to F32: {}", e))),
+        _ => Ok(tensor),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    /// Pickled `_rebuild_tensor_v2` call for a 2-element f16 tensor in storage "0"
+    #[cfg(feature = "candle")]
+    const HALF_TENSOR_PICKLE: &[u8] = b"ctorch._utils\n_rebuild_tensor_v2\nq\x02(\
+        (X\x07\x00\x00\x00storageq\x03ctorch\nHalfStorage\nq\x04X\x01\x00\x00\x000q\x05X\x03\x00\x00\x00cpuq\x06K\x02Ntq\x07Q\
+        K\x00K\x02\x85q\x08K\x01\x85q\x09\x89ccollections\nOrderedDict\nq\x0a)Rq\x0btq\x0cRq\x0d";
+    
+    /// 1.0 and 2.0 as little-endian f16
+    #[cfg(feature = "candle")]
+    const HALF_TENSOR_DATA: [u8; 4] = [0x00, 0x3c, 0x00, 0x40];
+    
+    /// Pickle `{"w": tensor}`, nested under `"state_dict"` when `nested` is set
+    #[cfg(feature = "candle")]
+    fn state_dict_pickle(nested: bool) -> Vec<u8> {
+        let mut pickle = b"\x80\x02}q\x00".to_vec();
+        if nested {
+            pickle.extend_from_slice(b"X\x0a\x00\x00\x00state_dictq\x0e}q\x0f");
+        }
+        pickle.extend_from_slice(b"X\x01\x00\x00\x00wq\x01");
+        pickle.extend_from_slice(HALF_TENSOR_PICKLE);
+        pickle.extend_from_slice(if nested { b"ss." } else { b"s." });
+        pickle
+    }
+    
+    /// Write a zip checkpoint the way `torch.save` lays it out
+    #[cfg(feature = "candle")]
+    fn zip_checkpoint(pickle: &[u8]) -> Vec<u8> {
+        use std::io::Write;
+        use zip::write::SimpleFileOptions;
+        
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Stored);
+        zip.start_file("archive/data.pkl", options).unwrap();
+        zip.write_all(pickle).unwrap();
+        zip.start_file("archive/data/0", options).unwrap();
+        zip.write_all(&HALF_TENSOR_DATA).unwrap();
+        zip.finish().unwrap().into_inner()
+    }
+    
+    /// Assert that a state dict holds just `w = [1.0, 2.0]` in f16
+    #[cfg(feature = "candle")]
+    fn assert_half_tensor(tensors: &[(String, CandleTensor)]) {
+        assert_eq!(tensors.len(), 1);
+        assert_eq!(tensors[0].0, "w");
+        assert_eq!(tensors[0].1.dtype(), DType::F16);
+        let values: Vec<f32> = upcast_half(tensors[0].1.clone()).unwrap().to_vec1().unwrap();
+        assert_eq!(values, vec![1.0, 2.0]);
+    }
+    
+    #[test]
+    fn test_checkpoint_detection() {
+        let mut legacy = vec![0x80, 0x02, 0x8a, 0x0a];
+        legacy.extend_from_slice(&LEGACY_MAGIC);
+        legacy.extend_from_slice(b".\x80\x02");
+        assert!(is_legacy_checkpoint(&legacy));
+        assert!(!is_zip_checkpoint(&legacy));
+        
+        assert!(is_zip_checkpoint(b"PK\x03\x04rest"));
+        assert!(!is_legacy_checkpoint(b"\x80\x02}q\x00"));
+    }
+    
+    #[cfg(feature = "candle")]
+    #[test]
+    fn test_legacy_state_dict() {
+        // torch.save({"w": torch.tensor([1.0, 2.0], dtype=torch.float16)}, f, _use_new_zipfile_serialization=False)
+        let mut bytes = vec![0x80, 0x02, 0x8a, 0x0a];
+        bytes.extend_from_slice(&LEGACY_MAGIC);
+        bytes.push(b'.');
+        bytes.extend_from_slice(b"\x80\x02M\xe9\x03.");
+        bytes.extend_from_slice(b"\x80\x02}q\x00.");
+        bytes.extend_from_slice(b"\x80\x02}q\x00X\x01\x00\x00\x00wq\x01");
+        bytes.extend_from_slice(b"ctorch._utils\n_rebuild_tensor_v2\nq\x02(");
+        bytes.extend_from_slice(b"(X\x07\x00\x00\x00storageq\x03ctorch\nHalfStorage\nq\x04X\x01\x00\x00\x000q\x05X\x03\x00\x00\x00cpuq\x06K\x02Ntq\x07Q");
+        bytes.extend_from_slice(b"K\x00K\x02\x85q\x08K\x01\x85q\x09\x89ccollections\nOrderedDict\nq\x0a)Rq\x0btq\x0cRq\x0ds.");
+        bytes.extend_from_slice(b"\x80\x02]q\x00X\x01\x00\x00\x000q\x01a.");
+        bytes.extend_from_slice(&2i64.to_le_bytes());
+        // 1.0 and 2.0 as little-endian f16
+        bytes.extend_from_slice(&[0x00, 0x3c, 0x00, 0x40]);
+        
+        let tensors = read_state_dict_from_bytes(&bytes, &Device::Cpu).unwrap();
+        assert_eq!(tensors.len(), 1);
+        assert_eq!(tensors[0].0, "w");
+        assert_eq!(tensors[0].1.dtype(), DType::F16);
+        
+        let values: Vec<f32> = upcast_half(tensors[0].1.clone()).unwrap().to_vec1().unwrap();
+        assert_eq!(values, vec![1.0, 2.0]);
+    }
+    
+    #[cfg(feature = "candle")]
+    #[test]
+    fn test_zip_state_dict() {
+        let bytes = zip_checkpoint(&state_dict_pickle(false));
+        assert!(is_zip_checkpoint(&bytes));
+        assert_half_tensor(&read_state_dict_from_bytes(&bytes, &Device::Cpu).unwrap());
+    }
+    
+    #[cfg(feature = "candle")]
+    #[test]
+    fn test_nested_state_dict() {
+        // torch.save({"state_dict": {"w": ...}}, f)
+        let bytes = zip_checkpoint(&state_dict_pickle(true));
+        assert_half_tensor(&read_state_dict_from_bytes(&bytes, &Device::Cpu).unwrap());
+        
+        // The same checkpoint in the legacy format
+        let mut bytes = vec![0x80, 0x02, 0x8a, 0x0a];
+        bytes.extend_from_slice(&LEGACY_MAGIC);
+        bytes.push(b'.');
+        bytes.extend_from_slice(b"\x80\x02M\xe9\x03.");
+        bytes.extend_from_slice(b"\x80\x02}q\x00.");
+        bytes.extend_from_slice(&state_dict_pickle(true));
+        bytes.extend_from_slice(b"\x80\x02]q\x00X\x01\x00\x00\x000q\x01a.");
+        bytes.extend_from_slice(&2i64.to_le_bytes());
+        bytes.extend_from_slice(&HALF_TENSOR_DATA);
+        assert_half_tensor(&read_state_dict_from_bytes(&bytes, &Device::Cpu).unwrap());
+    }
+    
+    #[cfg(feature = "candle")]
+    #[test]
+    fn test_negative_storage_count_is_rejected() {
+        let mut bytes = vec![0x80, 0x02, 0x8a, 0x0a];
+        bytes.extend_from_slice(&LEGACY_MAGIC);
+        bytes.push(b'.');
+        bytes.extend_from_slice(b"\x80\x02M\xe9\x03.");
+        bytes.extend_from_slice(b"\x80\x02}q\x00.");
+        bytes.extend_from_slice(&state_dict_pickle(false));
+        bytes.extend_from_slice(b"\x80\x02]q\x00X\x01\x00\x00\x000q\x01a.");
+        bytes.extend_from_slice(&(-2i64).to_le_bytes());
+        bytes.extend_from_slice(&HALF_TENSOR_DATA);
+        
+        let err = read_state_dict_from_bytes(&bytes, &Device::Cpu).unwrap_err();
+        assert!(matches!(err, InferenceError::ModelLoad(_)));
+        assert!(err.to_string().contains("Negative element count"));
+    }
+}
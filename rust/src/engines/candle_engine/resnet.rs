@@ -0,0 +1,212 @@
+use crate::models::{InferenceError, PredictContext, ResNetVariant};
+use candle_core::{Result, Tensor, D};
+use candle_nn::{BatchNorm, Conv2d, Conv2dConfig, Linear, Module, VarBuilder};
+use super::forward_error;
+
+/// Convolution without bias followed by batch norm
+#[derive(Debug)]
+struct ConvBn {
+    conv: Conv2d,
+    bn: BatchNorm,
+}
+
+impl ConvBn {
+    /// Load `{conv}.weight` and `{bn}.*`, padding odd kernels to keep the size
+    fn load(c_in: usize, c_out: usize, kernel: usize, stride: usize, vb: &VarBuilder, conv: &str, bn: &str) -> Result<Self> {
+        let config = Conv2dConfig { stride, padding: kernel / 2, ..Default::default() };
+        let conv = candle_nn::conv2d_no_bias(c_in, c_out, kernel, config, vb.pp(conv))?;
+        let bn = candle_nn::batch_norm(c_out, 1e-5, vb.pp(bn))?;
+        Ok(Self { conv, bn })
+    }
+    
+    fn forward(&self, xs: &Tensor) -> Result<Tensor> {
+        xs.apply(&self.conv)?.apply_t(&self.bn, false)
+    }
+}
+
+/// Residual block: basic (two 3x3 convs) or bottleneck (1x1, 3x3, 1x1)
+/// 
+/// Every conv but the last is followed by a ReLU; the shortcut is added
+/// before the final ReLU.
+#[derive(Debug)]
+struct Block {
+    layers: Vec<ConvBn>,
+    downsample: Option<ConvBn>,
+}
+
+impl Block {
+    fn basic(c_in: usize, c_out: usize, stride: usize, vb: VarBuilder) -> Result<Self> {
+        let layers = vec![
+            ConvBn::load(c_in, c_out, 3, stride, &vb, "conv1", "bn1")?,
+            ConvBn::load(c_out, c_out, 3, 1, &vb, "conv2", "bn2")?,
+        ];
+        Ok(Self { layers, downsample: Self::downsample(c_in, c_out, stride, vb)? })
+    }
+    
+    fn bottleneck(c_in: usize, c_out: usize, stride: usize, vb: VarBuilder) -> Result<Self> {
+        let layers = vec![
+            ConvBn::load(c_in, c_out, 1, 1, &vb, "conv1", "bn1")?,
+            ConvBn::load(c_out, c_out, 3, stride, &vb, "conv2", "bn2")?,
+            ConvBn::load(c_out, 4 * c_out, 1, 1, &vb, "conv3", "bn3")?,
+        ];
+        Ok(Self { layers, downsample: Self::downsample(c_in, 4 * c_out, stride, vb)? })
+    }
+    
+    fn downsample(c_in: usize, c_out: usize, stride: usize, vb: VarBuilder) -> Result<Option<ConvBn>> {
+        if stride == 1 && c_in == c_out {
+            return Ok(None);
+        }
+        Ok(Some(ConvBn::load(c_in, c_out, 1, stride, &vb.pp("downsample"), "0", "1")?))
+    }
+}
+
+impl Module for Block {
+    fn forward(&self, xs: &Tensor) -> Result<Tensor> {
+        let mut ys = xs.clone();
+        for (ix, layer) in self.layers.iter().enumerate() {
+            ys = layer.forward(&ys)?;
+            if ix + 1 < self.layers.len() {
+                ys = ys.relu()?;
+            }
+        }
+        let shortcut = match &self.downsample {
+            Some(downsample) => downsample.forward(xs)?,
+            None => xs.clone(),
+        };
+        (shortcut + ys)?.relu()
+    }
+}
+
+/// ResNet network for any `ResNetVariant` in the timm/torchvision weight layout
+/// 
+/// Same weights and numerics as `candle_transformers::models::resnet`, but the
+/// residual blocks are kept separate so inference can stop between them.
+#[derive(Debug)]
+pub struct ResNet {
+    stem: ConvBn,
+    blocks: Vec<Block>,
+    fc: Option<Linear>,
+}
+
+impl ResNet {
+    /// Build the network for a variant
+    /// 
+    /// `num_classes` is the classifier width, or `None` to return pooled features.
+    pub fn load(variant: &ResNetVariant, num_classes: Option<usize>, vb: VarBuilder) -> Result<Self> {
+        let (counts, bottleneck) = match variant {
+            ResNetVariant::ResNet18 => ([2, 2, 2, 2], false),
+            ResNetVariant::ResNet34 => ([3, 4, 6, 3], false),
+            ResNetVariant::ResNet50 => ([3, 4, 6, 3], true),
+            ResNetVariant::ResNet101 => ([3, 4, 23, 3], true),
+            ResNetVariant::ResNet152 => ([3, 8, 36, 3], true),
+        };
+        let expansion = if bottleneck { 4 } else { 1 };
+        
+        let stem = ConvBn::load(3, 64, 7, 2, &vb, "conv1", "bn1")?;
+        let mut blocks = Vec::new();
+        let mut c_in = 64;
+        for (stage, (&count, c_out)) in counts.iter().zip([64, 128, 256, 512]).enumerate() {
+            let stage_vb = vb.pp(format!("layer{}", stage + 1));
+            for index in 0..count {
+                let stride = if index == 0 && stage > 0 { 2 } else { 1 };
+                let block_vb = stage_vb.pp(index);
+                blocks.push(if bottleneck {
+                    Block::bottleneck(c_in, c_out, stride, block_vb)?
+                } else {
+                    Block::basic(c_in, c_out, stride, block_vb)?
+                });
+                c_in = expansion * c_out;
+            }
+        }
+        let fc = match num_classes {
+            Some(n) => Some(candle_nn::linear(c_in, n, vb.pp("fc"))?),
+            None => None,
+        };
+        Ok(Self { stem, blocks, fc })
+    }
+    
+    /// Run the network, checking for cancellation before each residual block
+    pub fn forward_with_context(&self, xs: &Tensor, ctx: &PredictContext) -> std::result::Result<Tensor, InferenceError> {
+        let forward_error = |e| forward_error("ResNet forward pass failed", e);
+        let mut xs = self.forward_stem(xs).map_err(forward_error)?;
+        for block in &self.blocks {
+            ctx.check()?;
+            xs = block.forward(&xs).map_err(forward_error)?;
+        }
+        ctx.check()?;
+        self.forward_head(&xs).map_err(forward_error)
+    }
+    
+    /// 7x7 convolution and 3x3 max pool
+    fn forward_stem(&self, xs: &Tensor) -> Result<Tensor> {
+        self.stem.forward(xs)?
+            .relu()?
+            .pad_with_same(D::Minus1, 1, 1)?
+            .pad_with_same(D::Minus2, 1, 1)?
+            .max_pool2d_with_stride(3, 2)
+    }
+    
+    /// Global average pool and the optional classifier
+    fn forward_head(&self, xs: &Tensor) -> Result<Tensor> {
+        let xs = xs.mean(D::Minus1)?.mean(D::Minus1)?;
+        match &self.fc {
+            Some(fc) => xs.apply(fc),
+            None => Ok(xs),
+        }
+    }
+}
+
+impl Module for ResNet {
+    fn forward(&self, xs: &Tensor) -> Result<Tensor> {
+        let mut xs = self.forward_stem(xs)?;
+        for block in &self.blocks {
+            xs = block.forward(&xs)?;
+        }
+        self.forward_head(&xs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CancellationToken;
+    use candle_core::{DType, Device};
+    use candle_transformers::models::resnet;
+    
+    #[test]
+    fn test_matches_candle_transformers() {
+        let xs = Tensor::rand(0f32, 1f32, (2, 3, 64, 64), &Device::Cpu).unwrap();
+        for (variant, reference) in [
+            (ResNetVariant::ResNet18, resnet::resnet18 as fn(usize, VarBuilder) -> Result<candle_nn::Func>),
+            (ResNetVariant::ResNet50, resnet::resnet50),
+        ] {
+            let varmap = candle_nn::VarMap::new();
+            let vb = VarBuilder::from_varmap(&varmap, DType::F32, &Device::Cpu);
+            let expected = reference(10, vb.clone()).unwrap().forward(&xs).unwrap();
+            
+            // Loading from the same varmap reuses the reference weights by name
+            let count = varmap.all_vars().len();
+            let model = ResNet::load(&variant, Some(10), vb).unwrap();
+            assert_eq!(varmap.all_vars().len(), count, "{:?} created weights the reference lacks", variant);
+            
+            let actual = model.forward(&xs).unwrap();
+            let diff = (actual - expected).unwrap().abs().unwrap().max_all().unwrap().to_scalar::<f32>().unwrap();
+            assert!(diff < 1e-4, "{:?} differs from the reference by {}", variant, diff);
+        }
+    }
+    
+    #[test]
+    fn test_forward_with_context_cancelled() {
+        let varmap = candle_nn::VarMap::new();
+        let vb = VarBuilder::from_varmap(&varmap, DType::F32, &Device::Cpu);
+        let model = ResNet::load(&ResNetVariant::ResNet18, None, vb).unwrap();
+        let xs = Tensor::rand(0f32, 1f32, (1, 3, 32, 32), &Device::Cpu).unwrap();
+        
+        let token = CancellationToken::new();
+        let ctx = PredictContext::new().with_token(token.clone());
+        assert_eq!(model.forward_with_context(&xs, &ctx).unwrap().dims(), &[1, 512]);
+        
+        token.cancel();
+        assert!(matches!(model.forward_with_context(&xs, &ctx), Err(InferenceError::Cancelled(_))));
+    }
+}
@@ -0,0 +1,429 @@
+use crate::models::InferenceError;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+use super::grammar::Grammar;
+
+/// Decoding settings shared by the decoder-only wrappers
+/// 
+/// The default is greedy decoding of up to 256 tokens with no penalties and
+/// no stop sequences.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenerationConfig {
+    /// Maximum number of tokens to generate
+    pub max_new_tokens: usize,
+    /// Softmax temperature; `0.0` always picks the most likely token
+    pub temperature: f32,
+    /// Keep only the `k` most likely tokens
+    pub top_k: Option<usize>,
+    /// Keep the smallest set of tokens whose probabilities add up to `p`
+    pub top_p: Option<f32>,
+    /// Drop tokens less likely than `min_p` times the most likely one
+    pub min_p: Option<f32>,
+    /// Divide positive (multiply negative) logits of recent tokens; `1.0` disables it
+    pub repetition_penalty: f32,
+    /// Subtract from the logits of recent tokens; `0.0` disables it
+    pub presence_penalty: f32,
+    /// How many recent tokens, prompt included, the penalties look at
+    pub penalty_last_n: usize,
+    /// Seed for reproducible sampling; drawn from the OS when `None`
+    pub seed: Option<u64>,
+    /// Stop once the completion contains any of these strings
+    pub stop_sequences: Vec<String>,
+    /// Only generate text this grammar accepts; end of sequence is allowed
+    /// once the grammar is complete
+    pub grammar: Option<Grammar>,
+}
+
+impl Default for GenerationConfig {
+    fn default() -> Self {
+        Self {
+            max_new_tokens: 256,
+            temperature: 0.0,
+            top_k: None,
+            top_p: None,
+            min_p: None,
+            repetition_penalty: 1.0,
+            presence_penalty: 0.0,
+            penalty_last_n: 64,
+            seed: None,
+            stop_sequences: Vec::new(),
+            grammar: None,
+        }
+    }
+}
+
+impl GenerationConfig {
+    /// Set the maximum number of new tokens
+    pub fn with_max_new_tokens(mut self, max_new_tokens: usize) -> Self {
+        self.max_new_tokens = max_new_tokens;
+        self
+    }
+    
+    /// Set the sampling temperature (`0.0` for greedy decoding)
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+    
+    /// Enable top-k filtering
+    pub fn with_top_k(mut self, top_k: usize) -> Self {
+        self.top_k = Some(top_k);
+        self
+    }
+    
+    /// Enable nucleus (top-p) filtering
+    pub fn with_top_p(mut self, top_p: f32) -> Self {
+        self.top_p = Some(top_p);
+        self
+    }
+    
+    /// Enable min-p filtering
+    pub fn with_min_p(mut self, min_p: f32) -> Self {
+        self.min_p = Some(min_p);
+        self
+    }
+    
+    /// Set the repetition penalty
+    pub fn with_repetition_penalty(mut self, penalty: f32) -> Self {
+        self.repetition_penalty = penalty;
+        self
+    }
+    
+    /// Set the presence penalty
+    pub fn with_presence_penalty(mut self, penalty: f32) -> Self {
+        self.presence_penalty = penalty;
+        self
+    }
+    
+    /// Set how many recent tokens the penalties consider
+    pub fn with_penalty_last_n(mut self, last_n: usize) -> Self {
+        self.penalty_last_n = last_n;
+        self
+    }
+    
+    /// Seed the random number generator
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+    
+    /// Add a stop sequence
+    pub fn with_stop_sequence<S: Into<String>>(mut self, stop: S) -> Self {
+        self.stop_sequences.push(stop.into());
+        self
+    }
+    
+    /// Constrain the output to a grammar
+    pub fn with_grammar(mut self, grammar: Grammar) -> Self {
+        self.grammar = Some(grammar);
+        self
+    }
+    
+    /// Whether tokens are picked by argmax rather than sampled
+    pub fn is_greedy(&self) -> bool {
+        self.temperature == 0.0
+    }
+    
+    /// Check that every setting is in range
+    pub fn validate(&self) -> Result<(), InferenceError> {
+        if self.temperature.is_nan() || self.temperature < 0.0 {
+            return Err(InferenceError::configuration(format!("temperature must be >= 0, got {}", self.temperature)));
+        }
+        if self.top_k == Some(0) {
+            return Err(InferenceError::configuration("top_k must be at least 1"));
+        }
+        if let Some(top_p) = self.top_p {
+            if !(top_p > 0.0 && top_p <= 1.0) {
+                return Err(InferenceError::configuration(format!("top_p must be in (0, 1], got {}", top_p)));
+            }
+        }
+        if let Some(min_p) = self.min_p {
+            if !(0.0..=1.0).contains(&min_p) {
+                return Err(InferenceError::configuration(format!("min_p must be in [0, 1], got {}", min_p)));
+            }
+        }
+        if self.repetition_penalty.is_nan() || self.repetition_penalty <= 0.0 {
+            return Err(InferenceError::configuration(format!(
+                "repetition_penalty must be > 0, got {}", self.repetition_penalty
+            )));
+        }
+        if !self.presence_penalty.is_finite() {
+            return Err(InferenceError::configuration("presence_penalty must be finite"));
+        }
+        Ok(())
+    }
+}
+
+fn log_softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let log_sum = logits.iter().map(|&l| (l - max).exp()).sum::<f32>().ln() + max;
+    logits.iter().map(|&l| l - log_sum).collect()
+}
+
+fn argmax(logits: &[f32]) -> u32 {
+    logits.iter()
+        .enumerate()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(id, _)| id as u32)
+        .unwrap_or(0)
+}
+
+/// Picks next tokens according to a [`GenerationConfig`]
+/// 
+/// Logits are penalised, then either reduced to their argmax or scaled by
+/// the temperature, filtered by top-k, top-p and min-p, and sampled.
+pub struct Sampler {
+    config: GenerationConfig,
+    rng: StdRng,
+}
+
+impl std::fmt::Debug for Sampler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Sampler")
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl Sampler {
+    /// Create a sampler, validating the configuration
+    pub fn new(config: &GenerationConfig) -> Result<Self, InferenceError> {
+        config.validate()?;
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Ok(Self {
+            config: config.clone(),
+            rng,
+        })
+    }
+    
+    /// Choose the next token from `[vocab]` logits given the sequence so far
+    /// 
+    /// Returns the token with its log probability under the model's own
+    /// distribution, before penalties and temperature.
+    pub fn sample(&mut self, logits: &[f32], tokens: &[u32]) -> Result<(u32, f32), InferenceError> {
+        if logits.is_empty() {
+            return Err(InferenceError::prediction("Model returned empty logits"));
+        }
+        let logprobs = log_softmax(logits);
+        
+        let mut logits = logits.to_vec();
+        self.apply_penalties(&mut logits, tokens);
+        
+        let token = if self.config.is_greedy() {
+            argmax(&logits)
+        } else {
+            let candidates = self.candidates(&logits);
+            self.draw(&candidates).unwrap_or_else(|| argmax(&logits))
+        };
+        Ok((token, logprobs[token as usize]))
+    }
+    
+    /// Penalise every distinct token among the last `penalty_last_n`
+    fn apply_penalties(&self, logits: &mut [f32], tokens: &[u32]) {
+        let repetition = self.config.repetition_penalty;
+        let presence = self.config.presence_penalty;
+        if repetition == 1.0 && presence == 0.0 {
+            return;
+        }
+        
+        let start = tokens.len().saturating_sub(self.config.penalty_last_n);
+        let recent: HashSet<u32> = tokens[start..].iter().copied().collect();
+        for token in recent {
+            if let Some(logit) = logits.get_mut(token as usize) {
+                if *logit > 0.0 {
+                    *logit /= repetition;
+                } else {
+                    *logit *= repetition;
+                }
+                *logit -= presence;
+            }
+        }
+    }
+    
+    /// Tokens left after temperature and filtering, most likely first, with normalised probabilities
+    fn candidates(&self, logits: &[f32]) -> Vec<(u32, f32)> {
+        let mut candidates: Vec<(u32, f32)> = logits.iter()
+            .enumerate()
+            .filter(|(_, l)| **l > f32::NEG_INFINITY)
+            .map(|(id, &l)| (id as u32, l))
+            .collect();
+        if candidates.is_empty() {
+            return candidates;
+        }
+        candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+        
+        if let Some(k) = self.config.top_k {
+            candidates.truncate(k);
+        }
+        
+        let max = candidates[0].1;
+        for candidate in candidates.iter_mut() {
+            candidate.1 = ((candidate.1 - max) / self.config.temperature).exp();
+        }
+        normalize(&mut candidates);
+        
+        if let Some(top_p) = self.config.top_p {
+            let mut cumulative = 0.0;
+            let keep = candidates.iter()
+                .position(|&(_, p)| {
+                    cumulative += p;
+                    cumulative >= top_p
+                })
+                .map(|i| i + 1)
+                .unwrap_or(candidates.len());
+            candidates.truncate(keep);
+        }
+        
+        if let Some(min_p) = self.config.min_p {
+            let threshold = candidates[0].1 * min_p;
+            candidates.retain(|&(_, p)| p >= threshold);
+        }
+        
+        normalize(&mut candidates);
+        candidates
+    }
+    
+    /// Draw a token from normalised candidates
+    fn draw(&mut self, candidates: &[(u32, f32)]) -> Option<u32> {
+        let mut r: f32 = self.rng.gen();
+        for &(token, p) in candidates {
+            if r < p {
+                return Some(token);
+            }
+            r -= p;
+        }
+        // Rounding can leave a sliver past the last candidate
+        candidates.last().map(|&(token, _)| token)
+    }
+}
+
+fn normalize(candidates: &mut [(u32, f32)]) {
+    let total: f32 = candidates.iter().map(|&(_, p)| p).sum();
+    if total > 0.0 {
+        for candidate in candidates.iter_mut() {
+            candidate.1 /= total;
+        }
+    }
+}
+
+/// Byte offset of the earliest stop sequence in `text`
+pub(crate) fn find_stop_sequence(text: &str, stop_sequences: &[String]) -> Option<usize> {
+    stop_sequences.iter()
+        .filter(|stop| !stop.is_empty())
+        .filter_map(|stop| text.find(stop.as_str()))
+        .min()
+}
+
+/// Length of the longest suffix of `text` that could be the start of a stop sequence
+pub(crate) fn partial_stop_sequence_len(text: &str, stop_sequences: &[String]) -> usize {
+    stop_sequences.iter()
+        .filter_map(|stop| {
+            (1..stop.len())
+                .rev()
+                .filter(|&n| stop.is_char_boundary(n))
+                .find(|&n| text.ends_with(&stop[..n]))
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const LOGITS: [f32; 5] = [1.0, 3.0, 2.0, 0.5, -1.0];
+    
+    fn candidate_ids(sampler: &Sampler, logits: &[f32]) -> Vec<u32> {
+        sampler.candidates(logits).into_iter().map(|(id, _)| id).collect()
+    }
+    
+    #[test]
+    fn test_greedy_picks_argmax_with_model_logprob() {
+        let mut sampler = Sampler::new(&GenerationConfig::default()).unwrap();
+        let (token, logprob) = sampler.sample(&LOGITS, &[]).unwrap();
+        assert_eq!(token, 1);
+        assert!((logprob - log_softmax(&LOGITS)[1]).abs() < 1e-6);
+    }
+    
+    #[test]
+    fn test_top_k_top_p_and_min_p_filters() {
+        let config = GenerationConfig::default().with_temperature(1.0);
+        
+        let sampler = Sampler::new(&config.clone().with_top_k(2)).unwrap();
+        assert_eq!(candidate_ids(&sampler, &LOGITS), vec![1, 2]);
+        
+        // Probabilities are roughly [0.09, 0.64, 0.23, 0.05, 0.01]
+        let sampler = Sampler::new(&config.clone().with_top_p(0.8)).unwrap();
+        assert_eq!(candidate_ids(&sampler, &LOGITS), vec![1, 2]);
+        let sampler = Sampler::new(&config.clone().with_top_p(0.5)).unwrap();
+        assert_eq!(candidate_ids(&sampler, &LOGITS), vec![1]);
+        
+        let sampler = Sampler::new(&config.clone().with_min_p(0.3)).unwrap();
+        assert_eq!(candidate_ids(&sampler, &LOGITS), vec![1, 2]);
+        
+        let candidates = Sampler::new(&config).unwrap().candidates(&LOGITS);
+        let total: f32 = candidates.iter().map(|&(_, p)| p).sum();
+        assert!((total - 1.0).abs() < 1e-5);
+    }
+    
+    #[test]
+    fn test_temperature_sharpens_distribution() {
+        let hot = Sampler::new(&GenerationConfig::default().with_temperature(2.0)).unwrap().candidates(&LOGITS);
+        let cold = Sampler::new(&GenerationConfig::default().with_temperature(0.5)).unwrap().candidates(&LOGITS);
+        assert!(cold[0].1 > hot[0].1);
+    }
+    
+    #[test]
+    fn test_penalties_steer_away_from_recent_tokens() {
+        let mut sampler = Sampler::new(&GenerationConfig::default().with_repetition_penalty(2.0)).unwrap();
+        assert_eq!(sampler.sample(&LOGITS, &[1]).unwrap().0, 2);
+        
+        let mut sampler = Sampler::new(&GenerationConfig::default().with_presence_penalty(1.5)).unwrap();
+        assert_eq!(sampler.sample(&LOGITS, &[1]).unwrap().0, 2);
+        
+        // Tokens outside the penalty window are left alone
+        let config = GenerationConfig::default()
+            .with_repetition_penalty(2.0)
+            .with_penalty_last_n(1);
+        let mut sampler = Sampler::new(&config).unwrap();
+        assert_eq!(sampler.sample(&LOGITS, &[1, 4]).unwrap().0, 1);
+    }
+    
+    #[test]
+    fn test_seeded_sampling_is_reproducible() {
+        let config = GenerationConfig::default().with_temperature(1.0).with_seed(42);
+        let draws = |config: &GenerationConfig| {
+            let mut sampler = Sampler::new(config).unwrap();
+            (0..32).map(|_| sampler.sample(&LOGITS, &[]).unwrap().0).collect::<Vec<_>>()
+        };
+        
+        let first = draws(&config);
+        assert_eq!(first, draws(&config));
+        // With five plausible tokens, 32 draws are not all the same
+        assert!(first.iter().any(|&t| t != first[0]));
+    }
+    
+    #[test]
+    fn test_invalid_config_rejected() {
+        assert!(Sampler::new(&GenerationConfig::default().with_temperature(-1.0)).is_err());
+        assert!(Sampler::new(&GenerationConfig::default().with_top_k(0)).is_err());
+        assert!(Sampler::new(&GenerationConfig::default().with_top_p(1.5)).is_err());
+        assert!(Sampler::new(&GenerationConfig::default().with_min_p(-0.1)).is_err());
+        assert!(Sampler::new(&GenerationConfig::default().with_repetition_penalty(0.0)).is_err());
+    }
+    
+    #[test]
+    fn test_stop_sequence_helpers() {
+        let stops = vec!["\nUser:".to_string(), "###".to_string()];
+        assert_eq!(find_stop_sequence("Hi there\nUser: ok", &stops), Some(8));
+        assert_eq!(find_stop_sequence("Hi there", &stops), None);
+        assert_eq!(partial_stop_sequence_len("Hi there\nUs", &stops), 3);
+        assert_eq!(partial_stop_sequence_len("Hi ##", &stops), 2);
+        assert_eq!(partial_stop_sequence_len("Hi there", &stops), 0);
+    }
+}
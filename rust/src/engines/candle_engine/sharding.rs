@@ -0,0 +1,314 @@
+use crate::models::InferenceError;
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use candle_core::safetensors::{Load, MmapedSafetensors};
+use candle_core::{Device, Tensor as CandleTensor};
+use serde::Deserialize;
+
+use super::model_wrappers::{download_from_huggingface, download_from_huggingface_with_progress};
+
+/// Suffix of the index that lists the shards of a SafeTensors checkpoint
+pub const INDEX_SUFFIX: &str = ".index.json";
+
+/// Whether `filename` names a shard index rather than a weights file
+pub fn is_index_filename(filename: &str) -> bool {
+    filename.ends_with(INDEX_SUFFIX)
+}
+
+/// Name of the index that replaces `filename` when the checkpoint is sharded
+/// 
+/// `model.safetensors` becomes `model.safetensors.index.json`, which is how
+/// `transformers` names the index next to `model-00001-of-0000N.safetensors`.
+pub fn index_filename(filename: &str) -> String {
+    format!("{}{}", filename, INDEX_SUFFIX)
+}
+
+/// Metadata block of a shard index
+#[derive(Debug, Clone, Default, Deserialize)]
+struct IndexMetadata {
+    #[serde(default)]
+    total_size: Option<u64>,
+}
+
+/// Index of a sharded SafeTensors checkpoint (`model.safetensors.index.json`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShardIndex {
+    #[serde(default)]
+    metadata: IndexMetadata,
+    /// Tensor name to the shard file that holds it
+    weight_map: HashMap<String, String>,
+}
+
+impl ShardIndex {
+    /// Parse an index from its JSON text
+    pub fn parse(json: &str) -> Result<Self, InferenceError> {
+        let index: Self = serde_json::from_str(json)
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse SafeTensors index: {}", e)))?;
+        if index.weight_map.is_empty() {
+            return Err(InferenceError::model_load("SafeTensors index has an empty weight_map"));
+        }
+        Ok(index)
+    }
+    
+    /// Read an index file
+    pub fn from_file(path: &Path) -> Result<Self, InferenceError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| InferenceError::model_load(format!("Failed to read SafeTensors index {}: {}", path.display(), e)))?;
+        Self::parse(&json)
+    }
+    
+    /// Total size of all tensors in bytes, as recorded by the index
+    pub fn total_size(&self) -> Option<u64> {
+        self.metadata.total_size
+    }
+    
+    /// Shard file holding `tensor`
+    pub fn shard_for(&self, tensor: &str) -> Option<&str> {
+        self.weight_map.get(tensor).map(|s| s.as_str())
+    }
+    
+    /// Names of all tensors in the checkpoint
+    pub fn tensor_names(&self) -> impl Iterator<Item = &str> {
+        self.weight_map.keys().map(|s| s.as_str())
+    }
+    
+    /// Unique shard files in name order
+    pub fn shard_files(&self) -> Vec<&str> {
+        self.weight_map.values()
+            .map(|s| s.as_str())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect()
+    }
+    
+    /// Shard paths resolved against the directory holding the index
+    pub fn shard_paths(&self, index_path: &Path) -> Vec<PathBuf> {
+        let dir = index_path.parent().unwrap_or_else(|| Path::new(""));
+        self.shard_files().into_iter().map(|shard| dir.join(shard)).collect()
+    }
+}
+
+/// Combined progress while fetching the files of a checkpoint
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShardProgress {
+    /// File being downloaded
+    pub filename: String,
+    /// Number of shards fully downloaded so far
+    pub completed_shards: usize,
+    /// Number of shards in the checkpoint
+    pub total_shards: usize,
+    /// Bytes of all shards downloaded so far
+    pub downloaded_bytes: u64,
+    /// Expected size of the whole checkpoint, if known
+    pub total_bytes: Option<u64>,
+}
+
+impl ShardProgress {
+    /// Progress as a percentage (0-100)
+    /// 
+    /// Uses bytes when the total is known and the shard count otherwise.
+    pub fn percentage(&self) -> f64 {
+        let fraction = match self.total_bytes {
+            Some(total) if total > 0 => self.downloaded_bytes as f64 / total as f64,
+            _ if self.total_shards > 0 => self.completed_shards as f64 / self.total_shards as f64,
+            _ => 0.0,
+        };
+        (fraction * 100.0).min(100.0)
+    }
+}
+
+/// Path of `shard` in the repository, relative to the index `index_name`
+fn sibling_file(index_name: &str, shard: &str) -> String {
+    match index_name.rsplit_once('/') {
+        Some((dir, _)) => format!("{}/{}", dir, shard),
+        None => shard.to_string(),
+    }
+}
+
+/// Download the SafeTensors weights `filename` from a HuggingFace repository
+/// 
+/// Falls back to `<filename>.index.json` when the repository does not have the
+/// single file, then downloads every shard it lists. `filename` may also name
+/// the index directly. `on_progress` is called as bytes arrive with the
+/// combined progress.
+pub async fn download_safetensors(
+    repo_id: &str,
+    filename: &str,
+    on_progress: &mut (dyn FnMut(&ShardProgress) + Send),
+) -> Result<Vec<PathBuf>, InferenceError> {
+    let index_name = if is_index_filename(filename) {
+        filename.to_string()
+    } else {
+        let mut progress = ShardProgress {
+            filename: filename.to_string(),
+            completed_shards: 0,
+            total_shards: 1,
+            downloaded_bytes: 0,
+            total_bytes: None,
+        };
+        let result = download_from_huggingface_with_progress(repo_id, filename, &mut |downloaded, total| {
+            progress.downloaded_bytes = downloaded;
+            progress.total_bytes = total;
+            on_progress(&progress);
+        }).await;
+        match result {
+            Ok(path) => {
+                progress.completed_shards = 1;
+                on_progress(&progress);
+                return Ok(vec![path]);
+            }
+            // Only a missing file means the checkpoint may be sharded
+            Err(InferenceError::ResourceNotFound(_)) if filename.ends_with(".safetensors") => {}
+            Err(e) => return Err(e),
+        }
+        println!("🔎 {} not found in {}, looking for a sharded checkpoint", filename, repo_id);
+        index_filename(filename)
+    };
+    
+    let index_path = download_from_huggingface(repo_id, &index_name).await?;
+    let index = ShardIndex::from_file(&index_path)?;
+    let shards = index.shard_files();
+    println!("🧩 Downloading {} SafeTensors shards from {}", shards.len(), repo_id);
+    
+    let mut progress = ShardProgress {
+        filename: index_name.clone(),
+        completed_shards: 0,
+        total_shards: shards.len(),
+        downloaded_bytes: 0,
+        total_bytes: index.total_size(),
+    };
+    let mut paths = Vec::with_capacity(shards.len());
+    let mut finished_bytes = 0;
+    for shard in shards {
+        progress.filename = shard.to_string();
+        let path = download_from_huggingface_with_progress(repo_id, &sibling_file(&index_name, shard), &mut |downloaded, _| {
+            progress.downloaded_bytes = finished_bytes + downloaded;
+            on_progress(&progress);
+        }).await?;
+        finished_bytes = progress.downloaded_bytes;
+        progress.completed_shards += 1;
+        on_progress(&progress);
+        paths.push(path);
+    }
+    Ok(paths)
+}
+
+/// Load the tensors of several SafeTensors files into one weight map
+/// 
+/// Files are memory-mapped and copied out one tensor at a time, so peak
+/// memory stays close to the model size. A tensor name may appear in only
+/// one file.
+pub fn load_tensors(paths: &[PathBuf], device: &Device) -> Result<HashMap<String, CandleTensor>, InferenceError> {
+    let mut tensors = HashMap::new();
+    for path in paths {
+        // SAFETY: the file is mapped read-only and every tensor is copied out
+        // before the mapping is dropped at the end of this iteration
+        let shard = unsafe { MmapedSafetensors::new(path) }
+            .map_err(|e| InferenceError::model_load(format!("Failed to map SafeTensors shard {}: {}", path.display(), e)))?;
+        for (name, view) in shard.tensors() {
+            let tensor = view.load(device)
+                .map_err(|e| InferenceError::model_load(format!("Failed to load tensor {} from {}: {}", name, path.display(), e)))?;
+            if tensors.insert(name.clone(), tensor).is_some() {
+                return Err(InferenceError::model_load(format!(
+                    "Tensor {} appears in more than one shard (again in {})", name, path.display()
+                )));
+            }
+        }
+    }
+    Ok(tensors)
+}
+
+/// Load every shard listed by a local index file into one weight map
+pub fn load_index(index_path: &Path, device: &Device) -> Result<HashMap<String, CandleTensor>, InferenceError> {
+    let index = ShardIndex::from_file(index_path)?;
+    let paths = index.shard_paths(index_path);
+    println!("🧩 Loading {} SafeTensors shards from {}", paths.len(), index_path.display());
+    
+    let tensors = load_tensors(&paths, device)?;
+    if let Some(missing) = index.tensor_names().find(|name| !tensors.contains_key(*name)) {
+        return Err(InferenceError::model_load(format!(
+            "Tensor {} is listed in {} but missing from {}",
+            missing, index_path.display(), index.shard_for(missing).unwrap_or("its shard")
+        )));
+    }
+    Ok(tensors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const INDEX: &str = r#"{
+        "metadata": {"total_size": 48},
+        "weight_map": {
+            "b.weight": "model-00002-of-00002.safetensors",
+            "a.weight": "model-00001-of-00002.safetensors",
+            "a.bias": "model-00001-of-00002.safetensors"
+        }
+    }"#;
+    
+    /// Write `tensors` to a SafeTensors file in `dir`
+    fn write_shard(dir: &Path, name: &str, tensors: &[(&str, Vec<f32>)]) {
+        let map: HashMap<String, CandleTensor> = tensors.iter()
+            .map(|(n, data)| (n.to_string(), CandleTensor::new(data.as_slice(), &Device::Cpu).unwrap()))
+            .collect();
+        candle_core::safetensors::save(&map, dir.join(name)).unwrap();
+    }
+    
+    #[test]
+    fn test_parse_index() {
+        let index = ShardIndex::parse(INDEX).unwrap();
+        assert_eq!(index.total_size(), Some(48));
+        assert_eq!(index.shard_files(), vec!["model-00001-of-00002.safetensors", "model-00002-of-00002.safetensors"]);
+        assert_eq!(index.shard_for("b.weight"), Some("model-00002-of-00002.safetensors"));
+        assert_eq!(index_filename("model.safetensors"), "model.safetensors.index.json");
+        assert_eq!(sibling_file("unet/model.safetensors.index.json", "a.safetensors"), "unet/a.safetensors");
+        
+        assert!(ShardIndex::parse(r#"{"weight_map": {}}"#).is_err());
+        assert!(ShardIndex::parse("not json").is_err());
+    }
+    
+    #[test]
+    fn test_progress_percentage() {
+        let mut progress = ShardProgress {
+            filename: "a".to_string(),
+            completed_shards: 1,
+            total_shards: 4,
+            downloaded_bytes: 30,
+            total_bytes: Some(120),
+        };
+        assert_eq!(progress.percentage(), 25.0);
+        
+        progress.total_bytes = None;
+        progress.completed_shards = 2;
+        assert_eq!(progress.percentage(), 50.0);
+        
+        // Shard files carry headers, so bytes can overshoot the index total
+        progress.total_bytes = Some(20);
+        assert_eq!(progress.percentage(), 100.0);
+    }
+    
+    #[test]
+    fn test_load_index_merges_shards() {
+        let dir = std::env::temp_dir().join(format!("inference_shards_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        write_shard(&dir, "model-00001-of-00002.safetensors", &[("a.weight", vec![1.0, 2.0]), ("a.bias", vec![3.0])]);
+        write_shard(&dir, "model-00002-of-00002.safetensors", &[("b.weight", vec![4.0, 5.0, 6.0])]);
+        std::fs::write(dir.join("model.safetensors.index.json"), INDEX).unwrap();
+        
+        let tensors = load_index(&dir.join("model.safetensors.index.json"), &Device::Cpu).unwrap();
+        assert_eq!(tensors.len(), 3);
+        assert_eq!(tensors["b.weight"].to_vec1::<f32>().unwrap(), vec![4.0, 5.0, 6.0]);
+        
+        // A tensor listed in the index but absent from its shard is an error
+        write_shard(&dir, "model-00002-of-00002.safetensors", &[("c.weight", vec![0.0])]);
+        assert!(load_index(&dir.join("model.safetensors.index.json"), &Device::Cpu).is_err());
+        
+        // So is a tensor stored in two shards
+        write_shard(&dir, "model-00002-of-00002.safetensors", &[("a.bias", vec![0.0]), ("b.weight", vec![0.0])]);
+        assert!(load_index(&dir.join("model.safetensors.index.json"), &Device::Cpu).is_err());
+        
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
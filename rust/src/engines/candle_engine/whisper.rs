@@ -0,0 +1,855 @@
+use crate::engines::{Model, EngineType};
+use crate::models::{InferenceError, Tensor, TensorSpec, DataType, PredictContext, Preprocessor};
+use crate::models::preprocessing::AudioPreprocessConfig;
+use async_trait::async_trait;
+use std::any::Any;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+use candle_core::{Device, IndexOp, Tensor as CandleTensor};
+use candle_nn::VarBuilder;
+use candle_transformers::models::whisper::{self as whisper, audio, model::Whisper, Config as WhisperConfig};
+use tokenizers::Tokenizer;
+
+use super::forward_error;
+use super::generation::load_tokenizer;
+use super::model_wrappers::download_from_huggingface;
+use super::sharding::download_safetensors;
+
+/// Language codes in Whisper's token order
+const LANGUAGES: [&str; 100] = [
+    "en", "zh", "de", "es", "ru", "ko", "fr", "ja", "pt", "tr", "pl", "ca", "nl", "ar", "sv",
+    "it", "id", "hi", "fi", "vi", "he", "uk", "el", "ms", "cs", "ro", "da", "hu", "ta", "no",
+    "th", "ur", "hr", "bg", "lt", "la", "mi", "ml", "cy", "sk", "te", "fa", "lv", "bn", "sr",
+    "az", "sl", "kn", "et", "mk", "br", "eu", "is", "hy", "ne", "mn", "bs", "kk", "sq", "sw",
+    "gl", "mr", "pa", "si", "km", "sn", "yo", "so", "af", "oc", "ka", "be", "tg", "sd", "gu",
+    "am", "yi", "lo", "uz", "fo", "ht", "ps", "tk", "nn", "mt", "sa", "lb", "my", "bo", "tl",
+    "mg", "as", "tt", "haw", "ln", "ha", "ba", "jw", "su", "yue",
+];
+
+/// Seconds between consecutive timestamp tokens
+const TIME_PRECISION: f64 = 0.02;
+
+/// Latest timestamp the first token of a window may take (one second)
+const MAX_INITIAL_TIMESTAMP: usize = 50;
+
+/// Mel frames per encoder position
+const INPUT_STRIDE: usize = 2;
+
+/// Vocabulary size of the multilingual checkpoints (English-only ones have one less)
+const MULTILINGUAL_VOCAB_SIZE: usize = 51865;
+
+/// Whether to transcribe in the spoken language or translate to English
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WhisperTask {
+    Transcribe,
+    Translate,
+}
+
+/// Options for [`WhisperModelWrapper::transcribe`]
+#[derive(Debug, Clone)]
+pub struct TranscriptionOptions {
+    /// Language code such as `"en"`; detected from the first window when `None`
+    pub language: Option<String>,
+    /// Transcribe or translate
+    pub task: WhisperTask,
+    /// Predict segment timestamps instead of one segment per 30 second window
+    pub timestamps: bool,
+}
+
+impl Default for TranscriptionOptions {
+    fn default() -> Self {
+        Self {
+            language: None,
+            task: WhisperTask::Transcribe,
+            timestamps: true,
+        }
+    }
+}
+
+impl TranscriptionOptions {
+    /// Force the spoken language instead of detecting it
+    pub fn with_language<S: Into<String>>(mut self, language: S) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+    
+    /// Set the task
+    pub fn with_task(mut self, task: WhisperTask) -> Self {
+        self.task = task;
+        self
+    }
+    
+    /// Enable or disable timestamp prediction
+    pub fn with_timestamps(mut self, timestamps: bool) -> Self {
+        self.timestamps = timestamps;
+        self
+    }
+}
+
+/// A timed span of transcribed text
+#[derive(Debug, Clone, PartialEq)]
+pub struct TranscriptionSegment {
+    /// Start time in seconds
+    pub start: f64,
+    /// End time in seconds
+    pub end: f64,
+    /// Transcribed text
+    pub text: String,
+    /// Mean log probability of the window's sampled tokens
+    pub avg_logprob: f64,
+    /// Probability that the window contains no speech
+    pub no_speech_prob: f64,
+}
+
+/// Result of transcribing an audio clip
+#[derive(Debug, Clone)]
+pub struct Transcription {
+    /// Full transcript
+    pub text: String,
+    /// Language code that was forced or detected (`None` for English-only models)
+    pub language: Option<String>,
+    /// Timed segments making up the transcript
+    pub segments: Vec<TranscriptionSegment>,
+}
+
+/// Tokens sampled for one 30 second window
+#[derive(Debug)]
+struct WindowResult {
+    tokens: Vec<u32>,
+    avg_logprob: f64,
+    no_speech_prob: f64,
+}
+
+/// Ids of the special tokens the decoder needs
+#[derive(Debug, Clone)]
+struct SpecialTokens {
+    sot: u32,
+    eot: u32,
+    transcribe: u32,
+    translate: u32,
+    no_timestamps: u32,
+    no_speech: Option<u32>,
+    timestamp_begin: u32,
+    languages: Vec<(&'static str, u32)>,
+}
+
+impl SpecialTokens {
+    fn from_tokenizer(tokenizer: &Tokenizer) -> Result<Self, InferenceError> {
+        let token_id = |token: &str| tokenizer.token_to_id(token)
+            .ok_or_else(|| InferenceError::model_load(format!("Whisper tokenizer has no {} token", token)));
+        
+        let no_timestamps = token_id(whisper::NO_TIMESTAMPS_TOKEN)?;
+        Ok(Self {
+            sot: token_id(whisper::SOT_TOKEN)?,
+            eot: token_id(whisper::EOT_TOKEN)?,
+            transcribe: token_id(whisper::TRANSCRIBE_TOKEN)?,
+            translate: token_id(whisper::TRANSLATE_TOKEN)?,
+            no_timestamps,
+            no_speech: whisper::NO_SPEECH_TOKENS.iter().find_map(|token| tokenizer.token_to_id(token)),
+            timestamp_begin: tokenizer.token_to_id("<|0.00|>").unwrap_or(no_timestamps + 1),
+            languages: LANGUAGES.iter()
+                .filter_map(|code| tokenizer.token_to_id(&format!("<|{}|>", code)).map(|id| (*code, id)))
+                .collect(),
+        })
+    }
+    
+    fn language(&self, code: &str) -> Result<u32, InferenceError> {
+        self.languages.iter()
+            .find(|(language, _)| *language == code)
+            .map(|(_, id)| *id)
+            .ok_or_else(|| InferenceError::configuration(format!("Unknown Whisper language: {}", code)))
+    }
+}
+
+/// Slaney-style mel filterbank matching Whisper's `mel_filters.npz`
+/// 
+/// Returns `n_mels` rows of `N_FFT / 2 + 1` weights, row-major.
+pub fn mel_filters(n_mels: usize) -> Vec<f32> {
+    const F_SP: f64 = 200.0 / 3.0;
+    const MIN_LOG_HZ: f64 = 1000.0;
+    const MIN_LOG_MEL: f64 = MIN_LOG_HZ / F_SP;
+    let log_step = 6.4f64.ln() / 27.0;
+    
+    let hz_to_mel = |hz: f64| if hz < MIN_LOG_HZ { hz / F_SP } else { MIN_LOG_MEL + (hz / MIN_LOG_HZ).ln() / log_step };
+    let mel_to_hz = |mel: f64| if mel < MIN_LOG_MEL { mel * F_SP } else { MIN_LOG_HZ * (log_step * (mel - MIN_LOG_MEL)).exp() };
+    
+    let n_freqs = whisper::N_FFT / 2 + 1;
+    let max_mel = hz_to_mel(whisper::SAMPLE_RATE as f64 / 2.0);
+    let edges: Vec<f64> = (0..n_mels + 2)
+        .map(|i| mel_to_hz(max_mel * i as f64 / (n_mels + 1) as f64))
+        .collect();
+    
+    let mut filters = vec![0f32; n_mels * n_freqs];
+    for m in 0..n_mels {
+        let (lower, center, upper) = (edges[m], edges[m + 1], edges[m + 2]);
+        let norm = 2.0 / (upper - lower);
+        for k in 0..n_freqs {
+            let freq = k as f64 * whisper::SAMPLE_RATE as f64 / whisper::N_FFT as f64;
+            let weight = ((freq - lower) / (center - lower)).min((upper - freq) / (upper - center));
+            filters[m * n_freqs + k] = (weight.max(0.0) * norm) as f32;
+        }
+    }
+    filters
+}
+
+fn log_softmax(logits: &[f32]) -> Vec<f32> {
+    let max = logits.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let log_sum = logits.iter().map(|&l| (l - max).exp()).sum::<f32>().ln() + max;
+    logits.iter().map(|&l| l - log_sum).collect()
+}
+
+fn log_sum_exp(values: &[f32]) -> f32 {
+    let max = values.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    if max == f32::NEG_INFINITY {
+        return max;
+    }
+    values.iter().map(|&v| (v - max).exp()).sum::<f32>().ln() + max
+}
+
+/// Constrain logits so timestamps come in well-formed, increasing pairs
+/// 
+/// Port of `ApplyTimestampRules` from the reference implementation; `sampled`
+/// holds the tokens generated so far in this window.
+fn apply_timestamp_rules(logits: &mut [f32], sampled: &[u32], eot: u32, timestamp_begin: u32) {
+    let ts = timestamp_begin as usize;
+    let is_timestamp = |token: u32| token >= timestamp_begin;
+    let last_was_timestamp = sampled.last().is_some_and(|&t| is_timestamp(t));
+    let penultimate_was_timestamp = sampled.len() < 2 || is_timestamp(sampled[sampled.len() - 2]);
+    
+    if last_was_timestamp {
+        if penultimate_was_timestamp {
+            // A pair just closed, so text must follow
+            logits[ts..].fill(f32::NEG_INFINITY);
+        } else {
+            // A segment is open, so it can only be closed
+            logits[..eot as usize].fill(f32::NEG_INFINITY);
+        }
+    }
+    
+    // Timestamps never go backwards
+    if let Some(&last_timestamp) = sampled.iter().rev().find(|&&t| is_timestamp(t)) {
+        let end = if last_was_timestamp && !penultimate_was_timestamp { last_timestamp } else { last_timestamp + 1 };
+        let end = (end as usize).min(logits.len());
+        logits[ts..end].fill(f32::NEG_INFINITY);
+    }
+    
+    // Each window starts with a timestamp no later than one second
+    if sampled.is_empty() {
+        logits[..ts].fill(f32::NEG_INFINITY);
+        let last_allowed = ts + MAX_INITIAL_TIMESTAMP;
+        if last_allowed + 1 < logits.len() {
+            logits[last_allowed + 1..].fill(f32::NEG_INFINITY);
+        }
+    }
+    
+    // Prefer a timestamp when together they outweigh every text token
+    let logprobs = log_softmax(logits);
+    let timestamp_logprob = log_sum_exp(&logprobs[ts..]);
+    let max_text_logprob = logprobs[..ts].iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    if timestamp_logprob > max_text_logprob {
+        logits[..ts].fill(f32::NEG_INFINITY);
+    }
+}
+
+/// Split one window's sampled tokens into segments
+/// 
+/// Returns `(start, end, text tokens)` with times relative to the window, and
+/// how many mel frames to advance before the next window.
+fn split_segments(
+    sampled: &[u32],
+    eot: u32,
+    timestamp_begin: u32,
+    segment_size: usize,
+) -> (Vec<(f64, f64, Vec<u32>)>, usize) {
+    let tokens: Vec<u32> = sampled.iter().copied().take_while(|&t| t != eot).collect();
+    let is_timestamp = |token: u32| token >= timestamp_begin;
+    let time = |token: u32| (token - timestamp_begin) as f64 * TIME_PRECISION;
+    let text = |slice: &[u32]| slice.iter().copied().filter(|&t| t < eot).collect::<Vec<u32>>();
+    let segment_duration = (segment_size * whisper::HOP_LENGTH) as f64 / whisper::SAMPLE_RATE as f64;
+    
+    let mut boundaries: Vec<usize> = (1..tokens.len())
+        .filter(|&i| is_timestamp(tokens[i - 1]) && is_timestamp(tokens[i]))
+        .collect();
+    
+    if boundaries.is_empty() {
+        let end = tokens.iter().rev()
+            .copied()
+            .find(|&t| is_timestamp(t) && t != timestamp_begin)
+            .map(time)
+            .unwrap_or(segment_duration);
+        return (vec![(0.0, end, text(&tokens))], segment_size);
+    }
+    
+    let n = tokens.len();
+    let single_timestamp_ending = n >= 2 && !is_timestamp(tokens[n - 2]) && is_timestamp(tokens[n - 1]);
+    if single_timestamp_ending {
+        boundaries.push(n);
+    }
+    
+    let mut segments = Vec::new();
+    let mut last = 0;
+    for &current in &boundaries {
+        let slice = &tokens[last..current];
+        let start = if is_timestamp(slice[0]) { time(slice[0]) } else { 0.0 };
+        let end = time(slice[slice.len() - 1]);
+        segments.push((start, end, text(slice)));
+        last = current;
+    }
+    
+    // Unless the window ended cleanly, resume from the last complete segment
+    let advance = if single_timestamp_ending {
+        segment_size
+    } else {
+        (tokens[last - 1] - timestamp_begin) as usize * INPUT_STRIDE
+    };
+    let advance = if advance == 0 { segment_size } else { advance.min(segment_size) };
+    (segments, advance)
+}
+
+fn candle_error(e: candle_core::Error) -> InferenceError {
+    forward_error("Whisper inference failed", e)
+}
+
+/// Whisper speech recognition model using candle-transformers
+/// 
+/// Turns 16 kHz PCM into log-mel features, encodes 30 second windows and
+/// decodes them greedily with timestamp rules and language detection.
+/// `Model::predict` exposes the encoder alone: log-mel features in, audio
+/// features out.
+pub struct WhisperModelWrapper {
+    model: Mutex<Whisper>,
+    tokenizer: Tokenizer,
+    config: WhisperConfig,
+    tokens: SpecialTokens,
+    mel_filters: Vec<f32>,
+    device: Device,
+    input_specs: Vec<TensorSpec>,
+    output_specs: Vec<TensorSpec>,
+}
+
+impl std::fmt::Debug for WhisperModelWrapper {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WhisperModelWrapper")
+            .field("config", &self.config)
+            .field("device", &self.device)
+            .finish()
+    }
+}
+
+impl WhisperModelWrapper {
+    /// Build the model from a variable builder, config and tokenizer
+    pub fn new(vb: VarBuilder, config: WhisperConfig, tokenizer: Tokenizer) -> Result<Self, InferenceError> {
+        let device = vb.device().clone();
+        let model = Whisper::load(&vb, config.clone())
+            .map_err(|e| InferenceError::model_load(format!("Failed to build Whisper model: {}", e)))?;
+        let tokens = SpecialTokens::from_tokenizer(&tokenizer)?;
+        
+        let input_specs = vec![TensorSpec::new(
+            "input_features".to_string(),
+            vec![Some(1), Some(config.num_mel_bins), Some(whisper::N_FRAMES)],
+            DataType::F32,
+        )];
+        let output_specs = vec![TensorSpec::new(
+            "encoder_hidden_states".to_string(),
+            vec![Some(1), Some(config.max_source_positions), Some(config.d_model)],
+            DataType::F32,
+        )];
+        
+        Ok(Self {
+            model: Mutex::new(model),
+            tokenizer,
+            mel_filters: mel_filters(config.num_mel_bins),
+            config,
+            tokens,
+            device,
+            input_specs,
+            output_specs,
+        })
+    }
+    
+    /// Load SafeTensors weights with a HuggingFace config and tokenizer
+    pub fn load<P: AsRef<Path>>(
+        config_path: P,
+        weights_path: P,
+        tokenizer_path: P,
+        device: &Device,
+    ) -> Result<Self, InferenceError> {
+        Self::load_sharded(config_path, &[weights_path.as_ref().to_path_buf()], tokenizer_path, device)
+    }
+    
+    /// Load weights split across several SafeTensors files
+    pub fn load_sharded<P: AsRef<Path>>(
+        config_path: P,
+        weight_paths: &[PathBuf],
+        tokenizer_path: P,
+        device: &Device,
+    ) -> Result<Self, InferenceError> {
+        let config_json = std::fs::read_to_string(config_path.as_ref())
+            .map_err(|e| InferenceError::model_load(format!("Failed to read Whisper config: {}", e)))?;
+        let config: WhisperConfig = serde_json::from_str(&config_json)
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse Whisper config: {}", e)))?;
+        let tokenizer = load_tokenizer(tokenizer_path.as_ref())?;
+        
+        // SAFETY: the weight files are memory-mapped read-only and must not be
+        // modified while the model is alive, the same contract as candle's examples
+        let vb = unsafe { VarBuilder::from_mmaped_safetensors(weight_paths, whisper::DTYPE, device) }
+            .map_err(|e| InferenceError::model_load(format!("Failed to map Whisper weights: {}", e)))?;
+        
+        let wrapper = Self::new(vb, config, tokenizer)?;
+        println!("✅ Loaded Whisper model with {} encoder and {} decoder layers",
+            wrapper.config.encoder_layers, wrapper.config.decoder_layers);
+        Ok(wrapper)
+    }
+    
+    /// Load a Whisper model from a HuggingFace repository
+    pub async fn load_from_huggingface(
+        device: &Device,
+        repo_id: &str,
+        filename: Option<&str>,
+    ) -> Result<Self, InferenceError> {
+        println!("🤗 Loading Whisper model from HuggingFace: {}", repo_id);
+        
+        let filename = filename.unwrap_or("model.safetensors");
+        let config_path = download_from_huggingface(repo_id, "config.json").await?;
+        let tokenizer_path = download_from_huggingface(repo_id, "tokenizer.json").await?;
+        let weight_paths = download_safetensors(repo_id, filename, &mut |_| {}).await?;
+        
+        let wrapper = Self::load_sharded(&config_path, &weight_paths, &tokenizer_path, device)?;
+        println!("✅ Successfully loaded Whisper model from HuggingFace");
+        Ok(wrapper)
+    }
+    
+    /// Get the model configuration
+    pub fn config(&self) -> &WhisperConfig {
+        &self.config
+    }
+    
+    /// Whether the checkpoint handles languages other than English
+    pub fn is_multilingual(&self) -> bool {
+        self.config.vocab_size >= MULTILINGUAL_VOCAB_SIZE
+    }
+    
+    /// Compute log-mel features `[1, n_mels, frames]` for 16 kHz samples
+    /// 
+    /// The audio is padded with silence so every 30 second window is complete.
+    pub fn log_mel_spectrogram(&self, samples: &[f32]) -> Result<CandleTensor, InferenceError> {
+        let mel = audio::pcm_to_mel(&self.config, samples, &self.mel_filters);
+        let frames = mel.len() / self.config.num_mel_bins;
+        CandleTensor::from_vec(mel, (1, self.config.num_mel_bins, frames), &self.device)
+            .map_err(candle_error)
+    }
+    
+    /// Transcribe mono PCM samples at any sample rate
+    pub fn transcribe(
+        &self,
+        samples: &[f32],
+        sample_rate: u32,
+        options: &TranscriptionOptions,
+        ctx: &PredictContext,
+    ) -> Result<Transcription, InferenceError> {
+        let samples = if sample_rate as usize == whisper::SAMPLE_RATE {
+            samples.to_vec()
+        } else {
+            let config = AudioPreprocessConfig {
+                normalize: false,
+                target_sample_rate: Some(whisper::SAMPLE_RATE as u32),
+                ..Default::default()
+            };
+            Preprocessor::preprocess_audio(samples, sample_rate, &config)?.to_f32_vec()?
+        };
+        
+        let mut language = options.language.clone();
+        let mut language_token = match &language {
+            Some(code) if self.is_multilingual() => Some(self.tokens.language(code)?),
+            _ => None,
+        };
+        
+        let mel = self.log_mel_spectrogram(&samples)?;
+        let total_frames = mel.dim(2).map_err(candle_error)?;
+        let content_frames = samples.len() / whisper::HOP_LENGTH;
+        let mut model = self.model()?;
+        let mut segments = Vec::new();
+        let mut seek = 0;
+        
+        while seek < content_frames {
+            ctx.check()?;
+            
+            let segment_size = usize::min(whisper::N_FRAMES, content_frames - seek);
+            let time_offset = (seek * whisper::HOP_LENGTH) as f64 / whisper::SAMPLE_RATE as f64;
+            let mel_window = mel.narrow(2, seek, usize::min(whisper::N_FRAMES, total_frames - seek))
+                .map_err(candle_error)?;
+            let audio_features = model.encoder.forward(&mel_window, true).map_err(candle_error)?;
+            
+            if self.is_multilingual() && language_token.is_none() {
+                let (code, token) = self.detect_language(&mut model, &audio_features)?;
+                println!("🗣️ Detected language: {}", code);
+                language = Some(code.to_string());
+                language_token = Some(token);
+            }
+            
+            let prompt = self.prompt(language_token, options);
+            let window = self.decode_window(&mut model, &audio_features, &prompt, options.timestamps, ctx)?;
+            
+            // Skip windows that are most likely silence
+            if window.no_speech_prob > whisper::NO_SPEECH_THRESHOLD && window.avg_logprob < whisper::LOGPROB_THRESHOLD {
+                seek += segment_size;
+                continue;
+            }
+            
+            let (window_segments, advance) = split_segments(&window.tokens, self.tokens.eot, self.tokens.timestamp_begin, segment_size);
+            for (start, end, tokens) in window_segments {
+                let text = self.tokenizer.decode(&tokens, true)
+                    .map_err(|e| InferenceError::prediction(format!("Failed to decode Whisper tokens: {}", e)))?;
+                let text = text.trim();
+                if text.is_empty() {
+                    continue;
+                }
+                segments.push(TranscriptionSegment {
+                    start: time_offset + start,
+                    end: time_offset + end,
+                    text: text.to_string(),
+                    avg_logprob: window.avg_logprob,
+                    no_speech_prob: window.no_speech_prob,
+                });
+            }
+            seek += advance;
+        }
+        
+        let text = segments.iter().map(|s| s.text.as_str()).collect::<Vec<_>>().join(" ");
+        Ok(Transcription {
+            text,
+            language: if self.is_multilingual() { language } else { None },
+            segments,
+        })
+    }
+    
+    fn model(&self) -> Result<MutexGuard<'_, Whisper>, InferenceError> {
+        self.model.lock()
+            .map_err(|_| InferenceError::prediction("Whisper model lock poisoned"))
+    }
+    
+    /// Decoder prompt: start of transcript, language, task and timestamp mode
+    fn prompt(&self, language_token: Option<u32>, options: &TranscriptionOptions) -> Vec<u32> {
+        let mut prompt = vec![self.tokens.sot];
+        if self.is_multilingual() {
+            prompt.extend(language_token);
+            prompt.push(match options.task {
+                WhisperTask::Transcribe => self.tokens.transcribe,
+                WhisperTask::Translate => self.tokens.translate,
+            });
+        }
+        if !options.timestamps {
+            prompt.push(self.tokens.no_timestamps);
+        }
+        prompt
+    }
+    
+    /// Pick the most likely language token after the start-of-transcript token
+    fn detect_language(&self, model: &mut Whisper, audio_features: &CandleTensor) -> Result<(&'static str, u32), InferenceError> {
+        let tokens = CandleTensor::new(&[self.tokens.sot], &self.device)
+            .and_then(|t| t.unsqueeze(0))
+            .map_err(candle_error)?;
+        let hidden = model.decoder.forward(&tokens, audio_features, true).map_err(candle_error)?;
+        let logits: Vec<f32> = model.decoder.final_linear(&hidden)
+            .and_then(|l| l.i((0, 0)))
+            .and_then(|l| l.to_vec1())
+            .map_err(candle_error)?;
+        
+        self.tokens.languages.iter()
+            .copied()
+            .max_by(|a, b| logits[a.1 as usize].total_cmp(&logits[b.1 as usize]))
+            .ok_or_else(|| InferenceError::prediction("Whisper tokenizer has no language tokens"))
+    }
+    
+    /// Greedily decode one window of audio features
+    fn decode_window(
+        &self,
+        model: &mut Whisper,
+        audio_features: &CandleTensor,
+        prompt: &[u32],
+        timestamps: bool,
+        ctx: &PredictContext,
+    ) -> Result<WindowResult, InferenceError> {
+        let sample_len = self.config.max_target_positions / 2;
+        let mut tokens = prompt.to_vec();
+        let mut sum_logprob = 0f64;
+        let mut no_speech_prob = 0f64;
+        
+        for step in 0..sample_len {
+            ctx.check()?;
+            
+            let input = CandleTensor::new(tokens.as_slice(), &self.device)
+                .and_then(|t| t.unsqueeze(0))
+                .map_err(candle_error)?;
+            let hidden = model.decoder.forward(&input, audio_features, step == 0).map_err(candle_error)?;
+            
+            // The no-speech probability is read at the start-of-transcript position
+            if step == 0 {
+                if let Some(no_speech) = self.tokens.no_speech {
+                    let logits: Vec<f32> = model.decoder.final_linear(&hidden.i(..1).map_err(candle_error)?)
+                        .and_then(|l| l.i((0, 0)))
+                        .and_then(|l| l.to_vec1())
+                        .map_err(candle_error)?;
+                    no_speech_prob = log_softmax(&logits)[no_speech as usize].exp() as f64;
+                }
+            }
+            
+            let seq_len = hidden.dim(1).map_err(candle_error)?;
+            let mut logits: Vec<f32> = hidden.i((..1, seq_len - 1..))
+                .and_then(|h| model.decoder.final_linear(&h))
+                .and_then(|l| l.i((0, 0)))
+                .and_then(|l| l.to_vec1())
+                .map_err(candle_error)?;
+            self.suppress(&mut logits, &tokens[prompt.len()..], timestamps);
+            
+            let logprobs = log_softmax(&logits);
+            let next = logits.iter()
+                .enumerate()
+                .max_by(|a, b| a.1.total_cmp(b.1))
+                .map(|(id, _)| id as u32)
+                .unwrap_or(self.tokens.eot);
+            sum_logprob += logprobs[next as usize] as f64;
+            tokens.push(next);
+            
+            if next == self.tokens.eot || tokens.len() >= self.config.max_target_positions {
+                break;
+            }
+        }
+        
+        let sampled = tokens.split_off(prompt.len());
+        Ok(WindowResult {
+            avg_logprob: sum_logprob / sampled.len().max(1) as f64,
+            tokens: sampled,
+            no_speech_prob,
+        })
+    }
+    
+    /// Mask tokens that must not be sampled next
+    fn suppress(&self, logits: &mut [f32], sampled: &[u32], timestamps: bool) {
+        for &token in &self.config.suppress_tokens {
+            if let Some(logit) = logits.get_mut(token as usize) {
+                *logit = f32::NEG_INFINITY;
+            }
+        }
+        
+        // Control tokens between end-of-text and the timestamps are never generated
+        let eot = self.tokens.eot as usize;
+        let timestamp_begin = self.tokens.timestamp_begin as usize;
+        logits[eot + 1..timestamp_begin].fill(f32::NEG_INFINITY);
+        
+        // Never end a window before producing anything
+        if sampled.is_empty() {
+            logits[eot] = f32::NEG_INFINITY;
+        }
+        
+        if timestamps {
+            apply_timestamp_rules(logits, sampled, self.tokens.eot, self.tokens.timestamp_begin);
+        } else {
+            logits[timestamp_begin..].fill(f32::NEG_INFINITY);
+        }
+    }
+}
+
+#[async_trait]
+impl Model for WhisperModelWrapper {
+    /// Encode log-mel features `[n_mels, frames]` or `[1, n_mels, frames]`
+    async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+        let n_mels = self.config.num_mel_bins;
+        let frames = match input.shape() {
+            [mels, frames] | [1, mels, frames] if *mels == n_mels => *frames,
+            shape => return Err(InferenceError::invalid_shape_msg(format!(
+                "Expected log-mel features of shape [{}, frames] or [1, {}, frames], got {:?}", n_mels, n_mels, shape
+            ))),
+        };
+        
+        let mel = CandleTensor::from_vec(input.to_f32_vec()?, (1, n_mels, frames), &self.device)
+            .map_err(candle_error)?;
+        let features = self.model()?.encoder.forward(&mel, true).map_err(candle_error)?;
+        
+        let shape = features.dims().to_vec();
+        let data: Vec<f32> = features.flatten_all()
+            .and_then(|t| t.to_vec1())
+            .map_err(candle_error)?;
+        Tensor::from_f32(data, shape)
+    }
+    
+    async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+        let mut results = Vec::new();
+        for input in inputs {
+            results.push(self.predict(input).await?);
+        }
+        Ok(results)
+    }
+    
+    fn input_specs(&self) -> &[TensorSpec] {
+        &self.input_specs
+    }
+    
+    fn output_specs(&self) -> &[TensorSpec] {
+        &self.output_specs
+    }
+    
+    fn engine_type(&self) -> EngineType {
+        EngineType::Candle
+    }
+    
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candle_nn::VarMap;
+    use std::collections::HashMap;
+    use tokenizers::models::wordlevel::WordLevel;
+    
+    const EOT: u32 = 10;
+    const TS: u32 = 20;
+    
+    /// English-only Whisper with random weights and a word-level tokenizer
+    fn tiny_whisper() -> WhisperModelWrapper {
+        let mut words: Vec<String> = ["the", "cat", "sat", "on", "mat"].iter().map(|w| w.to_string()).collect();
+        words.extend([
+            whisper::EOT_TOKEN, whisper::SOT_TOKEN, whisper::TRANSLATE_TOKEN, whisper::TRANSCRIBE_TOKEN,
+            "<|nospeech|>", whisper::NO_TIMESTAMPS_TOKEN,
+        ].iter().map(|t| t.to_string()));
+        words.extend((0..=1500).map(|i| format!("<|{:.2}|>", i as f64 * TIME_PRECISION)));
+        let vocab: HashMap<String, u32> = words.iter().enumerate().map(|(id, w)| (w.clone(), id as u32)).collect();
+        let tokenizer = Tokenizer::new(WordLevel::builder().vocab(vocab).unk_token("the".to_string()).build().unwrap());
+        
+        let config: WhisperConfig = serde_json::from_value(serde_json::json!({
+            "num_mel_bins": 80,
+            "max_source_positions": 1500,
+            "d_model": 8,
+            "encoder_attention_heads": 2,
+            "encoder_layers": 1,
+            "vocab_size": words.len(),
+            "max_target_positions": 16,
+            "decoder_attention_heads": 2,
+            "decoder_layers": 1
+        })).unwrap();
+        let varmap = VarMap::new();
+        let vb = VarBuilder::from_varmap(&varmap, whisper::DTYPE, &Device::Cpu);
+        WhisperModelWrapper::new(vb, config, tokenizer).unwrap()
+    }
+    
+    /// One second of a 440 Hz tone at `sample_rate`
+    fn tone(sample_rate: u32) -> Vec<f32> {
+        (0..sample_rate).map(|i| (i as f32 * 440.0 * std::f32::consts::TAU / sample_rate as f32).sin() * 0.5).collect()
+    }
+    
+    #[test]
+    fn test_transcribe_end_to_end() {
+        let model = tiny_whisper();
+        assert!(!model.is_multilingual());
+        
+        // 8 kHz input is resampled to 16 kHz first. Random weights rarely close
+        // timestamp pairs, so decode plain text to get segments
+        let options = TranscriptionOptions::default().with_timestamps(false);
+        let transcription = model.transcribe(&tone(8000), 8000, &options, &PredictContext::new()).unwrap();
+        assert_eq!(transcription.language, None);
+        assert!(!transcription.segments.is_empty());
+        assert_eq!(transcription.text, transcription.segments.iter().map(|s| s.text.as_str()).collect::<Vec<_>>().join(" "));
+        for segment in &transcription.segments {
+            assert!(segment.start <= segment.end && segment.end <= 30.0);
+            assert!(segment.avg_logprob.is_finite() && segment.avg_logprob <= 0.0);
+            assert!((0.0..=1.0).contains(&segment.no_speech_prob));
+        }
+        
+        // Decoding is greedy, so the transcript is deterministic
+        let again = model.transcribe(&tone(8000), 8000, &options, &PredictContext::new()).unwrap();
+        assert_eq!(again.segments, transcription.segments);
+    }
+    
+    #[test]
+    fn test_transcribe_honours_cancellation() {
+        let model = tiny_whisper();
+        let token = crate::models::CancellationToken::new();
+        token.cancel();
+        let ctx = PredictContext::new().with_token(token);
+        
+        let result = model.transcribe(&tone(16000), 16000, &TranscriptionOptions::default(), &ctx);
+        assert!(matches!(result, Err(InferenceError::Cancelled(_))));
+    }
+    
+    #[test]
+    fn test_mel_filters_shape_and_coverage() {
+        let n_freqs = whisper::N_FFT / 2 + 1;
+        let filters = mel_filters(80);
+        assert_eq!(filters.len(), 80 * n_freqs);
+        assert!(filters.iter().all(|&w| w >= 0.0));
+        
+        // Every filter is a triangle that covers at least one FFT bin
+        for row in filters.chunks(n_freqs) {
+            assert!(row.iter().any(|&w| w > 0.0));
+        }
+        
+        // Filters move up in frequency
+        let peak = |row: &[f32]| row.iter().enumerate().max_by(|a, b| a.1.total_cmp(b.1)).unwrap().0;
+        assert!(peak(&filters[..n_freqs]) < peak(&filters[79 * n_freqs..]));
+        
+        // mel_filters.npz["mel_80"][0][1]
+        assert!((filters[1] - 0.02486259).abs() < 1e-7);
+    }
+    
+    #[test]
+    fn test_timestamp_rules_start_and_pairs() {
+        let vocab = TS as usize + 100;
+        
+        // The first token must be an early timestamp
+        let mut logits = vec![0f32; vocab];
+        apply_timestamp_rules(&mut logits, &[], EOT, TS);
+        assert!(logits[..TS as usize].iter().all(|l| l.is_infinite()));
+        assert!(logits[TS as usize..=TS as usize + MAX_INITIAL_TIMESTAMP].iter().all(|l| l.is_finite()));
+        assert!(logits[TS as usize + MAX_INITIAL_TIMESTAMP + 1].is_infinite());
+        
+        // An open segment can only be closed by a later timestamp
+        let mut logits = vec![0f32; vocab];
+        logits[3] = 10.0;
+        apply_timestamp_rules(&mut logits, &[TS + 5, 3], EOT, TS);
+        assert!(logits[3].is_finite());
+        assert!(logits[TS as usize + 5].is_infinite());
+        let mut logits = vec![0f32; vocab];
+        logits[EOT as usize] = 10.0;
+        apply_timestamp_rules(&mut logits, &[TS + 5, 3, TS + 9], EOT, TS);
+        assert!(logits[3].is_infinite());
+        assert!(logits[EOT as usize].is_finite());
+        assert!(logits[TS as usize + 8].is_infinite());
+        assert!(logits[TS as usize + 9].is_finite());
+        
+        // After a closed pair, text must follow
+        let mut logits = vec![0f32; vocab];
+        logits[4] = 5.0;
+        apply_timestamp_rules(&mut logits, &[TS, 3, TS + 9, TS + 9], EOT, TS);
+        assert!(logits[TS as usize..].iter().all(|l| l.is_infinite()));
+        assert!(logits[4].is_finite());
+    }
+    
+    #[test]
+    fn test_split_segments_with_timestamps() {
+        // <0.00> a b <0.10><0.10> c <0.20> <eot>
+        let sampled = [TS, 1, 2, TS + 5, TS + 5, 3, TS + 10, EOT];
+        let (segments, advance) = split_segments(&sampled, EOT, TS, 3000);
+        assert_eq!(segments.len(), 2);
+        assert_eq!(segments[0].2, vec![1, 2]);
+        assert!((segments[0].1 - 0.1).abs() < 1e-9);
+        assert!((segments[1].0 - 0.1).abs() < 1e-9);
+        assert!((segments[1].1 - 0.2).abs() < 1e-9);
+        assert_eq!(advance, 3000);
+        
+        // A window cut off mid-segment resumes after the last complete one
+        let (segments, advance) = split_segments(&[TS, 1, TS + 5, TS + 6, 2], EOT, TS, 3000);
+        assert_eq!(segments.len(), 1);
+        assert_eq!(advance, 5 * INPUT_STRIDE);
+    }
+    
+    #[test]
+    fn test_split_segments_without_timestamps() {
+        let (segments, advance) = split_segments(&[1, 2, 3, EOT], EOT, TS, 1500);
+        assert_eq!(segments, vec![(0.0, 15.0, vec![1, 2, 3])]);
+        assert_eq!(advance, 1500);
+    }
+}
@@ -1,11 +1,14 @@
-use crate::engines::{InferenceEngine, EngineType, ModelFormat};
-use crate::models::InferenceError;
+use crate::engines::{InferenceEngine, EngineType, ModelFormat, EngineRegistry};
+use crate::models::{DeviceSelection, InferenceError, OptimizationConfig};
+use crate::utils::ModelDetector;
 use std::path::Path;
 
 #[cfg(feature = "candle")]
 use crate::engines::CandleEngine;
 #[cfg(feature = "linfa")]
 use crate::engines::LinfaEngine;
+#[cfg(feature = "onnx")]
+use crate::engines::OnnxEngine;
 
 /// Engine factory for automatic engine selection
 /// 
@@ -13,6 +16,9 @@ use crate::engines::LinfaEngine;
 /// - File extension
 /// - File content magic bytes
 /// - Explicit engine type specification
+/// 
+/// Engines registered with the `EngineRegistry` are consulted for formats
+/// the built-in engines don't recognize.
 pub struct EngineFactory;
 
 impl EngineFactory {
@@ -31,7 +37,7 @@ impl EngineFactory {
     /// Create an engine for a specific format
     pub fn create_engine(format: ModelFormat) -> Result<Box<dyn InferenceEngine>, InferenceError> {
         match format {
-            ModelFormat::SafeTensors | ModelFormat::PyTorch => {
+            ModelFormat::SafeTensors | ModelFormat::PyTorch | ModelFormat::Gguf => {
                 #[cfg(feature = "candle")]
                 {
                     let engine = CandleEngine::new()?;
@@ -57,20 +63,42 @@ impl EngineFactory {
                     ))
                 }
             }
+            ModelFormat::Onnx => {
+                #[cfg(feature = "onnx")]
+                {
+                    let engine = OnnxEngine::new()?;
+                    Ok(Box::new(engine))
+                }
+                #[cfg(not(feature = "onnx"))]
+                {
+                    Err(InferenceError::configuration(
+                        "ONNX engine not available - compile with 'onnx' feature"
+                    ))
+                }
+            }
+            ModelFormat::Plugin(name) => EngineRegistry::create_engine(&name),
         }
     }
     
     /// Create an engine for a specific engine type
     pub fn create_engine_by_type(engine_type: EngineType) -> Result<Box<dyn InferenceEngine>, InferenceError> {
+        Self::create_engine_on_device(engine_type, DeviceSelection::Auto)
+    }
+    
+    /// Create an engine for a specific engine type on the selected device
+    /// 
+    /// Only the Candle engine can use GPUs; other engines always run on the CPU.
+    pub fn create_engine_on_device(engine_type: EngineType, device: DeviceSelection) -> Result<Box<dyn InferenceEngine>, InferenceError> {
         match engine_type {
             EngineType::Candle => {
                 #[cfg(feature = "candle")]
                 {
-                    let engine = CandleEngine::new()?;
+                    let engine = CandleEngine::for_device(device)?;
                     Ok(Box::new(engine))
                 }
                 #[cfg(not(feature = "candle"))]
                 {
+                    let _ = device;
                     Err(InferenceError::configuration(
                         "Candle engine not available - compile with 'candle' feature"
                     ))
@@ -89,6 +117,20 @@ impl EngineFactory {
                     ))
                 }
             }
+            EngineType::Onnx => {
+                #[cfg(feature = "onnx")]
+                {
+                    let engine = OnnxEngine::new()?;
+                    Ok(Box::new(engine))
+                }
+                #[cfg(not(feature = "onnx"))]
+                {
+                    Err(InferenceError::configuration(
+                        "ONNX engine not available - compile with 'onnx' feature"
+                    ))
+                }
+            }
+            EngineType::Plugin(name) => EngineRegistry::create_engine(&name),
         }
     }
     
@@ -102,8 +144,14 @@ impl EngineFactory {
         match extension.to_lowercase().as_str() {
             "safetensors" => Ok(ModelFormat::SafeTensors),
             "pt" | "pth" => Ok(ModelFormat::PyTorch),
+            "gguf" => Ok(ModelFormat::Gguf),
+            "onnx" => Ok(ModelFormat::Onnx),
             "linfa" | "lfa" => Ok(ModelFormat::Linfa),
-            _ => {
+            ext => {
+                if let Some(name) = EngineRegistry::find_by_extension(ext) {
+                    return Ok(ModelFormat::Plugin(name));
+                }
+                
                 // Try to read file and detect from content
                 let bytes = std::fs::read(path)
                     .map_err(|e| InferenceError::model_load(format!("Failed to read file {}: {}", path, e)))?;
@@ -118,6 +166,10 @@ impl EngineFactory {
             return Err(InferenceError::unsupported_format("Empty file content"));
         }
         
+        // GGUF format detection (magic bytes)
+        if bytes.starts_with(b"GGUF") {
+            return Ok(ModelFormat::Gguf);
+        }
         
         // SafeTensors format detection (starts with JSON metadata)
         if bytes.starts_with(b"{") {
@@ -134,6 +186,16 @@ impl EngineFactory {
             return Ok(ModelFormat::PyTorch);
         }
         
+        // ONNX format detection (protobuf ModelProto)
+        if ModelDetector::is_onnx_format(bytes) {
+            return Ok(ModelFormat::Onnx);
+        }
+        
+        // Plugin formats recognized by their sniffing hooks
+        if let Some(name) = EngineRegistry::find_by_content(bytes) {
+            return Ok(ModelFormat::Plugin(name));
+        }
+        
         // Linfa format detection (bincode serialized)
         if bytes.len() > 8 {
             // Try to deserialize as bincode - if it works, likely Linfa
@@ -157,6 +219,11 @@ impl EngineFactory {
         #[cfg(feature = "linfa")]
         engines.push(EngineType::Linfa);
         
+        #[cfg(feature = "onnx")]
+        engines.push(EngineType::Onnx);
+        
+        engines.extend(EngineRegistry::names().into_iter().map(EngineType::Plugin));
+        
         engines
     }
     
@@ -165,35 +232,79 @@ impl EngineFactory {
         match engine_type {
             EngineType::Candle => cfg!(feature = "candle"),
             EngineType::Linfa => cfg!(feature = "linfa"),
+            EngineType::Onnx => cfg!(feature = "onnx"),
+            EngineType::Plugin(name) => EngineRegistry::contains(&name),
         }
     }
     
     /// Get the preferred engine for a given format
     pub fn preferred_engine_for_format(format: ModelFormat) -> EngineType {
         match format {
-            ModelFormat::SafeTensors | ModelFormat::PyTorch => EngineType::Candle,
+            ModelFormat::SafeTensors | ModelFormat::PyTorch | ModelFormat::Gguf => EngineType::Candle,
             ModelFormat::Linfa => EngineType::Linfa,
+            ModelFormat::Onnx => EngineType::Onnx,
+            ModelFormat::Plugin(name) => EngineType::Plugin(name),
         }
     }
     
     /// Create the best available engine for a format
     pub fn create_best_engine_for_format(format: ModelFormat) -> Result<Box<dyn InferenceEngine>, InferenceError> {
+        Self::create_engine_by_type(Self::best_engine_for_format(format)?)
+    }
+    
+    /// Pick the best available engine type for a format
+    pub fn best_engine_for_format(format: ModelFormat) -> Result<EngineType, InferenceError> {
         let preferred = Self::preferred_engine_for_format(format);
         
         // Try preferred engine first
-        if Self::is_engine_available(preferred) {
-            return Self::create_engine_by_type(preferred);
+        if Self::is_engine_available(preferred.clone()) {
+            return Ok(preferred);
         }
         
         // Fall back to any available engine
-        let available = Self::available_engines();
-        if available.is_empty() {
-            return Err(InferenceError::configuration(
-                "No inference engines available - compile with at least one engine feature (candle, ort, linfa)"
-            ));
-        }
-        
-        Self::create_engine_by_type(available[0])
+        Self::available_engines().into_iter().next().ok_or_else(|| InferenceError::configuration(
+            "No inference engines available - compile with at least one engine feature (candle, linfa, onnx)"
+        ))
+    }
+}
+
+/// An engine together with the device it runs on
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backend {
+    pub engine_type: EngineType,
+    pub device: DeviceSelection,
+}
+
+impl Backend {
+    /// Create a backend
+    pub fn new(engine_type: EngineType, device: DeviceSelection) -> Self {
+        Self { engine_type, device }
+    }
+    
+    /// Parse a backend written as `engine` or `engine@device` (e.g. "candle@cuda:0")
+    pub fn parse(spec: &str) -> Result<Self, InferenceError> {
+        let (engine, device) = match spec.split_once('@') {
+            Some((engine, device)) => (engine, Some(device)),
+            None => (spec, None),
+        };
+        let engine_type = EngineType::from_name(engine.trim())
+            .ok_or_else(|| InferenceError::configuration(format!("Unknown engine in backend '{}'", spec)))?;
+        let device = match device {
+            Some(device) => DeviceSelection::from_name(device.trim())
+                .ok_or_else(|| InferenceError::configuration(format!("Unknown device in backend '{}'", spec)))?,
+            None => DeviceSelection::Auto,
+        };
+        Ok(Self::new(engine_type, device))
+    }
+    
+    /// Get the backend name in `engine@device` form
+    pub fn name(&self) -> String {
+        format!("{}@{}", self.engine_type.name(), self.device.name())
+    }
+    
+    /// Create an engine for this backend
+    pub fn create_engine(&self) -> Result<Box<dyn InferenceEngine>, InferenceError> {
+        EngineFactory::create_engine_on_device(self.engine_type.clone(), self.device)
     }
 }
 
@@ -206,6 +317,14 @@ pub struct EngineConfig {
     pub allow_fallback: bool,
     /// Whether to enable GPU acceleration when available
     pub gpu_acceleration: bool,
+    /// Device to run on; `Auto` honours `gpu_acceleration`
+    pub device: DeviceSelection,
+    /// Backends tried in order when the selected one fails to load or to serve its first prediction
+    pub fallback_chain: Vec<Backend>,
+    /// Weight conversion applied by the created engine; fallback backends load weights unconverted
+    pub optimization: OptimizationConfig,
+    /// Layer graph (JSON) for generic weight files, replacing a `*.graph.json` next to them
+    pub model_graph: Option<String>,
 }
 
 impl Default for EngineConfig {
@@ -214,6 +333,10 @@ impl Default for EngineConfig {
             preferred_engine: None,
             allow_fallback: true,
             gpu_acceleration: true,
+            device: DeviceSelection::Auto,
+            fallback_chain: Vec::new(),
+            optimization: OptimizationConfig::default(),
+            model_graph: None,
         }
     }
 }
@@ -242,12 +365,63 @@ impl EngineConfig {
         self
     }
     
+    /// Set the device to run on
+    pub fn with_device(mut self, device: DeviceSelection) -> Self {
+        self.device = device;
+        self
+    }
+    
+    /// Set the backends to fall back to, in order
+    pub fn with_fallback_chain(mut self, chain: Vec<Backend>) -> Self {
+        self.fallback_chain = chain;
+        self
+    }
+    
+    /// Set the weight conversion applied while loading
+    pub fn with_optimization(mut self, optimization: OptimizationConfig) -> Self {
+        self.optimization = optimization;
+        self
+    }
+    
+    /// Set the layer graph generic weight files are run with
+    pub fn with_model_graph<S: Into<String>>(mut self, graph_json: S) -> Self {
+        self.model_graph = Some(graph_json.into());
+        self
+    }
+    
+    /// Get the backend tried first for a format
+    pub fn primary_backend(&self, format: ModelFormat) -> Backend {
+        let engine_type = self.preferred_engine.clone()
+            .unwrap_or_else(|| EngineFactory::preferred_engine_for_format(format));
+        Backend::new(engine_type, self.effective_device())
+    }
+    
+    /// Get the device engines are created on
+    pub fn effective_device(&self) -> DeviceSelection {
+        match self.device {
+            DeviceSelection::Auto if !self.gpu_acceleration => DeviceSelection::Cpu,
+            device => device,
+        }
+    }
+    
     /// Create an engine using this configuration
     pub fn create_engine(&self, format: ModelFormat) -> Result<Box<dyn InferenceEngine>, InferenceError> {
+        let mut engine = self.select_engine(format)?;
+        engine.set_optimization(self.optimization.clone())?;
+        if let Some(graph) = &self.model_graph {
+            engine.set_model_graph(graph)?;
+        }
+        Ok(engine)
+    }
+    
+    /// Create the preferred engine, or the best one for the format
+    fn select_engine(&self, format: ModelFormat) -> Result<Box<dyn InferenceEngine>, InferenceError> {
+        let device = self.effective_device();
+        
         // Try preferred engine first
-        if let Some(preferred) = self.preferred_engine {
-            if EngineFactory::is_engine_available(preferred) {
-                return EngineFactory::create_engine_by_type(preferred);
+        if let Some(preferred) = &self.preferred_engine {
+            if EngineFactory::is_engine_available(preferred.clone()) {
+                return EngineFactory::create_engine_on_device(preferred.clone(), device);
             } else if !self.allow_fallback {
                 return Err(InferenceError::configuration(
                     format!("Preferred engine {:?} not available and fallback disabled", preferred)
@@ -257,7 +431,7 @@ impl EngineConfig {
         
         // Fall back to best engine for format
         if self.allow_fallback {
-            EngineFactory::create_best_engine_for_format(format)
+            EngineFactory::create_engine_on_device(EngineFactory::best_engine_for_format(format)?, device)
         } else {
             Err(InferenceError::configuration(
                 "No suitable engine available and fallback disabled"
@@ -284,6 +458,14 @@ mod tests {
             EngineFactory::detect_format_from_path("model.pth").unwrap(),
             ModelFormat::PyTorch
         );
+        assert_eq!(
+            EngineFactory::detect_format_from_path("model.gguf").unwrap(),
+            ModelFormat::Gguf
+        );
+        assert_eq!(
+            EngineFactory::detect_format_from_path("model.onnx").unwrap(),
+            ModelFormat::Onnx
+        );
         assert_eq!(
             EngineFactory::detect_format_from_path("model.linfa").unwrap(),
             ModelFormat::Linfa
@@ -307,6 +489,20 @@ mod tests {
             ModelFormat::PyTorch
         );
         
+        // GGUF format (magic bytes)
+        let gguf_bytes = b"GGUF\x03\x00\x00\x00";
+        assert_eq!(
+            EngineFactory::detect_format_from_bytes(gguf_bytes).unwrap(),
+            ModelFormat::Gguf
+        );
+        
+        // ONNX format (protobuf, ir_version then graph fields)
+        let onnx_bytes = b"\x08\x07\x12\x07pytorch";
+        assert_eq!(
+            EngineFactory::detect_format_from_bytes(onnx_bytes).unwrap(),
+            ModelFormat::Onnx
+        );
+        
         // Unknown format defaults to SafeTensors
         let unknown_bytes = b"unknown format";
         assert_eq!(
@@ -336,10 +532,18 @@ mod tests {
             EngineFactory::preferred_engine_for_format(ModelFormat::PyTorch),
             EngineType::Candle
         );
+        assert_eq!(
+            EngineFactory::preferred_engine_for_format(ModelFormat::Gguf),
+            EngineType::Candle
+        );
         assert_eq!(
             EngineFactory::preferred_engine_for_format(ModelFormat::Linfa),
             EngineType::Linfa
         );
+        assert_eq!(
+            EngineFactory::preferred_engine_for_format(ModelFormat::Onnx),
+            EngineType::Onnx
+        );
     }
     
     #[test]
@@ -354,12 +558,38 @@ mod tests {
         assert!(config.gpu_acceleration);
     }
     
+    #[test]
+    fn test_engine_config_device() {
+        assert_eq!(EngineConfig::new().effective_device(), DeviceSelection::Auto);
+        assert_eq!(
+            EngineConfig::new().with_gpu_acceleration(false).effective_device(),
+            DeviceSelection::Cpu
+        );
+        
+        // An explicit device wins over the legacy flag
+        let config = EngineConfig::new()
+            .with_gpu_acceleration(false)
+            .with_device(DeviceSelection::Cuda { ordinal: 1 });
+        assert_eq!(config.effective_device(), DeviceSelection::Cuda { ordinal: 1 });
+    }
+    
+    #[test]
+    fn test_backend_parsing() {
+        let backend = Backend::parse("candle@cuda:1").unwrap();
+        assert_eq!(backend, Backend::new(EngineType::Candle, DeviceSelection::Cuda { ordinal: 1 }));
+        assert_eq!(backend.name(), "candle@cuda:1");
+        
+        assert_eq!(Backend::parse("ONNX").unwrap(), Backend::new(EngineType::Onnx, DeviceSelection::Auto));
+        assert!(Backend::parse("tensorflow@cpu").is_err());
+        assert!(Backend::parse("candle@tpu").is_err());
+    }
+    
     #[test]
     fn test_create_engine_for_available_formats() {
         let available_engines = EngineFactory::available_engines();
         
         for engine_type in available_engines {
-            let result = EngineFactory::create_engine_by_type(engine_type);
+            let result = EngineFactory::create_engine_by_type(engine_type.clone());
             assert!(result.is_ok(), "Should be able to create available engine {:?}", engine_type);
             
             let engine = result.unwrap();
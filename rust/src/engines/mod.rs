@@ -1,27 +1,56 @@
-use crate::models::{InferenceError, Tensor, TensorSpec};
+use crate::models::{InferenceError, Tensor, TensorSpec, PredictContext, OptimizationConfig, OptimizationLevel, OptimizationReport};
 use async_trait::async_trait;
 use std::fmt::Debug;
 use std::any::Any;
+use std::borrow::Cow;
 
 /// Represents the supported ML engine types
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum EngineType {
     Candle,
     Linfa,
+    Onnx,
+    /// Third-party engine registered with the `EngineRegistry`, by a static
+    /// or runtime (e.g. config-provided) name
+    Plugin(Cow<'static, str>),
+}
+
+impl EngineType {
+    /// Look up an engine by name, including registered plugins
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "candle" => Some(EngineType::Candle),
+            "linfa" => Some(EngineType::Linfa),
+            "onnx" => Some(EngineType::Onnx),
+            name => EngineRegistry::get(name).map(|plugin| plugin.engine_type()),
+        }
+    }
+    
+    /// Get the lowercase name used by the public API
+    pub fn name(&self) -> String {
+        match self {
+            EngineType::Plugin(name) => name.to_string(),
+            other => format!("{:?}", other).to_lowercase(),
+        }
+    }
 }
 
 /// Represents supported model formats
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ModelFormat {
     SafeTensors,
     PyTorch,
+    Gguf,
     Linfa,
+    Onnx,
+    /// Format handled by the named plugin engine
+    Plugin(Cow<'static, str>),
 }
 
 /// Core trait for ML inference engines
 /// 
 /// This trait provides a unified interface for loading and managing ML models
-/// across different backend engines (Candle, Linfa).
+/// across different backend engines (Candle, Linfa, ONNX).
 #[async_trait]
 pub trait InferenceEngine: Send + Sync + Debug {
     /// Load a model from a file path
@@ -38,6 +67,36 @@ pub trait InferenceEngine: Send + Sync + Debug {
     
     /// Get the engine type
     fn engine_type(&self) -> EngineType;
+    
+    /// Get the device this engine runs on (e.g. "cpu", "cuda:0")
+    fn device_name(&self) -> String {
+        "cpu".to_string()
+    }
+    
+    /// Get why the engine runs on the CPU instead of the requested device, if it does
+    fn device_fallback_reason(&self) -> Option<String> {
+        None
+    }
+    
+    /// Set the weight conversion applied to models loaded afterwards
+    /// 
+    /// Engines that can't convert weights reject any level but `None`.
+    fn set_optimization(&mut self, config: OptimizationConfig) -> Result<(), InferenceError> {
+        if config.level != OptimizationLevel::None {
+            return Err(InferenceError::configuration(format!(
+                "{} engine can't apply optimization level {}", self.engine_name(), config.level.name()
+            )));
+        }
+        Ok(())
+    }
+    
+    /// Set the layer graph (JSON) that generic weight files are run with
+    /// 
+    /// Engines whose models carry their own structure reject it.
+    fn set_model_graph(&mut self, graph_json: &str) -> Result<(), InferenceError> {
+        let _ = graph_json;
+        Err(InferenceError::configuration(format!("{} engine does not run model graphs", self.engine_name())))
+    }
 }
 
 /// Core trait for loaded ML models
@@ -52,6 +111,49 @@ pub trait Model: Send + Sync + Debug {
     /// Make batch predictions
     async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError>;
     
+    /// Make a single prediction that honours cancellation and deadlines
+    /// 
+    /// The default implementation checks the context before and after calling
+    /// `predict`. Models with multi-step inference should override this and
+    /// check the context between layers or generated tokens.
+    async fn predict_with_context(&self, input: &Tensor, ctx: &PredictContext) -> Result<Tensor, InferenceError> {
+        ctx.check()?;
+        let output = self.predict(input).await?;
+        ctx.check()?;
+        Ok(output)
+    }
+    
+    /// Make batch predictions that honour cancellation and deadlines
+    /// 
+    /// The default implementation runs items one at a time and checks the
+    /// context before each of them.
+    async fn predict_batch_with_context(&self, inputs: &[Tensor], ctx: &PredictContext) -> Result<Vec<Tensor>, InferenceError> {
+        let mut results = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            ctx.check()?;
+            results.push(self.predict_with_context(input, ctx).await?);
+        }
+        Ok(results)
+    }
+    
+    /// Make a prediction from inputs matched to the input specs by name
+    /// 
+    /// Returns every output, named after its output spec. The default
+    /// implementation accepts a single input and forwards it to `predict`;
+    /// models with several inputs should override this.
+    async fn predict_named(&self, inputs: &[(String, Tensor)]) -> Result<Vec<(String, Tensor)>, InferenceError> {
+        let [(_, input)] = inputs else {
+            return Err(InferenceError::invalid_tensor_data(format!(
+                "Model takes a single input, got {}", inputs.len()
+            )));
+        };
+        let output = self.predict(input).await?;
+        let name = self.output_specs().first()
+            .map(|spec| spec.name.clone())
+            .unwrap_or_else(|| "output".to_string());
+        Ok(vec![(name, output)])
+    }
+    
     /// Get input tensor specifications
     fn input_specs(&self) -> &[TensorSpec];
     
@@ -66,18 +168,31 @@ pub trait Model: Send + Sync + Debug {
         None
     }
     
+    /// Get how the weights were converted at load time, if they were
+    fn optimization_report(&self) -> Option<&OptimizationReport> {
+        None
+    }
+    
     /// Get reference to the underlying model as Any for downcasting
     fn as_any(&self) -> &dyn Any;
 }
 
 /// Optional metadata for models
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct ModelMetadata {
     pub name: Option<String>,
     pub version: Option<String>,
     pub description: Option<String>,
     pub author: Option<String>,
     pub license: Option<String>,
+    /// Model architecture (e.g. "llama")
+    pub architecture: Option<String>,
+    /// Maximum context length in tokens
+    pub context_length: Option<usize>,
+    /// Tokenizer model (e.g. "llama", "gpt2")
+    pub tokenizer: Option<String>,
+    /// Predominant weight quantization (e.g. "Q4_K")
+    pub quantization: Option<String>,
 }
 
 // Re-export engine implementations
@@ -92,6 +207,15 @@ pub mod linfa_engine;
 #[cfg(feature = "linfa")]
 pub use linfa_engine::LinfaEngine;
 
+#[cfg(feature = "onnx")]
+pub mod onnx_engine;
+#[cfg(feature = "onnx")]
+pub use onnx_engine::OnnxEngine;
+
 // Engine factory for auto-selection
 pub mod factory;
-pub use factory::EngineFactory; 
\ No newline at end of file
+pub use factory::{Backend, EngineFactory};
+
+// Registry for third-party engines
+pub mod registry;
+pub use registry::{EnginePlugin, EngineRegistry}; 
\ No newline at end of file
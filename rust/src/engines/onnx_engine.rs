@@ -0,0 +1,355 @@
+use crate::engines::{InferenceEngine, Model, EngineType, ModelFormat};
+use crate::models::{InferenceError, Tensor, TensorSpec, DataType};
+use async_trait::async_trait;
+use std::any::Any;
+
+use tract_onnx::tract_core::internal::DimLike;
+use tract_onnx::prelude::{
+    DatumType, Framework, InferenceModel, InferenceModelExt, IntoTValue, OutletId,
+    TValue, Tensor as TractTensor, TractError, TVec, TypedFact, TypedModel, TypedSimplePlan,
+};
+
+/// ONNX engine implementation backed by tract
+/// 
+/// Loads `.onnx` graphs with the pure-Rust tract runtime, so no native
+/// ONNX Runtime library is needed on device.
+#[derive(Debug, Clone)]
+pub struct OnnxEngine {
+    /// Whether to run tract's graph optimizer after loading
+    optimize: bool,
+}
+
+impl OnnxEngine {
+    /// Create a new ONNX engine with graph optimization enabled
+    pub fn new() -> Result<Self, InferenceError> {
+        Ok(Self { optimize: true })
+    }
+    
+    /// Enable or disable graph optimization
+    pub fn with_optimization(mut self, optimize: bool) -> Self {
+        self.optimize = optimize;
+        self
+    }
+    
+    /// Build a runnable model from a parsed ONNX graph
+    fn build_model(&self, model: InferenceModel) -> Result<Box<dyn Model>, InferenceError> {
+        let typed = model.into_typed()
+            .map_err(|e| InferenceError::model_load(format!("Failed to analyse ONNX graph: {}", e)))?;
+        
+        // Read specs before optimization, which may rename or fuse nodes
+        let outlets_error = |e: TractError| InferenceError::model_load(format!("Failed to read ONNX graph outlets: {}", e));
+        let input_outlets = typed.input_outlets().map_err(outlets_error)?;
+        let input_specs = graph_specs(&typed, input_outlets, "input")?;
+        let input_types = input_outlets.iter()
+            .map(|&outlet| typed.outlet_fact(outlet).map(|fact| fact.datum_type))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| InferenceError::model_load(format!("Failed to read ONNX tensor fact: {}", e)))?;
+        let output_specs = graph_specs(&typed, typed.output_outlets().map_err(outlets_error)?, "output")?;
+        
+        let typed = if self.optimize {
+            typed.into_optimized()
+                .map_err(|e| InferenceError::model_load(format!("Failed to optimize ONNX graph: {}", e)))?
+        } else {
+            typed
+        };
+        
+        let plan = typed.into_runnable()
+            .map_err(|e| InferenceError::model_load(format!("Failed to prepare ONNX graph: {}", e)))?;
+        
+        println!("✅ Loaded ONNX model with {} inputs and {} outputs", input_specs.len(), output_specs.len());
+        
+        Ok(Box::new(OnnxModel {
+            plan,
+            input_types,
+            input_specs,
+            output_specs,
+        }))
+    }
+}
+
+impl Default for OnnxEngine {
+    fn default() -> Self {
+        Self::new().expect("Failed to create default ONNX engine")
+    }
+}
+
+#[async_trait]
+impl InferenceEngine for OnnxEngine {
+    async fn load_model(&self, path: &str) -> Result<Box<dyn Model>, InferenceError> {
+        println!("🔧 Loading ONNX file: {}", path);
+        let model = tract_onnx::onnx()
+            .model_for_path(path)
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse ONNX file {}: {}", path, e)))?;
+        self.build_model(model)
+    }
+    
+    async fn load_from_bytes(&self, bytes: &[u8]) -> Result<Box<dyn Model>, InferenceError> {
+        println!("🔧 Parsing ONNX graph from {} bytes", bytes.len());
+        let model = tract_onnx::onnx()
+            .model_for_read(&mut std::io::Cursor::new(bytes))
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse ONNX graph: {}", e)))?;
+        self.build_model(model)
+    }
+    
+    fn supports_format(&self, format: &ModelFormat) -> bool {
+        matches!(format, ModelFormat::Onnx)
+    }
+    
+    fn engine_name(&self) -> &'static str {
+        "onnx"
+    }
+    
+    fn engine_type(&self) -> EngineType {
+        EngineType::Onnx
+    }
+}
+
+/// Read tensor specs for graph inputs or outputs
+/// 
+/// Symbolic dimensions (such as a dynamic batch size) become `None`.
+fn graph_specs(model: &TypedModel, outlets: &[OutletId], fallback_name: &str) -> Result<Vec<TensorSpec>, InferenceError> {
+    outlets.iter().enumerate().map(|(ix, &outlet)| {
+        let fact: &TypedFact = model.outlet_fact(outlet)
+            .map_err(|e| InferenceError::model_load(format!("Failed to read ONNX tensor fact: {}", e)))?;
+        
+        let name = model.outlet_label(outlet)
+            .map(|label| label.to_string())
+            .unwrap_or_else(|| {
+                let node_name = &model.node(outlet.node).name;
+                if node_name.is_empty() { format!("{}_{}", fallback_name, ix) } else { node_name.clone() }
+            });
+        let shape = fact.shape.iter().map(|dim| dim.to_usize().ok()).collect();
+        
+        Ok(TensorSpec::new(name, shape, datum_to_data_type(fact.datum_type)))
+    }).collect()
+}
+
+/// Map a tract datum type to our data type, defaulting to F32
+fn datum_to_data_type(datum_type: DatumType) -> DataType {
+    match datum_type {
+        DatumType::F64 => DataType::F64,
+        DatumType::I32 => DataType::I32,
+        DatumType::I64 => DataType::I64,
+        DatumType::U8 => DataType::U8,
+        DatumType::U32 => DataType::U32,
+        DatumType::Bool => DataType::Bool,
+        _ => DataType::F32,
+    }
+}
+
+/// Loaded ONNX model ready for inference
+pub struct OnnxModel {
+    plan: TypedSimplePlan<TypedModel>,
+    /// Declared element type of each graph input, in input order
+    input_types: Vec<DatumType>,
+    input_specs: Vec<TensorSpec>,
+    output_specs: Vec<TensorSpec>,
+}
+
+impl std::fmt::Debug for OnnxModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OnnxModel")
+            .field("input_specs", &self.input_specs)
+            .field("output_specs", &self.output_specs)
+            .finish()
+    }
+}
+
+impl OnnxModel {
+    /// Convert our Tensor to a tract tensor of the given datum type
+    /// 
+    /// Inputs are cast to the type the graph declares, so token ids passed
+    /// as I64 can feed an I32 input and bool masks carried as bytes become bools.
+    fn tensor_to_tract(&self, tensor: &Tensor, datum_type: DatumType) -> Result<TractTensor, InferenceError> {
+        let shape = tensor.shape();
+        let result = match tensor.data_type() {
+            DataType::F32 => TractTensor::from_shape(shape, &tensor.to_f32_vec()?),
+            DataType::F64 => TractTensor::from_shape(shape, &tensor.to_f64_vec()?),
+            DataType::I64 => TractTensor::from_shape(shape, &tensor.to_i64_vec()?),
+            DataType::I32 => {
+                let data: Vec<i32> = tensor.to_i64_vec()?.into_iter().map(|v| v as i32).collect();
+                TractTensor::from_shape(shape, &data)
+            }
+            DataType::U8 | DataType::Bool => TractTensor::from_shape(shape, tensor.data()),
+            DataType::U32 => {
+                let data: Vec<u32> = tensor.data()
+                    .chunks_exact(4)
+                    .map(|c| u32::from_le_bytes([c[0], c[1], c[2], c[3]]))
+                    .collect();
+                TractTensor::from_shape(shape, &data)
+            }
+        };
+        
+        let result = result
+            .map_err(|e| InferenceError::prediction(format!("Failed to create ONNX input tensor: {}", e)))?;
+        if result.datum_type() == datum_type {
+            return Ok(result);
+        }
+        result.cast_to_dt(datum_type)
+            .map(|t| t.into_owned())
+            .map_err(|e| InferenceError::prediction(format!("Failed to cast ONNX input to {:?}: {}", datum_type, e)))
+    }
+    
+    /// Run the graph on inputs given in input spec order
+    fn run(&self, inputs: &[&Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+        if inputs.len() != self.input_specs.len() {
+            return Err(InferenceError::invalid_tensor_data(format!(
+                "ONNX model takes {} inputs, got {}", self.input_specs.len(), inputs.len()
+            )));
+        }
+        
+        let inputs = inputs.iter().zip(&self.input_types)
+            .map(|(tensor, &datum_type)| self.tensor_to_tract(tensor, datum_type).map(IntoTValue::into_tvalue))
+            .collect::<Result<TVec<TValue>, _>>()?;
+        let outputs = self.plan.run(inputs)
+            .map_err(|e| InferenceError::prediction(format!("ONNX inference failed: {}", e)))?;
+        
+        outputs.iter().map(|output| self.tract_to_tensor(output)).collect()
+    }
+    
+    /// Convert a tract tensor back to our Tensor
+    fn tract_to_tensor(&self, tensor: &TractTensor) -> Result<Tensor, InferenceError> {
+        let shape = tensor.shape().to_vec();
+        let convert = |e: TractError| {
+            InferenceError::prediction(format!("Failed to read ONNX output: {}", e))
+        };
+        
+        match tensor.datum_type() {
+            DatumType::F64 => Tensor::from_f64(tensor.as_slice::<f64>().map_err(convert)?.to_vec(), shape),
+            DatumType::I64 => Tensor::from_i64(tensor.as_slice::<i64>().map_err(convert)?.to_vec(), shape),
+            DatumType::I32 => Tensor::from_i32(tensor.as_slice::<i32>().map_err(convert)?.to_vec(), shape),
+            _ => {
+                let values = tensor.cast_to::<f32>().map_err(convert)?;
+                Tensor::from_f32(values.as_slice::<f32>().map_err(convert)?.to_vec(), shape)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Model for OnnxModel {
+    async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+        self.run(&[input])?
+            .into_iter()
+            .next()
+            .ok_or_else(|| InferenceError::prediction("ONNX graph produced no outputs"))
+    }
+    
+    async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+        let mut results = Vec::new();
+        for input in inputs {
+            results.push(self.predict(input).await?);
+        }
+        Ok(results)
+    }
+    
+    async fn predict_named(&self, inputs: &[(String, Tensor)]) -> Result<Vec<(String, Tensor)>, InferenceError> {
+        if let Some((name, _)) = inputs.iter().find(|(name, _)| !self.input_specs.iter().any(|spec| &spec.name == name)) {
+            return Err(InferenceError::invalid_tensor_data(format!("ONNX model has no input named '{}'", name)));
+        }
+        
+        let ordered = self.input_specs.iter()
+            .map(|spec| inputs.iter()
+                .find(|(name, _)| name == &spec.name)
+                .map(|(_, tensor)| tensor)
+                .ok_or_else(|| InferenceError::invalid_tensor_data(format!("Missing ONNX input '{}'", spec.name))))
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        let outputs = self.run(&ordered)?;
+        Ok(self.output_specs.iter().map(|spec| spec.name.clone()).zip(outputs).collect())
+    }
+    
+    fn input_specs(&self) -> &[TensorSpec] {
+        &self.input_specs
+    }
+    
+    fn output_specs(&self) -> &[TensorSpec] {
+        &self.output_specs
+    }
+    
+    fn engine_type(&self) -> EngineType {
+        EngineType::Onnx
+    }
+    
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_onnx_engine_creation() {
+        let engine = OnnxEngine::new().unwrap();
+        assert_eq!(engine.engine_name(), "onnx");
+        assert_eq!(engine.engine_type(), EngineType::Onnx);
+        assert!(engine.supports_format(&ModelFormat::Onnx));
+        assert!(!engine.supports_format(&ModelFormat::SafeTensors));
+    }
+    
+    #[test]
+    fn test_optimization_level_is_rejected() {
+        use crate::models::{OptimizationConfig, OptimizationLevel};
+        
+        let mut engine = OnnxEngine::new().unwrap();
+        assert!(engine.set_optimization(OptimizationConfig::default()).is_ok());
+        assert!(matches!(
+            engine.set_optimization(OptimizationConfig::new(OptimizationLevel::F16)),
+            Err(InferenceError::Configuration(_))
+        ));
+    }
+    
+    #[tokio::test]
+    async fn test_invalid_onnx_bytes() {
+        let engine = OnnxEngine::new().unwrap();
+        let result = engine.load_from_bytes(&[0x08, 0x07, 0x3a, 0xff]).await;
+        assert!(matches!(result, Err(InferenceError::ModelLoad(_))));
+    }
+    
+    /// Load the two-input add/mul graph from the test fixtures
+    async fn load_tiny_onnx() -> Box<dyn Model> {
+        let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tiny_onnx/model.onnx");
+        OnnxEngine::new().unwrap().load_model(path.to_str().unwrap()).await.unwrap()
+    }
+    
+    #[tokio::test]
+    async fn test_tiny_onnx_specs() {
+        let model = load_tiny_onnx().await;
+        
+        let inputs: Vec<_> = model.input_specs().iter().map(|s| (s.name.as_str(), s.shape.clone(), s.data_type.clone())).collect();
+        assert_eq!(inputs, vec![
+            ("a", vec![None, Some(3)], DataType::F32),
+            ("b", vec![None, Some(3)], DataType::I64),
+        ]);
+        let outputs: Vec<_> = model.output_specs().iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(outputs, vec!["sum", "product"]);
+    }
+    
+    #[tokio::test]
+    async fn test_tiny_onnx_named_inference() {
+        let model = load_tiny_onnx().await;
+        
+        // `b` is declared int64; an f32 tensor is cast to it
+        let a = Tensor::from_f32(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], vec![2, 3]).unwrap();
+        let b = Tensor::from_f32(vec![2.0, 2.0, 2.0, 3.0, 3.0, 3.0], vec![2, 3]).unwrap();
+        let outputs = model.predict_named(&[("b".to_string(), b), ("a".to_string(), a)]).await.unwrap();
+        
+        assert_eq!(outputs[0].0, "sum");
+        assert_eq!(outputs[0].1.shape(), &[2, 3]);
+        assert_eq!(outputs[0].1.to_f32_vec().unwrap(), vec![3.0, 4.0, 5.0, 7.0, 8.0, 9.0]);
+        assert_eq!(outputs[1].0, "product");
+        assert_eq!(outputs[1].1.to_f32_vec().unwrap(), vec![2.0, 4.0, 6.0, 12.0, 15.0, 18.0]);
+    }
+    
+    #[tokio::test]
+    async fn test_tiny_onnx_rejects_mismatched_inputs() {
+        let model = load_tiny_onnx().await;
+        let a = Tensor::from_f32(vec![1.0, 2.0, 3.0], vec![1, 3]).unwrap();
+        
+        assert!(matches!(model.predict(&a).await, Err(InferenceError::InvalidTensorData(_))));
+        assert!(model.predict_named(&[("a".to_string(), a.clone())]).await.is_err());
+        assert!(model.predict_named(&[("a".to_string(), a.clone()), ("c".to_string(), a)]).await.is_err());
+    }
+}
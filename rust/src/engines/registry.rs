@@ -0,0 +1,335 @@
+use crate::engines::{EngineType, InferenceEngine};
+use crate::models::InferenceError;
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// Names reserved for the built-in engines
+const BUILTIN_NAMES: [&str; 3] = ["candle", "linfa", "onnx"];
+
+/// Constructor for a plugin engine
+pub type EngineConstructor = Arc<dyn Fn() -> Result<Box<dyn InferenceEngine>, InferenceError> + Send + Sync>;
+
+/// Hook that recognizes a plugin's model format from the leading bytes of a file
+pub type FormatSniffer = fn(&[u8]) -> bool;
+
+static PLUGINS: once_cell::sync::Lazy<RwLock<Vec<EnginePlugin>>> =
+    once_cell::sync::Lazy::new(|| RwLock::new(Vec::new()));
+
+/// A third-party engine registered with the `EngineRegistry`
+/// 
+/// Engines created by the constructor should report
+/// `EngineType::Plugin(name)` from `engine_type`.
+#[derive(Clone)]
+pub struct EnginePlugin {
+    name: Cow<'static, str>,
+    extensions: Vec<String>,
+    sniffer: Option<FormatSniffer>,
+    constructor: EngineConstructor,
+}
+
+impl EnginePlugin {
+    /// Create a plugin with a unique name and an engine constructor
+    /// 
+    /// The name may be a literal or a `String` chosen at runtime.
+    pub fn new<N, F>(name: N, constructor: F) -> Self
+    where
+        N: Into<Cow<'static, str>>,
+        F: Fn() -> Result<Box<dyn InferenceEngine>, InferenceError> + Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            extensions: Vec::new(),
+            sniffer: None,
+            constructor: Arc::new(constructor),
+        }
+    }
+    
+    /// Set the file extensions (without the dot) this engine loads
+    pub fn with_extensions(mut self, extensions: &[&str]) -> Self {
+        self.extensions = extensions.iter().map(|ext| ext.trim_start_matches('.').to_lowercase()).collect();
+        self
+    }
+    
+    /// Set the hook used to recognize files by content
+    pub fn with_sniffer(mut self, sniffer: FormatSniffer) -> Self {
+        self.sniffer = Some(sniffer);
+        self
+    }
+    
+    /// Get the plugin name
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    
+    /// Get the engine type reported by this plugin's engines
+    pub fn engine_type(&self) -> EngineType {
+        EngineType::Plugin(self.name.clone())
+    }
+    
+    /// Get the supported file extensions
+    pub fn extensions(&self) -> &[String] {
+        &self.extensions
+    }
+    
+    /// Check whether this plugin loads files with the given extension
+    pub fn supports_extension(&self, extension: &str) -> bool {
+        let extension = extension.trim_start_matches('.');
+        self.extensions.iter().any(|ext| ext.eq_ignore_ascii_case(extension))
+    }
+    
+    /// Check whether the sniffing hook recognizes the given bytes
+    pub fn sniff(&self, bytes: &[u8]) -> bool {
+        self.sniffer.is_some_and(|sniffer| sniffer(bytes))
+    }
+    
+    /// Create a new engine instance
+    pub fn create_engine(&self) -> Result<Box<dyn InferenceEngine>, InferenceError> {
+        let engine = (self.constructor)()?;
+        if engine.engine_type() != self.engine_type() {
+            return Err(InferenceError::configuration(format!(
+                "Engine plugin '{}' created an engine reporting {:?}", self.name, engine.engine_type()
+            )));
+        }
+        Ok(engine)
+    }
+}
+
+impl fmt::Debug for EnginePlugin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EnginePlugin")
+            .field("name", &self.name)
+            .field("extensions", &self.extensions)
+            .field("has_sniffer", &self.sniffer.is_some())
+            .finish()
+    }
+}
+
+/// Global registry of third-party engines
+/// 
+/// Built-in engines always take precedence: plugins are consulted for
+/// extensions and content that no built-in engine recognizes.
+pub struct EngineRegistry;
+
+impl EngineRegistry {
+    /// Register a plugin engine
+    pub fn register(plugin: EnginePlugin) -> Result<(), InferenceError> {
+        if plugin.name.is_empty() || BUILTIN_NAMES.contains(&plugin.name.to_lowercase().as_str()) {
+            return Err(InferenceError::configuration(format!(
+                "Invalid plugin engine name '{}'", plugin.name
+            )));
+        }
+        
+        let mut plugins = PLUGINS.write().unwrap_or_else(|e| e.into_inner());
+        if plugins.iter().any(|p| p.name.eq_ignore_ascii_case(&plugin.name)) {
+            return Err(InferenceError::configuration(format!(
+                "Engine '{}' is already registered", plugin.name
+            )));
+        }
+        println!("🔌 Registered engine plugin: {}", plugin.name);
+        plugins.push(plugin);
+        Ok(())
+    }
+    
+    /// Remove a plugin engine, returning whether it was registered
+    pub fn unregister(name: &str) -> bool {
+        let mut plugins = PLUGINS.write().unwrap_or_else(|e| e.into_inner());
+        let before = plugins.len();
+        plugins.retain(|p| !p.name.eq_ignore_ascii_case(name));
+        plugins.len() != before
+    }
+    
+    /// Look up a plugin by name (case-insensitive)
+    pub fn get(name: &str) -> Option<EnginePlugin> {
+        let plugins = PLUGINS.read().unwrap_or_else(|e| e.into_inner());
+        plugins.iter().find(|p| p.name.eq_ignore_ascii_case(name)).cloned()
+    }
+    
+    /// Check whether a plugin is registered
+    pub fn contains(name: &str) -> bool {
+        Self::get(name).is_some()
+    }
+    
+    /// Get the names of all registered plugins, in registration order
+    pub fn names() -> Vec<Cow<'static, str>> {
+        let plugins = PLUGINS.read().unwrap_or_else(|e| e.into_inner());
+        plugins.iter().map(|p| p.name.clone()).collect()
+    }
+    
+    /// Find the plugin that loads files with the given extension
+    pub fn find_by_extension(extension: &str) -> Option<Cow<'static, str>> {
+        let plugins = PLUGINS.read().unwrap_or_else(|e| e.into_inner());
+        plugins.iter().find(|p| p.supports_extension(extension)).map(|p| p.name.clone())
+    }
+    
+    /// Find the first plugin whose sniffing hook recognizes the bytes
+    pub fn find_by_content(bytes: &[u8]) -> Option<Cow<'static, str>> {
+        let plugins = PLUGINS.read().unwrap_or_else(|e| e.into_inner());
+        plugins.iter().find(|p| p.sniff(bytes)).map(|p| p.name.clone())
+    }
+    
+    /// Create an engine from a registered plugin
+    pub fn create_engine(name: &str) -> Result<Box<dyn InferenceEngine>, InferenceError> {
+        Self::get(name)
+            .ok_or_else(|| InferenceError::configuration(format!("Engine plugin '{}' is not registered", name)))?
+            .create_engine()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::engines::{EngineFactory, Model, ModelFormat};
+    use crate::models::{Tensor, TensorSpec};
+    use crate::utils::ModelDetector;
+    use async_trait::async_trait;
+    use std::any::Any;
+    
+    /// Engine that loads any file into an identity model
+    #[derive(Debug)]
+    pub(crate) struct EchoEngine {
+        name: Cow<'static, str>,
+    }
+    
+    #[derive(Debug)]
+    struct EchoModel {
+        name: Cow<'static, str>,
+    }
+    
+    #[async_trait]
+    impl InferenceEngine for EchoEngine {
+        async fn load_model(&self, _path: &str) -> Result<Box<dyn Model>, InferenceError> {
+            Ok(Box::new(EchoModel { name: self.name.clone() }))
+        }
+        
+        async fn load_from_bytes(&self, _bytes: &[u8]) -> Result<Box<dyn Model>, InferenceError> {
+            Ok(Box::new(EchoModel { name: self.name.clone() }))
+        }
+        
+        fn supports_format(&self, format: &ModelFormat) -> bool {
+            *format == ModelFormat::Plugin(self.name.clone())
+        }
+        
+        fn engine_name(&self) -> &'static str {
+            match self.name {
+                Cow::Borrowed(name) => name,
+                Cow::Owned(_) => "echo",
+            }
+        }
+        
+        fn engine_type(&self) -> EngineType {
+            EngineType::Plugin(self.name.clone())
+        }
+    }
+    
+    #[async_trait]
+    impl Model for EchoModel {
+        async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+            Ok(input.clone())
+        }
+        
+        async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+            Ok(inputs.to_vec())
+        }
+        
+        fn input_specs(&self) -> &[TensorSpec] {
+            &[]
+        }
+        
+        fn output_specs(&self) -> &[TensorSpec] {
+            &[]
+        }
+        
+        fn engine_type(&self) -> EngineType {
+            EngineType::Plugin(self.name.clone())
+        }
+        
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+    
+    /// Plugin for `EchoEngine` with its own extension and magic bytes
+    pub(crate) fn echo_plugin<N: Into<Cow<'static, str>>>(name: N, extension: &str) -> EnginePlugin {
+        let name = name.into();
+        EnginePlugin::new(name.clone(), move || Ok(Box::new(EchoEngine { name: name.clone() }) as Box<dyn InferenceEngine>))
+            .with_extensions(&[extension])
+            .with_sniffer(|bytes| bytes.starts_with(b"ECHO"))
+    }
+    
+    #[test]
+    fn test_register_and_detect_plugin() {
+        EngineRegistry::register(echo_plugin("echo_detect", "echod")).unwrap();
+        
+        assert!(EngineRegistry::contains("ECHO_DETECT"));
+        assert_eq!(EngineRegistry::find_by_extension(".ECHOD").as_deref(), Some("echo_detect"));
+        assert_eq!(
+            ModelDetector::detect_engine_from_path("model.echod"),
+            EngineType::Plugin("echo_detect".into())
+        );
+        assert_eq!(
+            ModelDetector::detect_format_from_path("model.echod").unwrap(),
+            ModelFormat::Plugin("echo_detect".into())
+        );
+        
+        // Built-in formats still win over plugin sniffers
+        assert_eq!(
+            ModelDetector::detect_format_from_bytes(b"GGUF\x03\x00\x00\x00").unwrap(),
+            ModelFormat::Gguf
+        );
+        assert!(matches!(
+            ModelDetector::detect_format_from_bytes(b"ECHO\x01\x02").unwrap(),
+            ModelFormat::Plugin(_)
+        ));
+        
+        assert!(EngineRegistry::unregister("echo_detect"));
+        assert!(!EngineRegistry::unregister("echo_detect"));
+        assert!(ModelDetector::detect_format_from_path("missing.echod").is_err());
+    }
+    
+    #[test]
+    fn test_register_rejects_duplicates_and_builtins() {
+        assert!(EngineRegistry::register(echo_plugin("onnx", "x")).is_err());
+        assert!(EngineRegistry::register(echo_plugin("", "x")).is_err());
+        
+        EngineRegistry::register(echo_plugin("echo_dup", "echodup")).unwrap();
+        assert!(EngineRegistry::register(echo_plugin("Echo_Dup", "other")).is_err());
+        EngineRegistry::unregister("echo_dup");
+    }
+    
+    #[test]
+    fn test_create_plugin_engine() {
+        EngineRegistry::register(echo_plugin("echo_create", "echoc")).unwrap();
+        
+        assert!(EngineFactory::available_engines().contains(&EngineType::Plugin("echo_create".into())));
+        assert!(EngineFactory::is_engine_available(EngineType::Plugin("echo_create".into())));
+        let engine = EngineFactory::create_engine(ModelFormat::Plugin("echo_create".into())).unwrap();
+        assert_eq!(engine.engine_name(), "echo_create");
+        
+        let engine = EngineRegistry::create_engine("echo_create").unwrap();
+        assert_eq!(engine.engine_type(), EngineType::Plugin("echo_create".into()));
+        assert!(engine.supports_format(&ModelFormat::Plugin("echo_create".into())));
+        
+        // Engines must report the plugin they were registered under
+        let mismatched = EnginePlugin::new("echo_mismatch", || {
+            Ok(Box::new(EchoEngine { name: "other".into() }) as Box<dyn InferenceEngine>)
+        });
+        assert!(mismatched.create_engine().is_err());
+        assert!(EngineRegistry::create_engine("echo_missing").is_err());
+    }
+    
+    #[test]
+    fn test_plugin_with_runtime_name() {
+        // Names read from configuration are not `&'static str`
+        let name = format!("echo_{}", "runtime");
+        EngineRegistry::register(echo_plugin(name.clone(), "echor")).unwrap();
+        
+        let engine_type = EngineType::from_name("ECHO_RUNTIME").unwrap();
+        assert_eq!(engine_type, EngineType::Plugin(name.clone().into()));
+        assert_eq!(engine_type.name(), "echo_runtime");
+        assert_eq!(ModelDetector::detect_format_from_path("model.echor").unwrap(), ModelFormat::Plugin(name.into()));
+        let engine = EngineFactory::create_engine_by_type(engine_type.clone()).unwrap();
+        assert_eq!(engine.engine_type(), engine_type);
+        EngineRegistry::unregister("echo_runtime");
+    }
+}
@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 585097583;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1734381454;
 
 // Section: executor
 
@@ -45,7 +45,7 @@ flutter_rust_bridge::frb_generated_default_handler!();
 
 // Section: wire_funcs
 
-fn wire__crate__api__inference__clear_cache_impl(
+fn wire__crate__api__inference__apply_chat_template_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -53,7 +53,7 @@ fn wire__crate__api__inference__clear_cache_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "clear_cache",
+            debug_name: "apply_chat_template",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -67,11 +67,20 @@ fn wire__crate__api__inference__clear_cache_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_messages =
+                <Vec<crate::api::inference::ChatMessage>>::sse_decode(&mut deserializer);
+            let api_add_generation_prompt = <bool>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
-                        let output_ok = crate::api::inference::clear_cache().await?;
+                        let output_ok = crate::api::inference::apply_chat_template(
+                            api_session_handle,
+                            api_messages,
+                            api_add_generation_prompt,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -80,16 +89,17 @@ fn wire__crate__api__inference__clear_cache_impl(
         },
     )
 }
-fn wire__crate__api__inference__detect_engine_from_bytes_impl(
+fn wire__crate__api__inference__cancel_prediction_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "detect_engine_from_bytes",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "cancel_prediction",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -101,25 +111,31 @@ fn wire__crate__api__inference__detect_engine_from_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_model_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_token = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, crate::models::error::InferenceError>((move || {
-                let output_ok = crate::api::inference::detect_engine_from_bytes(api_model_bytes)?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::cancel_prediction(api_token).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__inference__detect_engine_from_path_impl(
+fn wire__crate__api__inference__chat_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "detect_engine_from_path",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "chat_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -131,18 +147,38 @@ fn wire__crate__api__inference__detect_engine_from_path_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_model_path = <String>::sse_decode(&mut deserializer);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_messages =
+                <Vec<crate::api::inference::ChatMessage>>::sse_decode(&mut deserializer);
+            let api_generation =
+                <crate::api::inference::GenerationOptions>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::inference::PredictOptions>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::inference::GenerationEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::inference::detect_engine_from_path(api_model_path),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::chat_stream(
+                            api_session_handle,
+                            api_messages,
+                            api_generation,
+                            api_options,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__inference__dispose_session_impl(
+fn wire__crate__api__inference__clear_cache_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -150,7 +186,7 @@ fn wire__crate__api__inference__dispose_session_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "dispose_session",
+            debug_name: "clear_cache",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -164,13 +200,11 @@ fn wire__crate__api__inference__dispose_session_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_session_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::inference::dispose_session(api_session_handle).await?;
+                        let output_ok = crate::api::inference::clear_cache().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -179,16 +213,17 @@ fn wire__crate__api__inference__dispose_session_impl(
         },
     )
 }
-fn wire__crate__api__inference__get_available_engines_impl(
+fn wire__crate__api__inference__create_cancellation_token_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_available_engines",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "create_cancellation_token",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -201,15 +236,21 @@ fn wire__crate__api__inference__get_available_engines_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::inference::get_available_engines())?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::inference::create_cancellation_token().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__inference__get_cache_size_impl(
+fn wire__crate__api__inference__create_conversation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -217,7 +258,7 @@ fn wire__crate__api__inference__get_cache_size_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_cache_size",
+            debug_name: "create_conversation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -231,11 +272,19 @@ fn wire__crate__api__inference__get_cache_size_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_system_prompt = <Option<String>>::sse_decode(&mut deserializer);
+            let api_chat_template = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
-                        let output_ok = crate::api::inference::get_cache_size().await?;
+                        let output_ok = crate::api::inference::create_conversation(
+                            api_session_handle,
+                            api_system_prompt,
+                            api_chat_template,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -244,17 +293,16 @@ fn wire__crate__api__inference__get_cache_size_impl(
         },
     )
 }
-fn wire__crate__api__inference__get_download_progress_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__inference__detect_engine_from_bytes_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_download_progress",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "detect_engine_from_bytes",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -266,23 +314,48 @@ fn wire__crate__api__inference__get_download_progress_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_repo = <String>::sse_decode(&mut deserializer);
+            let api_model_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::inference::get_download_progress(api_repo).await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
+            transform_result_sse::<_, crate::models::error::InferenceError>((move || {
+                let output_ok = crate::api::inference::detect_engine_from_bytes(api_model_bytes)?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__inference__detect_engine_from_path_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "detect_engine_from_path",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
                 )
-            }
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_model_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::inference::detect_engine_from_path(api_model_path),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__inference__get_session_info_impl(
+fn wire__crate__api__inference__dispose_conversation_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -290,7 +363,7 @@ fn wire__crate__api__inference__get_session_info_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "get_session_info",
+            debug_name: "dispose_conversation",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -304,13 +377,13 @@ fn wire__crate__api__inference__get_session_info_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_conversation = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::inference::get_session_info(api_session_handle).await?;
+                            crate::api::inference::dispose_conversation(api_conversation).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -319,16 +392,17 @@ fn wire__crate__api__inference__get_session_info_impl(
         },
     )
 }
-fn wire__crate__api__simple__greet_impl(
+fn wire__crate__api__inference__dispose_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "greet",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "dispose_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -340,24 +414,30 @@ fn wire__crate__api__simple__greet_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_name = <String>::sse_decode(&mut deserializer);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::inference::dispose_session(api_session_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__simple__init_app_impl(
+fn wire__crate__api__inference__generate_stream_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_app",
+            debug_name: "generate_stream",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -371,19 +451,37 @@ fn wire__crate__api__simple__init_app_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_prompt = <String>::sse_decode(&mut deserializer);
+            let api_generation =
+                <crate::api::inference::GenerationOptions>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::inference::PredictOptions>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::inference::GenerationEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::simple::init_app();
-                    })?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::generate_stream(
+                            api_session_handle,
+                            api_prompt,
+                            api_generation,
+                            api_options,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__inference__init_inference_impl(
+fn wire__crate__api__inference__generation_options_default_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -391,7 +489,7 @@ fn wire__crate__api__inference__init_inference_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "init_inference",
+            debug_name: "generation_options_default",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -408,23 +506,22 @@ fn wire__crate__api__inference__init_inference_impl(
             deserializer.end();
             move |context| {
                 transform_result_sse::<_, ()>((move || {
-                    let output_ok = Result::<_, ()>::Ok({
-                        crate::api::inference::init_inference();
-                    })?;
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::inference::GenerationOptions::default())?;
                     Ok(output_ok)
                 })())
             }
         },
     )
 }
-fn wire__crate__api__inference__is_engine_available_impl(
+fn wire__crate__api__inference__get_available_engines_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "is_engine_available",
+            debug_name: "get_available_engines",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -438,18 +535,16 @@ fn wire__crate__api__inference__is_engine_available_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_engine_type = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(crate::api::inference::is_engine_available(
-                    api_engine_type,
-                ))?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::inference::get_available_engines())?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__inference__load_from_huggingface_impl(
+fn wire__crate__api__inference__get_cache_size_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -457,7 +552,7 @@ fn wire__crate__api__inference__load_from_huggingface_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_from_huggingface",
+            debug_name: "get_cache_size",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -471,19 +566,11 @@ fn wire__crate__api__inference__load_from_huggingface_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_repo = <String>::sse_decode(&mut deserializer);
-            let api_revision = <Option<String>>::sse_decode(&mut deserializer);
-            let api_filename = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
-                        let output_ok = crate::api::inference::load_from_huggingface(
-                            api_repo,
-                            api_revision,
-                            api_filename,
-                        )
-                        .await?;
+                        let output_ok = crate::api::inference::get_cache_size().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -492,7 +579,7 @@ fn wire__crate__api__inference__load_from_huggingface_impl(
         },
     )
 }
-fn wire__crate__api__inference__load_model_impl(
+fn wire__crate__api__inference__get_conversation_messages_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -500,7 +587,7 @@ fn wire__crate__api__inference__load_model_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_model",
+            debug_name: "get_conversation_messages",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -514,12 +601,14 @@ fn wire__crate__api__inference__load_model_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_model_path = <String>::sse_decode(&mut deserializer);
+            let api_conversation = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
-                        let output_ok = crate::api::inference::load_model(api_model_path).await?;
+                        let output_ok =
+                            crate::api::inference::get_conversation_messages(api_conversation)
+                                .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -528,7 +617,7 @@ fn wire__crate__api__inference__load_model_impl(
         },
     )
 }
-fn wire__crate__api__inference__load_model_from_bytes_impl(
+fn wire__crate__api__inference__get_download_progress_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -536,7 +625,7 @@ fn wire__crate__api__inference__load_model_from_bytes_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_model_from_bytes",
+            debug_name: "get_download_progress",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -550,17 +639,14 @@ fn wire__crate__api__inference__load_model_from_bytes_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_model_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
-            let api_config = <crate::api::inference::SessionConfig>::sse_decode(&mut deserializer);
+            let api_repo = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, crate::models::error::InferenceError>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = crate::api::inference::load_model_from_bytes(
-                            api_model_bytes,
-                            api_config,
-                        )
-                        .await?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::inference::get_download_progress(api_repo).await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -569,7 +655,7 @@ fn wire__crate__api__inference__load_model_from_bytes_impl(
         },
     )
 }
-fn wire__crate__api__inference__load_model_from_file_impl(
+fn wire__crate__api__inference__get_session_info_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -577,7 +663,7 @@ fn wire__crate__api__inference__load_model_from_file_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_model_from_file",
+            debug_name: "get_session_info",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -591,13 +677,13 @@ fn wire__crate__api__inference__load_model_from_file_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_file_path = <String>::sse_decode(&mut deserializer);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
                         let output_ok =
-                            crate::api::inference::load_model_from_file(api_file_path).await?;
+                            crate::api::inference::get_session_info(api_session_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -606,7 +692,7 @@ fn wire__crate__api__inference__load_model_from_file_impl(
         },
     )
 }
-fn wire__crate__api__inference__load_model_from_url_impl(
+fn wire__crate__api__inference__get_session_stats_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -614,7 +700,7 @@ fn wire__crate__api__inference__load_model_from_url_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_model_from_url",
+            debug_name: "get_session_stats",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -628,19 +714,13 @@ fn wire__crate__api__inference__load_model_from_url_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_url = <String>::sse_decode(&mut deserializer);
-            let api_cache = <bool>::sse_decode(&mut deserializer);
-            let api_cache_key = <Option<String>>::sse_decode(&mut deserializer);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
-                        let output_ok = crate::api::inference::load_model_from_url(
-                            api_url,
-                            api_cache,
-                            api_cache_key,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::inference::get_session_stats(api_session_handle).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -649,15 +729,45 @@ fn wire__crate__api__inference__load_model_from_url_impl(
         },
     )
 }
-fn wire__crate__api__inference__load_model_with_candle_impl(
+fn wire__crate__api__simple__greet_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "greet",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::simple::greet(api_name))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__simple__init_app_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_model_with_candle",
+            debug_name: "init_app",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -671,30 +781,27 @@ fn wire__crate__api__inference__load_model_with_candle_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_model_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::models::error::InferenceError>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::inference::load_model_with_candle(api_model_path).await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::simple::init_app();
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__inference__load_model_with_config_impl(
+fn wire__crate__api__inference__init_inference_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "load_model_with_config",
+            debug_name: "init_inference",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -708,26 +815,80 @@ fn wire__crate__api__inference__load_model_with_config_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_model_path = <String>::sse_decode(&mut deserializer);
-            let api_config = <crate::api::inference::SessionConfig>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, crate::models::error::InferenceError>(
-                    (move || async move {
-                        let output_ok = crate::api::inference::load_model_with_config(
-                            api_model_path,
-                            api_config,
-                        )
-                        .await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok({
+                        crate::api::inference::init_inference();
+                    })?;
+                    Ok(output_ok)
+                })())
             }
         },
     )
 }
-fn wire__crate__api__inference__predict_impl(
+fn wire__crate__api__inference__is_engine_available_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "is_engine_available",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_engine_type = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::inference::is_engine_available(
+                    api_engine_type,
+                ))?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__inference__list_devices_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "list_devices",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(crate::api::inference::list_devices())?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__inference__load_from_huggingface_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -735,7 +896,7 @@ fn wire__crate__api__inference__predict_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "predict",
+            debug_name: "load_from_huggingface",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -749,14 +910,19 @@ fn wire__crate__api__inference__predict_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_session_handle = <u64>::sse_decode(&mut deserializer);
-            let api_input = <crate::api::inference::InferenceInput>::sse_decode(&mut deserializer);
+            let api_repo = <String>::sse_decode(&mut deserializer);
+            let api_revision = <Option<String>>::sse_decode(&mut deserializer);
+            let api_filename = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::inference::predict(api_session_handle, api_input).await?;
+                        let output_ok = crate::api::inference::load_from_huggingface(
+                            api_repo,
+                            api_revision,
+                            api_filename,
+                        )
+                        .await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -765,7 +931,7 @@ fn wire__crate__api__inference__predict_impl(
         },
     )
 }
-fn wire__crate__api__inference__predict_batch_impl(
+fn wire__crate__api__inference__load_model_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -773,7 +939,7 @@ fn wire__crate__api__inference__predict_batch_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "predict_batch",
+            debug_name: "load_model",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -787,16 +953,12 @@ fn wire__crate__api__inference__predict_batch_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_session_handle = <u64>::sse_decode(&mut deserializer);
-            let api_inputs =
-                <Vec<crate::api::inference::InferenceInput>>::sse_decode(&mut deserializer);
+            let api_model_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::inference::predict_batch(api_session_handle, api_inputs)
-                                .await?;
+                        let output_ok = crate::api::inference::load_model(api_model_path).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -805,15 +967,15 @@ fn wire__crate__api__inference__predict_batch_impl(
         },
     )
 }
-fn wire__crate__api__inference__session_config_default_impl(
+fn wire__crate__api__inference__load_model_from_bytes_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "session_config_default",
+            debug_name: "load_model_from_bytes",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -827,18 +989,26 @@ fn wire__crate__api__inference__session_config_default_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_model_bytes = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::inference::SessionConfig>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| {
-                transform_result_sse::<_, ()>((move || {
-                    let output_ok =
-                        Result::<_, ()>::Ok(crate::api::inference::SessionConfig::default())?;
-                    Ok(output_ok)
-                })())
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::load_model_from_bytes(
+                            api_model_bytes,
+                            api_config,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
             }
         },
     )
 }
-fn wire__crate__api__inference__start_download_with_progress_impl(
+fn wire__crate__api__inference__load_model_from_file_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -846,7 +1016,7 @@ fn wire__crate__api__inference__start_download_with_progress_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "start_download_with_progress",
+            debug_name: "load_model_from_file",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -860,19 +1030,13 @@ fn wire__crate__api__inference__start_download_with_progress_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_repo = <String>::sse_decode(&mut deserializer);
-            let api_revision = <Option<String>>::sse_decode(&mut deserializer);
-            let api_filename = <Option<String>>::sse_decode(&mut deserializer);
+            let api_file_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
-                        let output_ok = crate::api::inference::start_download_with_progress(
-                            api_repo,
-                            api_revision,
-                            api_filename,
-                        )
-                        .await?;
+                        let output_ok =
+                            crate::api::inference::load_model_from_file(api_file_path).await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -881,7 +1045,7 @@ fn wire__crate__api__inference__start_download_with_progress_impl(
         },
     )
 }
-fn wire__crate__api__inference__train_linfa_model_impl(
+fn wire__crate__api__inference__load_model_from_url_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -889,7 +1053,7 @@ fn wire__crate__api__inference__train_linfa_model_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "train_linfa_model",
+            debug_name: "load_model_from_url",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -903,19 +1067,592 @@ fn wire__crate__api__inference__train_linfa_model_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_features = <Vec<Vec<f64>>>::sse_decode(&mut deserializer);
-            let api_algorithm = <String>::sse_decode(&mut deserializer);
-            let api_params =
-                <std::collections::HashMap<String, String>>::sse_decode(&mut deserializer);
+            let api_url = <String>::sse_decode(&mut deserializer);
+            let api_cache = <bool>::sse_decode(&mut deserializer);
+            let api_cache_key = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, crate::models::error::InferenceError>(
                     (move || async move {
-                        let output_ok = crate::api::inference::train_linfa_model(
-                            api_features,
-                            api_algorithm,
-                            api_params,
-                        )
+                        let output_ok = crate::api::inference::load_model_from_url(
+                            api_url,
+                            api_cache,
+                            api_cache_key,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__load_model_with_candle_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_model_with_candle",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_model_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::inference::load_model_with_candle(api_model_path).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__load_model_with_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "load_model_with_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_model_path = <String>::sse_decode(&mut deserializer);
+            let api_config = <crate::api::inference::SessionConfig>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::load_model_with_config(
+                            api_model_path,
+                            api_config,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__predict_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "predict",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_input = <crate::api::inference::InferenceInput>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::inference::predict(api_session_handle, api_input).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__predict_batch_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "predict_batch",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_inputs =
+                <Vec<crate::api::inference::InferenceInput>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::inference::predict_batch(api_session_handle, api_inputs)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__predict_batch_with_options_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "predict_batch_with_options",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_inputs =
+                <Vec<crate::api::inference::InferenceInput>>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::inference::PredictOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::predict_batch_with_options(
+                            api_session_handle,
+                            api_inputs,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__predict_named_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "predict_named",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_inputs =
+                <Vec<crate::api::inference::NamedInput>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::inference::predict_named(api_session_handle, api_inputs)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__predict_with_options_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "predict_with_options",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_input = <crate::api::inference::InferenceInput>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::inference::PredictOptions>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::predict_with_options(
+                            api_session_handle,
+                            api_input,
+                            api_options,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__release_cancellation_token_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "release_cancellation_token",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::inference::release_cancellation_token(api_token).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__reload_session_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "reload_session",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::inference::reload_session(api_session_handle).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__send_message_stream_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "send_message_stream",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_conversation = <u64>::sse_decode(&mut deserializer);
+            let api_content = <String>::sse_decode(&mut deserializer);
+            let api_generation =
+                <crate::api::inference::GenerationOptions>::sse_decode(&mut deserializer);
+            let api_options =
+                <crate::api::inference::PredictOptions>::sse_decode(&mut deserializer);
+            let api_sink = <StreamSink<
+                crate::api::inference::GenerationEvent,
+                flutter_rust_bridge::for_generated::SseCodec,
+            >>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::send_message_stream(
+                            api_conversation,
+                            api_content,
+                            api_generation,
+                            api_options,
+                            api_sink,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__session_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "session_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok =
+                        Result::<_, ()>::Ok(crate::api::inference::SessionConfig::default())?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__start_download_with_progress_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "start_download_with_progress",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_repo = <String>::sse_decode(&mut deserializer);
+            let api_revision = <Option<String>>::sse_decode(&mut deserializer);
+            let api_filename = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::start_download_with_progress(
+                            api_repo,
+                            api_revision,
+                            api_filename,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__train_linfa_model_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "train_linfa_model",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_features = <Vec<Vec<f64>>>::sse_decode(&mut deserializer);
+            let api_algorithm = <String>::sse_decode(&mut deserializer);
+            let api_params =
+                <std::collections::HashMap<String, String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::train_linfa_model(
+                            api_features,
+                            api_algorithm,
+                            api_params,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__inference__transcribe_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "transcribe",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_session_handle = <u64>::sse_decode(&mut deserializer);
+            let api_samples = <Vec<f32>>::sse_decode(&mut deserializer);
+            let api_sample_rate = <u32>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, crate::models::error::InferenceError>(
+                    (move || async move {
+                        let output_ok = crate::api::inference::transcribe(
+                            api_session_handle,
+                            api_samples,
+                            api_sample_rate,
+                        )
                         .await?;
                         Ok(output_ok)
                     })()
@@ -928,6 +1665,14 @@ fn wire__crate__api__inference__train_linfa_model_impl(
 
 // Section: dart2rust
 
+impl SseDecode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return flutter_rust_bridge::for_generated::anyhow::anyhow!("{}", inner);
+    }
+}
+
 impl SseDecode for std::collections::HashMap<String, String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -936,6 +1681,19 @@ impl SseDecode for std::collections::HashMap<String, String> {
     }
 }
 
+impl SseDecode
+    for StreamSink<
+        crate::api::inference::GenerationEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <String>::sse_decode(deserializer);
+        return StreamSink::deserialize(inner);
+    }
+}
+
 impl SseDecode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -951,6 +1709,31 @@ impl SseDecode for bool {
     }
 }
 
+impl SseDecode for crate::api::inference::ChatMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_role = <crate::api::inference::ChatRole>::sse_decode(deserializer);
+        let mut var_content = <String>::sse_decode(deserializer);
+        return crate::api::inference::ChatMessage {
+            role: var_role,
+            content: var_content,
+        };
+    }
+}
+
+impl SseDecode for crate::api::inference::ChatRole {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::inference::ChatRole::System,
+            1 => crate::api::inference::ChatRole::User,
+            2 => crate::api::inference::ChatRole::Assistant,
+            _ => unreachable!("Invalid variant for ChatRole: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for crate::models::tensor::DataType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -968,6 +1751,56 @@ impl SseDecode for crate::models::tensor::DataType {
     }
 }
 
+impl SseDecode for crate::models::device::DeviceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_device = <crate::models::device::DeviceSelection>::sse_decode(deserializer);
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_available = <bool>::sse_decode(deserializer);
+        let mut var_unavailableReason = <Option<String>>::sse_decode(deserializer);
+        let mut var_supportsF16 = <bool>::sse_decode(deserializer);
+        let mut var_supportsBf16 = <bool>::sse_decode(deserializer);
+        return crate::models::device::DeviceInfo {
+            device: var_device,
+            name: var_name,
+            available: var_available,
+            unavailable_reason: var_unavailableReason,
+            supports_f16: var_supportsF16,
+            supports_bf16: var_supportsBf16,
+        };
+    }
+}
+
+impl SseDecode for crate::models::device::DeviceSelection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::models::device::DeviceSelection::Auto;
+            }
+            1 => {
+                return crate::models::device::DeviceSelection::Cpu;
+            }
+            2 => {
+                let mut var_ordinal = <usize>::sse_decode(deserializer);
+                return crate::models::device::DeviceSelection::Cuda {
+                    ordinal: var_ordinal,
+                };
+            }
+            3 => {
+                let mut var_ordinal = <usize>::sse_decode(deserializer);
+                return crate::models::device::DeviceSelection::Metal {
+                    ordinal: var_ordinal,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseDecode for crate::api::inference::DownloadPhase {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1016,6 +1849,92 @@ impl SseDecode for f64 {
     }
 }
 
+impl SseDecode for crate::api::inference::GenerationEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                let mut var_text = <String>::sse_decode(deserializer);
+                let mut var_tokenId = <u32>::sse_decode(deserializer);
+                let mut var_logprob = <f32>::sse_decode(deserializer);
+                return crate::api::inference::GenerationEvent::Token {
+                    text: var_text,
+                    token_id: var_tokenId,
+                    logprob: var_logprob,
+                };
+            }
+            1 => {
+                let mut var_text = <String>::sse_decode(deserializer);
+                let mut var_stopReason =
+                    <crate::api::inference::GenerationStopReason>::sse_decode(deserializer);
+                let mut var_promptTokens = <usize>::sse_decode(deserializer);
+                let mut var_generatedTokens = <usize>::sse_decode(deserializer);
+                let mut var_timeToFirstTokenMs = <Option<u64>>::sse_decode(deserializer);
+                let mut var_totalTimeMs = <u64>::sse_decode(deserializer);
+                return crate::api::inference::GenerationEvent::Finished {
+                    text: var_text,
+                    stop_reason: var_stopReason,
+                    prompt_tokens: var_promptTokens,
+                    generated_tokens: var_generatedTokens,
+                    time_to_first_token_ms: var_timeToFirstTokenMs,
+                    total_time_ms: var_totalTimeMs,
+                };
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::inference::GenerationOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_maxNewTokens = <usize>::sse_decode(deserializer);
+        let mut var_temperature = <f32>::sse_decode(deserializer);
+        let mut var_topK = <Option<usize>>::sse_decode(deserializer);
+        let mut var_topP = <Option<f32>>::sse_decode(deserializer);
+        let mut var_minP = <Option<f32>>::sse_decode(deserializer);
+        let mut var_repetitionPenalty = <f32>::sse_decode(deserializer);
+        let mut var_presencePenalty = <f32>::sse_decode(deserializer);
+        let mut var_penaltyLastN = <usize>::sse_decode(deserializer);
+        let mut var_seed = <Option<u64>>::sse_decode(deserializer);
+        let mut var_stopSequences = <Vec<String>>::sse_decode(deserializer);
+        let mut var_grammar = <Option<String>>::sse_decode(deserializer);
+        let mut var_jsonSchema = <Option<String>>::sse_decode(deserializer);
+        return crate::api::inference::GenerationOptions {
+            max_new_tokens: var_maxNewTokens,
+            temperature: var_temperature,
+            top_k: var_topK,
+            top_p: var_topP,
+            min_p: var_minP,
+            repetition_penalty: var_repetitionPenalty,
+            presence_penalty: var_presencePenalty,
+            penalty_last_n: var_penaltyLastN,
+            seed: var_seed,
+            stop_sequences: var_stopSequences,
+            grammar: var_grammar,
+            json_schema: var_jsonSchema,
+        };
+    }
+}
+
+impl SseDecode for crate::api::inference::GenerationStopReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::api::inference::GenerationStopReason::EndOfSequence,
+            1 => crate::api::inference::GenerationStopReason::MaxTokens,
+            2 => crate::api::inference::GenerationStopReason::ContextFull,
+            3 => crate::api::inference::GenerationStopReason::StopSequence,
+            4 => crate::api::inference::GenerationStopReason::GrammarIncomplete,
+            _ => unreachable!("Invalid variant for GenerationStopReason: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1088,6 +2007,18 @@ impl SseDecode for crate::models::error::InferenceError {
                 let mut var_field0 = <String>::sse_decode(deserializer);
                 return crate::models::error::InferenceError::FormatDetection(var_field0);
             }
+            14 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::models::error::InferenceError::Cancelled(var_field0);
+            }
+            15 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::models::error::InferenceError::DeadlineExceeded(var_field0);
+            }
+            16 => {
+                let mut var_field0 = <String>::sse_decode(deserializer);
+                return crate::models::error::InferenceError::QueueFull(var_field0);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -1135,6 +2066,34 @@ impl SseDecode for Vec<String> {
     }
 }
 
+impl SseDecode for Vec<crate::api::inference::ChatMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::inference::ChatMessage>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::models::device::DeviceInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::models::device::DeviceInfo>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::inference::InferenceInput> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1175,6 +2134,34 @@ impl SseDecode for Vec<Vec<f64>> {
     }
 }
 
+impl SseDecode for Vec<crate::api::inference::NamedInput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::inference::NamedInput>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::inference::NamedResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::inference::NamedResult>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<Option<usize>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1261,6 +2248,57 @@ impl SseDecode for Vec<crate::models::tensor::TensorSpec> {
     }
 }
 
+impl SseDecode for Vec<crate::api::inference::TranscriptSegment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::inference::TranscriptSegment>::sse_decode(
+                deserializer,
+            ));
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for crate::models::session::LoadMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::session::LoadMode::Eager,
+            1 => crate::models::session::LoadMode::Lazy,
+            2 => crate::models::session::LoadMode::EagerWithWarmup,
+            _ => unreachable!("Invalid variant for LoadMode: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::api::inference::NamedInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_input = <crate::api::inference::InferenceInput>::sse_decode(deserializer);
+        return crate::api::inference::NamedInput {
+            name: var_name,
+            input: var_input,
+        };
+    }
+}
+
+impl SseDecode for crate::api::inference::NamedResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_name = <String>::sse_decode(deserializer);
+        let mut var_result = <crate::api::inference::InferenceResult>::sse_decode(deserializer);
+        return crate::api::inference::NamedResult {
+            name: var_name,
+            result: var_result,
+        };
+    }
+}
+
 impl SseDecode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1285,6 +2323,30 @@ impl SseDecode for Option<crate::api::inference::DownloadProgress> {
     }
 }
 
+impl SseDecode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<f32>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::models::optimization::OptimizationReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::models::optimization::OptimizationReport>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1307,6 +2369,70 @@ impl SseDecode for Option<usize> {
     }
 }
 
+impl SseDecode for crate::models::optimization::OptimizationLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::optimization::OptimizationLevel::None,
+            1 => crate::models::optimization::OptimizationLevel::F16,
+            2 => crate::models::optimization::OptimizationLevel::Bf16,
+            3 => crate::models::optimization::OptimizationLevel::Int8,
+            4 => crate::models::optimization::OptimizationLevel::Q4,
+            _ => unreachable!("Invalid variant for OptimizationLevel: {}", inner),
+        };
+    }
+}
+
+impl SseDecode for crate::models::optimization::OptimizationReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_level =
+            <crate::models::optimization::OptimizationLevel>::sse_decode(deserializer);
+        let mut var_tensorsConverted = <usize>::sse_decode(deserializer);
+        let mut var_tensorsSkipped = <usize>::sse_decode(deserializer);
+        let mut var_sizeBeforeBytes = <usize>::sse_decode(deserializer);
+        let mut var_sizeAfterBytes = <usize>::sse_decode(deserializer);
+        let mut var_maxAbsError = <Option<f32>>::sse_decode(deserializer);
+        let mut var_meanAbsError = <Option<f32>>::sse_decode(deserializer);
+        return crate::models::optimization::OptimizationReport {
+            level: var_level,
+            tensors_converted: var_tensorsConverted,
+            tensors_skipped: var_tensorsSkipped,
+            size_before_bytes: var_sizeBeforeBytes,
+            size_after_bytes: var_sizeAfterBytes,
+            max_abs_error: var_maxAbsError,
+            mean_abs_error: var_meanAbsError,
+        };
+    }
+}
+
+impl SseDecode for crate::api::inference::PredictOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_cancellationToken = <Option<u64>>::sse_decode(deserializer);
+        let mut var_timeoutMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_priority = <crate::models::scheduler::Priority>::sse_decode(deserializer);
+        return crate::api::inference::PredictOptions {
+            cancellation_token: var_cancellationToken,
+            timeout_ms: var_timeoutMs,
+            priority: var_priority,
+        };
+    }
+}
+
+impl SseDecode for crate::models::scheduler::Priority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut inner = <i32>::sse_decode(deserializer);
+        return match inner {
+            0 => crate::models::scheduler::Priority::Interactive,
+            1 => crate::models::scheduler::Priority::Background,
+            _ => unreachable!("Invalid variant for Priority: {}", inner),
+        };
+    }
+}
+
 impl SseDecode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1321,13 +2447,29 @@ impl SseDecode for crate::api::inference::SessionConfig {
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
         let mut var_engineType = <Option<String>>::sse_decode(deserializer);
         let mut var_gpuAcceleration = <bool>::sse_decode(deserializer);
+        let mut var_device = <crate::models::device::DeviceSelection>::sse_decode(deserializer);
+        let mut var_fallbackChain = <Vec<String>>::sse_decode(deserializer);
         let mut var_numThreads = <Option<usize>>::sse_decode(deserializer);
         let mut var_optimizationLevel = <Option<String>>::sse_decode(deserializer);
+        let mut var_loadMode = <crate::models::session::LoadMode>::sse_decode(deserializer);
+        let mut var_maxConcurrency = <Option<usize>>::sse_decode(deserializer);
+        let mut var_maxQueueDepth = <Option<usize>>::sse_decode(deserializer);
+        let mut var_cacheMaxEntries = <Option<usize>>::sse_decode(deserializer);
+        let mut var_cacheMaxBytes = <Option<usize>>::sse_decode(deserializer);
+        let mut var_modelGraph = <Option<String>>::sse_decode(deserializer);
         return crate::api::inference::SessionConfig {
             engine_type: var_engineType,
             gpu_acceleration: var_gpuAcceleration,
+            device: var_device,
+            fallback_chain: var_fallbackChain,
             num_threads: var_numThreads,
             optimization_level: var_optimizationLevel,
+            load_mode: var_loadMode,
+            max_concurrency: var_maxConcurrency,
+            max_queue_depth: var_maxQueueDepth,
+            cache_max_entries: var_cacheMaxEntries,
+            cache_max_bytes: var_cacheMaxBytes,
+            model_graph: var_modelGraph,
         };
     }
 }
@@ -1340,11 +2482,56 @@ impl SseDecode for crate::api::inference::SessionInfo {
         let mut var_inputSpecs = <Vec<crate::models::tensor::TensorSpec>>::sse_decode(deserializer);
         let mut var_outputSpecs =
             <Vec<crate::models::tensor::TensorSpec>>::sse_decode(deserializer);
+        let mut var_specsPending = <bool>::sse_decode(deserializer);
+        let mut var_loadMode = <crate::models::session::LoadMode>::sse_decode(deserializer);
+        let mut var_isLoaded = <bool>::sse_decode(deserializer);
+        let mut var_loadTimeMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_warmupTimeMs = <Option<u64>>::sse_decode(deserializer);
+        let mut var_device = <String>::sse_decode(deserializer);
+        let mut var_deviceFallbackReason = <Option<String>>::sse_decode(deserializer);
+        let mut var_fallbackReasons = <Vec<String>>::sse_decode(deserializer);
+        let mut var_optimization =
+            <Option<crate::models::optimization::OptimizationReport>>::sse_decode(deserializer);
         return crate::api::inference::SessionInfo {
             handle: var_handle,
             engine_type: var_engineType,
             input_specs: var_inputSpecs,
             output_specs: var_outputSpecs,
+            specs_pending: var_specsPending,
+            load_mode: var_loadMode,
+            is_loaded: var_isLoaded,
+            load_time_ms: var_loadTimeMs,
+            warmup_time_ms: var_warmupTimeMs,
+            device: var_device,
+            device_fallback_reason: var_deviceFallbackReason,
+            fallback_reasons: var_fallbackReasons,
+            optimization: var_optimization,
+        };
+    }
+}
+
+impl SseDecode for crate::models::session::SessionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_activeRequests = <usize>::sse_decode(deserializer);
+        let mut var_queuedInteractive = <usize>::sse_decode(deserializer);
+        let mut var_queuedBackground = <usize>::sse_decode(deserializer);
+        let mut var_completedRequests = <u64>::sse_decode(deserializer);
+        let mut var_rejectedRequests = <u64>::sse_decode(deserializer);
+        let mut var_cacheHits = <u64>::sse_decode(deserializer);
+        let mut var_cacheMisses = <u64>::sse_decode(deserializer);
+        let mut var_cacheEntries = <usize>::sse_decode(deserializer);
+        let mut var_cacheBytes = <usize>::sse_decode(deserializer);
+        return crate::models::session::SessionStats {
+            active_requests: var_activeRequests,
+            queued_interactive: var_queuedInteractive,
+            queued_background: var_queuedBackground,
+            completed_requests: var_completedRequests,
+            rejected_requests: var_rejectedRequests,
+            cache_hits: var_cacheHits,
+            cache_misses: var_cacheMisses,
+            cache_entries: var_cacheEntries,
+            cache_bytes: var_cacheBytes,
         };
     }
 }
@@ -1365,6 +2552,46 @@ impl SseDecode for crate::models::tensor::TensorSpec {
     }
 }
 
+impl SseDecode for crate::api::inference::TranscriptSegment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_startSeconds = <f64>::sse_decode(deserializer);
+        let mut var_endSeconds = <f64>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_avgLogprob = <f64>::sse_decode(deserializer);
+        let mut var_noSpeechProb = <f64>::sse_decode(deserializer);
+        return crate::api::inference::TranscriptSegment {
+            start_seconds: var_startSeconds,
+            end_seconds: var_endSeconds,
+            text: var_text,
+            avg_logprob: var_avgLogprob,
+            no_speech_prob: var_noSpeechProb,
+        };
+    }
+}
+
+impl SseDecode for crate::api::inference::TranscriptionResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_language = <Option<String>>::sse_decode(deserializer);
+        let mut var_segments =
+            <Vec<crate::api::inference::TranscriptSegment>>::sse_decode(deserializer);
+        return crate::api::inference::TranscriptionResult {
+            text: var_text,
+            language: var_language,
+            segments: var_segments,
+        };
+    }
+}
+
+impl SseDecode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        deserializer.cursor.read_u32::<NativeEndian>().unwrap()
+    }
+}
+
 impl SseDecode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -1400,69 +2627,129 @@ fn pde_ffi_dispatcher_primary_impl(
 ) {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        1 => wire__crate__api__inference__clear_cache_impl(port, ptr, rust_vec_len, data_len),
-        4 => wire__crate__api__inference__dispose_session_impl(port, ptr, rust_vec_len, data_len),
-        6 => wire__crate__api__inference__get_cache_size_impl(port, ptr, rust_vec_len, data_len),
-        7 => wire__crate__api__inference__get_download_progress_impl(
+        1 => {
+            wire__crate__api__inference__apply_chat_template_impl(port, ptr, rust_vec_len, data_len)
+        }
+        2 => wire__crate__api__inference__cancel_prediction_impl(port, ptr, rust_vec_len, data_len),
+        3 => wire__crate__api__inference__chat_stream_impl(port, ptr, rust_vec_len, data_len),
+        4 => wire__crate__api__inference__clear_cache_impl(port, ptr, rust_vec_len, data_len),
+        5 => wire__crate__api__inference__create_cancellation_token_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        6 => {
+            wire__crate__api__inference__create_conversation_impl(port, ptr, rust_vec_len, data_len)
+        }
+        9 => wire__crate__api__inference__dispose_conversation_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        10 => wire__crate__api__inference__dispose_session_impl(port, ptr, rust_vec_len, data_len),
+        11 => wire__crate__api__inference__generate_stream_impl(port, ptr, rust_vec_len, data_len),
+        12 => wire__crate__api__inference__generation_options_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        14 => wire__crate__api__inference__get_cache_size_impl(port, ptr, rust_vec_len, data_len),
+        15 => wire__crate__api__inference__get_conversation_messages_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        16 => wire__crate__api__inference__get_download_progress_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        8 => wire__crate__api__inference__get_session_info_impl(port, ptr, rust_vec_len, data_len),
-        10 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        11 => wire__crate__api__inference__init_inference_impl(port, ptr, rust_vec_len, data_len),
-        13 => wire__crate__api__inference__load_from_huggingface_impl(
+        17 => wire__crate__api__inference__get_session_info_impl(port, ptr, rust_vec_len, data_len),
+        18 => {
+            wire__crate__api__inference__get_session_stats_impl(port, ptr, rust_vec_len, data_len)
+        }
+        20 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        21 => wire__crate__api__inference__init_inference_impl(port, ptr, rust_vec_len, data_len),
+        24 => wire__crate__api__inference__load_from_huggingface_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        14 => wire__crate__api__inference__load_model_impl(port, ptr, rust_vec_len, data_len),
-        15 => wire__crate__api__inference__load_model_from_bytes_impl(
+        25 => wire__crate__api__inference__load_model_impl(port, ptr, rust_vec_len, data_len),
+        26 => wire__crate__api__inference__load_model_from_bytes_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        16 => wire__crate__api__inference__load_model_from_file_impl(
+        27 => wire__crate__api__inference__load_model_from_file_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        17 => {
+        28 => {
             wire__crate__api__inference__load_model_from_url_impl(port, ptr, rust_vec_len, data_len)
         }
-        18 => wire__crate__api__inference__load_model_with_candle_impl(
+        29 => wire__crate__api__inference__load_model_with_candle_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => wire__crate__api__inference__load_model_with_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__inference__predict_impl(port, ptr, rust_vec_len, data_len),
+        32 => wire__crate__api__inference__predict_batch_impl(port, ptr, rust_vec_len, data_len),
+        33 => wire__crate__api__inference__predict_batch_with_options_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__inference__predict_named_impl(port, ptr, rust_vec_len, data_len),
+        35 => wire__crate__api__inference__predict_with_options_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        19 => wire__crate__api__inference__load_model_with_config_impl(
+        36 => wire__crate__api__inference__release_cancellation_token_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        20 => wire__crate__api__inference__predict_impl(port, ptr, rust_vec_len, data_len),
-        21 => wire__crate__api__inference__predict_batch_impl(port, ptr, rust_vec_len, data_len),
-        22 => wire__crate__api__inference__session_config_default_impl(
+        37 => wire__crate__api__inference__reload_session_impl(port, ptr, rust_vec_len, data_len),
+        38 => {
+            wire__crate__api__inference__send_message_stream_impl(port, ptr, rust_vec_len, data_len)
+        }
+        39 => wire__crate__api__inference__session_config_default_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        23 => wire__crate__api__inference__start_download_with_progress_impl(
+        40 => wire__crate__api__inference__start_download_with_progress_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        24 => {
+        41 => {
             wire__crate__api__inference__train_linfa_model_impl(port, ptr, rust_vec_len, data_len)
         }
+        42 => wire__crate__api__inference__transcribe_impl(port, ptr, rust_vec_len, data_len),
         _ => unreachable!(),
     }
 }
@@ -1475,19 +2762,63 @@ fn pde_ffi_dispatcher_sync_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        2 => {
+        7 => {
             wire__crate__api__inference__detect_engine_from_bytes_impl(ptr, rust_vec_len, data_len)
         }
-        3 => wire__crate__api__inference__detect_engine_from_path_impl(ptr, rust_vec_len, data_len),
-        5 => wire__crate__api__inference__get_available_engines_impl(ptr, rust_vec_len, data_len),
-        9 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        12 => wire__crate__api__inference__is_engine_available_impl(ptr, rust_vec_len, data_len),
-        _ => unreachable!(),
+        8 => wire__crate__api__inference__detect_engine_from_path_impl(ptr, rust_vec_len, data_len),
+        13 => wire__crate__api__inference__get_available_engines_impl(ptr, rust_vec_len, data_len),
+        19 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        22 => wire__crate__api__inference__is_engine_available_impl(ptr, rust_vec_len, data_len),
+        23 => wire__crate__api__inference__list_devices_impl(ptr, rust_vec_len, data_len),
+        _ => unreachable!(),
+    }
+}
+
+// Section: rust2dart
+
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::inference::ChatMessage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.role.into_into_dart().into_dart(),
+            self.content.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::inference::ChatMessage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::ChatMessage>
+    for crate::api::inference::ChatMessage
+{
+    fn into_into_dart(self) -> crate::api::inference::ChatMessage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::inference::ChatRole {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::System => 0.into_dart(),
+            Self::User => 1.into_dart(),
+            Self::Assistant => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::inference::ChatRole
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::ChatRole>
+    for crate::api::inference::ChatRole
+{
+    fn into_into_dart(self) -> crate::api::inference::ChatRole {
+        self
     }
 }
-
-// Section: rust2dart
-
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::models::tensor::DataType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -1515,6 +2846,60 @@ impl flutter_rust_bridge::IntoIntoDart<crate::models::tensor::DataType>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::device::DeviceInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.device.into_into_dart().into_dart(),
+            self.name.into_into_dart().into_dart(),
+            self.available.into_into_dart().into_dart(),
+            self.unavailable_reason.into_into_dart().into_dart(),
+            self.supports_f16.into_into_dart().into_dart(),
+            self.supports_bf16.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::device::DeviceInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::device::DeviceInfo>
+    for crate::models::device::DeviceInfo
+{
+    fn into_into_dart(self) -> crate::models::device::DeviceInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::device::DeviceSelection {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::models::device::DeviceSelection::Auto => [0.into_dart()].into_dart(),
+            crate::models::device::DeviceSelection::Cpu => [1.into_dart()].into_dart(),
+            crate::models::device::DeviceSelection::Cuda { ordinal } => {
+                [2.into_dart(), ordinal.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::device::DeviceSelection::Metal { ordinal } => {
+                [3.into_dart(), ordinal.into_into_dart().into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::device::DeviceSelection
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::device::DeviceSelection>
+    for crate::models::device::DeviceSelection
+{
+    fn into_into_dart(self) -> crate::models::device::DeviceSelection {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::inference::DownloadPhase {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -1564,6 +2949,110 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::DownloadProgress>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::inference::GenerationEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::inference::GenerationEvent::Token {
+                text,
+                token_id,
+                logprob,
+            } => [
+                0.into_dart(),
+                text.into_into_dart().into_dart(),
+                token_id.into_into_dart().into_dart(),
+                logprob.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            crate::api::inference::GenerationEvent::Finished {
+                text,
+                stop_reason,
+                prompt_tokens,
+                generated_tokens,
+                time_to_first_token_ms,
+                total_time_ms,
+            } => [
+                1.into_dart(),
+                text.into_into_dart().into_dart(),
+                stop_reason.into_into_dart().into_dart(),
+                prompt_tokens.into_into_dart().into_dart(),
+                generated_tokens.into_into_dart().into_dart(),
+                time_to_first_token_ms.into_into_dart().into_dart(),
+                total_time_ms.into_into_dart().into_dart(),
+            ]
+            .into_dart(),
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::inference::GenerationEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::GenerationEvent>
+    for crate::api::inference::GenerationEvent
+{
+    fn into_into_dart(self) -> crate::api::inference::GenerationEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::inference::GenerationOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.max_new_tokens.into_into_dart().into_dart(),
+            self.temperature.into_into_dart().into_dart(),
+            self.top_k.into_into_dart().into_dart(),
+            self.top_p.into_into_dart().into_dart(),
+            self.min_p.into_into_dart().into_dart(),
+            self.repetition_penalty.into_into_dart().into_dart(),
+            self.presence_penalty.into_into_dart().into_dart(),
+            self.penalty_last_n.into_into_dart().into_dart(),
+            self.seed.into_into_dart().into_dart(),
+            self.stop_sequences.into_into_dart().into_dart(),
+            self.grammar.into_into_dart().into_dart(),
+            self.json_schema.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::inference::GenerationOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::GenerationOptions>
+    for crate::api::inference::GenerationOptions
+{
+    fn into_into_dart(self) -> crate::api::inference::GenerationOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::inference::GenerationStopReason {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::EndOfSequence => 0.into_dart(),
+            Self::MaxTokens => 1.into_dart(),
+            Self::ContextFull => 2.into_dart(),
+            Self::StopSequence => 3.into_dart(),
+            Self::GrammarIncomplete => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::inference::GenerationStopReason
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::GenerationStopReason>
+    for crate::api::inference::GenerationStopReason
+{
+    fn into_into_dart(self) -> crate::api::inference::GenerationStopReason {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::models::error::InferenceError {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -1612,6 +3101,15 @@ impl flutter_rust_bridge::IntoDart for crate::models::error::InferenceError {
             crate::models::error::InferenceError::FormatDetection(field0) => {
                 [13.into_dart(), field0.into_into_dart().into_dart()].into_dart()
             }
+            crate::models::error::InferenceError::Cancelled(field0) => {
+                [14.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::error::InferenceError::DeadlineExceeded(field0) => {
+                [15.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
+            crate::models::error::InferenceError::QueueFull(field0) => {
+                [16.into_dart(), field0.into_into_dart().into_dart()].into_dart()
+            }
             _ => {
                 unimplemented!("");
             }
@@ -1674,75 +3172,330 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::InferenceResult>
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::session::LoadMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Eager => 0.into_dart(),
+            Self::Lazy => 1.into_dart(),
+            Self::EagerWithWarmup => 2.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::session::LoadMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::session::LoadMode>
+    for crate::models::session::LoadMode
+{
+    fn into_into_dart(self) -> crate::models::session::LoadMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::inference::NamedInput {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.input.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::inference::NamedInput
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::NamedInput>
+    for crate::api::inference::NamedInput
+{
+    fn into_into_dart(self) -> crate::api::inference::NamedInput {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::inference::NamedResult {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.result.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::inference::NamedResult
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::NamedResult>
+    for crate::api::inference::NamedResult
+{
+    fn into_into_dart(self) -> crate::api::inference::NamedResult {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::optimization::OptimizationLevel {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::None => 0.into_dart(),
+            Self::F16 => 1.into_dart(),
+            Self::Bf16 => 2.into_dart(),
+            Self::Int8 => 3.into_dart(),
+            Self::Q4 => 4.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::optimization::OptimizationLevel
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::optimization::OptimizationLevel>
+    for crate::models::optimization::OptimizationLevel
+{
+    fn into_into_dart(self) -> crate::models::optimization::OptimizationLevel {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::optimization::OptimizationReport {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.level.into_into_dart().into_dart(),
+            self.tensors_converted.into_into_dart().into_dart(),
+            self.tensors_skipped.into_into_dart().into_dart(),
+            self.size_before_bytes.into_into_dart().into_dart(),
+            self.size_after_bytes.into_into_dart().into_dart(),
+            self.max_abs_error.into_into_dart().into_dart(),
+            self.mean_abs_error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::optimization::OptimizationReport
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::optimization::OptimizationReport>
+    for crate::models::optimization::OptimizationReport
+{
+    fn into_into_dart(self) -> crate::models::optimization::OptimizationReport {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::inference::PredictOptions {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.cancellation_token.into_into_dart().into_dart(),
+            self.timeout_ms.into_into_dart().into_dart(),
+            self.priority.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::inference::PredictOptions
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::PredictOptions>
+    for crate::api::inference::PredictOptions
+{
+    fn into_into_dart(self) -> crate::api::inference::PredictOptions {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::scheduler::Priority {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            Self::Interactive => 0.into_dart(),
+            Self::Background => 1.into_dart(),
+            _ => unreachable!(),
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::scheduler::Priority
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::scheduler::Priority>
+    for crate::models::scheduler::Priority
+{
+    fn into_into_dart(self) -> crate::models::scheduler::Priority {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::inference::SessionConfig {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
             self.engine_type.into_into_dart().into_dart(),
             self.gpu_acceleration.into_into_dart().into_dart(),
+            self.device.into_into_dart().into_dart(),
+            self.fallback_chain.into_into_dart().into_dart(),
             self.num_threads.into_into_dart().into_dart(),
             self.optimization_level.into_into_dart().into_dart(),
+            self.load_mode.into_into_dart().into_dart(),
+            self.max_concurrency.into_into_dart().into_dart(),
+            self.max_queue_depth.into_into_dart().into_dart(),
+            self.cache_max_entries.into_into_dart().into_dart(),
+            self.cache_max_bytes.into_into_dart().into_dart(),
+            self.model_graph.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::inference::SessionConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::SessionConfig>
+    for crate::api::inference::SessionConfig
+{
+    fn into_into_dart(self) -> crate::api::inference::SessionConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::inference::SessionInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.handle.into_into_dart().into_dart(),
+            self.engine_type.into_into_dart().into_dart(),
+            self.input_specs.into_into_dart().into_dart(),
+            self.output_specs.into_into_dart().into_dart(),
+            self.specs_pending.into_into_dart().into_dart(),
+            self.load_mode.into_into_dart().into_dart(),
+            self.is_loaded.into_into_dart().into_dart(),
+            self.load_time_ms.into_into_dart().into_dart(),
+            self.warmup_time_ms.into_into_dart().into_dart(),
+            self.device.into_into_dart().into_dart(),
+            self.device_fallback_reason.into_into_dart().into_dart(),
+            self.fallback_reasons.into_into_dart().into_dart(),
+            self.optimization.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::inference::SessionInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::SessionInfo>
+    for crate::api::inference::SessionInfo
+{
+    fn into_into_dart(self) -> crate::api::inference::SessionInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::session::SessionStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.active_requests.into_into_dart().into_dart(),
+            self.queued_interactive.into_into_dart().into_dart(),
+            self.queued_background.into_into_dart().into_dart(),
+            self.completed_requests.into_into_dart().into_dart(),
+            self.rejected_requests.into_into_dart().into_dart(),
+            self.cache_hits.into_into_dart().into_dart(),
+            self.cache_misses.into_into_dart().into_dart(),
+            self.cache_entries.into_into_dart().into_dart(),
+            self.cache_bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::models::session::SessionStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::models::session::SessionStats>
+    for crate::models::session::SessionStats
+{
+    fn into_into_dart(self) -> crate::models::session::SessionStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::models::tensor::TensorSpec {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.name.into_into_dart().into_dart(),
+            self.shape.into_into_dart().into_dart(),
+            self.data_type.into_into_dart().into_dart(),
+            self.description.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::inference::SessionConfig
+    for crate::models::tensor::TensorSpec
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::SessionConfig>
-    for crate::api::inference::SessionConfig
+impl flutter_rust_bridge::IntoIntoDart<crate::models::tensor::TensorSpec>
+    for crate::models::tensor::TensorSpec
 {
-    fn into_into_dart(self) -> crate::api::inference::SessionConfig {
+    fn into_into_dart(self) -> crate::models::tensor::TensorSpec {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::api::inference::SessionInfo {
+impl flutter_rust_bridge::IntoDart for crate::api::inference::TranscriptSegment {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.handle.into_into_dart().into_dart(),
-            self.engine_type.into_into_dart().into_dart(),
-            self.input_specs.into_into_dart().into_dart(),
-            self.output_specs.into_into_dart().into_dart(),
+            self.start_seconds.into_into_dart().into_dart(),
+            self.end_seconds.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.avg_logprob.into_into_dart().into_dart(),
+            self.no_speech_prob.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::inference::SessionInfo
+    for crate::api::inference::TranscriptSegment
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::SessionInfo>
-    for crate::api::inference::SessionInfo
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::TranscriptSegment>
+    for crate::api::inference::TranscriptSegment
 {
-    fn into_into_dart(self) -> crate::api::inference::SessionInfo {
+    fn into_into_dart(self) -> crate::api::inference::TranscriptSegment {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart for crate::models::tensor::TensorSpec {
+impl flutter_rust_bridge::IntoDart for crate::api::inference::TranscriptionResult {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.name.into_into_dart().into_dart(),
-            self.shape.into_into_dart().into_dart(),
-            self.data_type.into_into_dart().into_dart(),
-            self.description.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.language.into_into_dart().into_dart(),
+            self.segments.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::models::tensor::TensorSpec
+    for crate::api::inference::TranscriptionResult
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::models::tensor::TensorSpec>
-    for crate::models::tensor::TensorSpec
+impl flutter_rust_bridge::IntoIntoDart<crate::api::inference::TranscriptionResult>
+    for crate::api::inference::TranscriptionResult
 {
-    fn into_into_dart(self) -> crate::models::tensor::TensorSpec {
+    fn into_into_dart(self) -> crate::api::inference::TranscriptionResult {
         self
     }
 }
 
+impl SseEncode for flutter_rust_bridge::for_generated::anyhow::Error {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(format!("{:?}", self), serializer);
+    }
+}
+
 impl SseEncode for std::collections::HashMap<String, String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1750,6 +3503,18 @@ impl SseEncode for std::collections::HashMap<String, String> {
     }
 }
 
+impl SseEncode
+    for StreamSink<
+        crate::api::inference::GenerationEvent,
+        flutter_rust_bridge::for_generated::SseCodec,
+    >
+{
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        unimplemented!("")
+    }
+}
+
 impl SseEncode for String {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1764,6 +3529,31 @@ impl SseEncode for bool {
     }
 }
 
+impl SseEncode for crate::api::inference::ChatMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::inference::ChatRole>::sse_encode(self.role, serializer);
+        <String>::sse_encode(self.content, serializer);
+    }
+}
+
+impl SseEncode for crate::api::inference::ChatRole {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::inference::ChatRole::System => 0,
+                crate::api::inference::ChatRole::User => 1,
+                crate::api::inference::ChatRole::Assistant => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for crate::models::tensor::DataType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1785,6 +3575,43 @@ impl SseEncode for crate::models::tensor::DataType {
     }
 }
 
+impl SseEncode for crate::models::device::DeviceInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::models::device::DeviceSelection>::sse_encode(self.device, serializer);
+        <String>::sse_encode(self.name, serializer);
+        <bool>::sse_encode(self.available, serializer);
+        <Option<String>>::sse_encode(self.unavailable_reason, serializer);
+        <bool>::sse_encode(self.supports_f16, serializer);
+        <bool>::sse_encode(self.supports_bf16, serializer);
+    }
+}
+
+impl SseEncode for crate::models::device::DeviceSelection {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::models::device::DeviceSelection::Auto => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::models::device::DeviceSelection::Cpu => {
+                <i32>::sse_encode(1, serializer);
+            }
+            crate::models::device::DeviceSelection::Cuda { ordinal } => {
+                <i32>::sse_encode(2, serializer);
+                <usize>::sse_encode(ordinal, serializer);
+            }
+            crate::models::device::DeviceSelection::Metal { ordinal } => {
+                <i32>::sse_encode(3, serializer);
+                <usize>::sse_encode(ordinal, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
 impl SseEncode for crate::api::inference::DownloadPhase {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1830,6 +3657,80 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for crate::api::inference::GenerationEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::inference::GenerationEvent::Token {
+                text,
+                token_id,
+                logprob,
+            } => {
+                <i32>::sse_encode(0, serializer);
+                <String>::sse_encode(text, serializer);
+                <u32>::sse_encode(token_id, serializer);
+                <f32>::sse_encode(logprob, serializer);
+            }
+            crate::api::inference::GenerationEvent::Finished {
+                text,
+                stop_reason,
+                prompt_tokens,
+                generated_tokens,
+                time_to_first_token_ms,
+                total_time_ms,
+            } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(text, serializer);
+                <crate::api::inference::GenerationStopReason>::sse_encode(stop_reason, serializer);
+                <usize>::sse_encode(prompt_tokens, serializer);
+                <usize>::sse_encode(generated_tokens, serializer);
+                <Option<u64>>::sse_encode(time_to_first_token_ms, serializer);
+                <u64>::sse_encode(total_time_ms, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::inference::GenerationOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.max_new_tokens, serializer);
+        <f32>::sse_encode(self.temperature, serializer);
+        <Option<usize>>::sse_encode(self.top_k, serializer);
+        <Option<f32>>::sse_encode(self.top_p, serializer);
+        <Option<f32>>::sse_encode(self.min_p, serializer);
+        <f32>::sse_encode(self.repetition_penalty, serializer);
+        <f32>::sse_encode(self.presence_penalty, serializer);
+        <usize>::sse_encode(self.penalty_last_n, serializer);
+        <Option<u64>>::sse_encode(self.seed, serializer);
+        <Vec<String>>::sse_encode(self.stop_sequences, serializer);
+        <Option<String>>::sse_encode(self.grammar, serializer);
+        <Option<String>>::sse_encode(self.json_schema, serializer);
+    }
+}
+
+impl SseEncode for crate::api::inference::GenerationStopReason {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::api::inference::GenerationStopReason::EndOfSequence => 0,
+                crate::api::inference::GenerationStopReason::MaxTokens => 1,
+                crate::api::inference::GenerationStopReason::ContextFull => 2,
+                crate::api::inference::GenerationStopReason::StopSequence => 3,
+                crate::api::inference::GenerationStopReason::GrammarIncomplete => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for i32 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1898,6 +3799,18 @@ impl SseEncode for crate::models::error::InferenceError {
                 <i32>::sse_encode(13, serializer);
                 <String>::sse_encode(field0, serializer);
             }
+            crate::models::error::InferenceError::Cancelled(field0) => {
+                <i32>::sse_encode(14, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::models::error::InferenceError::DeadlineExceeded(field0) => {
+                <i32>::sse_encode(15, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
+            crate::models::error::InferenceError::QueueFull(field0) => {
+                <i32>::sse_encode(16, serializer);
+                <String>::sse_encode(field0, serializer);
+            }
             _ => {
                 unimplemented!("");
             }
@@ -1933,6 +3846,26 @@ impl SseEncode for Vec<String> {
     }
 }
 
+impl SseEncode for Vec<crate::api::inference::ChatMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::inference::ChatMessage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::models::device::DeviceInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::models::device::DeviceInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::inference::InferenceInput> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -1963,6 +3896,26 @@ impl SseEncode for Vec<Vec<f64>> {
     }
 }
 
+impl SseEncode for Vec<crate::api::inference::NamedInput> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::inference::NamedInput>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::inference::NamedResult> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::inference::NamedResult>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<Option<usize>> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2033,6 +3986,49 @@ impl SseEncode for Vec<crate::models::tensor::TensorSpec> {
     }
 }
 
+impl SseEncode for Vec<crate::api::inference::TranscriptSegment> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::inference::TranscriptSegment>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for crate::models::session::LoadMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::session::LoadMode::Eager => 0,
+                crate::models::session::LoadMode::Lazy => 1,
+                crate::models::session::LoadMode::EagerWithWarmup => 2,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::api::inference::NamedInput {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <crate::api::inference::InferenceInput>::sse_encode(self.input, serializer);
+    }
+}
+
+impl SseEncode for crate::api::inference::NamedResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.name, serializer);
+        <crate::api::inference::InferenceResult>::sse_encode(self.result, serializer);
+    }
+}
+
 impl SseEncode for Option<String> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2053,6 +4049,26 @@ impl SseEncode for Option<crate::api::inference::DownloadProgress> {
     }
 }
 
+impl SseEncode for Option<f32> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <f32>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::models::optimization::OptimizationReport> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::models::optimization::OptimizationReport>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u64> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2073,6 +4089,63 @@ impl SseEncode for Option<usize> {
     }
 }
 
+impl SseEncode for crate::models::optimization::OptimizationLevel {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::optimization::OptimizationLevel::None => 0,
+                crate::models::optimization::OptimizationLevel::F16 => 1,
+                crate::models::optimization::OptimizationLevel::Bf16 => 2,
+                crate::models::optimization::OptimizationLevel::Int8 => 3,
+                crate::models::optimization::OptimizationLevel::Q4 => 4,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::optimization::OptimizationReport {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::models::optimization::OptimizationLevel>::sse_encode(self.level, serializer);
+        <usize>::sse_encode(self.tensors_converted, serializer);
+        <usize>::sse_encode(self.tensors_skipped, serializer);
+        <usize>::sse_encode(self.size_before_bytes, serializer);
+        <usize>::sse_encode(self.size_after_bytes, serializer);
+        <Option<f32>>::sse_encode(self.max_abs_error, serializer);
+        <Option<f32>>::sse_encode(self.mean_abs_error, serializer);
+    }
+}
+
+impl SseEncode for crate::api::inference::PredictOptions {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Option<u64>>::sse_encode(self.cancellation_token, serializer);
+        <Option<u64>>::sse_encode(self.timeout_ms, serializer);
+        <crate::models::scheduler::Priority>::sse_encode(self.priority, serializer);
+    }
+}
+
+impl SseEncode for crate::models::scheduler::Priority {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(
+            match self {
+                crate::models::scheduler::Priority::Interactive => 0,
+                crate::models::scheduler::Priority::Background => 1,
+                _ => {
+                    unimplemented!("");
+                }
+            },
+            serializer,
+        );
+    }
+}
+
 impl SseEncode for (String, String) {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -2086,8 +4159,16 @@ impl SseEncode for crate::api::inference::SessionConfig {
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
         <Option<String>>::sse_encode(self.engine_type, serializer);
         <bool>::sse_encode(self.gpu_acceleration, serializer);
+        <crate::models::device::DeviceSelection>::sse_encode(self.device, serializer);
+        <Vec<String>>::sse_encode(self.fallback_chain, serializer);
         <Option<usize>>::sse_encode(self.num_threads, serializer);
         <Option<String>>::sse_encode(self.optimization_level, serializer);
+        <crate::models::session::LoadMode>::sse_encode(self.load_mode, serializer);
+        <Option<usize>>::sse_encode(self.max_concurrency, serializer);
+        <Option<usize>>::sse_encode(self.max_queue_depth, serializer);
+        <Option<usize>>::sse_encode(self.cache_max_entries, serializer);
+        <Option<usize>>::sse_encode(self.cache_max_bytes, serializer);
+        <Option<String>>::sse_encode(self.model_graph, serializer);
     }
 }
 
@@ -2098,6 +4179,33 @@ impl SseEncode for crate::api::inference::SessionInfo {
         <String>::sse_encode(self.engine_type, serializer);
         <Vec<crate::models::tensor::TensorSpec>>::sse_encode(self.input_specs, serializer);
         <Vec<crate::models::tensor::TensorSpec>>::sse_encode(self.output_specs, serializer);
+        <bool>::sse_encode(self.specs_pending, serializer);
+        <crate::models::session::LoadMode>::sse_encode(self.load_mode, serializer);
+        <bool>::sse_encode(self.is_loaded, serializer);
+        <Option<u64>>::sse_encode(self.load_time_ms, serializer);
+        <Option<u64>>::sse_encode(self.warmup_time_ms, serializer);
+        <String>::sse_encode(self.device, serializer);
+        <Option<String>>::sse_encode(self.device_fallback_reason, serializer);
+        <Vec<String>>::sse_encode(self.fallback_reasons, serializer);
+        <Option<crate::models::optimization::OptimizationReport>>::sse_encode(
+            self.optimization,
+            serializer,
+        );
+    }
+}
+
+impl SseEncode for crate::models::session::SessionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <usize>::sse_encode(self.active_requests, serializer);
+        <usize>::sse_encode(self.queued_interactive, serializer);
+        <usize>::sse_encode(self.queued_background, serializer);
+        <u64>::sse_encode(self.completed_requests, serializer);
+        <u64>::sse_encode(self.rejected_requests, serializer);
+        <u64>::sse_encode(self.cache_hits, serializer);
+        <u64>::sse_encode(self.cache_misses, serializer);
+        <usize>::sse_encode(self.cache_entries, serializer);
+        <usize>::sse_encode(self.cache_bytes, serializer);
     }
 }
 
@@ -2111,6 +4219,33 @@ impl SseEncode for crate::models::tensor::TensorSpec {
     }
 }
 
+impl SseEncode for crate::api::inference::TranscriptSegment {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <f64>::sse_encode(self.start_seconds, serializer);
+        <f64>::sse_encode(self.end_seconds, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <f64>::sse_encode(self.avg_logprob, serializer);
+        <f64>::sse_encode(self.no_speech_prob, serializer);
+    }
+}
+
+impl SseEncode for crate::api::inference::TranscriptionResult {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.text, serializer);
+        <Option<String>>::sse_encode(self.language, serializer);
+        <Vec<crate::api::inference::TranscriptSegment>>::sse_encode(self.segments, serializer);
+    }
+}
+
+impl SseEncode for u32 {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        serializer.cursor.write_u32::<NativeEndian>(self).unwrap();
+    }
+}
+
 impl SseEncode for u64 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -5,10 +5,10 @@ pub mod utils;
 mod frb_generated;
 
 // Re-export core types for convenience
-pub use engines::{InferenceEngine, Model, EngineType, ModelFormat};
-pub use models::{InferenceError, Tensor, TensorSpec, DataType, Preprocessor};
+pub use engines::{InferenceEngine, Model, EngineType, ModelFormat, EnginePlugin, EngineRegistry};
+pub use models::{InferenceError, Tensor, TensorSpec, DataType, Preprocessor, CancellationToken, PredictContext};
 pub use models::tensor::TensorInfo;
-pub use models::session::{Session, SessionMetadata, SessionBuilder};
+pub use models::session::{Session, SessionMetadata, SessionBuilder, SessionStats, LoadMode};
 pub use models::preprocessing::{
     ImagePreprocessConfig, TextPreprocessConfig, AudioPreprocessConfig,
     Normalization, ImageFormat
@@ -0,0 +1,318 @@
+use crate::models::Tensor;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+/// Capacity limits for a prediction cache
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CacheConfig {
+    /// Maximum number of cached outputs
+    pub max_entries: usize,
+    /// Maximum total size of cached input and output data in bytes
+    pub max_bytes: usize,
+}
+
+impl CacheConfig {
+    /// Create a cache configuration with the given limits
+    pub fn new(max_entries: usize, max_bytes: usize) -> Self {
+        Self {
+            max_entries,
+            max_bytes,
+        }
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: 256,
+            max_bytes: 64 * 1024 * 1024,
+        }
+    }
+}
+
+/// Snapshot of cache activity
+#[derive(Debug, Clone, Default)]
+pub struct CacheStats {
+    /// Lookups that returned a cached output
+    pub hits: u64,
+    /// Lookups that had to run the model
+    pub misses: u64,
+    /// Number of cached outputs
+    pub entries: usize,
+    /// Total size of cached input and output data in bytes
+    pub bytes: usize,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    /// Full input, compared on lookup since different inputs can share a key
+    input: Tensor,
+    output: Tensor,
+    last_used: u64,
+}
+
+impl CacheEntry {
+    fn matches(&self, input: &Tensor) -> bool {
+        self.input.data_type() == input.data_type()
+            && self.input.shape() == input.shape()
+            && self.input.data() == input.data()
+    }
+    
+    fn size(&self) -> usize {
+        self.input.data().len() + self.output.data().len()
+    }
+}
+
+#[derive(Debug, Default)]
+struct CacheState {
+    entries: HashMap<u64, CacheEntry>,
+    /// Access tick -> key, oldest first
+    recency: BTreeMap<u64, u64>,
+    tick: u64,
+    bytes: usize,
+}
+
+impl CacheState {
+    fn touch(&mut self, key: u64) {
+        self.tick += 1;
+        let tick = self.tick;
+        if let Some(entry) = self.entries.get_mut(&key) {
+            self.recency.remove(&entry.last_used);
+            entry.last_used = tick;
+            self.recency.insert(tick, key);
+        }
+    }
+    
+    fn remove(&mut self, key: u64) {
+        if let Some(entry) = self.entries.remove(&key) {
+            self.recency.remove(&entry.last_used);
+            self.bytes -= entry.size();
+        }
+    }
+    
+    fn evict_oldest(&mut self) -> bool {
+        let oldest = self.recency.iter().next().map(|(_, &key)| key);
+        match oldest {
+            Some(key) => {
+                self.remove(key);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// LRU cache of model outputs keyed by a hash of the input tensor
+/// 
+/// Keys cover the input bytes, shape and data type; each entry also keeps its
+/// input so a hash collision is a miss rather than a wrong output. Entries
+/// are evicted least-recently-used first once either the entry or byte limit
+/// is reached.
+#[derive(Debug)]
+pub struct PredictionCache {
+    config: CacheConfig,
+    state: Mutex<CacheState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl PredictionCache {
+    /// Create an empty cache with the given limits
+    pub fn new(config: CacheConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(CacheState::default()),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+    
+    /// Get the cache configuration
+    pub fn config(&self) -> CacheConfig {
+        self.config
+    }
+    
+    /// Compute the cache key for an input tensor
+    pub fn key_for(input: &Tensor) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        input.data_type().name().hash(&mut hasher);
+        input.shape().hash(&mut hasher);
+        input.data().hash(&mut hasher);
+        hasher.finish()
+    }
+    
+    /// Look up the cached output for an input, counting a hit or miss
+    pub fn get(&self, input: &Tensor) -> Option<Tensor> {
+        let key = Self::key_for(input);
+        let mut state = self.state.lock().unwrap();
+        
+        let output = state.entries.get(&key)
+            .filter(|entry| entry.matches(input))
+            .map(|entry| entry.output.clone());
+        
+        match output {
+            Some(output) => {
+                state.touch(key);
+                self.hits.fetch_add(1, Ordering::SeqCst);
+                Some(output)
+            }
+            None => {
+                self.misses.fetch_add(1, Ordering::SeqCst);
+                None
+            }
+        }
+    }
+    
+    /// Store the output for an input, evicting old entries as needed
+    /// 
+    /// Entries larger than the byte limit are not cached.
+    pub fn insert(&self, input: &Tensor, output: &Tensor) {
+        let size = input.data().len() + output.data().len();
+        if self.config.max_entries == 0 || size > self.config.max_bytes {
+            return;
+        }
+        
+        let key = Self::key_for(input);
+        let mut state = self.state.lock().unwrap();
+        state.remove(key);
+        
+        while state.entries.len() >= self.config.max_entries
+            || state.bytes + size > self.config.max_bytes
+        {
+            if !state.evict_oldest() {
+                break;
+            }
+        }
+        
+        state.tick += 1;
+        let tick = state.tick;
+        state.entries.insert(key, CacheEntry {
+            input: input.clone(),
+            output: output.clone(),
+            last_used: tick,
+        });
+        state.recency.insert(tick, key);
+        state.bytes += size;
+    }
+    
+    /// Remove all cached outputs, keeping the hit/miss counters
+    pub fn clear(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.entries.clear();
+        state.recency.clear();
+        state.bytes = 0;
+    }
+    
+    /// Get a snapshot of cache activity
+    pub fn stats(&self) -> CacheStats {
+        let state = self.state.lock().unwrap();
+        CacheStats {
+            hits: self.hits.load(Ordering::SeqCst),
+            misses: self.misses.load(Ordering::SeqCst),
+            entries: state.entries.len(),
+            bytes: state.bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn tensor(values: Vec<f32>) -> Tensor {
+        let len = values.len();
+        Tensor::from_f32(values, vec![len]).unwrap()
+    }
+    
+    #[test]
+    fn test_hit_and_miss_counters() {
+        let cache = PredictionCache::new(CacheConfig::default());
+        let input = tensor(vec![1.0, 2.0]);
+        
+        assert!(cache.get(&input).is_none());
+        cache.insert(&input, &tensor(vec![3.0]));
+        assert_eq!(cache.get(&input).unwrap().to_f32_vec().unwrap(), vec![3.0]);
+        
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 1);
+        assert_eq!(stats.entries, 1);
+        assert_eq!(stats.bytes, 12);
+    }
+    
+    #[test]
+    fn test_key_covers_shape_and_dtype() {
+        let flat = Tensor::from_f32(vec![1.0, 2.0], vec![2]).unwrap();
+        let matrix = Tensor::from_f32(vec![1.0, 2.0], vec![1, 2]).unwrap();
+        let ints = Tensor::from_i32(vec![1, 2], vec![2]).unwrap();
+        
+        assert_ne!(PredictionCache::key_for(&flat), PredictionCache::key_for(&matrix));
+        assert_ne!(PredictionCache::key_for(&flat), PredictionCache::key_for(&ints));
+    }
+    
+    #[test]
+    fn test_key_collision_is_a_miss() {
+        let cache = PredictionCache::new(CacheConfig::default());
+        let (a, b) = (tensor(vec![1.0]), tensor(vec![2.0]));
+        cache.insert(&a, &tensor(vec![3.0]));
+        
+        // Simulate `b` hashing to the same key as `a`
+        {
+            let mut state = cache.state.lock().unwrap();
+            let entry = state.entries.remove(&PredictionCache::key_for(&a)).unwrap();
+            state.entries.insert(PredictionCache::key_for(&b), entry);
+        }
+        
+        assert!(cache.get(&b).is_none());
+        assert_eq!(cache.stats().misses, 1);
+    }
+    
+    #[test]
+    fn test_lru_eviction_by_entries() {
+        let cache = PredictionCache::new(CacheConfig::new(2, usize::MAX));
+        let (a, b, c) = (tensor(vec![1.0]), tensor(vec![2.0]), tensor(vec![3.0]));
+        
+        cache.insert(&a, &a);
+        cache.insert(&b, &b);
+        cache.get(&a);
+        cache.insert(&c, &c);
+        
+        assert!(cache.get(&a).is_some());
+        assert!(cache.get(&b).is_none());
+        assert!(cache.get(&c).is_some());
+    }
+    
+    #[test]
+    fn test_eviction_by_bytes() {
+        let cache = PredictionCache::new(CacheConfig::new(16, 16));
+        let (a, b, c) = (tensor(vec![1.0]), tensor(vec![2.0]), tensor(vec![3.0]));
+        
+        cache.insert(&a, &a);
+        cache.insert(&b, &b);
+        cache.insert(&c, &c);
+        
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 2);
+        assert_eq!(stats.bytes, 16);
+        assert!(cache.get(&a).is_none());
+        
+        // Entries larger than the whole budget are skipped
+        cache.insert(&a, &tensor(vec![0.0; 4]));
+        assert!(cache.get(&a).is_none());
+    }
+    
+    #[test]
+    fn test_clear() {
+        let cache = PredictionCache::new(CacheConfig::default());
+        let input = tensor(vec![1.0]);
+        cache.insert(&input, &input);
+        cache.clear();
+        
+        assert!(cache.get(&input).is_none());
+        assert_eq!(cache.stats().entries, 0);
+        assert_eq!(cache.stats().bytes, 0);
+    }
+}
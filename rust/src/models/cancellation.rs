@@ -0,0 +1,156 @@
+use crate::models::{InferenceError, Priority};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Cooperative cancellation flag shared between a caller and a running prediction
+/// 
+/// Cloning a token yields a handle to the same flag, so cancelling any clone
+/// cancels every prediction observing it.
+#[derive(Debug, Clone)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Create a new, uncancelled token
+    pub fn new() -> Self {
+        Self {
+            cancelled: Arc::new(AtomicBool::new(false)),
+        }
+    }
+    
+    /// Request cancellation
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+    
+    /// Check whether cancellation has been requested
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
+
+impl Default for CancellationToken {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Per-call prediction context carrying cancellation, deadline and priority
+/// 
+/// Models call [`PredictContext::check`] at natural break points (between
+/// layers, generated tokens or batch items) and stop early when it fails.
+#[derive(Debug, Clone)]
+pub struct PredictContext {
+    token: Option<CancellationToken>,
+    deadline: Option<Instant>,
+    priority: Priority,
+}
+
+impl PredictContext {
+    /// Create a context with no cancellation token and no deadline
+    pub fn new() -> Self {
+        Self {
+            token: None,
+            deadline: None,
+            priority: Priority::Interactive,
+        }
+    }
+    
+    /// Attach a cancellation token
+    pub fn with_token(mut self, token: CancellationToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+    
+    /// Set an absolute deadline
+    pub fn with_deadline(mut self, deadline: Instant) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+    
+    /// Set a deadline relative to now
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        self.with_deadline(Instant::now() + timeout)
+    }
+    
+    /// Set the scheduling priority
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+    
+    /// Get the deadline, if any
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+    
+    /// Get the scheduling priority
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+    
+    /// Check whether cancellation has been requested
+    pub fn is_cancelled(&self) -> bool {
+        self.token.as_ref().map(|t| t.is_cancelled()).unwrap_or(false)
+    }
+    
+    /// Check whether the deadline has passed
+    pub fn is_expired(&self) -> bool {
+        self.deadline.map(|d| Instant::now() >= d).unwrap_or(false)
+    }
+    
+    /// Return an error if the call was cancelled or its deadline has passed
+    pub fn check(&self) -> Result<(), InferenceError> {
+        if self.is_cancelled() {
+            return Err(InferenceError::cancelled("Prediction cancelled by caller"));
+        }
+        
+        if self.is_expired() {
+            return Err(InferenceError::deadline_exceeded("Prediction did not finish before its deadline"));
+        }
+        
+        Ok(())
+    }
+}
+
+impl Default for PredictContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_cancellation_is_shared_between_clones() {
+        let token = CancellationToken::new();
+        let ctx = PredictContext::new().with_token(token.clone());
+        assert!(ctx.check().is_ok());
+        
+        token.cancel();
+        assert!(matches!(ctx.check(), Err(InferenceError::Cancelled(_))));
+    }
+    
+    #[test]
+    fn test_deadline_exceeded() {
+        let ctx = PredictContext::new().with_timeout(Duration::from_secs(60));
+        assert!(ctx.check().is_ok());
+        
+        let expired = PredictContext::new().with_deadline(Instant::now() - Duration::from_millis(1));
+        assert!(matches!(expired.check(), Err(InferenceError::DeadlineExceeded(_))));
+    }
+    
+    #[test]
+    fn test_cancellation_takes_precedence_over_deadline() {
+        let token = CancellationToken::new();
+        token.cancel();
+        let ctx = PredictContext::new()
+            .with_token(token)
+            .with_deadline(Instant::now() - Duration::from_millis(1));
+        assert!(matches!(ctx.check(), Err(InferenceError::Cancelled(_))));
+    }
+}
@@ -0,0 +1,121 @@
+/// Compute device requested for a session
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeviceSelection {
+    /// Best available accelerator, otherwise the CPU
+    #[default]
+    Auto,
+    /// Always run on the CPU
+    Cpu,
+    /// NVIDIA GPU with the given ordinal
+    Cuda { ordinal: usize },
+    /// Apple GPU with the given ordinal
+    Metal { ordinal: usize },
+}
+
+impl DeviceSelection {
+    /// Get the device name used by the public API (e.g. "cuda:0")
+    pub fn name(&self) -> String {
+        match self {
+            DeviceSelection::Auto => "auto".to_string(),
+            DeviceSelection::Cpu => "cpu".to_string(),
+            DeviceSelection::Cuda { ordinal } => format!("cuda:{}", ordinal),
+            DeviceSelection::Metal { ordinal } => format!("metal:{}", ordinal),
+        }
+    }
+    
+    /// Parse a device name produced by `name`
+    pub fn from_name(name: &str) -> Option<Self> {
+        let (kind, ordinal) = match name.split_once(':') {
+            Some((kind, ordinal)) => (kind, Some(ordinal.parse().ok()?)),
+            None => (name, None),
+        };
+        match (kind.to_lowercase().as_str(), ordinal) {
+            ("auto", None) => Some(DeviceSelection::Auto),
+            ("cpu", None) => Some(DeviceSelection::Cpu),
+            ("cuda", ordinal) => Some(DeviceSelection::Cuda { ordinal: ordinal.unwrap_or(0) }),
+            ("metal", ordinal) => Some(DeviceSelection::Metal { ordinal: ordinal.unwrap_or(0) }),
+            _ => None,
+        }
+    }
+    
+    /// Check whether this selection asks for a GPU
+    pub fn is_accelerator(&self) -> bool {
+        matches!(self, DeviceSelection::Cuda { .. } | DeviceSelection::Metal { .. })
+    }
+}
+
+/// Capability information for a compute device
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeviceInfo {
+    /// Selection that targets this device
+    pub device: DeviceSelection,
+    /// Human-readable device name
+    pub name: String,
+    /// Whether sessions can be created on this device
+    pub available: bool,
+    /// Why the device can't be used, when unavailable
+    pub unavailable_reason: Option<String>,
+    /// Whether half-precision (f16) weights are supported
+    pub supports_f16: bool,
+    /// Whether bfloat16 weights are supported
+    pub supports_bf16: bool,
+}
+
+impl DeviceInfo {
+    /// Describe a usable device
+    pub fn available<S: Into<String>>(device: DeviceSelection, name: S, supports_f16: bool, supports_bf16: bool) -> Self {
+        Self {
+            device,
+            name: name.into(),
+            available: true,
+            unavailable_reason: None,
+            supports_f16,
+            supports_bf16,
+        }
+    }
+    
+    /// Describe a device that can't be used
+    pub fn unavailable<S: Into<String>, R: Into<String>>(device: DeviceSelection, name: S, reason: R) -> Self {
+        Self {
+            device,
+            name: name.into(),
+            available: false,
+            unavailable_reason: Some(reason.into()),
+            supports_f16: false,
+            supports_bf16: false,
+        }
+    }
+    
+    /// Describe the CPU
+    /// 
+    /// Candle's CPU backend can't multiply bf16 matrices.
+    pub fn cpu() -> Self {
+        Self::available(DeviceSelection::Cpu, "CPU", true, false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_device_selection_names() {
+        assert_eq!(DeviceSelection::default(), DeviceSelection::Auto);
+        assert_eq!(DeviceSelection::Cpu.name(), "cpu");
+        assert_eq!(DeviceSelection::Cuda { ordinal: 1 }.name(), "cuda:1");
+        assert_eq!(DeviceSelection::Metal { ordinal: 0 }.name(), "metal:0");
+        assert_eq!(DeviceSelection::from_name("cuda:1"), Some(DeviceSelection::Cuda { ordinal: 1 }));
+        assert_eq!(DeviceSelection::from_name("CPU"), Some(DeviceSelection::Cpu));
+        assert_eq!(DeviceSelection::from_name("metal"), Some(DeviceSelection::Metal { ordinal: 0 }));
+        assert_eq!(DeviceSelection::from_name("cpu:0"), None);
+        assert_eq!(DeviceSelection::from_name("tpu"), None);
+        
+        assert!(DeviceSelection::Cuda { ordinal: 0 }.is_accelerator());
+        assert!(!DeviceSelection::Auto.is_accelerator());
+        
+        let unavailable = DeviceInfo::unavailable(DeviceSelection::Metal { ordinal: 0 }, "Metal:0", "not compiled");
+        assert!(!unavailable.available);
+        assert_eq!(unavailable.unavailable_reason.as_deref(), Some("not compiled"));
+        assert!(DeviceInfo::cpu().available);
+    }
+}
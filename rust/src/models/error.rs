@@ -44,6 +44,15 @@ pub enum InferenceError {
     
     #[error("Model format detection failed: {0}")]
     FormatDetection(String),
+    
+    #[error("Operation cancelled: {0}")]
+    Cancelled(String),
+    
+    #[error("Deadline exceeded: {0}")]
+    DeadlineExceeded(String),
+    
+    #[error("Request queue full: {0}")]
+    QueueFull(String),
 }
 
 impl InferenceError {
@@ -101,6 +110,36 @@ impl InferenceError {
     pub fn format_detection<T: Into<String>>(msg: T) -> Self {
         Self::FormatDetection(msg.into())
     }
+    
+    /// Create a cancellation error
+    pub fn cancelled<T: Into<String>>(msg: T) -> Self {
+        Self::Cancelled(msg.into())
+    }
+    
+    /// Create a deadline exceeded error
+    pub fn deadline_exceeded<T: Into<String>>(msg: T) -> Self {
+        Self::DeadlineExceeded(msg.into())
+    }
+    
+    /// Create a queue full error
+    pub fn queue_full<T: Into<String>>(msg: T) -> Self {
+        Self::QueueFull(msg.into())
+    }
+    
+    /// Check whether the error comes from the backend rather than the request
+    /// 
+    /// Backend failures (load errors, unsupported ops, device errors and
+    /// out-of-memory) may succeed on another engine or device. Prediction
+    /// errors are not among them: they are mostly caused by the input, and
+    /// engines report device and kernel failures during a forward pass as
+    /// GPU errors.
+    pub fn is_backend_failure(&self) -> bool {
+        matches!(
+            self,
+            Self::ModelLoad(_) | Self::UnsupportedFormat(_)
+                | Self::Engine(_) | Self::MemoryAllocation(_) | Self::Gpu(_)
+        )
+    }
 }
 
 // Manual conversion implementations to handle the error types that FRB can't serialize
@@ -2,9 +2,19 @@ pub mod error;
 pub mod tensor;
 pub mod session;
 pub mod preprocessing;
+pub mod cancellation;
+pub mod scheduler;
+pub mod cache;
+pub mod device;
+pub mod optimization;
 
 pub use error::InferenceError;
 pub use tensor::{Tensor, TensorSpec, DataType};
+pub use cancellation::{CancellationToken, PredictContext};
+pub use scheduler::{Priority, RequestScheduler, SchedulerConfig, SchedulerStats};
+pub use cache::{CacheConfig, CacheStats, PredictionCache};
+pub use device::{DeviceInfo, DeviceSelection};
+pub use optimization::{AccuracyHook, OptimizationConfig, OptimizationLevel, OptimizationReport};
 
 pub use preprocessing::Preprocessor;
 
@@ -98,6 +108,9 @@ impl ModelConfig {
     }
     
     /// Get default filename for architecture
+    /// 
+    /// Sharded checkpoints are found through `<filename>.index.json` when the
+    /// single file is missing.
     pub fn default_filename(&self) -> &str {
         match &self.architecture {
             ModelArchitecture::Bert => "model.safetensors",
@@ -116,13 +129,19 @@ impl ModelConfig {
             ModelArchitecture::Bert => Some("bert-base-uncased"),
             ModelArchitecture::ResNet { variant } => match variant {
                 ResNetVariant::ResNet18 => Some("microsoft/resnet-18"),
+                ResNetVariant::ResNet34 => Some("timm/resnet34.a1_in1k"),
                 ResNetVariant::ResNet50 => Some("microsoft/resnet-50"),
-                _ => None,
+                ResNetVariant::ResNet101 => Some("timm/resnet101.a1_in1k"),
+                ResNetVariant::ResNet152 => Some("timm/resnet152.a1_in1k"),
             },
             ModelArchitecture::MobileNet { variant } => match variant {
+                MobileNetVariant::V1 => Some("google/mobilenet_v1_1.0_224"),
                 MobileNetVariant::V2 => Some("google/mobilenet_v2_1.0_224"),
-                _ => None,
+                MobileNetVariant::V3Small => Some("timm/mobilenetv3_small_100.lamb_in1k"),
+                MobileNetVariant::V3Large => Some("timm/mobilenetv3_large_100.ra_in1k"),
             },
+            ModelArchitecture::Llama => Some("TinyLlama/TinyLlama-1.1B-Chat-v1.0"),
+            ModelArchitecture::Whisper => Some("openai/whisper-tiny"),
             _ => None,
         }
     }
@@ -0,0 +1,188 @@
+use crate::models::{InferenceError, Tensor};
+use std::fmt;
+use std::sync::Arc;
+
+/// Load-time weight transform selected by `SessionConfig::optimization_level`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OptimizationLevel {
+    /// Keep weights as loaded
+    #[default]
+    None,
+    /// Cast floating-point weights to half precision
+    F16,
+    /// Cast floating-point weights to bfloat16
+    Bf16,
+    /// Quantize Linear weights to 8-bit blocks (Q8_0)
+    Int8,
+    /// Quantize Linear weights to 4-bit blocks (Q4_0)
+    Q4,
+}
+
+impl OptimizationLevel {
+    /// Get the level name used by the public API
+    pub fn name(&self) -> &'static str {
+        match self {
+            OptimizationLevel::None => "none",
+            OptimizationLevel::F16 => "f16",
+            OptimizationLevel::Bf16 => "bf16",
+            OptimizationLevel::Int8 => "int8",
+            OptimizationLevel::Q4 => "q4",
+        }
+    }
+    
+    /// Parse a level name, accepting common aliases (e.g. "fp16", "q8")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "none" | "f32" | "fp32" => Some(OptimizationLevel::None),
+            "f16" | "fp16" | "half" => Some(OptimizationLevel::F16),
+            "bf16" => Some(OptimizationLevel::Bf16),
+            "int8" | "q8" | "q8_0" => Some(OptimizationLevel::Int8),
+            "q4" | "int4" | "q4_0" => Some(OptimizationLevel::Q4),
+            _ => None,
+        }
+    }
+    
+    /// Check whether this level quantizes weights rather than casting them
+    pub fn is_quantization(&self) -> bool {
+        matches!(self, OptimizationLevel::Int8 | OptimizationLevel::Q4)
+    }
+}
+
+/// Outcome of converting a model's weights at load time
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptimizationReport {
+    /// Transform that was applied
+    pub level: OptimizationLevel,
+    /// Number of tensors that were cast or quantized
+    pub tensors_converted: usize,
+    /// Number of eligible tensors left unchanged (e.g. shapes quantization can't handle)
+    pub tensors_skipped: usize,
+    /// Size of the weights before conversion
+    pub size_before_bytes: usize,
+    /// Size of the weights after conversion
+    pub size_after_bytes: usize,
+    /// Largest absolute output difference from the unconverted model on sample inputs
+    pub max_abs_error: Option<f32>,
+    /// Mean absolute output difference from the unconverted model on sample inputs
+    pub mean_abs_error: Option<f32>,
+}
+
+impl OptimizationReport {
+    /// Get how many times smaller the weights became
+    pub fn compression_ratio(&self) -> f32 {
+        if self.size_after_bytes == 0 {
+            return 1.0;
+        }
+        self.size_before_bytes as f32 / self.size_after_bytes as f32
+    }
+}
+
+/// Hook that accepts or rejects a conversion after outputs were compared
+pub type AccuracyHook = Arc<dyn Fn(&OptimizationReport) -> Result<(), InferenceError> + Send + Sync>;
+
+/// Options for converting weights while a model loads
+#[derive(Clone, Default)]
+pub struct OptimizationConfig {
+    /// Transform to apply
+    pub level: OptimizationLevel,
+    /// Inputs on which converted and unconverted outputs are compared;
+    /// synthetic inputs built from the input specs are used when empty
+    pub sample_inputs: Vec<Tensor>,
+    accuracy_check: Option<AccuracyHook>,
+}
+
+impl OptimizationConfig {
+    /// Create a configuration for a level
+    pub fn new(level: OptimizationLevel) -> Self {
+        Self {
+            level,
+            ..Self::default()
+        }
+    }
+    
+    /// Set the inputs used to compare outputs
+    pub fn with_sample_inputs(mut self, inputs: Vec<Tensor>) -> Self {
+        self.sample_inputs = inputs;
+        self
+    }
+    
+    /// Set a hook that can reject the conversion, failing the load
+    pub fn with_accuracy_check<F>(mut self, check: F) -> Self
+    where
+        F: Fn(&OptimizationReport) -> Result<(), InferenceError> + Send + Sync + 'static,
+    {
+        self.accuracy_check = Some(Arc::new(check));
+        self
+    }
+    
+    /// Reject the conversion when outputs differ by more than `tolerance`
+    /// 
+    /// The load also fails when the outputs couldn't be compared.
+    pub fn with_max_abs_error(self, tolerance: f32) -> Self {
+        self.with_accuracy_check(move |report| match report.max_abs_error {
+            Some(error) if error <= tolerance => Ok(()),
+            Some(error) => Err(InferenceError::model_load(format!(
+                "{} conversion changed outputs by up to {} (tolerance {})", report.level.name(), error, tolerance
+            ))),
+            None => Err(InferenceError::model_load(format!(
+                "{} conversion could not be checked against sample inputs", report.level.name()
+            ))),
+        })
+    }
+    
+    /// Get the accuracy hook, if one is set
+    pub fn accuracy_check(&self) -> Option<&AccuracyHook> {
+        self.accuracy_check.as_ref()
+    }
+}
+
+impl fmt::Debug for OptimizationConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OptimizationConfig")
+            .field("level", &self.level)
+            .field("sample_inputs", &self.sample_inputs.len())
+            .field("has_accuracy_check", &self.accuracy_check.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn test_optimization_level_names() {
+        assert_eq!(OptimizationLevel::default(), OptimizationLevel::None);
+        assert_eq!(OptimizationLevel::from_name("FP16"), Some(OptimizationLevel::F16));
+        assert_eq!(OptimizationLevel::from_name("q8"), Some(OptimizationLevel::Int8));
+        assert_eq!(OptimizationLevel::from_name("int4"), Some(OptimizationLevel::Q4));
+        assert_eq!(OptimizationLevel::from_name("turbo"), None);
+        for level in [OptimizationLevel::None, OptimizationLevel::F16, OptimizationLevel::Bf16, OptimizationLevel::Int8, OptimizationLevel::Q4] {
+            assert_eq!(OptimizationLevel::from_name(level.name()), Some(level));
+        }
+        assert!(OptimizationLevel::Q4.is_quantization());
+        assert!(!OptimizationLevel::Bf16.is_quantization());
+    }
+    
+    #[test]
+    fn test_max_abs_error_check() {
+        let config = OptimizationConfig::new(OptimizationLevel::Int8).with_max_abs_error(0.1);
+        let check = config.accuracy_check().unwrap();
+        let mut report = OptimizationReport {
+            level: OptimizationLevel::Int8,
+            tensors_converted: 1,
+            tensors_skipped: 0,
+            size_before_bytes: 4096,
+            size_after_bytes: 1088,
+            max_abs_error: Some(0.05),
+            mean_abs_error: Some(0.01),
+        };
+        assert!(check(&report).is_ok());
+        assert!(report.compression_ratio() > 3.7);
+        
+        report.max_abs_error = Some(0.5);
+        assert!(check(&report).is_err());
+        report.max_abs_error = None;
+        assert!(check(&report).is_err());
+    }
+}
@@ -0,0 +1,304 @@
+use crate::models::InferenceError;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+
+/// Priority of a prediction request
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Priority {
+    /// User-facing work that should be served first
+    #[default]
+    Interactive,
+    /// Bulk work that yields to interactive requests
+    Background,
+}
+
+/// Concurrency limits for a session
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SchedulerConfig {
+    /// Maximum number of predictions running at once
+    pub max_concurrency: usize,
+    /// Maximum number of requests waiting for a slot before new ones are rejected
+    pub max_queue_depth: usize,
+}
+
+impl SchedulerConfig {
+    /// Create a configuration with the given limits
+    pub fn new(max_concurrency: usize, max_queue_depth: usize) -> Self {
+        Self {
+            max_concurrency: max_concurrency.max(1),
+            max_queue_depth,
+        }
+    }
+    
+    /// Configuration without any limits
+    pub fn unbounded() -> Self {
+        Self {
+            max_concurrency: usize::MAX,
+            max_queue_depth: usize::MAX,
+        }
+    }
+}
+
+impl Default for SchedulerConfig {
+    fn default() -> Self {
+        Self::unbounded()
+    }
+}
+
+/// Snapshot of scheduler activity
+#[derive(Debug, Clone, Default)]
+pub struct SchedulerStats {
+    /// Requests currently holding a slot
+    pub active: usize,
+    /// Interactive requests waiting for a slot
+    pub queued_interactive: usize,
+    /// Background requests waiting for a slot
+    pub queued_background: usize,
+    /// Requests that finished (successfully or not)
+    pub completed: u64,
+    /// Requests rejected because the queue was full
+    pub rejected: u64,
+}
+
+#[derive(Debug, Default)]
+struct SchedulerState {
+    active: usize,
+    interactive: VecDeque<oneshot::Sender<()>>,
+    background: VecDeque<oneshot::Sender<()>>,
+}
+
+impl SchedulerState {
+    fn queued(&self) -> usize {
+        self.interactive.len() + self.background.len()
+    }
+    
+    /// Drop queue entries whose requests were abandoned
+    fn prune(&mut self) {
+        self.interactive.retain(|waiter| !waiter.is_closed());
+        self.background.retain(|waiter| !waiter.is_closed());
+    }
+}
+
+#[derive(Debug)]
+struct SchedulerInner {
+    config: SchedulerConfig,
+    state: Mutex<SchedulerState>,
+    completed: AtomicU64,
+    rejected: AtomicU64,
+}
+
+impl SchedulerInner {
+    /// Hand the slot to the next waiter, or free it if nobody is waiting
+    fn release(&self) {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            let next = match state.interactive.pop_front() {
+                Some(waiter) => Some(waiter),
+                None => state.background.pop_front(),
+            };
+            match next {
+                // The slot is transferred as-is, so `active` stays unchanged
+                Some(waiter) => {
+                    if waiter.send(()).is_ok() {
+                        return;
+                    }
+                }
+                None => {
+                    state.active -= 1;
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// Per-session request scheduler with a concurrency limit and priority queue
+/// 
+/// Requests beyond `max_concurrency` wait in a queue where interactive
+/// requests are always served before background ones. Once the queue holds
+/// `max_queue_depth` requests, further requests are rejected immediately.
+#[derive(Debug, Clone)]
+pub struct RequestScheduler {
+    inner: Arc<SchedulerInner>,
+}
+
+impl RequestScheduler {
+    /// Create a scheduler with the given limits
+    pub fn new(config: SchedulerConfig) -> Self {
+        Self {
+            inner: Arc::new(SchedulerInner {
+                config,
+                state: Mutex::new(SchedulerState::default()),
+                completed: AtomicU64::new(0),
+                rejected: AtomicU64::new(0),
+            }),
+        }
+    }
+    
+    /// Get the scheduler configuration
+    pub fn config(&self) -> SchedulerConfig {
+        self.inner.config
+    }
+    
+    /// Wait for a slot to run a request
+    /// 
+    /// Fails with `QueueFull` when the queue is at capacity. Dropping the
+    /// returned future while queued gives up the place in the queue.
+    pub async fn acquire(&self, priority: Priority) -> Result<SchedulerPermit, InferenceError> {
+        let receiver = {
+            let mut state = self.inner.state.lock().unwrap();
+            state.prune();
+            
+            if state.active < self.inner.config.max_concurrency && state.queued() == 0 {
+                state.active += 1;
+                return Ok(SchedulerPermit { inner: self.inner.clone() });
+            }
+            
+            if state.queued() >= self.inner.config.max_queue_depth {
+                self.inner.rejected.fetch_add(1, Ordering::SeqCst);
+                return Err(InferenceError::queue_full(format!(
+                    "{} requests already waiting (max queue depth {}), {} running",
+                    state.queued(), self.inner.config.max_queue_depth, state.active
+                )));
+            }
+            
+            let (sender, receiver) = oneshot::channel();
+            match priority {
+                Priority::Interactive => state.interactive.push_back(sender),
+                Priority::Background => state.background.push_back(sender),
+            }
+            receiver
+        };
+        
+        let mut waiter = QueuedRequest {
+            receiver: Some(receiver),
+            inner: self.inner.clone(),
+        };
+        
+        if let Some(receiver) = waiter.receiver.as_mut() {
+            receiver.await
+                .map_err(|_| InferenceError::ThreadPool("Request scheduler shut down".to_string()))?;
+        }
+        waiter.receiver = None;
+        
+        Ok(SchedulerPermit { inner: self.inner.clone() })
+    }
+    
+    /// Get a snapshot of scheduler activity
+    pub fn stats(&self) -> SchedulerStats {
+        let mut state = self.inner.state.lock().unwrap();
+        state.prune();
+        SchedulerStats {
+            active: state.active,
+            queued_interactive: state.interactive.len(),
+            queued_background: state.background.len(),
+            completed: self.inner.completed.load(Ordering::SeqCst),
+            rejected: self.inner.rejected.load(Ordering::SeqCst),
+        }
+    }
+}
+
+impl Default for RequestScheduler {
+    fn default() -> Self {
+        Self::new(SchedulerConfig::default())
+    }
+}
+
+/// Slot held by a running request; frees the slot when dropped
+#[derive(Debug)]
+pub struct SchedulerPermit {
+    inner: Arc<SchedulerInner>,
+}
+
+impl Drop for SchedulerPermit {
+    fn drop(&mut self) {
+        self.inner.completed.fetch_add(1, Ordering::SeqCst);
+        self.inner.release();
+    }
+}
+
+/// Queue entry that gives back a slot it was handed if dropped before use
+struct QueuedRequest {
+    receiver: Option<oneshot::Receiver<()>>,
+    inner: Arc<SchedulerInner>,
+}
+
+impl Drop for QueuedRequest {
+    fn drop(&mut self) {
+        if let Some(mut receiver) = self.receiver.take() {
+            receiver.close();
+            if receiver.try_recv().is_ok() {
+                self.inner.release();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[tokio::test]
+    async fn test_queue_depth_limit_rejects_excess_requests() {
+        let scheduler = RequestScheduler::new(SchedulerConfig::new(1, 0));
+        let permit = scheduler.acquire(Priority::Interactive).await.unwrap();
+        
+        let result = scheduler.acquire(Priority::Background).await;
+        assert!(matches!(result, Err(InferenceError::QueueFull(_))));
+        assert_eq!(scheduler.stats().rejected, 1);
+        
+        drop(permit);
+        assert!(scheduler.acquire(Priority::Background).await.is_ok());
+    }
+    
+    #[tokio::test]
+    async fn test_interactive_requests_are_served_first() {
+        let scheduler = RequestScheduler::new(SchedulerConfig::new(1, 8));
+        let permit = scheduler.acquire(Priority::Interactive).await.unwrap();
+        
+        let order = Arc::new(Mutex::new(Vec::new()));
+        let mut handles = Vec::new();
+        for (name, priority) in [("background", Priority::Background), ("interactive", Priority::Interactive)] {
+            let waiting = scheduler.clone();
+            let order = order.clone();
+            handles.push(tokio::spawn(async move {
+                let _permit = waiting.acquire(priority).await.unwrap();
+                order.lock().unwrap().push(name);
+            }));
+            // Make sure the background request is queued first
+            while scheduler.stats().queued_interactive + scheduler.stats().queued_background < handles.len() {
+                tokio::task::yield_now().await;
+            }
+        }
+        
+        drop(permit);
+        for handle in handles {
+            handle.await.unwrap();
+        }
+        
+        assert_eq!(*order.lock().unwrap(), vec!["interactive", "background"]);
+        assert_eq!(scheduler.stats().active, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_abandoned_waiter_releases_slot() {
+        let scheduler = RequestScheduler::new(SchedulerConfig::new(1, 8));
+        let permit = scheduler.acquire(Priority::Interactive).await.unwrap();
+        
+        let waiting = scheduler.clone();
+        let handle = tokio::spawn(async move {
+            let _ = waiting.acquire(Priority::Background).await;
+        });
+        while scheduler.stats().queued_background == 0 {
+            tokio::task::yield_now().await;
+        }
+        handle.abort();
+        let _ = handle.await;
+        
+        drop(permit);
+        assert_eq!(scheduler.stats().active, 0);
+        assert!(scheduler.acquire(Priority::Interactive).await.is_ok());
+    }
+}
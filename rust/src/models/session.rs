@@ -1,7 +1,29 @@
-use crate::engines::{InferenceEngine, Model, EngineType};
-use crate::models::{InferenceError, Tensor, TensorSpec};
+use crate::engines::{Backend, InferenceEngine, Model, EngineType, ModelMetadata};
+use crate::models::{InferenceError, Tensor, TensorSpec, PredictContext, OptimizationConfig, OptimizationReport};
+use crate::models::scheduler::{Priority, RequestScheduler, SchedulerConfig, SchedulerPermit};
+use crate::models::cache::{CacheConfig, PredictionCache};
+use crate::models::device::DeviceSelection;
+use async_trait::async_trait;
+use std::any::Any;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
 
 
+/// How model weights are materialized when a session is created
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LoadMode {
+    /// Load all weights immediately
+    #[default]
+    Eager,
+    /// Defer weight loading until the first prediction
+    Lazy,
+    /// Load immediately, then run synthetic inputs built from the input specs
+    EagerWithWarmup,
+}
+
 /// Unified session interface for all ML engines
 /// 
 /// This provides a common abstraction over different engine implementations,
@@ -14,6 +36,35 @@ pub struct Session {
     engine_type: EngineType,
     /// Optional session metadata
     metadata: Option<SessionMetadata>,
+    /// How the model weights were loaded
+    load_mode: LoadMode,
+    /// Time spent loading weights (eager modes only)
+    load_time: Option<Duration>,
+    /// Time spent running warm-up inputs
+    warmup_time: Option<Duration>,
+    /// Concurrency limit and request queue
+    scheduler: RequestScheduler,
+    /// Optional cache of outputs keyed by input hash
+    cache: Option<PredictionCache>,
+    /// Backends left to try if the model fails before serving a prediction
+    fallback: Mutex<Option<Fallback>>,
+    /// Why earlier backends were abandoned, in order
+    fallback_reasons: Vec<String>,
+    /// Whether the model has served a prediction
+    served: AtomicBool,
+    /// Incremented each time the session is replaced by a reload or fallback
+    backend_generation: u64,
+    /// Layer graph (JSON) engines run generic weights with, applied again on reload and fallback
+    model_graph: Option<String>,
+    /// Weight conversion the engine was configured with, applied again on reload
+    optimization: OptimizationConfig,
+}
+
+/// Remaining fallback chain and the source to reload the model from
+#[derive(Debug)]
+struct Fallback {
+    source: ModelSource,
+    remaining: VecDeque<Backend>,
 }
 
 impl Session {
@@ -23,31 +74,376 @@ impl Session {
             model,
             engine_type,
             metadata: None,
+            load_mode: LoadMode::Eager,
+            load_time: None,
+            warmup_time: None,
+            scheduler: RequestScheduler::default(),
+            cache: None,
+            fallback: Mutex::new(None),
+            fallback_reasons: Vec::new(),
+            served: AtomicBool::new(false),
+            backend_generation: 0,
+            model_graph: None,
+            optimization: OptimizationConfig::default(),
         }
     }
     
+    /// Create a session whose model is loaded on first prediction
+    pub fn lazy(engine: Box<dyn InferenceEngine>, source: ModelSource) -> Self {
+        let engine_type = engine.engine_type();
+        let mut session = Self::new(Box::new(LazyModel::new(engine, source)), engine_type);
+        session.load_mode = LoadMode::Lazy;
+        session
+    }
+    
     /// Create a session with metadata
     pub fn with_metadata(mut self, metadata: SessionMetadata) -> Self {
         self.metadata = Some(metadata);
         self
     }
     
+    /// Record how long the model took to load
+    pub fn with_load_time(mut self, load_time: Duration) -> Self {
+        self.load_time = Some(load_time);
+        self
+    }
+    
+    /// Set the concurrency limit and queue depth
+    pub fn with_scheduler_config(mut self, config: SchedulerConfig) -> Self {
+        self.scheduler = RequestScheduler::new(config);
+        self
+    }
+    
+    /// Enable the prediction cache with the given limits
+    pub fn with_cache(mut self, config: CacheConfig) -> Self {
+        self.cache = Some(PredictionCache::new(config));
+        self
+    }
+    
+    /// Set the backends to try if the model fails before its first successful prediction
+    pub fn with_fallback(mut self, source: ModelSource, chain: Vec<Backend>) -> Self {
+        self.fallback = Mutex::new((!chain.is_empty()).then(|| Fallback { source, remaining: chain.into() }));
+        self
+    }
+    
+    /// Record the weight conversion the engine was configured with
+    /// 
+    /// Kept whole, including sample inputs and the accuracy check, so a
+    /// reload converts the weights the same way.
+    pub fn with_optimization(mut self, optimization: OptimizationConfig) -> Self {
+        self.optimization = optimization;
+        self
+    }
+    
+    /// Record the layer graph (JSON) the engine was configured with
+    /// 
+    /// Engines created for a fallback or a reload are given the same graph.
+    pub fn with_model_graph<S: Into<String>>(mut self, graph_json: S) -> Self {
+        self.model_graph = Some(graph_json.into());
+        self
+    }
+    
+    /// Record why earlier backends were abandoned
+    pub fn with_fallback_reasons(mut self, reasons: Vec<String>) -> Self {
+        self.fallback_reasons = reasons;
+        self
+    }
+    
+    /// Get why earlier backends were abandoned, in order
+    pub fn fallback_reasons(&self) -> &[String] {
+        &self.fallback_reasons
+    }
+    
+    /// Get a counter that changes whenever the session is replaced by a reload or fallback
+    pub fn backend_generation(&self) -> u64 {
+        self.backend_generation
+    }
+    
+    /// Check whether a failed prediction should move the session to the next backend
+    /// 
+    /// Only backend failures before the first successful prediction trigger a fallback.
+    pub fn should_fall_back(&self, error: &InferenceError) -> bool {
+        error.is_backend_failure()
+            && !self.served.load(Ordering::SeqCst)
+            && self.fallback.lock().is_ok_and(|fallback| {
+                fallback.as_ref().is_some_and(|fallback| !fallback.remaining.is_empty())
+            })
+    }
+    
+    /// Build the session for the next backend in the fallback chain after `error`
+    /// 
+    /// The chain is taken from this session, so concurrent failures fall back
+    /// only once. Backends that fail to load are skipped. Fails when the chain
+    /// is exhausted.
+    pub async fn fall_back(&self, error: &InferenceError) -> Result<Session, InferenceError> {
+        let mut fallback = self.fallback.lock()
+            .map_err(|_| InferenceError::configuration("Session fallback lock poisoned"))?
+            .take()
+            .ok_or_else(|| InferenceError::configuration("Session has no fallback backends"))?;
+        let mut reasons = self.fallback_reasons.clone();
+        let mut reason = format!("{} failed on first prediction: {}", self.backend_name(), error);
+        
+        while let Some(backend) = fallback.remaining.pop_front() {
+            println!("⚠️  {}; falling back to {}", reason, backend.name());
+            reasons.push(reason);
+            
+            let start = Instant::now();
+            match load_on_backend(&backend, &fallback.source, self.model_graph.as_deref()).await {
+                Ok((engine, model)) => {
+                    let metadata = self.metadata.clone().unwrap_or_default();
+                    let mut session = self.with_replaced_model(model, start.elapsed());
+                    session.engine_type = engine.engine_type();
+                    session.metadata = Some(metadata.with_device_of(engine.as_ref(), backend.device));
+                    session.fallback = Mutex::new(Some(fallback));
+                    session.fallback_reasons = reasons;
+                    // Fallback backends load weights unconverted
+                    session.optimization = OptimizationConfig::default();
+                    return Ok(session);
+                }
+                Err(e) => reason = format!("{} failed to load: {}", backend.name(), e),
+            }
+        }
+        
+        reasons.push(reason);
+        Err(InferenceError::model_load(format!(
+            "All fallback backends failed: {}", reasons.join("; ")
+        )))
+    }
+    
+    /// Get the name of the backend currently serving the session
+    fn backend_name(&self) -> String {
+        let device = self.metadata.as_ref().and_then(|m| m.device.as_deref()).unwrap_or("cpu");
+        format!("{}@{}", self.engine_type.name(), device)
+    }
+    
+    /// Remember that the model has served a prediction, which ends fallback
+    /// 
+    /// The fallback chain is dropped with the model source it holds, which
+    /// for byte-loaded models is a copy of the whole model.
+    fn mark_served(&self) {
+        if !self.served.swap(true, Ordering::SeqCst) {
+            if let Ok(mut fallback) = self.fallback.lock() {
+                fallback.take();
+            }
+        }
+    }
+    
     /// Make a prediction with the model
     pub async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
-        self.model.predict(input).await
+        if let Some(output) = self.cache_lookup(input) {
+            return Ok(output);
+        }
+        
+        let _permit = self.scheduler.acquire(Priority::default()).await?;
+        let output = self.model.predict(input).await?;
+        self.mark_served();
+        self.cache_store(input, &output);
+        Ok(output)
     }
     
     /// Make batch predictions
+    /// 
+    /// Cached items are answered from the cache; only the rest reach the model.
     pub async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
-        self.model.predict_batch(inputs).await
+        let cached: Vec<Option<Tensor>> = inputs.iter().map(|input| self.cache_lookup(input)).collect();
+        let misses = Self::batch_misses(inputs, &cached);
+        
+        let computed = if misses.is_empty() {
+            Vec::new()
+        } else {
+            let _permit = self.scheduler.acquire(Priority::default()).await?;
+            let outputs = self.model.predict_batch(&misses).await?;
+            self.mark_served();
+            outputs
+        };
+        
+        self.merge_batch(cached, &misses, computed)
+    }
+    
+    /// Make a prediction that honours cancellation, deadlines and priority
+    pub async fn predict_with_context(&self, input: &Tensor, ctx: &PredictContext) -> Result<Tensor, InferenceError> {
+        if let Some(output) = self.cache_lookup(input) {
+            return Ok(output);
+        }
+        
+        let _permit = self.acquire_slot(ctx).await?;
+        let output = self.model.predict_with_context(input, ctx).await?;
+        self.mark_served();
+        self.cache_store(input, &output);
+        Ok(output)
+    }
+    
+    /// Make batch predictions that honour cancellation, deadlines and priority
+    /// 
+    /// The uncached part of the batch occupies a single concurrency slot.
+    pub async fn predict_batch_with_context(&self, inputs: &[Tensor], ctx: &PredictContext) -> Result<Vec<Tensor>, InferenceError> {
+        let cached: Vec<Option<Tensor>> = inputs.iter().map(|input| self.cache_lookup(input)).collect();
+        let misses = Self::batch_misses(inputs, &cached);
+        
+        let computed = if misses.is_empty() {
+            Vec::new()
+        } else {
+            let _permit = self.acquire_slot(ctx).await?;
+            let outputs = self.model.predict_batch_with_context(&misses, ctx).await?;
+            self.mark_served();
+            outputs
+        };
+        
+        self.merge_batch(cached, &misses, computed)
+    }
+    
+    /// Make a prediction from inputs matched to the input specs by name
+    /// 
+    /// Named predictions bypass the cache.
+    pub async fn predict_named(&self, inputs: &[(String, Tensor)]) -> Result<Vec<(String, Tensor)>, InferenceError> {
+        let _permit = self.scheduler.acquire(Priority::default()).await?;
+        let outputs = self.model.predict_named(inputs).await?;
+        self.mark_served();
+        Ok(outputs)
+    }
+    
+    /// Build a session that serves `model` in place of this one
+    /// 
+    /// The replacement keeps this session's settings, remaining fallback
+    /// chain and request queue, so concurrency limits also cover requests
+    /// still running on the old model. It starts with an empty cache.
+    pub fn with_replaced_model(&self, model: Box<dyn Model>, load_time: Duration) -> Session {
+        Session {
+            model,
+            engine_type: self.engine_type.clone(),
+            metadata: self.metadata.clone(),
+            load_mode: self.load_mode,
+            load_time: Some(load_time),
+            warmup_time: None,
+            scheduler: self.scheduler.clone(),
+            cache: self.cache.as_ref().map(|cache| PredictionCache::new(cache.config())),
+            fallback: Mutex::new(self.fallback.lock().ok().and_then(|mut fallback| fallback.take())),
+            fallback_reasons: self.fallback_reasons.clone(),
+            served: AtomicBool::new(false),
+            backend_generation: self.backend_generation + 1,
+            model_graph: self.model_graph.clone(),
+            optimization: self.optimization.clone(),
+        }
+    }
+    
+    /// Replace the model, invalidating any cached outputs
+    pub fn replace_model(&mut self, model: Box<dyn Model>, load_time: Duration) {
+        self.model = model;
+        self.load_time = Some(load_time);
+        self.warmup_time = None;
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+    }
+    
+    /// Look up a cached output for an input
+    fn cache_lookup(&self, input: &Tensor) -> Option<Tensor> {
+        self.cache.as_ref().and_then(|cache| cache.get(input))
+    }
+    
+    /// Store an output in the cache, if caching is enabled
+    fn cache_store(&self, input: &Tensor, output: &Tensor) {
+        if let Some(cache) = &self.cache {
+            cache.insert(input, output);
+        }
+    }
+    
+    /// Collect the batch inputs that were not answered from the cache
+    fn batch_misses<'a>(inputs: &'a [Tensor], cached: &[Option<Tensor>]) -> Cow<'a, [Tensor]> {
+        if cached.iter().all(|hit| hit.is_none()) {
+            return Cow::Borrowed(inputs);
+        }
+        
+        Cow::Owned(inputs.iter()
+            .zip(cached)
+            .filter(|(_, hit)| hit.is_none())
+            .map(|(input, _)| input.clone())
+            .collect())
+    }
+    
+    /// Interleave cached and freshly computed outputs back into input order
+    fn merge_batch(
+        &self,
+        cached: Vec<Option<Tensor>>,
+        misses: &[Tensor],
+        computed: Vec<Tensor>,
+    ) -> Result<Vec<Tensor>, InferenceError> {
+        if computed.len() != misses.len() {
+            return Err(InferenceError::prediction(format!(
+                "Model returned {} outputs for {} inputs", computed.len(), misses.len()
+            )));
+        }
+        
+        let mut computed = misses.iter().zip(computed);
+        let mut results = Vec::with_capacity(cached.len());
+        for hit in cached {
+            match hit {
+                Some(output) => results.push(output),
+                None => {
+                    if let Some((input, output)) = computed.next() {
+                        self.cache_store(input, &output);
+                        results.push(output);
+                    }
+                }
+            }
+        }
+        
+        Ok(results)
+    }
+    
+    /// Wait for a concurrency slot, giving up when the context's deadline passes
+    pub(crate) async fn acquire_slot(&self, ctx: &PredictContext) -> Result<SchedulerPermit, InferenceError> {
+        ctx.check()?;
+        match ctx.deadline() {
+            Some(deadline) => {
+                tokio::time::timeout_at(deadline.into(), self.scheduler.acquire(ctx.priority()))
+                    .await
+                    .map_err(|_| InferenceError::deadline_exceeded("Deadline passed while waiting in the request queue"))?
+            }
+            None => self.scheduler.acquire(ctx.priority()).await,
+        }
+    }
+    
+    /// Run synthetic inputs through the model to pay first-call costs up front
+    /// 
+    /// One input is generated per input spec, with dynamic dimensions set
+    /// to 1. Models without input specs are left untouched.
+    pub async fn warm_up(&mut self) -> Result<Duration, InferenceError> {
+        let start = Instant::now();
+        
+        let inputs = self.model.input_specs().iter()
+            .map(|spec| Ok((spec.name.clone(), spec.synthetic_tensor()?)))
+            .collect::<Result<Vec<_>, InferenceError>>()?;
+        match inputs.as_slice() {
+            [] => {}
+            [(_, input)] => {
+                self.model.predict(input).await?;
+            }
+            _ => {
+                self.model.predict_named(&inputs).await?;
+            }
+        }
+        
+        let elapsed = start.elapsed();
+        self.load_mode = LoadMode::EagerWithWarmup;
+        self.warmup_time = Some(elapsed);
+        Ok(elapsed)
     }
     
     /// Get input tensor specifications
+    /// 
+    /// Lazily loaded sessions report no specs until the model is materialized;
+    /// see [`Session::specs_pending`].
     pub fn input_specs(&self) -> &[TensorSpec] {
         self.model.input_specs()
     }
     
-    /// Get output tensor specifications  
+    /// Check whether the input and output specs are unknown until the model loads
+    pub fn specs_pending(&self) -> bool {
+        !self.is_loaded()
+    }
+    
+    /// Get output tensor specifications
     pub fn output_specs(&self) -> &[TensorSpec] {
         self.model.output_specs()
     }
@@ -62,10 +458,244 @@ impl Session {
         self.metadata.as_ref()
     }
     
+    /// Get how the model weights were converted at load time, once loaded
+    pub fn optimization_report(&self) -> Option<&OptimizationReport> {
+        self.model.optimization_report()
+    }
+    
+    /// Get the weight conversion the engine was configured with
+    /// 
+    /// Unlike `optimization_report`, this is known before a lazy model loads.
+    pub fn optimization(&self) -> &OptimizationConfig {
+        &self.optimization
+    }
+    
+    /// Get the layer graph (JSON) the engine was configured with
+    pub fn model_graph(&self) -> Option<&str> {
+        self.model_graph.as_deref()
+    }
+    
+    /// Get the load mode used for this session
+    pub fn load_mode(&self) -> LoadMode {
+        self.load_mode
+    }
+    
+    /// Check whether the model weights have been materialized
+    pub fn is_loaded(&self) -> bool {
+        match self.model.as_any().downcast_ref::<LazyModel>() {
+            Some(lazy) => lazy.is_loaded(),
+            None => true,
+        }
+    }
+    
+    /// Get the time spent loading the model, if it has been loaded
+    pub fn load_time(&self) -> Option<Duration> {
+        match self.model.as_any().downcast_ref::<LazyModel>() {
+            Some(lazy) => lazy.load_time(),
+            None => self.load_time,
+        }
+    }
+    
+    /// Get the time spent warming up the model, if a warm-up was run
+    pub fn warmup_time(&self) -> Option<Duration> {
+        self.warmup_time
+    }
+    
+    /// Get the concurrency limits for this session
+    pub fn scheduler_config(&self) -> SchedulerConfig {
+        self.scheduler.config()
+    }
+    
+    /// Get a snapshot of request activity for this session
+    pub fn stats(&self) -> SessionStats {
+        let scheduler = self.scheduler.stats();
+        let cache = self.cache.as_ref().map(|cache| cache.stats()).unwrap_or_default();
+        SessionStats {
+            active_requests: scheduler.active,
+            queued_interactive: scheduler.queued_interactive,
+            queued_background: scheduler.queued_background,
+            completed_requests: scheduler.completed,
+            rejected_requests: scheduler.rejected,
+            cache_hits: cache.hits,
+            cache_misses: cache.misses,
+            cache_entries: cache.entries,
+            cache_bytes: cache.bytes,
+        }
+    }
+    
     /// Get the underlying model (for engine-specific operations)
     pub fn model(&self) -> &dyn Model {
         self.model.as_ref()
     }
+    
+    /// Get the underlying model, loading it first if the session is lazy
+    /// 
+    /// Use this instead of [`Session::model`] before downcasting to a
+    /// concrete model type.
+    pub async fn loaded_model(&self) -> Result<&dyn Model, InferenceError> {
+        match self.model.as_any().downcast_ref::<LazyModel>() {
+            Some(lazy) => lazy.materialize().await,
+            None => Ok(self.model.as_ref()),
+        }
+    }
+    
+    /// Get the underlying model if it is loaded, without loading a lazy session
+    pub fn try_loaded_model(&self) -> Option<&dyn Model> {
+        match self.model.as_any().downcast_ref::<LazyModel>() {
+            Some(lazy) => lazy.get(),
+            None => Some(self.model.as_ref()),
+        }
+    }
+}
+
+/// Request statistics for a session
+#[derive(Debug, Clone)]
+pub struct SessionStats {
+    /// Predictions currently running
+    pub active_requests: usize,
+    /// Interactive predictions waiting for a slot
+    pub queued_interactive: usize,
+    /// Background predictions waiting for a slot
+    pub queued_background: usize,
+    /// Predictions that have finished
+    pub completed_requests: u64,
+    /// Predictions rejected because the queue was full
+    pub rejected_requests: u64,
+    /// Predictions answered from the cache
+    pub cache_hits: u64,
+    /// Cache lookups that had to run the model
+    pub cache_misses: u64,
+    /// Number of cached outputs
+    pub cache_entries: usize,
+    /// Total size of cached inputs and outputs in bytes
+    pub cache_bytes: usize,
+}
+
+/// Where a model's weights come from
+#[derive(Clone)]
+pub enum ModelSource {
+    /// Model file on disk
+    Path(String),
+    /// Model held in memory
+    Bytes(Vec<u8>),
+}
+
+impl std::fmt::Debug for ModelSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ModelSource::Path(path) => f.debug_tuple("Path").field(path).finish(),
+            ModelSource::Bytes(bytes) => write!(f, "Bytes({} bytes)", bytes.len()),
+        }
+    }
+}
+
+/// Model wrapper that defers loading until the first prediction
+/// 
+/// The engine and model source are kept around so weights are only
+/// materialized when they are actually needed.
+pub struct LazyModel {
+    engine: Box<dyn InferenceEngine>,
+    source: ModelSource,
+    model: tokio::sync::OnceCell<Box<dyn Model>>,
+    load_time: OnceLock<Duration>,
+}
+
+impl std::fmt::Debug for LazyModel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyModel")
+            .field("engine", &self.engine.engine_name())
+            .field("source", &self.source)
+            .field("loaded", &self.is_loaded())
+            .finish()
+    }
+}
+
+impl LazyModel {
+    /// Create a lazy model for the given engine and source
+    pub fn new(engine: Box<dyn InferenceEngine>, source: ModelSource) -> Self {
+        Self {
+            engine,
+            source,
+            model: tokio::sync::OnceCell::new(),
+            load_time: OnceLock::new(),
+        }
+    }
+    
+    /// Check whether the underlying model has been loaded
+    pub fn is_loaded(&self) -> bool {
+        self.model.initialized()
+    }
+    
+    /// Get the underlying model if it has been loaded
+    pub fn get(&self) -> Option<&dyn Model> {
+        self.model.get().map(|model| model.as_ref())
+    }
+    
+    /// Get the time spent loading the underlying model
+    pub fn load_time(&self) -> Option<Duration> {
+        self.load_time.get().copied()
+    }
+    
+    /// Load the underlying model if it has not been loaded yet
+    pub async fn materialize(&self) -> Result<&dyn Model, InferenceError> {
+        let model = self.model.get_or_try_init(|| async {
+            let start = Instant::now();
+            let model = load_source(self.engine.as_ref(), &self.source).await?;
+            let _ = self.load_time.set(start.elapsed());
+            Ok::<_, InferenceError>(model)
+        }).await?;
+        
+        Ok(model.as_ref())
+    }
+}
+
+#[async_trait]
+impl Model for LazyModel {
+    async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+        self.materialize().await?.predict(input).await
+    }
+    
+    async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+        self.materialize().await?.predict_batch(inputs).await
+    }
+    
+    async fn predict_with_context(&self, input: &Tensor, ctx: &PredictContext) -> Result<Tensor, InferenceError> {
+        ctx.check()?;
+        self.materialize().await?.predict_with_context(input, ctx).await
+    }
+    
+    async fn predict_batch_with_context(&self, inputs: &[Tensor], ctx: &PredictContext) -> Result<Vec<Tensor>, InferenceError> {
+        ctx.check()?;
+        self.materialize().await?.predict_batch_with_context(inputs, ctx).await
+    }
+    
+    async fn predict_named(&self, inputs: &[(String, Tensor)]) -> Result<Vec<(String, Tensor)>, InferenceError> {
+        self.materialize().await?.predict_named(inputs).await
+    }
+    
+    fn input_specs(&self) -> &[TensorSpec] {
+        self.model.get().map(|m| m.input_specs()).unwrap_or(&[])
+    }
+    
+    fn output_specs(&self) -> &[TensorSpec] {
+        self.model.get().map(|m| m.output_specs()).unwrap_or(&[])
+    }
+    
+    fn engine_type(&self) -> EngineType {
+        self.engine.engine_type()
+    }
+    
+    fn metadata(&self) -> Option<&ModelMetadata> {
+        self.model.get().and_then(|m| m.metadata())
+    }
+    
+    fn optimization_report(&self) -> Option<&OptimizationReport> {
+        self.model.get().and_then(|m| m.optimization_report())
+    }
+    
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
 
 /// Session metadata and configuration
@@ -79,6 +709,10 @@ pub struct SessionMetadata {
     pub model_version: Option<String>,
     /// Session creation timestamp
     pub created_at: std::time::SystemTime,
+    /// Device the model runs on (e.g. "cpu", "cuda:0")
+    pub device: Option<String>,
+    /// Why the model runs on the CPU instead of the requested device
+    pub device_fallback_reason: Option<String>,
     /// Additional custom metadata
     pub custom_metadata: std::collections::HashMap<String, String>,
 }
@@ -91,6 +725,8 @@ impl SessionMetadata {
             model_name: None,
             model_version: None,
             created_at: std::time::SystemTime::now(),
+            device: None,
+            device_fallback_reason: None,
             custom_metadata: std::collections::HashMap::new(),
         }
     }
@@ -113,6 +749,24 @@ impl SessionMetadata {
         self
     }
     
+    /// Set the device and, if the requested one was unavailable, the fallback reason
+    pub fn with_device<S: Into<String>>(mut self, device: S, fallback_reason: Option<String>) -> Self {
+        self.device = Some(device.into());
+        self.device_fallback_reason = fallback_reason;
+        self
+    }
+    
+    /// Record the device an engine runs on, given the device that was requested
+    pub fn with_device_of(self, engine: &dyn InferenceEngine, requested: DeviceSelection) -> Self {
+        let device = engine.device_name();
+        let fallback_reason = engine.device_fallback_reason().or_else(|| {
+            (requested.is_accelerator() && device == "cpu").then(|| {
+                format!("{} engine only runs on the CPU", engine.engine_name())
+            })
+        });
+        self.with_device(device, fallback_reason)
+    }
+    
     /// Add custom metadata
     pub fn with_custom<K: Into<String>, V: Into<String>>(mut self, key: K, value: V) -> Self {
         self.custom_metadata.insert(key.into(), value.into());
@@ -131,6 +785,14 @@ impl Default for SessionMetadata {
 pub struct SessionBuilder {
     engine: Option<Box<dyn InferenceEngine>>,
     metadata: SessionMetadata,
+    load_mode: LoadMode,
+    scheduler_config: SchedulerConfig,
+    cache_config: Option<CacheConfig>,
+    device: DeviceSelection,
+    fallback_chain: Vec<Backend>,
+    fallback_reasons: Vec<String>,
+    model_graph: Option<String>,
+    optimization: OptimizationConfig,
 }
 
 impl SessionBuilder {
@@ -139,6 +801,14 @@ impl SessionBuilder {
         Self {
             engine: None,
             metadata: SessionMetadata::new(),
+            load_mode: LoadMode::Eager,
+            scheduler_config: SchedulerConfig::default(),
+            cache_config: None,
+            device: DeviceSelection::Auto,
+            fallback_chain: Vec::new(),
+            fallback_reasons: Vec::new(),
+            model_graph: None,
+            optimization: OptimizationConfig::default(),
         }
     }
     
@@ -154,30 +824,154 @@ impl SessionBuilder {
         self
     }
     
+    /// Set the device the engine was asked to run on
+    /// 
+    /// Used to record why a session ended up on the CPU.
+    pub fn with_device(mut self, device: DeviceSelection) -> Self {
+        self.device = device;
+        self
+    }
+    
+    /// Set the load mode
+    pub fn with_load_mode(mut self, load_mode: LoadMode) -> Self {
+        self.load_mode = load_mode;
+        self
+    }
+    
+    /// Set the concurrency limit and queue depth
+    pub fn with_scheduler_config(mut self, config: SchedulerConfig) -> Self {
+        self.scheduler_config = config;
+        self
+    }
+    
+    /// Enable the prediction cache with the given limits
+    pub fn with_cache(mut self, config: CacheConfig) -> Self {
+        self.cache_config = Some(config);
+        self
+    }
+    
+    /// Set the backends to try, in order, when loading or the first prediction fails
+    /// 
+    /// Without an engine the first backend in the chain is used.
+    pub fn with_fallback_chain(mut self, chain: Vec<Backend>) -> Self {
+        self.fallback_chain = chain;
+        self
+    }
+    
+    /// Set the layer graph (JSON) that engines created from the fallback chain run the weights with
+    /// 
+    /// An engine passed to `with_engine` must already be configured with it.
+    /// The session keeps the graph for engines created later.
+    pub fn with_model_graph<S: Into<String>>(mut self, graph_json: S) -> Self {
+        self.model_graph = Some(graph_json.into());
+        self
+    }
+    
+    /// Set the weight conversion the engine passed to `with_engine` was configured with
+    /// 
+    /// The session keeps it for reloads; engines created from the fallback
+    /// chain load weights unconverted.
+    pub fn with_optimization(mut self, optimization: OptimizationConfig) -> Self {
+        self.optimization = optimization;
+        self
+    }
+    
+    /// Record why a backend was skipped before the builder was used
+    pub fn with_fallback_reason<S: Into<String>>(mut self, reason: S) -> Self {
+        self.fallback_reasons.push(reason.into());
+        self
+    }
+    
     /// Load a model from path and create session
     pub async fn load_from_path<S: AsRef<str>>(self, path: S) -> Result<Session, InferenceError> {
-        let engine = self.engine.ok_or_else(|| {
-            InferenceError::configuration("No engine specified for session builder")
-        })?;
-        
-        let model = engine.load_model(path.as_ref()).await?;
-        let engine_type = engine.engine_type();
-        
-        let metadata = self.metadata.with_model_path(path.as_ref());
-        
-        Ok(Session::new(model, engine_type).with_metadata(metadata))
+        let metadata = self.metadata.clone().with_model_path(path.as_ref());
+        self.load(ModelSource::Path(path.as_ref().to_string()), metadata).await
     }
     
     /// Load a model from bytes and create session
     pub async fn load_from_bytes(self, bytes: &[u8]) -> Result<Session, InferenceError> {
-        let engine = self.engine.ok_or_else(|| {
-            InferenceError::configuration("No engine specified for session builder")
-        })?;
+        let metadata = self.metadata.clone();
+        self.load(ModelSource::Bytes(bytes.to_vec()), metadata).await
+    }
+    
+    /// Create a session from a model source according to the load mode
+    async fn load(self, source: ModelSource, metadata: SessionMetadata) -> Result<Session, InferenceError> {
+        let mut chain: VecDeque<Backend> = self.fallback_chain.into();
+        let mut reasons = self.fallback_reasons;
+        // Only the engine passed to `with_engine` converts weights
+        let mut converts_weights = self.engine.is_some();
+        let mut engine = match self.engine {
+            Some(engine) => engine,
+            None if !chain.is_empty() => next_engine(&mut chain, &mut reasons, self.model_graph.as_deref())?,
+            None => return Err(InferenceError::configuration("No engine specified for session builder")),
+        };
         
-        let model = engine.load_from_bytes(bytes).await?;
-        let engine_type = engine.engine_type();
+        if self.load_mode == LoadMode::Lazy {
+            let metadata = metadata.with_device_of(engine.as_ref(), self.device);
+            let fallback_source = (!chain.is_empty()).then(|| source.clone());
+            let mut session = Session::lazy(engine, source)
+                .with_metadata(metadata)
+                .with_scheduler_config(self.scheduler_config)
+                .with_fallback_reasons(reasons);
+            if let Some(fallback_source) = fallback_source {
+                session = session.with_fallback(fallback_source, chain.into());
+            }
+            if let Some(graph) = self.model_graph {
+                session = session.with_model_graph(graph);
+            }
+            if converts_weights {
+                session = session.with_optimization(self.optimization);
+            }
+            return Ok(match self.cache_config {
+                Some(config) => session.with_cache(config),
+                None => session,
+            });
+        }
+        
+        // Walk the fallback chain until a backend loads the model
+        let (model, load_time) = loop {
+            let start = Instant::now();
+            match load_source(engine.as_ref(), &source).await {
+                Ok(model) => break (model, start.elapsed()),
+                Err(e) if e.is_backend_failure() && !chain.is_empty() => {
+                    let reason = format!("{} failed to load: {}", backend_label(engine.as_ref()), e);
+                    println!("⚠️  {}", reason);
+                    reasons.push(reason);
+                    engine = next_engine(&mut chain, &mut reasons, self.model_graph.as_deref())?;
+                    converts_weights = false;
+                }
+                Err(e) => return Err(e),
+            }
+        };
+        let metadata = metadata.with_device_of(engine.as_ref(), self.device);
+        
+        let mut session = Session::new(model, engine.engine_type())
+            .with_metadata(metadata)
+            .with_load_time(load_time)
+            .with_scheduler_config(self.scheduler_config)
+            .with_fallback_reasons(reasons)
+            .with_fallback(source, chain.into());
+        if let Some(graph) = self.model_graph {
+            session = session.with_model_graph(graph);
+        }
+        if converts_weights {
+            session = session.with_optimization(self.optimization);
+        }
+        if let Some(config) = self.cache_config {
+            session = session.with_cache(config);
+        }
+        
+        if self.load_mode == LoadMode::EagerWithWarmup {
+            // The warm-up is the first prediction, so it may also trigger a fallback
+            while let Err(e) = session.warm_up().await {
+                if !session.should_fall_back(&e) {
+                    return Err(e);
+                }
+                session = session.fall_back(&e).await?;
+            }
+        }
         
-        Ok(Session::new(model, engine_type).with_metadata(self.metadata))
+        Ok(session)
     }
 }
 
@@ -185,4 +979,551 @@ impl Default for SessionBuilder {
     fn default() -> Self {
         Self::new()
     }
-} 
\ No newline at end of file
+}
+
+/// Load a model from its source with the given engine
+async fn load_source(engine: &dyn InferenceEngine, source: &ModelSource) -> Result<Box<dyn Model>, InferenceError> {
+    match source {
+        ModelSource::Path(path) => engine.load_model(path).await,
+        ModelSource::Bytes(bytes) => engine.load_from_bytes(bytes).await,
+    }
+}
+
+/// Create the engine for a backend, configured with the session's layer graph
+fn create_engine(backend: &Backend, model_graph: Option<&str>) -> Result<Box<dyn InferenceEngine>, InferenceError> {
+    let mut engine = backend.create_engine()?;
+    if let Some(graph) = model_graph {
+        engine.set_model_graph(graph)?;
+    }
+    Ok(engine)
+}
+
+/// Create the engine for a backend and load the model on it
+async fn load_on_backend(backend: &Backend, source: &ModelSource, model_graph: Option<&str>) -> Result<(Box<dyn InferenceEngine>, Box<dyn Model>), InferenceError> {
+    let engine = create_engine(backend, model_graph)?;
+    let model = load_source(engine.as_ref(), source).await?;
+    Ok((engine, model))
+}
+
+/// Create the engine for the next backend that can be constructed
+/// 
+/// Backends whose engine can't be created are skipped, recording why.
+fn next_engine(chain: &mut VecDeque<Backend>, reasons: &mut Vec<String>, model_graph: Option<&str>) -> Result<Box<dyn InferenceEngine>, InferenceError> {
+    while let Some(backend) = chain.pop_front() {
+        match create_engine(&backend, model_graph) {
+            Ok(engine) => return Ok(engine),
+            Err(e) => {
+                let reason = format!("{} unavailable: {}", backend.name(), e);
+                println!("⚠️  {}", reason);
+                reasons.push(reason);
+            }
+        }
+    }
+    
+    Err(InferenceError::model_load(format!("All fallback backends failed: {}", reasons.join("; "))))
+}
+
+/// Name an engine's backend in `engine@device` form
+fn backend_label(engine: &dyn InferenceEngine) -> String {
+    format!("{}@{}", engine.engine_type().name(), engine.device_name())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engines::ModelFormat;
+    use crate::models::DataType;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    
+    /// Identity model that counts predictions
+    #[derive(Debug)]
+    struct EchoModel {
+        specs: Vec<TensorSpec>,
+        calls: Arc<AtomicUsize>,
+    }
+    
+    #[async_trait]
+    impl Model for EchoModel {
+        async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(input.clone())
+        }
+        
+        async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+            Ok(inputs.to_vec())
+        }
+        
+        fn input_specs(&self) -> &[TensorSpec] {
+            &self.specs
+        }
+        
+        fn output_specs(&self) -> &[TensorSpec] {
+            &self.specs
+        }
+        
+        fn engine_type(&self) -> EngineType {
+            EngineType::Candle
+        }
+        
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+    
+    /// Engine that hands out echo models and counts loads
+    #[derive(Debug)]
+    struct EchoEngine {
+        loads: Arc<AtomicUsize>,
+        calls: Arc<AtomicUsize>,
+    }
+    
+    impl EchoEngine {
+        fn model(&self) -> Box<dyn Model> {
+            self.loads.fetch_add(1, Ordering::SeqCst);
+            Box::new(EchoModel {
+                specs: vec![TensorSpec::new("input".to_string(), vec![None, Some(4)], DataType::F32)],
+                calls: self.calls.clone(),
+            })
+        }
+    }
+    
+    #[async_trait]
+    impl InferenceEngine for EchoEngine {
+        async fn load_model(&self, _path: &str) -> Result<Box<dyn Model>, InferenceError> {
+            Ok(self.model())
+        }
+        
+        async fn load_from_bytes(&self, _bytes: &[u8]) -> Result<Box<dyn Model>, InferenceError> {
+            Ok(self.model())
+        }
+        
+        fn supports_format(&self, _format: &ModelFormat) -> bool {
+            true
+        }
+        
+        fn engine_name(&self) -> &'static str {
+            "echo"
+        }
+        
+        fn engine_type(&self) -> EngineType {
+            EngineType::Candle
+        }
+    }
+    
+    fn echo_engine() -> (Box<dyn InferenceEngine>, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+        let loads = Arc::new(AtomicUsize::new(0));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let engine = EchoEngine { loads: loads.clone(), calls: calls.clone() };
+        (Box::new(engine), loads, calls)
+    }
+    
+    /// Model whose predictions fail as if the GPU ran out of memory
+    #[derive(Debug)]
+    struct OutOfMemoryModel {
+        specs: Vec<TensorSpec>,
+    }
+    
+    #[async_trait]
+    impl Model for OutOfMemoryModel {
+        async fn predict(&self, _input: &Tensor) -> Result<Tensor, InferenceError> {
+            Err(InferenceError::gpu("out of memory"))
+        }
+        
+        async fn predict_batch(&self, _inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+            Err(InferenceError::gpu("out of memory"))
+        }
+        
+        fn input_specs(&self) -> &[TensorSpec] {
+            &self.specs
+        }
+        
+        fn output_specs(&self) -> &[TensorSpec] {
+            &self.specs
+        }
+        
+        fn engine_type(&self) -> EngineType {
+            EngineType::Candle
+        }
+        
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+    
+    /// Engine that fails either to load or on the first prediction
+    #[derive(Debug)]
+    struct FailingEngine {
+        fail_load: bool,
+    }
+    
+    #[async_trait]
+    impl InferenceEngine for FailingEngine {
+        async fn load_model(&self, _path: &str) -> Result<Box<dyn Model>, InferenceError> {
+            if self.fail_load {
+                return Err(InferenceError::model_load("unsupported operator"));
+            }
+            Ok(Box::new(OutOfMemoryModel {
+                specs: vec![TensorSpec::new("input".to_string(), vec![Some(1), Some(4)], DataType::F32)],
+            }))
+        }
+        
+        async fn load_from_bytes(&self, _bytes: &[u8]) -> Result<Box<dyn Model>, InferenceError> {
+            self.load_model("").await
+        }
+        
+        fn supports_format(&self, _format: &ModelFormat) -> bool {
+            true
+        }
+        
+        fn engine_name(&self) -> &'static str {
+            "failing"
+        }
+        
+        fn engine_type(&self) -> EngineType {
+            EngineType::Candle
+        }
+    }
+    
+    /// Fallback chain ending in a registered echo plugin
+    fn plugin_chain() -> Vec<Backend> {
+        use crate::engines::registry::tests::echo_plugin;
+        use crate::engines::EngineRegistry;
+        
+        if !EngineRegistry::contains("session_fallback") {
+            let _ = EngineRegistry::register(echo_plugin("session_fallback", "sfb"));
+        }
+        vec![Backend::new(EngineType::Plugin("session_fallback".into()), DeviceSelection::Cpu)]
+    }
+    
+    #[tokio::test]
+    async fn test_lazy_session_defers_loading() {
+        let (engine, loads, _) = echo_engine();
+        let session = SessionBuilder::new()
+            .with_engine(engine)
+            .with_load_mode(LoadMode::Lazy)
+            .load_from_path("model.bin")
+            .await
+            .unwrap();
+        
+        assert_eq!(session.load_mode(), LoadMode::Lazy);
+        assert!(!session.is_loaded());
+        assert!(session.input_specs().is_empty());
+        assert!(session.specs_pending());
+        assert_eq!(loads.load(Ordering::SeqCst), 0);
+        
+        let input = Tensor::from_f32(vec![1.0; 4], vec![1, 4]).unwrap();
+        session.predict(&input).await.unwrap();
+        session.predict(&input).await.unwrap();
+        
+        assert!(session.is_loaded());
+        assert!(session.load_time().is_some());
+        assert_eq!(session.input_specs().len(), 1);
+        assert!(!session.specs_pending());
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_builder_records_device_fallback() {
+        let (engine, _, _) = echo_engine();
+        let session = SessionBuilder::new()
+            .with_engine(engine)
+            .with_device(DeviceSelection::Cuda { ordinal: 0 })
+            .load_from_path("model.bin")
+            .await
+            .unwrap();
+        
+        let metadata = session.metadata().unwrap();
+        assert_eq!(metadata.device.as_deref(), Some("cpu"));
+        assert_eq!(metadata.device_fallback_reason.as_deref(), Some("echo engine only runs on the CPU"));
+        
+        // Asking for the CPU is not a fallback
+        let (engine, _, _) = echo_engine();
+        let session = SessionBuilder::new()
+            .with_engine(engine)
+            .with_device(DeviceSelection::Cpu)
+            .load_from_path("model.bin")
+            .await
+            .unwrap();
+        assert!(session.metadata().unwrap().device_fallback_reason.is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_cache_skips_model_and_is_invalidated_on_reload() {
+        let (engine, _, calls) = echo_engine();
+        let mut session = SessionBuilder::new()
+            .with_engine(engine)
+            .with_cache(CacheConfig::default())
+            .load_from_path("model.bin")
+            .await
+            .unwrap();
+        
+        let a = Tensor::from_f32(vec![1.0; 4], vec![1, 4]).unwrap();
+        let b = Tensor::from_f32(vec![2.0; 4], vec![1, 4]).unwrap();
+        session.predict(&a).await.unwrap();
+        session.predict(&a).await.unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        
+        let outputs = session.predict_batch(&[a.clone(), b.clone()]).await.unwrap();
+        assert_eq!(outputs[1].to_f32_vec().unwrap(), vec![2.0; 4]);
+        
+        let stats = session.stats();
+        assert_eq!(stats.cache_hits, 2);
+        assert_eq!(stats.cache_misses, 2);
+        assert_eq!(stats.cache_entries, 2);
+        
+        let (engine, _, _) = echo_engine();
+        let model = engine.load_model("model.bin").await.unwrap();
+        session.replace_model(model, Duration::from_millis(1));
+        assert_eq!(session.stats().cache_entries, 0);
+    }
+    
+    /// Model that cancels a token after serving its first prediction
+    #[derive(Debug)]
+    struct CancelAfterFirstModel {
+        specs: Vec<TensorSpec>,
+        token: crate::models::CancellationToken,
+        calls: Arc<AtomicUsize>,
+    }
+    
+    #[async_trait]
+    impl Model for CancelAfterFirstModel {
+        async fn predict(&self, input: &Tensor) -> Result<Tensor, InferenceError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.token.cancel();
+            Ok(input.clone())
+        }
+        
+        async fn predict_batch(&self, inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+            Ok(inputs.to_vec())
+        }
+        
+        fn input_specs(&self) -> &[TensorSpec] {
+            &self.specs
+        }
+        
+        fn output_specs(&self) -> &[TensorSpec] {
+            &self.specs
+        }
+        
+        fn engine_type(&self) -> EngineType {
+            EngineType::Candle
+        }
+        
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_batch_checks_cancellation_per_item() {
+        let token = crate::models::CancellationToken::new();
+        let calls = Arc::new(AtomicUsize::new(0));
+        let model = CancelAfterFirstModel {
+            specs: vec![TensorSpec::new("input".to_string(), vec![None, Some(4)], DataType::F32)],
+            token: token.clone(),
+            calls: calls.clone(),
+        };
+        
+        let inputs = vec![Tensor::from_f32(vec![1.0; 4], vec![1, 4]).unwrap(); 3];
+        let ctx = PredictContext::new().with_token(token);
+        let result = model.predict_batch_with_context(&inputs, &ctx).await;
+        assert!(matches!(result, Err(InferenceError::Cancelled(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_warmup_runs_synthetic_input() {
+        let (engine, loads, calls) = echo_engine();
+        let session = SessionBuilder::new()
+            .with_engine(engine)
+            .with_load_mode(LoadMode::EagerWithWarmup)
+            .load_from_bytes(&[0u8; 8])
+            .await
+            .unwrap();
+        
+        assert!(session.is_loaded());
+        assert_eq!(loads.load(Ordering::SeqCst), 1);
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(session.load_time().is_some());
+        assert!(session.warmup_time().is_some());
+    }
+    
+    #[tokio::test]
+    async fn test_session_keeps_optimization_config() {
+        use crate::models::OptimizationLevel;
+        
+        let sample = Tensor::from_f32(vec![1.0; 4], vec![1, 4]).unwrap();
+        let config = OptimizationConfig::new(OptimizationLevel::None)
+            .with_sample_inputs(vec![sample])
+            .with_max_abs_error(0.5);
+        let (engine, _, _) = echo_engine();
+        let session = SessionBuilder::new()
+            .with_engine(engine)
+            .with_optimization(config)
+            .with_load_mode(LoadMode::Lazy)
+            .load_from_bytes(&[0u8; 8])
+            .await
+            .unwrap();
+        
+        // Known before the lazy model loads, and carried over to a replacement
+        assert!(session.optimization_report().is_none());
+        let (engine, _, _) = echo_engine();
+        let replacement = session.with_replaced_model(engine.load_from_bytes(&[]).await.unwrap(), Duration::ZERO);
+        for session in [&session, &replacement] {
+            assert_eq!(session.optimization().sample_inputs.len(), 1);
+            assert!(session.optimization().accuracy_check().is_some());
+        }
+    }
+    
+    /// Model with two inputs that records the names it was given
+    #[derive(Debug)]
+    struct TwoInputModel {
+        specs: Vec<TensorSpec>,
+        seen: Arc<std::sync::Mutex<Vec<String>>>,
+    }
+    
+    #[async_trait]
+    impl Model for TwoInputModel {
+        async fn predict(&self, _input: &Tensor) -> Result<Tensor, InferenceError> {
+            Err(InferenceError::invalid_tensor_data("both inputs are required"))
+        }
+        
+        async fn predict_batch(&self, _inputs: &[Tensor]) -> Result<Vec<Tensor>, InferenceError> {
+            Err(InferenceError::invalid_tensor_data("both inputs are required"))
+        }
+        
+        async fn predict_named(&self, inputs: &[(String, Tensor)]) -> Result<Vec<(String, Tensor)>, InferenceError> {
+            self.seen.lock().unwrap().extend(inputs.iter().map(|(name, _)| name.clone()));
+            Ok(vec![("output".to_string(), inputs[0].1.clone())])
+        }
+        
+        fn input_specs(&self) -> &[TensorSpec] {
+            &self.specs
+        }
+        
+        fn output_specs(&self) -> &[TensorSpec] {
+            &self.specs[..1]
+        }
+        
+        fn engine_type(&self) -> EngineType {
+            EngineType::Candle
+        }
+        
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_warmup_feeds_every_input_spec() {
+        let seen = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let model = TwoInputModel {
+            specs: vec![
+                TensorSpec::new("input_ids".to_string(), vec![Some(1), None], DataType::I64),
+                TensorSpec::new("attention_mask".to_string(), vec![Some(1), None], DataType::I64),
+            ],
+            seen: seen.clone(),
+        };
+        let mut session = Session::new(Box::new(model), EngineType::Candle);
+        
+        session.warm_up().await.unwrap();
+        
+        assert_eq!(*seen.lock().unwrap(), vec!["input_ids".to_string(), "attention_mask".to_string()]);
+        assert_eq!(session.load_mode(), LoadMode::EagerWithWarmup);
+    }
+    
+    #[tokio::test]
+    async fn test_load_failure_falls_back() {
+        let session = SessionBuilder::new()
+            .with_engine(Box::new(FailingEngine { fail_load: true }))
+            .with_fallback_chain(plugin_chain())
+            .load_from_path("model.bin")
+            .await
+            .unwrap();
+        
+        assert_eq!(session.engine_type(), EngineType::Plugin("session_fallback".into()));
+        assert_eq!(session.fallback_reasons().len(), 1);
+        assert!(session.fallback_reasons()[0].contains("unsupported operator"));
+        
+        // Without a chain the load error is returned as is
+        let result = SessionBuilder::new()
+            .with_engine(Box::new(FailingEngine { fail_load: true }))
+            .load_from_path("model.bin")
+            .await;
+        assert!(matches!(result, Err(InferenceError::ModelLoad(_))));
+    }
+    
+    #[tokio::test]
+    async fn test_first_prediction_failure_falls_back() {
+        let session = SessionBuilder::new()
+            .with_engine(Box::new(FailingEngine { fail_load: false }))
+            .with_fallback_chain(plugin_chain())
+            .load_from_path("model.bin")
+            .await
+            .unwrap();
+        let input = Tensor::from_f32(vec![1.0; 4], vec![1, 4]).unwrap();
+        
+        let error = session.predict(&input).await.unwrap_err();
+        assert!(session.should_fall_back(&error));
+        let generation = session.backend_generation();
+        let replacement = session.fall_back(&error).await.unwrap();
+        
+        // The old session handed its chain over to the replacement
+        assert!(!session.should_fall_back(&error));
+        let session = replacement;
+        assert_eq!(session.backend_generation(), generation + 1);
+        assert_eq!(session.engine_type(), EngineType::Plugin("session_fallback".into()));
+        assert!(session.fallback_reasons()[0].contains("out of memory"));
+        assert_eq!(session.predict(&input).await.unwrap().to_f32_vec().unwrap(), vec![1.0; 4]);
+        
+        // The chain is exhausted, and a served session never falls back
+        assert!(!session.should_fall_back(&error));
+    }
+    
+    #[tokio::test]
+    async fn test_warmup_failure_falls_back() {
+        let session = SessionBuilder::new()
+            .with_engine(Box::new(FailingEngine { fail_load: false }))
+            .with_load_mode(LoadMode::EagerWithWarmup)
+            .with_fallback_chain(plugin_chain())
+            .load_from_bytes(&[0u8; 8])
+            .await
+            .unwrap();
+        
+        assert_eq!(session.engine_type(), EngineType::Plugin("session_fallback".into()));
+        assert_eq!(session.fallback_reasons().len(), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_no_fallback_after_successful_prediction() {
+        let (engine, _, _) = echo_engine();
+        let session = SessionBuilder::new()
+            .with_engine(engine)
+            .with_fallback_chain(plugin_chain())
+            .load_from_path("model.bin")
+            .await
+            .unwrap();
+        let input = Tensor::from_f32(vec![1.0; 4], vec![1, 4]).unwrap();
+        
+        session.predict(&input).await.unwrap();
+        assert!(!session.should_fall_back(&InferenceError::gpu("out of memory")));
+        assert!(session.fallback_reasons().is_empty());
+        // The source kept for fallback is released
+        assert!(session.fallback.lock().unwrap().is_none());
+    }
+    
+    #[tokio::test]
+    async fn test_bad_input_does_not_fall_back() {
+        let session = SessionBuilder::new()
+            .with_engine(Box::new(FailingEngine { fail_load: false }))
+            .with_fallback_chain(plugin_chain())
+            .load_from_bytes(&[0u8; 8])
+            .await
+            .unwrap();
+        
+        assert!(!session.should_fall_back(&InferenceError::prediction("shape mismatch in matmul")));
+        assert!(!session.should_fall_back(&InferenceError::invalid_shape(vec![1, 4], vec![1, 3])));
+        assert!(session.should_fall_back(&InferenceError::gpu("no cuda implementation for conv2d")));
+    }
+}
@@ -104,6 +104,12 @@ impl Tensor {
         Self::new(bytes, shape, DataType::I64)
     }
     
+    /// Create a zero-filled tensor of the given shape and type
+    pub fn zeros(shape: Vec<usize>, data_type: DataType) -> Result<Self, InferenceError> {
+        let size = shape.iter().product::<usize>() * data_type.size_bytes();
+        Self::new(vec![0u8; size], shape, data_type)
+    }
+    
     /// Create tensor from ndarray
     pub fn from_ndarray_f32(array: ArrayD<f32>) -> Result<Self, InferenceError> {
         let shape = array.shape().to_vec();
@@ -190,6 +196,34 @@ impl Tensor {
         }
     }
     
+    /// Convert to i64 vector (if compatible)
+    pub fn to_i64_vec(&self) -> Result<Vec<i64>, InferenceError> {
+        match self.data_type {
+            DataType::I64 => {
+                let mut result = Vec::with_capacity(self.len());
+                for chunk in self.data.chunks_exact(8) {
+                    let bytes = [
+                        chunk[0], chunk[1], chunk[2], chunk[3],
+                        chunk[4], chunk[5], chunk[6], chunk[7]
+                    ];
+                    result.push(i64::from_le_bytes(bytes));
+                }
+                Ok(result)
+            }
+            DataType::I32 => {
+                let mut result = Vec::with_capacity(self.len());
+                for chunk in self.data.chunks_exact(4) {
+                    let bytes = [chunk[0], chunk[1], chunk[2], chunk[3]];
+                    result.push(i32::from_le_bytes(bytes) as i64);
+                }
+                Ok(result)
+            }
+            _ => Err(InferenceError::invalid_tensor_data(format!(
+                "Cannot convert {:?} to i64 vector", self.data_type
+            )))
+        }
+    }
+    
     /// Convert to ndarray
     pub fn to_ndarray_f32(&self) -> Result<ArrayD<f32>, InferenceError> {
         let data = self.to_f32_vec()?;
@@ -362,6 +396,18 @@ impl TensorSpec {
         }
         Some(result)
     }
+    
+    /// Get a concrete shape, substituting 1 for every dynamic dimension
+    pub fn concrete_shape(&self) -> Vec<usize> {
+        self.shape.iter().map(|dim| dim.unwrap_or(1)).collect()
+    }
+    
+    /// Create a zero-filled tensor that satisfies this specification
+    /// 
+    /// Useful for warming up a model before real inputs are available.
+    pub fn synthetic_tensor(&self) -> Result<Tensor, InferenceError> {
+        Tensor::zeros(self.concrete_shape(), self.data_type.clone())
+    }
 }
 
 /// Type alias for TensorSpec to match BRD naming convention
@@ -0,0 +1,362 @@
+use crate::models::{InferenceError, MobileNetVariant, ModelArchitecture, ResNetVariant};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::Read;
+use std::path::Path;
+
+/// Largest SafeTensors header read when listing tensor names
+const MAX_HEADER_LEN: u64 = 100 * 1024 * 1024;
+
+/// An architecture guess and how sure the detector is about it
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArchitectureDetection {
+    /// Detected architecture
+    pub architecture: ModelArchitecture,
+    /// Confidence between 0 and 1
+    pub confidence: f32,
+    /// What the guess is based on, for logs and error messages
+    pub evidence: String,
+}
+
+impl ArchitectureDetection {
+    fn new(architecture: ModelArchitecture, confidence: f32, evidence: impl Into<String>) -> Self {
+        Self {
+            architecture,
+            confidence,
+            evidence: evidence.into(),
+        }
+    }
+}
+
+/// Detects which supported architecture a checkpoint belongs to
+pub struct ArchitectureDetector;
+
+impl ArchitectureDetector {
+    /// Detect the architecture from a HuggingFace `config.json`
+    /// 
+    /// `architectures` is trusted most, then `model_type`, then timm's
+    /// `architecture` name.
+    pub fn detect_from_config(config: &Value) -> Result<ArchitectureDetection, InferenceError> {
+        let architectures: Vec<&str> = config.get("architectures")
+            .and_then(|v| v.as_array())
+            .map(|list| list.iter().filter_map(|v| v.as_str()).collect())
+            .unwrap_or_default();
+        for class in &architectures {
+            if let Some(architecture) = Self::from_class_name(class, config) {
+                return Ok(ArchitectureDetection::new(architecture, 0.95, format!("config.json architectures: {}", class)));
+            }
+        }
+        
+        let model_type = config.get("model_type").and_then(|v| v.as_str());
+        if let Some((model_type, architecture)) = model_type.and_then(|t| Some((t, Self::from_model_type(t, config)?))) {
+            return Ok(ArchitectureDetection::new(architecture, 0.9, format!("config.json model_type: {}", model_type)));
+        }
+        
+        let timm_name = config.get("architecture").and_then(|v| v.as_str());
+        if let Some((timm_name, architecture)) = timm_name.and_then(|n| Some((n, Self::from_timm_name(n)?))) {
+            return Ok(ArchitectureDetection::new(architecture, 0.9, format!("timm architecture: {}", timm_name)));
+        }
+        
+        Err(InferenceError::unsupported_format(format!(
+            "Unknown model architecture in config.json (architectures: {:?}, model_type: {:?}) - \
+             supported are BERT, ResNet, MobileNet, Llama, Mistral and Whisper",
+            architectures, model_type
+        )))
+    }
+    
+    /// Detect the architecture from the tensor names of a bare checkpoint
+    /// 
+    /// Llama and Mistral share their weight names, so either is reported with
+    /// low confidence.
+    pub fn detect_from_tensor_names<'a>(names: impl IntoIterator<Item = &'a str>) -> Result<ArchitectureDetection, InferenceError> {
+        let names: BTreeSet<&str> = names.into_iter().collect();
+        let has = |pattern: &str| names.iter().any(|name| name.contains(pattern));
+        
+        if has("encoder.conv1.weight") && has("decoder.embed_tokens.weight") {
+            return Ok(ArchitectureDetection::new(ModelArchitecture::Whisper, 0.85, "Whisper encoder and decoder tensors"));
+        }
+        if has("embeddings.word_embeddings.weight") && has("attention.self.query.weight") {
+            return Ok(ArchitectureDetection::new(ModelArchitecture::Bert, 0.8, "BERT embedding and self-attention tensors"));
+        }
+        if has("layers.0.self_attn.q_proj.weight") && has("layers.0.mlp.gate_proj.weight") {
+            return Ok(ArchitectureDetection::new(
+                ModelArchitecture::Llama,
+                0.5,
+                "Llama-style decoder tensors (Mistral uses the same names)",
+            ));
+        }
+        if has("mobilenet_v1.conv_stem.") {
+            return Ok(ArchitectureDetection::new(ModelArchitecture::MobileNet { variant: MobileNetVariant::V1 }, 0.85, "MobileNetV1 tensors"));
+        }
+        if has("mobilenet_v2.conv_stem.") {
+            return Ok(ArchitectureDetection::new(ModelArchitecture::MobileNet { variant: MobileNetVariant::V2 }, 0.85, "MobileNetV2 tensors"));
+        }
+        if names.contains("conv_stem.weight") && names.contains("conv_head.weight") {
+            // timm MobileNetV3: small has six block stages, large has seven
+            let variant = if has("blocks.6.") { MobileNetVariant::V3Large } else { MobileNetVariant::V3Small };
+            return Ok(ArchitectureDetection::new(ModelArchitecture::MobileNet { variant }, 0.75, "timm MobileNetV3 tensors"));
+        }
+        if let Some(detection) = Self::resnet_from_tensor_names(&names) {
+            return Ok(detection);
+        }
+        
+        Err(InferenceError::unsupported_format(format!(
+            "Could not recognise the architecture from {} tensor names (e.g. {:?})",
+            names.len(),
+            names.iter().take(3).collect::<Vec<_>>()
+        )))
+    }
+    
+    /// Detect the architecture of a SafeTensors file from its header alone
+    pub fn detect_from_safetensors_file(path: &Path) -> Result<ArchitectureDetection, InferenceError> {
+        let names = Self::safetensors_tensor_names(path)?;
+        Self::detect_from_tensor_names(names.iter().map(|s| s.as_str()))
+    }
+    
+    /// Read the tensor names of a SafeTensors file without reading the weights
+    pub fn safetensors_tensor_names(path: &Path) -> Result<Vec<String>, InferenceError> {
+        let mut file = fs::File::open(path)
+            .map_err(|e| InferenceError::model_load(format!("Failed to open {}: {}", path.display(), e)))?;
+        let mut len = [0u8; 8];
+        file.read_exact(&mut len)
+            .map_err(|e| InferenceError::model_load(format!("Failed to read SafeTensors header: {}", e)))?;
+        let header_len = u64::from_le_bytes(len);
+        if header_len > MAX_HEADER_LEN {
+            return Err(InferenceError::model_load(format!("SafeTensors header of {} bytes is too large", header_len)));
+        }
+        
+        let mut header = vec![0u8; header_len as usize];
+        file.read_exact(&mut header)
+            .map_err(|e| InferenceError::model_load(format!("Failed to read SafeTensors header: {}", e)))?;
+        let header: serde_json::Map<String, Value> = serde_json::from_slice(&header)
+            .map_err(|e| InferenceError::model_load(format!("Failed to parse SafeTensors header: {}", e)))?;
+        
+        Ok(header.into_iter()
+            .map(|(name, _)| name)
+            .filter(|name| name != "__metadata__")
+            .collect())
+    }
+    
+    /// Map a transformers model class such as `BertForMaskedLM`
+    fn from_class_name(class: &str, config: &Value) -> Option<ModelArchitecture> {
+        let architecture = if class.starts_with("Bert") {
+            ModelArchitecture::Bert
+        } else if class.starts_with("Llama") {
+            ModelArchitecture::Llama
+        } else if class.starts_with("Mistral") {
+            ModelArchitecture::Mistral
+        } else if class.starts_with("Whisper") {
+            ModelArchitecture::Whisper
+        } else if class.starts_with("ResNet") {
+            ModelArchitecture::ResNet { variant: Self::resnet_variant_from_config(config) }
+        } else if class.starts_with("MobileNetV1") {
+            ModelArchitecture::MobileNet { variant: MobileNetVariant::V1 }
+        } else if class.starts_with("MobileNetV2") {
+            ModelArchitecture::MobileNet { variant: MobileNetVariant::V2 }
+        } else {
+            return None;
+        };
+        Some(architecture)
+    }
+    
+    /// Map a transformers `model_type`
+    fn from_model_type(model_type: &str, config: &Value) -> Option<ModelArchitecture> {
+        Some(match model_type {
+            "bert" => ModelArchitecture::Bert,
+            "llama" => ModelArchitecture::Llama,
+            "mistral" => ModelArchitecture::Mistral,
+            "whisper" => ModelArchitecture::Whisper,
+            "resnet" => ModelArchitecture::ResNet { variant: Self::resnet_variant_from_config(config) },
+            "mobilenet_v1" => ModelArchitecture::MobileNet { variant: MobileNetVariant::V1 },
+            "mobilenet_v2" => ModelArchitecture::MobileNet { variant: MobileNetVariant::V2 },
+            _ => return None,
+        })
+    }
+    
+    /// Map a timm model name such as `resnet50.a1_in1k` or `mobilenetv3_large_100`
+    fn from_timm_name(name: &str) -> Option<ModelArchitecture> {
+        let name = name.split('.').next().unwrap_or(name);
+        let resnet = |variant| Some(ModelArchitecture::ResNet { variant });
+        let mobilenet = |variant| Some(ModelArchitecture::MobileNet { variant });
+        match name {
+            "resnet18" => resnet(ResNetVariant::ResNet18),
+            "resnet34" => resnet(ResNetVariant::ResNet34),
+            "resnet50" => resnet(ResNetVariant::ResNet50),
+            "resnet101" => resnet(ResNetVariant::ResNet101),
+            "resnet152" => resnet(ResNetVariant::ResNet152),
+            _ if name.starts_with("mobilenetv3_small") => mobilenet(MobileNetVariant::V3Small),
+            _ if name.starts_with("mobilenetv3_large") => mobilenet(MobileNetVariant::V3Large),
+            _ if name.starts_with("mobilenetv2") => mobilenet(MobileNetVariant::V2),
+            _ if name.starts_with("mobilenetv1") => mobilenet(MobileNetVariant::V1),
+            _ => None,
+        }
+    }
+    
+    /// ResNet depth from a transformers config's `depths` and `layer_type`
+    fn resnet_variant_from_config(config: &Value) -> ResNetVariant {
+        let depths: Vec<usize> = config.get("depths")
+            .and_then(|v| v.as_array())
+            .map(|list| list.iter().filter_map(|v| v.as_u64()).map(|d| d as usize).collect())
+            .unwrap_or_default();
+        let bottleneck = config.get("layer_type").and_then(|v| v.as_str()) != Some("basic");
+        Self::resnet_variant(&depths, bottleneck).unwrap_or(ResNetVariant::ResNet50)
+    }
+    
+    /// ResNet variant for per-stage block counts
+    fn resnet_variant(depths: &[usize], bottleneck: bool) -> Option<ResNetVariant> {
+        Some(match (depths, bottleneck) {
+            ([2, 2, 2, 2], false) => ResNetVariant::ResNet18,
+            ([3, 4, 6, 3], false) => ResNetVariant::ResNet34,
+            ([3, 4, 6, 3], true) => ResNetVariant::ResNet50,
+            ([3, 4, 23, 3], true) => ResNetVariant::ResNet101,
+            ([3, 8, 36, 3], true) => ResNetVariant::ResNet152,
+            _ => return None,
+        })
+    }
+    
+    /// Detect a transformers or timm ResNet by counting blocks per stage
+    fn resnet_from_tensor_names(names: &BTreeSet<&str>) -> Option<ArchitectureDetection> {
+        // Stage index to the set of block indices seen in it
+        let mut stages: BTreeMap<usize, BTreeSet<usize>> = BTreeMap::new();
+        let mut bottleneck = false;
+        for name in names {
+            let name = name.strip_prefix("resnet.").unwrap_or(name);
+            let (stage, block, rest) = if let Some(rest) = name.strip_prefix("encoder.stages.") {
+                let mut parts = rest.splitn(4, '.');
+                let stage = parts.next().and_then(|s| s.parse::<usize>().ok());
+                let _ = parts.next();
+                let block = parts.next().and_then(|b| b.parse::<usize>().ok());
+                let rest = parts.next().unwrap_or_default();
+                bottleneck |= rest.starts_with("layer.2.");
+                (stage, block, rest)
+            } else if let Some(rest) = name.strip_prefix("layer") {
+                let mut parts = rest.splitn(3, '.');
+                let stage = parts.next().and_then(|s| s.parse::<usize>().ok()).map(|s| s.saturating_sub(1));
+                let block = parts.next().and_then(|b| b.parse::<usize>().ok());
+                let rest = parts.next().unwrap_or_default();
+                bottleneck |= rest.starts_with("conv3.");
+                (stage, block, rest)
+            } else {
+                continue;
+            };
+            if let (Some(stage), Some(block), false) = (stage, block, rest.is_empty()) {
+                stages.entry(stage).or_default().insert(block);
+            }
+        }
+        
+        let depths: Vec<usize> = stages.values().map(|blocks| blocks.len()).collect();
+        if depths.len() != 4 {
+            return None;
+        }
+        match Self::resnet_variant(&depths, bottleneck) {
+            Some(variant) => Some(ArchitectureDetection::new(
+                ModelArchitecture::ResNet { variant },
+                0.8,
+                format!("ResNet stages with {:?} blocks", depths),
+            )),
+            None => Some(ArchitectureDetection::new(
+                ModelArchitecture::ResNet { variant: ResNetVariant::ResNet50 },
+                0.4,
+                format!("ResNet-like stages with unrecognised depths {:?}", depths),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn test_detect_from_config() {
+        let detection = ArchitectureDetector::detect_from_config(&json!({
+            "architectures": ["BertForMaskedLM"],
+            "model_type": "bert"
+        })).unwrap();
+        assert_eq!(detection.architecture, ModelArchitecture::Bert);
+        assert_eq!(detection.confidence, 0.95);
+        
+        let detection = ArchitectureDetector::detect_from_config(&json!({"model_type": "mistral"})).unwrap();
+        assert_eq!(detection.architecture, ModelArchitecture::Mistral);
+        
+        let detection = ArchitectureDetector::detect_from_config(&json!({
+            "architectures": ["ResNetForImageClassification"],
+            "depths": [3, 4, 6, 3],
+            "layer_type": "basic"
+        })).unwrap();
+        assert_eq!(detection.architecture, ModelArchitecture::ResNet { variant: ResNetVariant::ResNet34 });
+        
+        let detection = ArchitectureDetector::detect_from_config(&json!({"architecture": "mobilenetv3_large_100"})).unwrap();
+        assert_eq!(detection.architecture, ModelArchitecture::MobileNet { variant: MobileNetVariant::V3Large });
+        
+        let err = ArchitectureDetector::detect_from_config(&json!({
+            "architectures": ["GPT2LMHeadModel"],
+            "model_type": "gpt2"
+        })).unwrap_err();
+        assert!(err.to_string().contains("GPT2LMHeadModel"));
+        assert!(err.to_string().contains("\"gpt2\""));
+    }
+    
+    #[test]
+    fn test_detect_from_tensor_names() {
+        let detect = |names: &[&str]| ArchitectureDetector::detect_from_tensor_names(names.iter().copied());
+        
+        let bert = detect(&["bert.embeddings.word_embeddings.weight", "bert.encoder.layer.0.attention.self.query.weight"]).unwrap();
+        assert_eq!(bert.architecture, ModelArchitecture::Bert);
+        
+        let llama = detect(&["model.layers.0.self_attn.q_proj.weight", "model.layers.0.mlp.gate_proj.weight"]).unwrap();
+        assert_eq!(llama.architecture, ModelArchitecture::Llama);
+        assert!(llama.confidence < 0.6);
+        
+        let whisper = detect(&["model.encoder.conv1.weight", "model.decoder.embed_tokens.weight"]).unwrap();
+        assert_eq!(whisper.architecture, ModelArchitecture::Whisper);
+        
+        let small = detect(&["conv_stem.weight", "blocks.5.0.conv.weight", "conv_head.weight"]).unwrap();
+        assert_eq!(small.architecture, ModelArchitecture::MobileNet { variant: MobileNetVariant::V3Small });
+        
+        assert!(detect(&["fc1.weight", "fc2.weight"]).is_err());
+    }
+    
+    #[test]
+    fn test_detect_resnet_depth_from_tensor_names() {
+        // timm layout, basic blocks [2, 2, 2, 2]
+        let mut names = vec!["conv1.weight".to_string(), "fc.weight".to_string()];
+        for stage in 1..=4 {
+            for block in 0..2 {
+                names.push(format!("layer{}.{}.conv1.weight", stage, block));
+                names.push(format!("layer{}.{}.conv2.weight", stage, block));
+            }
+        }
+        let detection = ArchitectureDetector::detect_from_tensor_names(names.iter().map(|s| s.as_str())).unwrap();
+        assert_eq!(detection.architecture, ModelArchitecture::ResNet { variant: ResNetVariant::ResNet18 });
+        
+        // transformers layout, bottleneck blocks [3, 4, 6, 3]
+        let mut names = Vec::new();
+        for (stage, depth) in [3, 4, 6, 3].iter().enumerate() {
+            for block in 0..*depth {
+                for layer in 0..3 {
+                    names.push(format!("resnet.encoder.stages.{}.layers.{}.layer.{}.convolution.weight", stage, block, layer));
+                }
+            }
+        }
+        let detection = ArchitectureDetector::detect_from_tensor_names(names.iter().map(|s| s.as_str())).unwrap();
+        assert_eq!(detection.architecture, ModelArchitecture::ResNet { variant: ResNetVariant::ResNet50 });
+    }
+    
+    #[test]
+    fn test_safetensors_tensor_names() {
+        let path = std::env::temp_dir().join(format!("inference_arch_{}.safetensors", std::process::id()));
+        let header = br#"{"__metadata__":{"format":"pt"},"a.weight":{"dtype":"F32","shape":[1],"data_offsets":[0,4]}}"#;
+        let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(header);
+        bytes.extend_from_slice(&[0u8; 4]);
+        std::fs::write(&path, &bytes).unwrap();
+        
+        assert_eq!(ArchitectureDetector::safetensors_tensor_names(&path).unwrap(), vec!["a.weight".to_string()]);
+        assert!(ArchitectureDetector::detect_from_safetensors_file(&path).is_err());
+        
+        std::fs::remove_file(&path).unwrap();
+    }
+}
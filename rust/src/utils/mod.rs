@@ -1,5 +1,7 @@
 pub mod model_detector;
+pub mod architecture_detector;
 pub mod converters;
 
 pub use model_detector::{ModelDetector, detect_engine, detect_format};
+pub use architecture_detector::{ArchitectureDetection, ArchitectureDetector};
 pub use converters::*; 
\ No newline at end of file
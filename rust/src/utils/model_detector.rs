@@ -1,7 +1,13 @@
-use crate::engines::{EngineType, ModelFormat};
+use crate::engines::{EngineType, ModelFormat, EngineRegistry};
 use crate::models::InferenceError;
 use std::path::Path;
 use std::fs;
+use std::io::Read;
+
+/// Number of leading bytes read when detecting a file's format from content
+/// 
+/// This is also what plugin sniffing hooks get to see.
+const HEADER_LEN: usize = 64;
 
 /// Model detector for automatic engine selection
 pub struct ModelDetector;
@@ -16,8 +22,13 @@ impl ModelDetector {
             match ext.to_lowercase().as_str() {
                 "safetensors" => return EngineType::Candle,
                 "pt" | "pth" => return EngineType::Candle,
-
-                _ => {}
+                "gguf" => return EngineType::Candle,
+                "onnx" => return EngineType::Onnx,
+                ext => {
+                    if let Some(name) = EngineRegistry::find_by_extension(ext) {
+                        return EngineType::Plugin(name);
+                    }
+                }
             }
         }
         
@@ -31,11 +42,14 @@ impl ModelDetector {
     }
     
     /// Detect engine type from file content
+    /// 
+    /// Only the first bytes of the file are read.
     pub fn detect_engine_from_content(path: &Path) -> Result<EngineType, InferenceError> {
-        let bytes = fs::read(path)
-            .map_err(|e| InferenceError::format_detection(format!("Failed to read file: {}", e)))?;
-        
-        Self::detect_engine_from_bytes(&bytes)
+        match Self::detect_format_from_content(path)? {
+            ModelFormat::Onnx => Ok(EngineType::Onnx),
+            ModelFormat::Plugin(name) => Ok(EngineType::Plugin(name)),
+            _ => Ok(EngineType::Candle),
+        }
     }
     
     /// Detect engine type from raw bytes
@@ -50,6 +64,21 @@ impl ModelDetector {
             return Ok(EngineType::Candle);
         }
         
+        // Check for GGUF format
+        if Self::is_gguf_format(bytes) {
+            return Ok(EngineType::Candle);
+        }
+        
+        // Check for ONNX format
+        if Self::is_onnx_format(bytes) {
+            return Ok(EngineType::Onnx);
+        }
+        
+        // Check plugin formats last so built-in formats always win
+        if let Some(name) = EngineRegistry::find_by_content(bytes) {
+            return Ok(EngineType::Plugin(name));
+        }
+        
         Err(InferenceError::format_detection(
             "Could not detect model format from content".to_string()
         ))
@@ -63,8 +92,13 @@ impl ModelDetector {
             match ext.to_lowercase().as_str() {
                 "safetensors" => return Ok(ModelFormat::SafeTensors),
                 "pt" | "pth" => return Ok(ModelFormat::PyTorch),
-
-                _ => {}
+                "gguf" => return Ok(ModelFormat::Gguf),
+                "onnx" => return Ok(ModelFormat::Onnx),
+                ext => {
+                    if let Some(name) = EngineRegistry::find_by_extension(ext) {
+                        return Ok(ModelFormat::Plugin(name));
+                    }
+                }
             }
         }
         
@@ -73,11 +107,24 @@ impl ModelDetector {
     }
     
     /// Detect model format from file content
+    /// 
+    /// Only the first bytes of the file are read, so large models are not
+    /// pulled into memory just to find their format.
     pub fn detect_format_from_content(path: &Path) -> Result<ModelFormat, InferenceError> {
-        let bytes = fs::read(path)
+        let mut file = fs::File::open(path)
+            .map_err(|e| InferenceError::format_detection(format!("Failed to read file: {}", e)))?;
+        let file_len = file.metadata()
+            .map_err(|e| InferenceError::format_detection(format!("Failed to read file: {}", e)))?
+            .len();
+        
+        let mut header = Vec::with_capacity(HEADER_LEN);
+        file.by_ref().take(HEADER_LEN as u64).read_to_end(&mut header)
             .map_err(|e| InferenceError::format_detection(format!("Failed to read file: {}", e)))?;
         
-        Self::detect_format_from_bytes(&bytes)
+        if Self::is_safetensors_header(&header, file_len) {
+            return Ok(ModelFormat::SafeTensors);
+        }
+        Self::detect_format_from_bytes(&header)
     }
     
     /// Detect model format from raw bytes
@@ -90,6 +137,18 @@ impl ModelDetector {
             return Ok(ModelFormat::PyTorch);
         }
         
+        if Self::is_gguf_format(bytes) {
+            return Ok(ModelFormat::Gguf);
+        }
+        
+        if Self::is_onnx_format(bytes) {
+            return Ok(ModelFormat::Onnx);
+        }
+        
+        if let Some(name) = EngineRegistry::find_by_content(bytes) {
+            return Ok(ModelFormat::Plugin(name));
+        }
+        
         Err(InferenceError::format_detection(
             "Could not detect model format from content".to_string()
         ))
@@ -99,7 +158,12 @@ impl ModelDetector {
     
     /// Check if bytes represent SafeTensors format
     fn is_safetensors_format(bytes: &[u8]) -> bool {
-        if bytes.len() < 8 {
+        Self::is_safetensors_header(bytes, bytes.len() as u64)
+    }
+    
+    /// Check if the start of a `total_len` byte file is a SafeTensors header
+    fn is_safetensors_header(bytes: &[u8], total_len: u64) -> bool {
+        if bytes.len() < 8 || total_len < 8 {
             return false;
         }
         
@@ -111,7 +175,7 @@ impl ModelDetector {
         ]);
         
         // Sanity check: JSON length should be reasonable
-        if json_len > (bytes.len() - 8) as u64 || json_len < 1 {
+        if json_len > total_len - 8 || json_len < 1 {
             return false;
         }
         
@@ -141,6 +205,67 @@ impl ModelDetector {
         bytes.starts_with(&[0x50, 0x4B]) || // ZIP signature
         bytes.starts_with(b"PK") // ZIP signature (alternative)
     }
+    
+    /// Check if bytes represent GGUF format
+    fn is_gguf_format(bytes: &[u8]) -> bool {
+        // GGUF files start with the ASCII magic "GGUF" followed by a u32 version
+        bytes.starts_with(b"GGUF")
+    }
+    
+    /// Check if bytes represent an ONNX protobuf (ModelProto)
+    /// 
+    /// Walks the top-level protobuf fields: the bytes must start with
+    /// `ir_version` and every following field must be a ModelProto field with
+    /// the matching wire type. Fields may run past the end of the bytes, since
+    /// usually only the file header is available.
+    pub(crate) fn is_onnx_format(bytes: &[u8]) -> bool {
+        let mut pos = 0;
+        let mut fields = 0;
+        
+        while pos < bytes.len() {
+            let Some(key) = read_varint(bytes, &mut pos) else { break };
+            let (field, wire_type) = (key >> 3, key & 0x7);
+            let valid = match field {
+                // ir_version comes first
+                1 => fields == 0 && wire_type == 0,
+                // model_version
+                5 => fields > 0 && wire_type == 0,
+                // producer_name, producer_version, domain, doc_string, graph,
+                // opset_import, metadata_props, training_info, functions
+                2 | 3 | 4 | 6 | 7 | 8 | 14 | 20 | 25 => fields > 0 && wire_type == 2,
+                _ => false,
+            };
+            if !valid {
+                return false;
+            }
+            fields += 1;
+            
+            let Some(value) = read_varint(bytes, &mut pos) else { break };
+            if field == 1 && !(1..=0x20).contains(&value) {
+                return false;
+            }
+            if wire_type == 2 {
+                pos = pos.saturating_add(value as usize);
+            }
+        }
+        
+        // ir_version alone is too weak a signal
+        fields >= 2
+    }
+}
+
+/// Read a protobuf varint, returning `None` if it is truncated or too long
+fn read_varint(bytes: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value = 0u64;
+    for shift in (0..64).step_by(7) {
+        let byte = *bytes.get(*pos)?;
+        *pos += 1;
+        value |= u64::from(byte & 0x7f) << shift;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
 }
 
 /// Convenience function for detecting engine type from path
@@ -162,6 +287,7 @@ mod tests {
         assert_eq!(detect_engine("model.safetensors"), EngineType::Candle);
         assert_eq!(detect_engine("model.pt"), EngineType::Candle);
         assert_eq!(detect_engine("model.pth"), EngineType::Candle);
+        assert_eq!(detect_engine("model.onnx"), EngineType::Onnx);
         assert_eq!(detect_engine("model.unknown"), EngineType::Candle); // fallback
     }
     
@@ -170,6 +296,8 @@ mod tests {
         assert_eq!(detect_format("model.safetensors").unwrap(), ModelFormat::SafeTensors);
         assert_eq!(detect_format("model.pt").unwrap(), ModelFormat::PyTorch);
         assert_eq!(detect_format("model.pth").unwrap(), ModelFormat::PyTorch);
+        assert_eq!(detect_format("model.gguf").unwrap(), ModelFormat::Gguf);
+        assert_eq!(detect_format("model.onnx").unwrap(), ModelFormat::Onnx);
     }
     
     
@@ -195,4 +323,59 @@ mod tests {
         let not_pytorch = vec![0x08, 0x01, 0x12, 0x04];
         assert!(!ModelDetector::is_pytorch_format(&not_pytorch));
     }
+    
+    #[test]
+    fn test_gguf_format_detection() {
+        let gguf_bytes = b"GGUF\x03\x00\x00\x00";
+        assert!(ModelDetector::is_gguf_format(gguf_bytes));
+        assert_eq!(ModelDetector::detect_format_from_bytes(gguf_bytes).unwrap(), ModelFormat::Gguf);
+        
+        assert!(!ModelDetector::is_gguf_format(b"GGML"));
+    }
+    
+    #[test]
+    fn test_onnx_format_detection() {
+        let onnx_bytes = vec![0x08, 0x07, 0x12, 0x07];
+        assert!(ModelDetector::is_onnx_format(&onnx_bytes));
+        assert_eq!(ModelDetector::detect_engine_from_bytes(&onnx_bytes).unwrap(), EngineType::Onnx);
+        
+        let not_onnx = vec![0x80, 0x02, 0x12, 0x04];
+        assert!(!ModelDetector::is_onnx_format(&not_onnx));
+    }
+    
+    #[test]
+    fn test_onnx_header_walks_model_proto_fields() {
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/tiny_onnx/model.onnx");
+        let bytes = fs::read(fixture).unwrap();
+        assert!(ModelDetector::is_onnx_format(&bytes));
+        assert!(ModelDetector::is_onnx_format(&bytes[..HEADER_LEN]));
+        
+        // ir_version alone, a repeated ir_version, an unknown field and a wrong wire type
+        assert!(!ModelDetector::is_onnx_format(&[0x08, 0x07]));
+        assert!(!ModelDetector::is_onnx_format(&[0x08, 0x07, 0x08, 0x07]));
+        assert!(!ModelDetector::is_onnx_format(&[0x08, 0x07, 0x4a, 0x01, 0x00]));
+        assert!(!ModelDetector::is_onnx_format(&[0x08, 0x07, 0x10, 0x01]));
+    }
+    
+    #[test]
+    fn test_detect_format_from_file_header() {
+        let path = std::env::temp_dir().join(format!("inference_detect_{}.bin", std::process::id()));
+        
+        // The header is longer than what is read, so only the file length can vouch for it
+        let header = br#"{"__metadata__":{"format":"pt"}}"#;
+        let mut bytes = (header.len() as u64).to_le_bytes().to_vec();
+        bytes.extend_from_slice(header);
+        std::fs::write(&path, &bytes).unwrap();
+        assert_eq!(ModelDetector::detect_format_from_content(&path).unwrap(), ModelFormat::SafeTensors);
+        assert_eq!(ModelDetector::detect_engine_from_content(&path).unwrap(), EngineType::Candle);
+        
+        // A header length past the end of the file is not SafeTensors
+        std::fs::write(&path, &bytes[..bytes.len() - 1]).unwrap();
+        assert!(ModelDetector::detect_format_from_content(&path).is_err());
+        
+        std::fs::write(&path, [0x08, 0x07, 0x12, 0x07]).unwrap();
+        assert_eq!(ModelDetector::detect_engine_from_content(&path).unwrap(), EngineType::Onnx);
+        
+        std::fs::remove_file(&path).unwrap();
+    }
 } 
\ No newline at end of file
@@ -0,0 +1,259 @@
+"""Generate the tiny BERT fixture and its reference outputs.
+
+Weights come from a fixed LCG so the checkpoint is reproducible without
+torch or numpy.
+
+The committed reference.json must come from the --transformers path: the
+outputs of transformers.BertModel run in float64 on the written checkpoint,
+so the Rust test checks candle against an independent implementation. This
+needs torch and transformers installed.
+
+Without --transformers the outputs come from the float64 forward pass below
+(erf GELU, additive key mask, tanh pooler over the [CLS] token). It is only a
+stand-in for machines without torch; the "reference" key in reference.json
+records which path produced the file.
+
+Run from this directory: python3 generate.py --transformers
+"""
+import json
+import math
+import struct
+import sys
+
+CONFIG = {
+    "architectures": ["BertModel"],
+    "model_type": "bert",
+    "vocab_size": 16,
+    "hidden_size": 8,
+    "num_hidden_layers": 2,
+    "num_attention_heads": 2,
+    "intermediate_size": 16,
+    "hidden_act": "gelu",
+    "hidden_dropout_prob": 0.0,
+    "attention_probs_dropout_prob": 0.0,
+    "max_position_embeddings": 16,
+    "type_vocab_size": 2,
+    "initializer_range": 0.02,
+    "layer_norm_eps": 1e-12,
+    "pad_token_id": 0,
+    "position_embedding_type": "absolute",
+    "use_cache": True,
+    "classifier_dropout": None,
+}
+
+INPUT_IDS = [[2, 5, 7, 9, 3, 0], [2, 11, 4, 3, 0, 0]]
+ATTENTION_MASK = [[1, 1, 1, 1, 1, 0], [1, 1, 1, 1, 0, 0]]
+TOKEN_TYPE_IDS = [[0, 0, 0, 1, 1, 0], [0, 0, 1, 1, 0, 0]]
+
+_state = 12345
+
+
+def rand(scale):
+    global _state
+    _state = (_state * 1103515245 + 12345) % (1 << 31)
+    return (_state / (1 << 31) - 0.5) * 2.0 * scale
+
+
+def f32(x):
+    return struct.unpack("<f", struct.pack("<f", x))[0]
+
+
+def matrix(rows, cols, scale):
+    return [[f32(rand(scale)) for _ in range(cols)] for _ in range(rows)]
+
+
+def vector(n, scale, offset=0.0):
+    return [f32(offset + rand(scale)) for _ in range(n)]
+
+
+def build_weights(cfg):
+    h, i = cfg["hidden_size"], cfg["intermediate_size"]
+    w = {}
+    w["bert.embeddings.word_embeddings.weight"] = matrix(cfg["vocab_size"], h, 1.0)
+    w["bert.embeddings.position_embeddings.weight"] = matrix(cfg["max_position_embeddings"], h, 0.5)
+    w["bert.embeddings.token_type_embeddings.weight"] = matrix(cfg["type_vocab_size"], h, 0.5)
+    w["bert.embeddings.LayerNorm.weight"] = vector(h, 0.2, 1.0)
+    w["bert.embeddings.LayerNorm.bias"] = vector(h, 0.1)
+    for layer in range(cfg["num_hidden_layers"]):
+        p = "bert.encoder.layer.%d." % layer
+        for name in ("query", "key", "value"):
+            w[p + "attention.self.%s.weight" % name] = matrix(h, h, 0.5)
+            w[p + "attention.self.%s.bias" % name] = vector(h, 0.1)
+        w[p + "attention.output.dense.weight"] = matrix(h, h, 0.5)
+        w[p + "attention.output.dense.bias"] = vector(h, 0.1)
+        w[p + "attention.output.LayerNorm.weight"] = vector(h, 0.2, 1.0)
+        w[p + "attention.output.LayerNorm.bias"] = vector(h, 0.1)
+        w[p + "intermediate.dense.weight"] = matrix(i, h, 0.5)
+        w[p + "intermediate.dense.bias"] = vector(i, 0.1)
+        w[p + "output.dense.weight"] = matrix(h, i, 0.5)
+        w[p + "output.dense.bias"] = vector(h, 0.1)
+        w[p + "output.LayerNorm.weight"] = vector(h, 0.2, 1.0)
+        w[p + "output.LayerNorm.bias"] = vector(h, 0.1)
+    w["bert.pooler.dense.weight"] = matrix(h, h, 0.5)
+    w["bert.pooler.dense.bias"] = vector(h, 0.1)
+    return w
+
+
+def linear(x, weight, bias):
+    return [sum(wi * xi for wi, xi in zip(row, x)) + b for row, b in zip(weight, bias)]
+
+
+def layer_norm(x, weight, bias, eps):
+    mean = sum(x) / len(x)
+    var = sum((v - mean) ** 2 for v in x) / len(x)
+    return [(v - mean) / math.sqrt(var + eps) * g + b for v, g, b in zip(x, weight, bias)]
+
+
+def gelu(x):
+    return 0.5 * x * (1.0 + math.erf(x / math.sqrt(2.0)))
+
+
+def softmax(xs):
+    m = max(xs)
+    e = [math.exp(v - m) for v in xs]
+    s = sum(e)
+    return [v / s for v in e]
+
+
+def forward(cfg, w, ids, mask, types):
+    h = cfg["hidden_size"]
+    heads = cfg["num_attention_heads"]
+    d = h // heads
+    eps = cfg["layer_norm_eps"]
+    seq = len(ids)
+    x = []
+    for pos in range(seq):
+        e = [
+            a + b + c
+            for a, b, c in zip(
+                w["bert.embeddings.word_embeddings.weight"][ids[pos]],
+                w["bert.embeddings.token_type_embeddings.weight"][types[pos]],
+                w["bert.embeddings.position_embeddings.weight"][pos],
+            )
+        ]
+        x.append(layer_norm(e, w["bert.embeddings.LayerNorm.weight"], w["bert.embeddings.LayerNorm.bias"], eps))
+    for layer in range(cfg["num_hidden_layers"]):
+        p = "bert.encoder.layer.%d." % layer
+        q = [linear(t, w[p + "attention.self.query.weight"], w[p + "attention.self.query.bias"]) for t in x]
+        k = [linear(t, w[p + "attention.self.key.weight"], w[p + "attention.self.key.bias"]) for t in x]
+        v = [linear(t, w[p + "attention.self.value.weight"], w[p + "attention.self.value.bias"]) for t in x]
+        context = [[0.0] * h for _ in range(seq)]
+        for head in range(heads):
+            lo, hi = head * d, (head + 1) * d
+            for i in range(seq):
+                scores = []
+                for j in range(seq):
+                    if mask[j]:
+                        scores.append(sum(a * b for a, b in zip(q[i][lo:hi], k[j][lo:hi])) / math.sqrt(d))
+                    else:
+                        scores.append(-1e30)
+                probs = softmax(scores)
+                for c in range(lo, hi):
+                    context[i][c] = sum(probs[j] * v[j][c] for j in range(seq))
+        attn = []
+        for i in range(seq):
+            o = linear(context[i], w[p + "attention.output.dense.weight"], w[p + "attention.output.dense.bias"])
+            attn.append(layer_norm(
+                [a + b for a, b in zip(o, x[i])],
+                w[p + "attention.output.LayerNorm.weight"],
+                w[p + "attention.output.LayerNorm.bias"],
+                eps,
+            ))
+        out = []
+        for i in range(seq):
+            inter = [gelu(t) for t in linear(attn[i], w[p + "intermediate.dense.weight"], w[p + "intermediate.dense.bias"])]
+            o = linear(inter, w[p + "output.dense.weight"], w[p + "output.dense.bias"])
+            out.append(layer_norm(
+                [a + b for a, b in zip(o, attn[i])],
+                w[p + "output.LayerNorm.weight"],
+                w[p + "output.LayerNorm.bias"],
+                eps,
+            ))
+        x = out
+    pooled = [math.tanh(t) for t in linear(x[0], w["bert.pooler.dense.weight"], w["bert.pooler.dense.bias"])]
+    return x, pooled
+
+
+def flatten(values):
+    if isinstance(values, list) and values and isinstance(values[0], list):
+        return [v for row in values for v in flatten(row)]
+    return list(values)
+
+
+def shape_of(values):
+    shape = []
+    while isinstance(values, list):
+        shape.append(len(values))
+        values = values[0]
+    return shape
+
+
+def write_safetensors(path, tensors):
+    header = {}
+    data = b""
+    for name in sorted(tensors):
+        raw = struct.pack("<%df" % len(flatten(tensors[name])), *flatten(tensors[name]))
+        header[name] = {
+            "dtype": "F32",
+            "shape": shape_of(tensors[name]),
+            "data_offsets": [len(data), len(data) + len(raw)],
+        }
+        data += raw
+    header_bytes = json.dumps(header, separators=(",", ":")).encode()
+    header_bytes += b" " * (-len(header_bytes) % 8)
+    with open(path, "wb") as f:
+        f.write(struct.pack("<Q", len(header_bytes)))
+        f.write(header_bytes)
+        f.write(data)
+
+
+def transformers_forward():
+    import torch
+    import transformers
+    from transformers import BertModel
+
+    model = BertModel.from_pretrained(".", torch_dtype=torch.float64).eval()
+    with torch.no_grad():
+        out = model(
+            input_ids=torch.tensor(INPUT_IDS),
+            attention_mask=torch.tensor(ATTENTION_MASK),
+            token_type_ids=torch.tensor(TOKEN_TYPE_IDS),
+        )
+    source = "transformers %s" % transformers.__version__
+    return source, out.last_hidden_state.tolist(), out.pooler_output.tolist()
+
+
+def main():
+    weights = build_weights(CONFIG)
+    write_safetensors("model.safetensors", weights)
+    with open("config.json", "w") as f:
+        json.dump(CONFIG, f, indent=2)
+        f.write("\n")
+
+    if "--transformers" in sys.argv[1:]:
+        source, hidden, pooled = transformers_forward()
+    else:
+        source = "generate.py forward"
+        hidden, pooled = [], []
+        for ids, mask, types in zip(INPUT_IDS, ATTENTION_MASK, TOKEN_TYPE_IDS):
+            h, p = forward(CONFIG, weights, ids, mask, types)
+            hidden.append(h)
+            pooled.append(p)
+
+    reference = {
+        "reference": source,
+        "input_ids": INPUT_IDS,
+        "attention_mask": ATTENTION_MASK,
+        "token_type_ids": TOKEN_TYPE_IDS,
+        "last_hidden_state_shape": shape_of(hidden),
+        "last_hidden_state": [round(v, 6) for v in flatten(hidden)],
+        "pooled_output_shape": shape_of(pooled),
+        "pooled_output": [round(v, 6) for v in flatten(pooled)],
+    }
+    with open("reference.json", "w") as f:
+        lines = ['  "%s": %s' % (key, json.dumps(value)) for key, value in reference.items()]
+        f.write("{\n" + ",\n".join(lines) + "\n}\n")
+
+
+if __name__ == "__main__":
+    main()
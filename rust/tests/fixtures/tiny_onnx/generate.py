@@ -0,0 +1,79 @@
+"""Generate the tiny two-input ONNX fixture.
+
+The graph takes `a` (float[batch, 3]) and `b` (int64[batch, 3]), casts `b`
+to float and returns `sum = a + b` and `product = a * b`. The ModelProto is
+encoded by hand so the fixture can be rebuilt without the onnx package.
+
+Run from this directory: python3 generate.py
+"""
+
+FLOAT = 1
+INT64 = 7
+
+
+def varint(value):
+    out = bytearray()
+    while True:
+        byte = value & 0x7F
+        value >>= 7
+        if value:
+            out.append(byte | 0x80)
+        else:
+            out.append(byte)
+            return bytes(out)
+
+
+def field_varint(number, value):
+    return varint(number << 3) + varint(value)
+
+
+def field_bytes(number, payload):
+    if isinstance(payload, str):
+        payload = payload.encode()
+    return varint(number << 3 | 2) + varint(len(payload)) + payload
+
+
+def value_info(name, elem_type, dims):
+    shape = b"".join(
+        field_bytes(1, field_bytes(2, dim) if isinstance(dim, str) else field_varint(1, dim))
+        for dim in dims
+    )
+    tensor_type = field_varint(1, elem_type) + field_bytes(2, shape)
+    return field_bytes(1, name) + field_bytes(2, field_bytes(1, tensor_type))
+
+
+def node(op_type, inputs, outputs, attributes=b""):
+    return (
+        b"".join(field_bytes(1, i) for i in inputs)
+        + b"".join(field_bytes(2, o) for o in outputs)
+        + field_bytes(3, outputs[0] + "_node")
+        + field_bytes(4, op_type)
+        + attributes
+    )
+
+
+def main():
+    # AttributeProto: name (1), i (3), type (20) = INT (2)
+    cast_to = field_bytes(5, field_bytes(1, "to") + field_varint(3, FLOAT) + field_varint(20, 2))
+    graph = (
+        field_bytes(1, node("Cast", ["b"], ["b_float"], cast_to))
+        + field_bytes(1, node("Add", ["a", "b_float"], ["sum"]))
+        + field_bytes(1, node("Mul", ["a", "b_float"], ["product"]))
+        + field_bytes(2, "tiny_onnx")
+        + field_bytes(11, value_info("a", FLOAT, ["batch", 3]))
+        + field_bytes(11, value_info("b", INT64, ["batch", 3]))
+        + field_bytes(12, value_info("sum", FLOAT, ["batch", 3]))
+        + field_bytes(12, value_info("product", FLOAT, ["batch", 3]))
+    )
+    model = (
+        field_varint(1, 7)
+        + field_bytes(2, "inference-tests")
+        + field_bytes(7, graph)
+        + field_bytes(8, field_bytes(1, "") + field_varint(2, 13))
+    )
+    with open("model.onnx", "wb") as f:
+        f.write(model)
+
+
+if __name__ == "__main__":
+    main()